@@ -1,25 +1,37 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use clap::{Args, Parser, Subcommand};
 
+use goose::agents::BudgetLimits;
 use goose::config::{Config, ExtensionConfig};
 
 use crate::commands::acp::run_acp_agent;
+use crate::commands::audit::handle_audit_export;
 use crate::commands::bench::agent_generator;
 use crate::commands::configure::handle_configure;
 use crate::commands::info::handle_info;
-use crate::commands::metrics::handle_metrics;
+use crate::commands::metrics::{
+    handle_metrics, handle_metrics_trend, MetricsGrouping, RollupPeriod,
+};
+use crate::commands::policy::handle_policy_test;
 use crate::commands::project::{handle_project_default, handle_projects_interactive};
-use crate::commands::recipe::{handle_deeplink, handle_list, handle_validate};
+use crate::commands::recipe::{
+    handle_deeplink, handle_list, handle_lock, handle_test, handle_validate,
+};
 // Import the new handlers from commands::schedule
 use crate::commands::schedule::{
-    handle_schedule_add, handle_schedule_cron_help, handle_schedule_list, handle_schedule_remove,
-    handle_schedule_run_now, handle_schedule_services_status, handle_schedule_services_stop,
-    handle_schedule_sessions,
+    handle_schedule_add, handle_schedule_cron_help, handle_schedule_history, handle_schedule_list,
+    handle_schedule_remove, handle_schedule_run_now, handle_schedule_services_status,
+    handle_schedule_services_stop, handle_schedule_sessions, schedule_hook, schedule_trigger,
+    ScheduleAddOptions,
 };
-use crate::commands::session::{handle_session_list, handle_session_remove};
+use crate::commands::session::{handle_session_list, handle_session_remove, handle_session_search};
+use crate::recipes::cassette::RecipeTestOptions;
 use crate::recipes::extract_from_cli::extract_recipe_info_from_cli;
 use crate::recipes::recipe::{explain_recipe, render_recipe_as_yaml};
 use crate::session::{build_session, SessionBuilderConfig, SessionSettings};
+use goose::scheduler_history::RetryPolicy;
+use goose::scheduler_policy::{BlackoutWindow, OverlapPolicy};
 use goose::session::SessionManager;
 use goose_bench::bench_config::BenchRunConfig;
 use goose_bench::runners::bench_runner::BenchRunner;
@@ -95,6 +107,24 @@ fn parse_key_val(s: &str) -> Result<(String, String), String> {
     }
 }
 
+fn parse_blackout_window(s: &str) -> Result<BlackoutWindow, String> {
+    let (start, end) = s
+        .split_once('/')
+        .ok_or_else(|| format!("invalid START/END: {}", s))?;
+    let parse = |timestamp: &str| {
+        DateTime::parse_from_rfc3339(timestamp)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| format!("invalid timestamp '{}': {}", timestamp, e))
+    };
+    let window = BlackoutWindow {
+        start: parse(start)?,
+        end: parse(end)?,
+        reason: None,
+    };
+    window.validate()?;
+    Ok(window)
+}
+
 #[derive(Subcommand)]
 enum SessionCommand {
     #[command(about = "List all available sessions")]
@@ -123,6 +153,55 @@ enum SessionCommand {
 
         #[arg(short = 'l', long = "limit", help = "Limit the number of results")]
         limit: Option<usize>,
+
+        #[arg(
+            long = "cost",
+            help = "Show estimated cost per session, broken down by model"
+        )]
+        cost: bool,
+    },
+    #[command(about = "Search message content across all sessions")]
+    Search {
+        #[arg(help = "Text to search for in messages, tool arguments and tool results")]
+        query: String,
+
+        #[arg(
+            short = 'p',
+            long = "working_dir",
+            help = "Only search sessions whose working directory contains this path"
+        )]
+        working_dir: Option<PathBuf>,
+
+        #[arg(
+            long,
+            value_name = "DATE",
+            help = "Only match messages since this date (YYYY-MM-DD, RFC 3339, or relative like 7d)"
+        )]
+        since: Option<String>,
+
+        #[arg(
+            long,
+            value_name = "DATE",
+            help = "Only match messages until this date (YYYY-MM-DD, RFC 3339, or relative like 7d)"
+        )]
+        until: Option<String>,
+
+        #[arg(
+            long,
+            help = "Only search sessions started from the recipe with this title"
+        )]
+        recipe: Option<String>,
+
+        #[arg(short = 'l', long = "limit", help = "Limit the number of results")]
+        limit: Option<usize>,
+
+        #[arg(
+            short,
+            long,
+            help = "Output format (text, json)",
+            default_value = "text"
+        )]
+        format: String,
     },
     #[command(about = "Remove sessions. Runs interactively if no ID or regex is provided.")]
     Remove {
@@ -167,15 +246,134 @@ enum SchedulerCommand {
         id: String,
         #[arg(
             long,
+            required_unless_present_any = ["on_file_change", "on_git_ref", "on_webhook"],
             help = "Cron expression for the schedule",
             long_help = "Cron expression for when to run the job. Examples:\n  '0 * * * *'     - Every hour at minute 0\n  '0 */2 * * *'   - Every 2 hours\n  '@hourly'       - Every hour (shorthand)\n  '0 9 * * *'     - Every day at 9:00 AM\n  '0 9 * * 1'     - Every Monday at 9:00 AM\n  '0 0 1 * *'     - First day of every month at midnight"
         )]
-        cron: String,
+        cron: Option<String>,
+        #[arg(
+            long,
+            value_name = "PATH",
+            conflicts_with_all = ["cron", "on_git_ref", "on_webhook"],
+            help = "Run the job when files under this path change instead of on a cron schedule",
+            long_help = "Run the job when files under this path change instead of on a cron schedule. The changed files are passed to the recipe as the 'changed_paths' parameter, one per line."
+        )]
+        on_file_change: Option<String>,
+        #[arg(
+            long,
+            value_name = "REF",
+            conflicts_with_all = ["cron", "on_webhook"],
+            help = "Run the job when this git branch or tag moves to a new commit",
+            long_help = "Run the job when this git branch or tag moves to a new commit. The recipe receives the 'ref', 'previous_commit' and 'commit' parameters."
+        )]
+        on_git_ref: Option<String>,
+        #[arg(
+            long,
+            value_name = "PATH",
+            default_value = ".",
+            requires = "on_git_ref",
+            help = "Repository whose ref --on-git-ref watches"
+        )]
+        git_repo: String,
         #[arg(
             long,
-            help = "Recipe source (path to file, or base64 encoded recipe string)"
+            conflicts_with = "cron",
+            help = "Run the job when POST /schedule/<ID>/webhook is called on goosed",
+            long_help = "Run the job when POST /schedule/<ID>/webhook is called on goosed. The fields of the JSON payload are passed to the recipe as parameters."
+        )]
+        on_webhook: bool,
+        #[arg(
+            long,
+            value_name = "SECONDS",
+            help = "Wait until a watched path or ref has been quiet for this many seconds before running (default: 2)"
+        )]
+        debounce: Option<u64>,
+        #[arg(
+            long,
+            help = "Recipe source (path to file, registry package such as code-review@^1.2, or base64 encoded recipe string)"
         )]
         recipe_source: String,
+        #[arg(
+            long,
+            help = "Warn once the job's runs in the last 24 hours have used this many tokens"
+        )]
+        soft_token_limit: Option<i64>,
+        #[arg(
+            long,
+            help = "Stop runs once the job has used this many tokens in the last 24 hours"
+        )]
+        hard_token_limit: Option<i64>,
+        #[arg(
+            long,
+            help = "Warn once the job's runs in the last 24 hours have cost this many USD"
+        )]
+        soft_cost_limit: Option<f64>,
+        #[arg(
+            long,
+            help = "Stop runs once the job has cost this many USD in the last 24 hours"
+        )]
+        hard_cost_limit: Option<f64>,
+        #[arg(long, help = "Model to switch to when a soft limit is reached")]
+        downgrade_model: Option<String>,
+        #[arg(long, help = "Retry a failed run up to this many times")]
+        max_retries: Option<u32>,
+        #[arg(
+            long,
+            requires = "max_retries",
+            help = "Seconds to wait before the first retry, doubling after each further retry"
+        )]
+        retry_backoff: Option<u64>,
+        #[arg(long, help = "Shell command to run when a run succeeds")]
+        on_success_command: Option<String>,
+        #[arg(
+            long,
+            conflicts_with = "on_success_command",
+            help = "Local URL to POST the run record to when a run succeeds"
+        )]
+        on_success_webhook: Option<String>,
+        #[arg(long, help = "Shell command to run when a run fails after all retries")]
+        on_failure_command: Option<String>,
+        #[arg(
+            long,
+            conflicts_with = "on_failure_command",
+            help = "Local URL to POST the run record to when a run fails after all retries"
+        )]
+        on_failure_webhook: Option<String>,
+        #[arg(
+            long,
+            value_name = "KEY=VALUE",
+            help = "Recipe parameter values for this schedule (e.g., --params team=platform)",
+            long_help = "Key-value parameters to pass to the recipe on every run. Can be specified multiple times.",
+            action = clap::ArgAction::Append,
+            value_parser = parse_key_val,
+        )]
+        params: Vec<(String, String)>,
+        #[arg(
+            long,
+            value_name = "POLICY",
+            help = "What to do when the previous run is still going: skip (default), queue or kill_previous"
+        )]
+        overlap: Option<OverlapPolicy>,
+        #[arg(
+            long,
+            value_name = "TIMEZONE",
+            help = "IANA timezone to evaluate the cron expression in (e.g., Europe/Berlin); defaults to UTC"
+        )]
+        timezone: Option<String>,
+        #[arg(
+            long,
+            value_name = "START/END",
+            help = "RFC 3339 period in which no runs start, e.g. a deploy freeze. Can be specified multiple times.",
+            action = clap::ArgAction::Append,
+            value_parser = parse_blackout_window,
+        )]
+        blackout: Vec<BlackoutWindow>,
+        #[arg(
+            long,
+            value_name = "SECONDS",
+            help = "Delay each run by a random amount of up to this many seconds"
+        )]
+        jitter: Option<u64>,
     },
     #[command(about = "List all scheduled jobs")]
     List {},
@@ -193,6 +391,14 @@ enum SchedulerCommand {
         #[arg(long, help = "Maximum number of sessions to return")]
         limit: Option<usize>,
     },
+    /// Show the recorded runs of a specific schedule
+    #[command(about = "Show the recorded runs of a specific schedule")]
+    History {
+        #[arg(long, help = "ID of the schedule")]
+        id: String,
+        #[arg(long, help = "Maximum number of runs to return")]
+        limit: Option<usize>,
+    },
     #[command(about = "Run a scheduled job immediately")]
     RunNow {
         /// ID of the schedule to run
@@ -264,6 +470,64 @@ pub enum BenchCommand {
     },
 }
 
+#[derive(Subcommand)]
+enum AuditCommand {
+    /// Export the permission audit log
+    #[command(about = "Export tool permission decisions as JSON lines or CSV")]
+    Export {
+        /// How far back to export
+        #[arg(
+            long,
+            default_value = "30d",
+            help = "Only export entries since this date or age (e.g. 30d, 2w, 2024-06-01)"
+        )]
+        since: String,
+
+        /// Output format
+        #[arg(long, default_value = "jsonl", help = "Output format: jsonl or csv")]
+        format: String,
+
+        /// Only export entries for this session
+        #[arg(
+            long,
+            value_name = "SESSION_ID",
+            help = "Only export entries for this session"
+        )]
+        session_id: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum PolicyCommand {
+    /// Dry-run the tool policy against a tool call
+    #[command(about = "Show what the tool policy decides for a tool call")]
+    Test {
+        /// Full tool name, e.g. developer__shell
+        #[arg(help = "Tool name, e.g. developer__shell")]
+        tool: String,
+
+        /// Tool arguments as a JSON object
+        #[arg(help = "Tool arguments as a JSON object, e.g. '{\"command\": \"cargo test\"}'")]
+        args: String,
+
+        /// Policy file to test instead of the one in the config dir
+        #[arg(
+            long,
+            value_name = "FILE",
+            help = "Policy file to test (defaults to policy.yaml in the config dir)"
+        )]
+        policy: Option<PathBuf>,
+
+        /// Working directory used for path rules
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "Working directory for outside_working_dir rules (defaults to the current dir)"
+        )]
+        working_dir: Option<PathBuf>,
+    },
+}
+
 #[derive(Subcommand)]
 enum RecipeCommand {
     /// Validate a recipe file
@@ -272,6 +536,65 @@ enum RecipeCommand {
         /// Recipe name to get recipe file to validate
         #[arg(help = "recipe name to get recipe file or full path to the recipe file to validate")]
         recipe_name: String,
+
+        /// Print the fully resolved recipe
+        #[arg(
+            long,
+            help = "Print the recipe with its extends chain resolved",
+            long_help = "Print the fully resolved recipe as YAML, after merging every recipe in its extends chain."
+        )]
+        resolved: bool,
+    },
+
+    /// Test a recipe against recorded provider and MCP cassettes
+    #[command(about = "Test a recipe against recorded cassettes")]
+    Test {
+        /// Recipe name to get recipe file to test
+        #[arg(help = "recipe name to get recipe file or full path to the recipe file to test")]
+        recipe_name: String,
+
+        /// Directory holding the cassettes and expectations
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "Directory holding the recorded cassettes and expectations.yaml"
+        )]
+        cassette: PathBuf,
+
+        /// Record new cassettes instead of replaying them
+        #[arg(
+            long,
+            help = "Record new cassettes with the configured provider and the real MCP servers"
+        )]
+        record: bool,
+
+        #[arg(
+            long,
+            value_name = "KEY=VALUE",
+            help = "Dynamic parameters (e.g., --params username=alice --params channel_name=goose-channel)",
+            long_help = "Key-value parameters to pass to the recipe file. Can be specified multiple times.",
+            action = clap::ArgAction::Append,
+            value_parser = parse_key_val,
+        )]
+        params: Vec<(String, String)>,
+
+        /// Working directory to run the recipe in
+        #[arg(
+            long,
+            value_name = "DIR",
+            help = "Working directory to run the recipe in (defaults to a new temporary directory)"
+        )]
+        working_dir: Option<PathBuf>,
+    },
+
+    /// Record or replay a stdio MCP server for recipe tests
+    #[command(hide = true)]
+    McpCassette {
+        file: String,
+        #[arg(long)]
+        record: bool,
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
     },
 
     /// Generate a deeplink for a recipe file
@@ -304,6 +627,23 @@ enum RecipeCommand {
         )]
         verbose: bool,
     },
+
+    /// Pin recipe packages from the recipe registry in goose-recipes.lock
+    #[command(about = "Pin recipe packages in goose-recipes.lock")]
+    Lock {
+        /// Recipe packages to lock
+        #[arg(
+            help = "Recipe packages to lock, e.g. code-review@^1.2 (defaults to every locked package)"
+        )]
+        references: Vec<String>,
+
+        /// Resolve the newest matching versions instead of keeping the locked ones
+        #[arg(
+            long,
+            help = "Resolve the newest versions matching the requirements instead of keeping the locked versions"
+        )]
+        update: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -338,6 +678,38 @@ enum Command {
         /// Export metrics to JSON format
         #[arg(long, help = "Export metrics to JSON format")]
         export_json: bool,
+
+        /// Roll estimated cost up across all sessions instead of analyzing one session
+        #[arg(
+            long,
+            value_enum,
+            help = "Show estimated cost across all sessions by day (last 30 days) or week (last 12 weeks)"
+        )]
+        rollup: Option<RollupPeriod>,
+
+        /// Aggregate metrics across all sessions instead of analyzing one session
+        #[arg(
+            long,
+            value_enum,
+            help = "Aggregate metrics across all sessions by day, model, recipe or working_dir"
+        )]
+        group_by: Option<MetricsGrouping>,
+
+        /// How far back to aggregate when grouping
+        #[arg(
+            long,
+            default_value = "30d",
+            help = "Only aggregate sessions since this date or age (e.g. 30d, 2w, 2024-06-01)"
+        )]
+        since: String,
+
+        /// Output format for aggregated metrics
+        #[arg(
+            long,
+            default_value = "table",
+            help = "Output format for --group-by (table, json, csv)"
+        )]
+        format: String,
     },
 
     /// Manage system prompts and behaviors
@@ -668,6 +1040,20 @@ enum Command {
         model: Option<String>,
     },
 
+    /// Permission audit log utilities
+    #[command(about = "Export the tool permission audit log")]
+    Audit {
+        #[command(subcommand)]
+        command: AuditCommand,
+    },
+
+    /// Tool policy utilities
+    #[command(about = "Test argument-aware tool permission rules")]
+    Policy {
+        #[command(subcommand)]
+        command: PolicyCommand,
+    },
+
     /// Recipe utilities for validation and deeplinking
     #[command(about = "Recipe utilities for validation and deeplinking")]
     Recipe {
@@ -780,6 +1166,8 @@ pub async fn cli() -> Result<()> {
         Some(Command::Schedule { .. }) => "schedule",
         Some(Command::Update { .. }) => "update",
         Some(Command::Bench { .. }) => "bench",
+        Some(Command::Audit { .. }) => "audit",
+        Some(Command::Policy { .. }) => "policy",
         Some(Command::Recipe { .. }) => "recipe",
         Some(Command::Web { .. }) => "web",
         None => "default_session",
@@ -797,7 +1185,7 @@ pub async fn cli() -> Result<()> {
                 .unwrap_or_else(|_| "Not Set (will use default)".to_string());
             let subagent_prompt_version = std::env::var("GOOSE_SUBAGENT_SYSTEM_PROMPT")
                 .unwrap_or_else(|_| "Not Set (will use default)".to_string());
-            
+
             println!("GOOSE_SYSTEM_PROMPT: {}", prompt_version);
             println!("GOOSE_SUBAGENT_SYSTEM_PROMPT: {}", subagent_prompt_version);
             return Ok(());
@@ -814,8 +1202,17 @@ pub async fn cli() -> Result<()> {
             session,
             detailed,
             export_json,
+            rollup,
+            group_by,
+            since,
+            format,
         }) => {
-            handle_metrics(session, detailed, export_json).await?;
+            if let Some(group_by) = group_by {
+                let format = if export_json { "json" } else { format.as_str() };
+                handle_metrics_trend(group_by, &since, format).await?;
+                return Ok(());
+            }
+            handle_metrics(session, detailed, export_json, rollup).await?;
             return Ok(());
         }
         Some(Command::Mcp { name }) => {
@@ -845,8 +1242,22 @@ pub async fn cli() -> Result<()> {
                     ascending,
                     working_dir,
                     limit,
+                    cost,
                 }) => {
-                    handle_session_list(format, ascending, working_dir, limit).await?;
+                    handle_session_list(format, ascending, working_dir, limit, cost).await?;
+                    Ok(())
+                }
+                Some(SessionCommand::Search {
+                    query,
+                    working_dir,
+                    since,
+                    until,
+                    recipe,
+                    limit,
+                    format,
+                }) => {
+                    handle_session_search(query, working_dir, since, until, recipe, limit, format)
+                        .await?;
                     Ok(())
                 }
                 Some(SessionCommand::Remove { id, regex }) => {
@@ -1190,9 +1601,62 @@ pub async fn cli() -> Result<()> {
                 SchedulerCommand::Add {
                     id,
                     cron,
+                    on_file_change,
+                    on_git_ref,
+                    git_repo,
+                    on_webhook,
+                    debounce,
                     recipe_source,
+                    soft_token_limit,
+                    hard_token_limit,
+                    soft_cost_limit,
+                    hard_cost_limit,
+                    downgrade_model,
+                    max_retries,
+                    retry_backoff,
+                    on_success_command,
+                    on_success_webhook,
+                    on_failure_command,
+                    on_failure_webhook,
+                    params,
+                    overlap,
+                    timezone,
+                    blackout,
+                    jitter,
                 } => {
-                    handle_schedule_add(id, cron, recipe_source).await?;
+                    let budget = BudgetLimits {
+                        soft_token_limit,
+                        hard_token_limit,
+                        soft_cost_limit,
+                        hard_cost_limit,
+                        downgrade_model,
+                        downgrade_provider: None,
+                    };
+                    let retry = max_retries.map(|max_retries| {
+                        let mut policy = RetryPolicy::new(max_retries);
+                        if let Some(backoff_seconds) = retry_backoff {
+                            policy.backoff_seconds = backoff_seconds;
+                        }
+                        policy
+                    });
+                    handle_schedule_add(
+                        id,
+                        schedule_trigger(cron, on_file_change, on_git_ref, git_repo, on_webhook),
+                        recipe_source,
+                        ScheduleAddOptions {
+                            budget,
+                            retry,
+                            on_success: schedule_hook(on_success_command, on_success_webhook),
+                            on_failure: schedule_hook(on_failure_command, on_failure_webhook),
+                            parameters: params,
+                            overlap_policy: overlap,
+                            timezone,
+                            blackout_windows: blackout,
+                            jitter_seconds: jitter,
+                            debounce_seconds: debounce,
+                        },
+                    )
+                    .await?;
                 }
                 SchedulerCommand::List {} => {
                     handle_schedule_list().await?;
@@ -1204,6 +1668,9 @@ pub async fn cli() -> Result<()> {
                     // New arm
                     handle_schedule_sessions(id, limit).await?;
                 }
+                SchedulerCommand::History { id, limit } => {
+                    handle_schedule_history(id, limit).await?;
+                }
                 SchedulerCommand::RunNow { id } => {
                     // New arm
                     handle_schedule_run_now(id).await?;
@@ -1250,15 +1717,73 @@ pub async fn cli() -> Result<()> {
         }
         Some(Command::Recipe { command }) => {
             match command {
-                RecipeCommand::Validate { recipe_name } => {
-                    handle_validate(&recipe_name)?;
+                RecipeCommand::Validate {
+                    recipe_name,
+                    resolved,
+                } => {
+                    handle_validate(&recipe_name, resolved)?;
+                }
+                RecipeCommand::Test {
+                    recipe_name,
+                    cassette,
+                    record,
+                    params,
+                    working_dir,
+                } => {
+                    handle_test(
+                        &recipe_name,
+                        params,
+                        RecipeTestOptions {
+                            cassette_dir: cassette,
+                            record,
+                            working_dir,
+                        },
+                    )
+                    .await?;
                 }
+                RecipeCommand::McpCassette {
+                    file,
+                    record,
+                    command,
+                } => match command.split_first() {
+                    Some((cmd, args)) if record => {
+                        goose_test::mcp::stdio::record::record(&file, cmd, args)?
+                    }
+                    None if record => {
+                        return Err(anyhow::anyhow!("A command is required to record"));
+                    }
+                    _ => goose_test::mcp::stdio::playback::playback(&file)?,
+                },
                 RecipeCommand::Deeplink { recipe_name } => {
                     handle_deeplink(&recipe_name)?;
                 }
                 RecipeCommand::List { format, verbose } => {
                     handle_list(&format, verbose)?;
                 }
+                RecipeCommand::Lock { references, update } => {
+                    handle_lock(&references, update)?;
+                }
+            }
+            return Ok(());
+        }
+        Some(Command::Audit { command }) => {
+            match command {
+                AuditCommand::Export {
+                    since,
+                    format,
+                    session_id,
+                } => handle_audit_export(&since, &format, session_id).await?,
+            }
+            return Ok(());
+        }
+        Some(Command::Policy { command }) => {
+            match command {
+                PolicyCommand::Test {
+                    tool,
+                    args,
+                    policy,
+                    working_dir,
+                } => handle_policy_test(&tool, &args, policy, working_dir)?,
             }
             return Ok(());
         }
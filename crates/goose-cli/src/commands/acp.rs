@@ -3,14 +3,22 @@ use agent_client_protocol::{
     ToolCallContent,
 };
 use anyhow::Result;
-use goose::agents::Agent;
-use goose::config::{Config, ExtensionConfigManager};
-use goose::conversation::message::{Message, MessageContent};
+use goose::agents::extension::Envs;
+use goose::agents::{Agent, SessionConfig};
+use goose::config::{Config, ExtensionConfig, ExtensionConfigManager};
+use goose::conversation::message::{Message, MessageContent, ToolConfirmationRequest};
 use goose::conversation::Conversation;
+use goose::permission::permission_confirmation::PrincipalType;
+use goose::permission::{Permission, PermissionConfirmation};
+use goose::providers::base::Provider;
 use goose::providers::create;
-use rmcp::model::{RawContent, ResourceContents};
+use goose::session::SessionManager;
+use rmcp::model::{ErrorCode, ErrorData, RawContent, ResourceContents, Role};
 use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot, Mutex};
 use tokio::task::JoinSet;
@@ -19,9 +27,12 @@ use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 use url::Url;
 
-/// Represents a single goose session for ACP
+/// Represents a single goose session for ACP, backed by a stored goose session of the same id
 struct GooseSession {
+    agent: Arc<Agent>, // Agent with the configured extensions and the client's MCP servers
     messages: Conversation,
+    working_dir: PathBuf,
+    goose_mode: String, // One of GOOSE_MODES, switched by the client through set_session_mode
     tool_call_ids: HashMap<String, String>, // Maps internal tool IDs to ACP tool call IDs
     cancel_token: Option<CancellationToken>, // Active cancellation token for prompt processing
 }
@@ -29,8 +40,186 @@ struct GooseSession {
 /// goose ACP Agent implementation that connects to real goose agents
 struct GooseAcpAgent {
     session_update_tx: mpsc::UnboundedSender<(acp::SessionNotification, oneshot::Sender<()>)>,
+    permission_request_tx: mpsc::UnboundedSender<(
+        acp::RequestPermissionRequest,
+        oneshot::Sender<Result<acp::RequestPermissionResponse, acp::Error>>,
+    )>,
     sessions: Arc<Mutex<HashMap<String, GooseSession>>>,
-    agent: Agent, // Shared agent instance
+    session_counter: AtomicUsize, // Numbers new sessions until the agent names them
+    provider: Arc<dyn Provider>,  // Shared by the agents of all sessions
+    extensions: Vec<ExtensionConfig>, // Enabled extensions from the goose config
+}
+
+/// goose modes offered to ACP clients as session modes: (id, name, description)
+const GOOSE_MODES: [(&str, &str, &str); 4] = [
+    ("auto", "Auto", "Run tools without asking for approval"),
+    (
+        "approve",
+        "Approve",
+        "Ask for approval before every tool call",
+    ),
+    (
+        "smart_approve",
+        "Smart Approve",
+        "Ask for approval only when a tool call may modify something",
+    ),
+    ("chat", "Chat", "Reply without calling any tools"),
+];
+
+/// The mode new and loaded sessions start in, following GOOSE_MODE like the CLI does
+fn default_goose_mode() -> String {
+    let mode: String = Config::global()
+        .get_param("GOOSE_MODE")
+        .unwrap_or_else(|_| "auto".to_string());
+    if GOOSE_MODES.iter().any(|(id, _, _)| *id == mode) {
+        mode
+    } else {
+        "auto".to_string()
+    }
+}
+
+fn session_mode_state(current_mode: &str) -> acp::SessionModeState {
+    acp::SessionModeState {
+        current_mode_id: acp::SessionModeId(current_mode.into()),
+        available_modes: GOOSE_MODES
+            .iter()
+            .map(|(id, name, description)| acp::SessionMode {
+                id: acp::SessionModeId((*id).into()),
+                name: name.to_string(),
+                description: Some(description.to_string()),
+                meta: None,
+            })
+            .collect(),
+        meta: None,
+    }
+}
+
+/// Convert an MCP server passed by the ACP client into a goose extension
+fn mcp_server_to_extension(server: acp::McpServer) -> ExtensionConfig {
+    let headers = |headers: Vec<acp::HttpHeader>| -> HashMap<String, String> {
+        headers.into_iter().map(|h| (h.name, h.value)).collect()
+    };
+
+    match server {
+        acp::McpServer::Http {
+            name,
+            url,
+            headers: h,
+        } => ExtensionConfig::StreamableHttp {
+            name,
+            uri: url,
+            envs: Envs::default(),
+            env_keys: Vec::new(),
+            headers: headers(h),
+            description: None,
+            timeout: Some(goose::config::DEFAULT_EXTENSION_TIMEOUT),
+            bundled: None,
+            available_tools: Vec::new(),
+        },
+        acp::McpServer::Sse {
+            name,
+            url,
+            headers: h,
+        } => {
+            if !h.is_empty() {
+                warn!("Ignoring headers for SSE MCP server '{}'", name);
+            }
+            ExtensionConfig::Sse {
+                name,
+                uri: url,
+                envs: Envs::default(),
+                env_keys: Vec::new(),
+                description: None,
+                timeout: Some(goose::config::DEFAULT_EXTENSION_TIMEOUT),
+                bundled: None,
+                available_tools: Vec::new(),
+            }
+        }
+        acp::McpServer::Stdio {
+            name,
+            command,
+            args,
+            env,
+        } => ExtensionConfig::Stdio {
+            name,
+            cmd: command.to_string_lossy().to_string(),
+            args,
+            envs: Envs::new(env.into_iter().map(|e| (e.name, e.value)).collect()),
+            env_keys: Vec::new(),
+            timeout: Some(goose::config::DEFAULT_EXTENSION_TIMEOUT),
+            description: None,
+            bundled: None,
+            available_tools: Vec::new(),
+        },
+    }
+}
+
+/// Extensions for the agent of one session: the configured ones plus the client's MCP servers.
+/// A client server whose name is already taken is skipped rather than replacing the extension.
+fn session_extensions(
+    configured: &[ExtensionConfig],
+    mcp_servers: Vec<acp::McpServer>,
+) -> Vec<ExtensionConfig> {
+    let mut extensions = configured.to_vec();
+    let mut names: HashSet<String> = extensions.iter().map(|e| e.name()).collect();
+    for server in mcp_servers {
+        let extension = mcp_server_to_extension(server);
+        if names.insert(extension.name()) {
+            extensions.push(extension);
+        } else {
+            warn!(
+                "Skipping MCP server '{}' from client: an extension with that name is already loaded",
+                extension.name()
+            );
+        }
+    }
+    extensions
+}
+
+const ALLOW_ONCE_OPTION: &str = "allow_once";
+const ALLOW_ALWAYS_OPTION: &str = "allow_always";
+const REJECT_ONCE_OPTION: &str = "reject_once";
+
+/// Options offered when asking the client to approve a tool call. Like the CLI, "Always Allow"
+/// is withheld when the call was flagged by the security inspector.
+fn permission_options(flagged: bool) -> Vec<acp::PermissionOption> {
+    let option = |id: &str, name: &str, kind| acp::PermissionOption {
+        id: acp::PermissionOptionId(id.into()),
+        name: name.to_string(),
+        kind,
+        meta: None,
+    };
+
+    let mut options = vec![option(
+        ALLOW_ONCE_OPTION,
+        "Allow",
+        acp::PermissionOptionKind::AllowOnce,
+    )];
+    if !flagged {
+        options.push(option(
+            ALLOW_ALWAYS_OPTION,
+            "Always Allow",
+            acp::PermissionOptionKind::AllowAlways,
+        ));
+    }
+    options.push(option(
+        REJECT_ONCE_OPTION,
+        "Deny",
+        acp::PermissionOptionKind::RejectOnce,
+    ));
+    options
+}
+
+/// Map the client's answer to a permission request onto goose's confirmation choices
+fn permission_from_outcome(outcome: &acp::RequestPermissionOutcome) -> Permission {
+    match outcome {
+        acp::RequestPermissionOutcome::Selected { option_id } => match &*option_id.0 {
+            ALLOW_ONCE_OPTION => Permission::AllowOnce,
+            ALLOW_ALWAYS_OPTION => Permission::AlwaysAllow,
+            _ => Permission::DenyOnce,
+        },
+        acp::RequestPermissionOutcome::Cancelled => Permission::Cancel,
+    }
 }
 
 fn read_resource_link(link: acp::ResourceLink) -> Option<String> {
@@ -96,6 +285,10 @@ fn format_tool_name(tool_name: &str) -> String {
 impl GooseAcpAgent {
     async fn new(
         session_update_tx: mpsc::UnboundedSender<(acp::SessionNotification, oneshot::Sender<()>)>,
+        permission_request_tx: mpsc::UnboundedSender<(
+            acp::RequestPermissionRequest,
+            oneshot::Sender<Result<acp::RequestPermissionResponse, acp::Error>>,
+        )>,
     ) -> Result<Self> {
         // Load config and create provider
         let config = Config::global();
@@ -119,10 +312,6 @@ impl GooseAcpAgent {
         };
         let provider = create(&provider_name, model_config)?;
 
-        // Create a shared agent instance
-        let agent = Agent::new();
-        agent.update_provider(provider.clone()).await?;
-
         // Load and add extensions just like the normal CLI
         let extensions_to_run: Vec<_> = ExtensionConfigManager::get_all()
             .map_err(|e| anyhow::anyhow!("Failed to load extensions: {}", e))?
@@ -131,48 +320,66 @@ impl GooseAcpAgent {
             .map(|ext| ext.config)
             .collect();
 
+        Ok(Self::with_provider(
+            session_update_tx,
+            permission_request_tx,
+            provider,
+            extensions_to_run,
+        ))
+    }
+
+    fn with_provider(
+        session_update_tx: mpsc::UnboundedSender<(acp::SessionNotification, oneshot::Sender<()>)>,
+        permission_request_tx: mpsc::UnboundedSender<(
+            acp::RequestPermissionRequest,
+            oneshot::Sender<Result<acp::RequestPermissionResponse, acp::Error>>,
+        )>,
+        provider: Arc<dyn Provider>,
+        extensions: Vec<ExtensionConfig>,
+    ) -> Self {
+        Self {
+            session_update_tx,
+            permission_request_tx,
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+            session_counter: AtomicUsize::new(0),
+            provider,
+            extensions,
+        }
+    }
+
+    /// Create the agent for one session, so MCP servers a client passes for a session are only
+    /// visible to that session and go away with it. Extensions that fail to start are logged
+    /// and skipped so the session stays usable.
+    async fn create_session_agent(
+        &self,
+        mcp_servers: Vec<acp::McpServer>,
+    ) -> Result<Arc<Agent>, acp::Error> {
+        let agent = Arc::new(Agent::new());
+        agent
+            .update_provider(self.provider.clone())
+            .await
+            .map_err(|e| {
+                error!("Failed to set provider: {}", e);
+                acp::Error::internal_error()
+            })?;
+
         // Add extensions to the agent in parallel
-        let agent_ptr = Arc::new(agent);
         let mut set = JoinSet::new();
-        let mut waiting_on = HashSet::new();
-
-        for extension in extensions_to_run {
-            waiting_on.insert(extension.name());
-            let agent_ptr_clone = agent_ptr.clone();
-            set.spawn(async move {
-                (
-                    extension.name(),
-                    agent_ptr_clone.add_extension(extension.clone()).await,
-                )
-            });
+        for extension in session_extensions(&self.extensions, mcp_servers) {
+            let agent = agent.clone();
+            set.spawn(async move { (extension.name(), agent.add_extension(extension).await) });
         }
 
         // Wait for all extensions to load
         while let Some(result) = set.join_next().await {
             match result {
-                Ok((name, Ok(_))) => {
-                    waiting_on.remove(&name);
-                    info!("Loaded extension: {}", name);
-                }
-                Ok((name, Err(e))) => {
-                    warn!("Failed to load extension '{}': {}", name, e);
-                    waiting_on.remove(&name);
-                }
-                Err(e) => {
-                    error!("Task error while loading extension: {}", e);
-                }
+                Ok((name, Ok(_))) => info!("Loaded extension: {}", name),
+                Ok((name, Err(e))) => warn!("Failed to load extension '{}': {}", name, e),
+                Err(e) => error!("Task error while loading extension: {}", e),
             }
         }
 
-        // Unwrap the Arc to get the agent back
-        let agent = Arc::try_unwrap(agent_ptr)
-            .map_err(|_| anyhow::anyhow!("Failed to unwrap agent Arc"))?;
-
-        Ok(Self {
-            session_update_tx,
-            sessions: Arc::new(Mutex::new(HashMap::new())),
-            agent,
-        })
+        Ok(agent)
     }
 
     fn convert_acp_prompt_to_message(&self, prompt: Vec<acp::ContentBlock>) -> Message {
@@ -445,6 +652,95 @@ impl GooseAcpAgent {
 
         Ok(())
     }
+
+    /// Ask the client whether a tool call may run, then hand the answer to the agent, which
+    /// runs or declines the call and records "Always Allow" in the PermissionManager
+    async fn request_tool_permission(
+        &self,
+        confirmation: &ToolConfirmationRequest,
+        session_id: &acp::SessionId,
+        acp_tool_id: String,
+        agent: &Agent,
+    ) -> Result<Permission, acp::Error> {
+        let request = acp::RequestPermissionRequest {
+            session_id: session_id.clone(),
+            tool_call: acp::ToolCallUpdate {
+                id: acp::ToolCallId(acp_tool_id.into()),
+                fields: acp::ToolCallUpdateFields {
+                    title: Some(format_tool_name(&confirmation.tool_name)),
+                    raw_input: Some(serde_json::Value::Object(confirmation.arguments.clone())),
+                    content: confirmation
+                        .prompt
+                        .clone()
+                        .map(|prompt| vec![prompt.into()]),
+                    ..Default::default()
+                },
+                meta: None,
+            },
+            options: permission_options(confirmation.prompt.is_some()),
+            meta: None,
+        };
+
+        let (tx, rx) = oneshot::channel();
+        self.permission_request_tx
+            .send((request, tx))
+            .map_err(|_| acp::Error::internal_error())?;
+        let response = rx.await.map_err(|_| acp::Error::internal_error())??;
+
+        let permission = permission_from_outcome(&response.outcome);
+        if permission != Permission::Cancel {
+            agent
+                .handle_confirmation(
+                    confirmation.id.clone(),
+                    PermissionConfirmation {
+                        principal_type: PrincipalType::Tool,
+                        permission: permission.clone(),
+                    },
+                )
+                .await;
+        }
+
+        Ok(permission)
+    }
+
+    /// Replay a stored message to the client as the session updates it originally produced
+    async fn replay_message(
+        &self,
+        message: &Message,
+        session_id: &acp::SessionId,
+        session: &mut GooseSession,
+    ) -> Result<(), acp::Error> {
+        if !message.is_user_visible() {
+            return Ok(());
+        }
+
+        for content_item in &message.content {
+            match (&message.role, content_item) {
+                (Role::User, MessageContent::Text(text)) => {
+                    let (tx, rx) = oneshot::channel();
+                    self.session_update_tx
+                        .send((
+                            SessionNotification {
+                                session_id: session_id.clone(),
+                                update: acp::SessionUpdate::UserMessageChunk {
+                                    content: text.text.clone().into(),
+                                },
+                                meta: None,
+                            },
+                            tx,
+                        ))
+                        .map_err(|_| acp::Error::internal_error())?;
+                    rx.await.map_err(|_| acp::Error::internal_error())?;
+                }
+                _ => {
+                    self.handle_message_content(content_item, session_id, session)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait(?Send)]
@@ -457,7 +753,7 @@ impl acp::Agent for GooseAcpAgent {
 
         // Advertise Goose's capabilities
         let agent_capabilities = acp::AgentCapabilities {
-            load_session: false, // TODO: Implement session persistence
+            load_session: true, // Sessions are persisted through SessionManager
             prompt_capabilities: acp::PromptCapabilities {
                 image: true,            // Goose supports image inputs via providers
                 audio: false,           // TODO: Add audio support when providers support it
@@ -465,8 +761,8 @@ impl acp::Agent for GooseAcpAgent {
                 meta: None,
             },
             mcp_capabilities: acp::McpCapabilities {
-                http: false, // TODO: Add MCP HTTP support if needed
-                sse: false,  // TODO: Add MCP SSE support if needed
+                http: true, // Added as streamable HTTP extensions
+                sse: true,  // Added as SSE extensions
                 meta: None,
             },
             meta: None,
@@ -494,11 +790,27 @@ impl acp::Agent for GooseAcpAgent {
     ) -> Result<acp::NewSessionResponse, acp::Error> {
         info!("ACP: Received new session request {:?}", args);
 
-        // Generate a unique session ID
-        let session_id = uuid::Uuid::new_v4().to_string();
+        // Persist the session so it can be loaded again after a restart. Like sessions started
+        // from the desktop app, it is renamed from its first messages once the agent replies.
+        let counter = self.session_counter.fetch_add(1, Ordering::SeqCst) + 1;
+        let description = format!("New session {}", counter);
+        let stored = SessionManager::create_session(args.cwd.clone(), description)
+            .await
+            .map_err(|e| {
+                error!("Failed to create session: {}", e);
+                acp::Error::internal_error()
+            })?;
+        let session_id = stored.id;
+
+        let agent = self.create_session_agent(args.mcp_servers).await?;
 
+        let goose_mode = default_goose_mode();
+        let modes = session_mode_state(&goose_mode);
         let session = GooseSession {
+            agent,
             messages: Conversation::new_unvalidated(Vec::new()),
+            working_dir: stored.working_dir,
+            goose_mode,
             tool_call_ids: HashMap::new(),
             cancel_token: None,
         };
@@ -511,7 +823,7 @@ impl acp::Agent for GooseAcpAgent {
 
         Ok(acp::NewSessionResponse {
             session_id: acp::SessionId(session_id.into()),
-            modes: None, // TODO: Implement session modes if needed
+            modes: Some(modes),
             meta: None,
         })
     }
@@ -521,19 +833,48 @@ impl acp::Agent for GooseAcpAgent {
         args: acp::LoadSessionRequest,
     ) -> Result<acp::LoadSessionResponse, acp::Error> {
         info!("ACP: Received load session request {:?}", args);
-        // For now, will start a new session. We could use goose session storage as an enhancement
-        // we would need to map ACP session IDs to goose session ids (which by default are auto generated)
-        // normal goose session restore in CLI doesn't load conversation visually.
-        //
-        // Example flow:
-        // - Load session file by session_id (might need to map ACP session IDs to Goose session paths)
-        // - For each message in history:
-        //   - If user message: send user_message_chunk notification
-        //   - If assistant message: send agent_message_chunk notification
-        //   - If tool calls/responses: send appropriate notifications
-
-        // For now, we don't support loading previous sessions
-        Err(acp::Error::method_not_found())
+
+        let session_id = args.session_id.0.to_string();
+        let stored = SessionManager::get_session(&session_id, true)
+            .await
+            .map_err(|e| {
+                warn!("Failed to load session {}: {}", session_id, e);
+                acp::Error::invalid_params()
+            })?;
+
+        // A fresh agent replaces the one of an already open session, so loading a session again
+        // does not add the client's MCP servers twice
+        let agent = self.create_session_agent(args.mcp_servers).await?;
+
+        let messages = stored
+            .conversation
+            .unwrap_or_else(|| Conversation::new_unvalidated(Vec::new()));
+        let goose_mode = default_goose_mode();
+        let modes = session_mode_state(&goose_mode);
+        let mut session = GooseSession {
+            agent,
+            messages: messages.clone(),
+            working_dir: stored.working_dir,
+            goose_mode,
+            tool_call_ids: HashMap::new(),
+            cancel_token: None,
+        };
+
+        // Stream the history back before the session becomes usable, as the protocol requires
+        for message in messages.messages() {
+            self.replay_message(message, &args.session_id, &mut session)
+                .await?;
+        }
+
+        let mut sessions = self.sessions.lock().await;
+        sessions.insert(session_id.clone(), session);
+
+        info!("Loaded session with ID: {}", session_id);
+
+        Ok(acp::LoadSessionResponse {
+            modes: Some(modes),
+            meta: None,
+        })
     }
 
     async fn prompt(&self, args: acp::PromptRequest) -> Result<acp::PromptResponse, acp::Error> {
@@ -549,7 +890,7 @@ impl acp::Agent for GooseAcpAgent {
         let user_message = self.convert_acp_prompt_to_message(args.prompt);
 
         // Prepare for agent reply
-        let messages = {
+        let (agent, messages, working_dir, goose_mode) = {
             let mut sessions = self.sessions.lock().await;
             let session = sessions
                 .get_mut(&session_id)
@@ -562,13 +903,31 @@ impl acp::Agent for GooseAcpAgent {
             session.cancel_token = Some(cancel_token.clone());
 
             // Clone what we need for the reply call
-            session.messages.clone()
+            (
+                session.agent.clone(),
+                session.messages.clone(),
+                session.working_dir.clone(),
+                session.goose_mode.clone(),
+            )
+        };
+
+        // The agent stores the conversation in the session as it replies
+        let session_config = SessionConfig {
+            id: session_id.clone(),
+            working_dir,
+            schedule_id: None,
+            execution_mode: None,
+            max_turns: None,
+            goose_mode: Some(goose_mode),
+            retry_config: None,
+            budget: None,
+            schedule_budget: None,
+            context_strategy: None,
         };
 
         // Get agent's reply through the Goose agent
-        let mut stream = self
-            .agent
-            .reply(messages, None, Some(cancel_token.clone()))
+        let mut stream = agent
+            .reply(messages, Some(session_config), Some(cancel_token.clone()))
             .await
             .map_err(|e| {
                 error!("Error getting agent reply: {}", e);
@@ -590,6 +949,49 @@ impl acp::Agent for GooseAcpAgent {
 
             match event {
                 Ok(goose::agents::AgentEvent::Message(message)) => {
+                    // Confirmation requests go to the client and are not kept in the conversation
+                    if let Some(MessageContent::ToolConfirmationRequest(confirmation)) =
+                        message.content.first()
+                    {
+                        let acp_tool_id = {
+                            let sessions = self.sessions.lock().await;
+                            sessions
+                                .get(&session_id)
+                                .and_then(|s| s.tool_call_ids.get(&confirmation.id).cloned())
+                                .unwrap_or_else(|| format!("tool_{}", uuid::Uuid::new_v4()))
+                        };
+
+                        // The lock is not held while waiting so the client can still cancel
+                        let permission = self
+                            .request_tool_permission(
+                                confirmation,
+                                &args.session_id,
+                                acp_tool_id,
+                                &agent,
+                            )
+                            .await?;
+
+                        if permission == Permission::Cancel {
+                            let mut sessions = self.sessions.lock().await;
+                            if let Some(session) = sessions.get_mut(&session_id) {
+                                session.messages.push(Message::user().with_tool_response(
+                                    confirmation.id.clone(),
+                                    Err(ErrorData {
+                                        code: ErrorCode::INVALID_REQUEST,
+                                        message: std::borrow::Cow::from(
+                                            "Tool call cancelled by user".to_string(),
+                                        ),
+                                        data: None,
+                                    }),
+                                ));
+                            }
+                            cancel_token.cancel();
+                            was_cancelled = true;
+                            break;
+                        }
+                        continue;
+                    }
+
                     // Re-acquire the lock to add message to conversation
                     let mut sessions = self.sessions.lock().await;
                     let session = sessions
@@ -652,10 +1054,24 @@ impl acp::Agent for GooseAcpAgent {
 
     async fn set_session_mode(
         &self,
-        _args: acp::SetSessionModeRequest,
+        args: acp::SetSessionModeRequest,
     ) -> Result<acp::SetSessionModeResponse, acp::Error> {
-        // TODO: Implement session modes if needed
-        Err(acp::Error::method_not_found())
+        info!("ACP: Received set session mode request {:?}", args);
+
+        let mode = args.mode_id.0.to_string();
+        if !GOOSE_MODES.iter().any(|(id, _, _)| *id == mode) {
+            return Err(acp::Error::invalid_params());
+        }
+
+        // Takes effect from the next prompt, as the agent reads the mode when a reply starts
+        let session_id = args.session_id.0.to_string();
+        let mut sessions = self.sessions.lock().await;
+        let session = sessions
+            .get_mut(&session_id)
+            .ok_or_else(acp::Error::invalid_params)?;
+        session.goose_mode = mode;
+
+        Ok(acp::SetSessionModeResponse { meta: None })
     }
 
     async fn ext_method(
@@ -687,9 +1103,10 @@ pub async fn run_acp_agent() -> Result<()> {
     local_set
         .run_until(async move {
             let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let (permission_tx, mut permission_rx) = tokio::sync::mpsc::unbounded_channel();
 
             // Start up the GooseAcpAgent connected to stdio.
-            let agent = GooseAcpAgent::new(tx)
+            let agent = GooseAcpAgent::new(tx, permission_tx)
                 .await
                 .map_err(|e| anyhow::anyhow!("Failed to create ACP agent: {}", e))?;
             let (conn, handle_io) =
@@ -697,10 +1114,15 @@ pub async fn run_acp_agent() -> Result<()> {
                     tokio::task::spawn_local(fut);
                 });
 
+            let conn = Rc::new(conn);
+
             // Kick off a background task to send the agent's session notifications to the client.
+            let notification_conn = conn.clone();
             tokio::task::spawn_local(async move {
                 while let Some((session_notification, tx)) = rx.recv().await {
-                    let result = conn.session_notification(session_notification).await;
+                    let result = notification_conn
+                        .session_notification(session_notification)
+                        .await;
                     if let Err(e) = result {
                         error!("ACP session notification error: {}", e);
                         break;
@@ -709,6 +1131,21 @@ pub async fn run_acp_agent() -> Result<()> {
                 }
             });
 
+            // Forward tool permission requests to the client, each on its own task so a pending
+            // request does not hold up others.
+            tokio::task::spawn_local(async move {
+                while let Some((request, tx)) = permission_rx.recv().await {
+                    let conn = conn.clone();
+                    tokio::task::spawn_local(async move {
+                        let result = conn.request_permission(request).await;
+                        if let Err(e) = &result {
+                            error!("ACP permission request error: {}", e);
+                        }
+                        tx.send(result).ok();
+                    });
+                }
+            });
+
             // Run until stdin/stdout are closed.
             handle_io.await
         })
@@ -723,7 +1160,208 @@ mod tests {
     use std::io::Write;
     use tempfile::NamedTempFile;
 
-    use crate::commands::acp::{format_tool_name, read_resource_link};
+    use crate::commands::acp::{
+        format_tool_name, mcp_server_to_extension, permission_from_outcome, permission_options,
+        read_resource_link, session_extensions, session_mode_state, GooseAcpAgent,
+    };
+    use acp::Agent as _;
+    use agent_client_protocol as acp;
+    use async_trait::async_trait;
+    use goose::config::ExtensionConfig;
+    use goose::conversation::message::Message;
+    use goose::model::ModelConfig;
+    use goose::permission::Permission;
+    use goose::providers::base::{Provider, ProviderMetadata, ProviderUsage, Usage};
+    use goose::providers::errors::ProviderError;
+    use goose::session::SessionManager;
+    use rmcp::model::Tool;
+    use serde_json::json;
+    use std::sync::Arc;
+    use tokio::sync::{mpsc, Mutex};
+
+    struct MockProvider {
+        model_config: ModelConfig,
+    }
+
+    #[async_trait]
+    impl Provider for MockProvider {
+        fn metadata() -> ProviderMetadata {
+            ProviderMetadata::empty()
+        }
+
+        fn get_model_config(&self) -> ModelConfig {
+            self.model_config.clone()
+        }
+
+        async fn complete_with_model(
+            &self,
+            _model_config: &ModelConfig,
+            _system: &str,
+            _messages: &[Message],
+            _tools: &[Tool],
+        ) -> Result<(Message, ProviderUsage), ProviderError> {
+            Ok((
+                Message::assistant().with_text("Hi there"),
+                ProviderUsage::new("mock".to_string(), Usage::default()),
+            ))
+        }
+    }
+
+    /// An ACP agent backed by the mock provider, and the message chunks it sends to the
+    /// client as (role, text)
+    async fn mock_acp_agent() -> (GooseAcpAgent, Arc<Mutex<Vec<(String, String)>>>) {
+        let (update_tx, mut update_rx) = mpsc::unbounded_channel();
+        let (permission_tx, _permission_rx) = mpsc::unbounded_channel();
+        let provider = Arc::new(MockProvider {
+            model_config: ModelConfig::new("mock-model").unwrap(),
+        });
+        let agent = GooseAcpAgent::with_provider(update_tx, permission_tx, provider, Vec::new());
+
+        // Acknowledge notifications like the stdio connection does
+        let chunks = Arc::new(Mutex::new(Vec::new()));
+        let recorded = chunks.clone();
+        tokio::spawn(async move {
+            while let Some((notification, ack)) = update_rx.recv().await {
+                let chunk = match notification.update {
+                    acp::SessionUpdate::UserMessageChunk { content } => Some(("user", content)),
+                    acp::SessionUpdate::AgentMessageChunk { content } => Some(("agent", content)),
+                    _ => None,
+                };
+                if let Some((role, acp::ContentBlock::Text(text))) = chunk {
+                    recorded.lock().await.push((role.to_string(), text.text));
+                }
+                ack.send(()).ok();
+            }
+        });
+        (agent, chunks)
+    }
+
+    #[tokio::test]
+    async fn test_load_session_replays_prompted_messages() {
+        let (agent, chunks) = mock_acp_agent().await;
+        let cwd = tempfile::tempdir().unwrap();
+
+        let session = agent
+            .new_session(
+                serde_json::from_value(json!({"cwd": cwd.path(), "mcpServers": []})).unwrap(),
+            )
+            .await
+            .unwrap();
+        let session_id = session.session_id.0.to_string();
+        let stored = SessionManager::get_session(&session_id, false)
+            .await
+            .unwrap();
+        assert!(stored.description.starts_with("New session "));
+
+        agent
+            .prompt(
+                serde_json::from_value(json!({
+                    "sessionId": session_id,
+                    "prompt": [{"type": "text", "text": "Hello"}]
+                }))
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let prompted = std::mem::take(&mut *chunks.lock().await);
+        assert_eq!(
+            prompted,
+            vec![("agent".to_string(), "Hi there".to_string())]
+        );
+
+        // A fresh agent stands in for a restarted server
+        let (restarted, replayed) = mock_acp_agent().await;
+        restarted
+            .load_session(
+                serde_json::from_value(json!({
+                    "sessionId": session_id,
+                    "cwd": cwd.path(),
+                    "mcpServers": []
+                }))
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            *replayed.lock().await,
+            vec![
+                ("user".to_string(), "Hello".to_string()),
+                ("agent".to_string(), "Hi there".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sessions_get_their_own_agents() {
+        let (agent, _chunks) = mock_acp_agent().await;
+        let cwd = tempfile::tempdir().unwrap();
+        let new_session = || {
+            agent.new_session(
+                serde_json::from_value(json!({"cwd": cwd.path(), "mcpServers": []})).unwrap(),
+            )
+        };
+
+        let first = new_session().await.unwrap().session_id;
+        let second = new_session().await.unwrap().session_id;
+        let first_agent = agent.sessions.lock().await[&first.0.to_string()]
+            .agent
+            .clone();
+        let second_agent = agent.sessions.lock().await[&second.0.to_string()]
+            .agent
+            .clone();
+        assert!(!Arc::ptr_eq(&first_agent, &second_agent));
+
+        // Loading an open session again swaps in a fresh agent instead of adding to the old one
+        agent
+            .load_session(
+                serde_json::from_value(json!({
+                    "sessionId": first.0.to_string(),
+                    "cwd": cwd.path(),
+                    "mcpServers": []
+                }))
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let reloaded_agent = agent.sessions.lock().await[&first.0.to_string()]
+            .agent
+            .clone();
+        assert!(!Arc::ptr_eq(&first_agent, &reloaded_agent));
+        assert!(Arc::ptr_eq(
+            &second_agent,
+            &agent.sessions.lock().await[&second.0.to_string()].agent
+        ));
+    }
+
+    #[test]
+    fn test_session_extensions_scoped_to_client_servers() {
+        let docs = || acp::McpServer::Sse {
+            name: "docs".to_string(),
+            url: "https://example.com/sse".to_string(),
+            headers: Vec::new(),
+        };
+        let names = |extensions: Vec<ExtensionConfig>| -> Vec<String> {
+            extensions.iter().map(|e| e.name()).collect()
+        };
+
+        let configured = vec![mcp_server_to_extension(acp::McpServer::Sse {
+            name: "developer".to_string(),
+            url: "https://example.com/developer".to_string(),
+            headers: Vec::new(),
+        })];
+        assert_eq!(
+            names(session_extensions(&configured, vec![docs()])),
+            vec!["developer", "docs"]
+        );
+        assert_eq!(
+            names(session_extensions(&configured, Vec::new())),
+            vec!["developer"]
+        );
+        assert_eq!(
+            names(session_extensions(&configured, vec![docs(), docs()])),
+            vec!["developer", "docs"]
+        );
+    }
 
     fn new_resource_link(content: &str) -> anyhow::Result<(ResourceLink, NamedTempFile)> {
         let mut file = NamedTempFile::new()?;
@@ -792,4 +1430,80 @@ print(\"hello, world\")
         assert_eq!(format_tool_name("extension__"), "Extension: ");
         assert_eq!(format_tool_name("__tool"), ": Tool");
     }
+
+    #[test]
+    fn test_session_mode_state_lists_goose_modes() {
+        let state = session_mode_state("smart_approve");
+        assert_eq!(&*state.current_mode_id.0, "smart_approve");
+        let ids: Vec<_> = state
+            .available_modes
+            .iter()
+            .map(|mode| mode.id.0.to_string())
+            .collect();
+        assert_eq!(ids, vec!["auto", "approve", "smart_approve", "chat"]);
+    }
+
+    #[test]
+    fn test_mcp_server_to_extension() {
+        let http = mcp_server_to_extension(acp::McpServer::Http {
+            name: "docs".to_string(),
+            url: "https://example.com/mcp".to_string(),
+            headers: vec![acp::HttpHeader {
+                name: "Authorization".to_string(),
+                value: "Bearer token".to_string(),
+                meta: None,
+            }],
+        });
+        match http {
+            ExtensionConfig::StreamableHttp {
+                name, uri, headers, ..
+            } => {
+                assert_eq!(name, "docs");
+                assert_eq!(uri, "https://example.com/mcp");
+                assert_eq!(headers.get("Authorization").unwrap(), "Bearer token");
+            }
+            other => panic!("expected streamable HTTP extension, got {:?}", other),
+        }
+
+        let sse = mcp_server_to_extension(acp::McpServer::Sse {
+            name: "events".to_string(),
+            url: "https://example.com/sse".to_string(),
+            headers: Vec::new(),
+        });
+        assert!(matches!(
+            sse,
+            ExtensionConfig::Sse { ref uri, .. } if uri == "https://example.com/sse"
+        ));
+    }
+
+    #[test]
+    fn test_permission_options_round_trip() {
+        let options = permission_options(false);
+        let permissions: Vec<_> = options
+            .iter()
+            .map(|option| {
+                permission_from_outcome(&acp::RequestPermissionOutcome::Selected {
+                    option_id: option.id.clone(),
+                })
+            })
+            .collect();
+        assert_eq!(
+            permissions,
+            vec![
+                Permission::AllowOnce,
+                Permission::AlwaysAllow,
+                Permission::DenyOnce
+            ]
+        );
+
+        // Flagged calls cannot be always allowed
+        assert!(permission_options(true)
+            .iter()
+            .all(|option| option.kind != acp::PermissionOptionKind::AllowAlways));
+
+        assert_eq!(
+            permission_from_outcome(&acp::RequestPermissionOutcome::Cancelled),
+            Permission::Cancel
+        );
+    }
 }
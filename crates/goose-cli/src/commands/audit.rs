@@ -0,0 +1,95 @@
+use anyhow::Result;
+use goose::session::{AuditFilter, AuditRecord, SessionManager};
+
+use crate::commands::metrics::csv_field;
+use crate::commands::session::parse_date_arg;
+
+const CSV_HEADER: &str =
+    "id,created_at,session_id,tool_request_id,tool_name,kind,inspector,action,reason,confidence,principal";
+
+/// Print the permission audit log since `since` as JSON lines or CSV
+pub async fn handle_audit_export(
+    since: &str,
+    format: &str,
+    session_id: Option<String>,
+) -> Result<()> {
+    let filter = AuditFilter {
+        since: Some(parse_date_arg(since)?),
+        session_id,
+        limit: None,
+    };
+    let records = SessionManager::get_audit_records(&filter).await?;
+
+    match format {
+        "jsonl" => {
+            for record in &records {
+                println!("{}", serde_json::to_string(record)?);
+            }
+        }
+        "csv" => print!("{}", audit_csv(&records)),
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Unsupported format '{}', expected jsonl or csv",
+                format
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+fn audit_csv(records: &[AuditRecord]) -> String {
+    let mut out = format!("{}\n", CSV_HEADER);
+    for record in records {
+        let entry = &record.entry;
+        let row = [
+            record.id.to_string(),
+            csv_field(&record.created_at),
+            csv_field(entry.session_id.as_deref().unwrap_or("")),
+            csv_field(&entry.tool_request_id),
+            csv_field(&entry.tool_name),
+            entry.kind.as_str().to_string(),
+            csv_field(&entry.inspector),
+            entry.action.as_str().to_string(),
+            csv_field(&entry.reason),
+            entry.confidence.map(|c| c.to_string()).unwrap_or_default(),
+            csv_field(&entry.principal),
+        ];
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use goose::session::{AuditAction, AuditEntry, AuditKind};
+
+    #[test]
+    fn test_audit_csv_quotes_reasons() {
+        let records = vec![AuditRecord {
+            id: 3,
+            created_at: "2025-01-02 03:04:05".to_string(),
+            entry: AuditEntry {
+                session_id: None,
+                tool_request_id: "req_1".to_string(),
+                tool_name: "developer__shell".to_string(),
+                kind: AuditKind::Inspection,
+                inspector: "security".to_string(),
+                action: AuditAction::Deny,
+                reason: "Matched \"rm -rf\", critical".to_string(),
+                confidence: Some(0.95),
+                principal: "alice".to_string(),
+            },
+        }];
+
+        assert_eq!(
+            audit_csv(&records),
+            format!(
+                "{}\n3,2025-01-02 03:04:05,,req_1,developer__shell,inspection,security,deny,\"Matched \"\"rm -rf\"\", critical\",0.95,alice\n",
+                CSV_HEADER
+            )
+        );
+    }
+}
@@ -1,7 +1,40 @@
 use anyhow::Result;
 use console::style;
-use goose::session::{ensure_session_dir, legacy, SessionManager};
+use goose::session::{
+    ensure_session_dir, legacy, CostPeriod, MetricsGroup, MetricsGroupBy, ModelCost, SessionManager,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::commands::session::format_cost;
+
+/// Period used when rolling cost up across all sessions
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum RollupPeriod {
+    Day,
+    Week,
+}
+
+/// Dimension used when aggregating metrics across sessions
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum MetricsGrouping {
+    Day,
+    Model,
+    Recipe,
+    #[value(name = "working_dir")]
+    WorkingDir,
+}
+
+impl From<MetricsGrouping> for MetricsGroupBy {
+    fn from(grouping: MetricsGrouping) -> Self {
+        match grouping {
+            MetricsGrouping::Day => MetricsGroupBy::Day,
+            MetricsGrouping::Model => MetricsGroupBy::Model,
+            MetricsGrouping::Recipe => MetricsGroupBy::Recipe,
+            MetricsGrouping::WorkingDir => MetricsGroupBy::WorkingDir,
+        }
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 struct SessionMetrics {
@@ -24,6 +57,10 @@ struct SessionMetrics {
     accumulated_output_tokens: i32,
     tokens_per_minute: f64,
     
+    // Cost Metrics
+    accumulated_cost: Option<f64>,
+    model_costs: BTreeMap<String, ModelCost>,
+    
     // Message Metrics
     message_count: usize,
     user_messages: usize,
@@ -55,7 +92,12 @@ pub async fn handle_metrics(
     session_id: Option<String>,
     detailed: bool,
     export_json: bool,
+    rollup: Option<RollupPeriod>,
 ) -> Result<()> {
+    if let Some(period) = rollup {
+        return display_cost_rollup(period, export_json).await;
+    }
+
     // Get the session to analyze
     let target_session_id = if let Some(id) = session_id {
         // Try to find by ID or name
@@ -326,6 +368,8 @@ async fn calculate_metrics(session: &goose::session::Session) -> Result<SessionM
         accumulated_input_tokens: session.accumulated_input_tokens.unwrap_or(0),
         accumulated_output_tokens: session.accumulated_output_tokens.unwrap_or(0),
         tokens_per_minute,
+        accumulated_cost: session.accumulated_cost,
+        model_costs: session.model_costs.clone(),
         message_count,
         user_messages,
         assistant_messages,
@@ -377,6 +421,25 @@ fn display_metrics(metrics: &SessionMetrics, detailed: bool) {
     println!("  Output Tokens:     {}", metrics.accumulated_output_tokens);
     println!("  Tokens/Minute:     {:.0}", metrics.tokens_per_minute);
     println!();
+
+    // Cost Metrics
+    println!("{}", style("COST METRICS").cyan().bold());
+    println!(
+        "  Estimated Cost:    {}",
+        format_cost(metrics.accumulated_cost)
+    );
+    for (model, usage) in &metrics.model_costs {
+        println!(
+            "  {} ({}): {} calls, {} in / {} out tokens, {}",
+            model,
+            usage.provider,
+            usage.calls,
+            usage.input_tokens,
+            usage.output_tokens,
+            format_cost(usage.cost)
+        );
+    }
+    println!();
     
     // Message Metrics
     println!("{}", style("MESSAGE METRICS").cyan().bold());
@@ -448,6 +511,13 @@ fn display_basic_metrics(session: &goose::session::Session) {
     println!("  Input Tokens:      {}", session.accumulated_input_tokens.unwrap_or(0));
     println!("  Output Tokens:     {}", session.accumulated_output_tokens.unwrap_or(0));
     println!();
+
+    println!("{}", style("COST METRICS").cyan().bold());
+    println!(
+        "  Estimated Cost:    {}",
+        format_cost(session.accumulated_cost)
+    );
+    println!();
     
     println!("{}", style("MESSAGE COUNT").cyan().bold());
     println!("  Total Messages:    {}", session.message_count);
@@ -488,3 +558,252 @@ fn display_detailed_metrics(metrics: &SessionMetrics) {
         (metrics.commands_executed as f64 / metrics.tool_uses.max(1) as f64) * 100.0);
     println!();
 }
+
+async fn display_cost_rollup(period: RollupPeriod, export_json: bool) -> Result<()> {
+    let (period, since, label) = match period {
+        RollupPeriod::Day => (CostPeriod::Day, chrono::Duration::days(30), "DAILY"),
+        RollupPeriod::Week => (CostPeriod::Week, chrono::Duration::weeks(12), "WEEKLY"),
+    };
+    let rollup = SessionManager::get_cost_rollup(period, Some(chrono::Utc::now() - since)).await?;
+
+    if export_json {
+        println!("{}", serde_json::to_string_pretty(&rollup)?);
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "{}",
+        style(format!("=== {} COST ROLLUP ===", label))
+            .cyan()
+            .bold()
+    );
+    println!();
+
+    if rollup.is_empty() {
+        println!("  No provider usage recorded in this period.");
+        println!();
+        return Ok(());
+    }
+
+    println!(
+        "  {:<12} {:<40} {:>7} {:>12} {:>12} {:>10}",
+        "Period", "Model", "Calls", "Input", "Output", "Cost"
+    );
+    let mut total = 0.0;
+    for entry in &rollup {
+        println!(
+            "  {:<12} {:<40} {:>7} {:>12} {:>12} {:>10}",
+            entry.period_start,
+            format!("{}/{}", entry.provider, entry.model),
+            entry.calls,
+            entry.input_tokens,
+            entry.output_tokens,
+            format!("${:.4}", entry.cost)
+        );
+        total += entry.cost;
+    }
+    println!();
+    println!("  Total:             {}", format_cost(Some(total)));
+    println!();
+
+    Ok(())
+}
+
+/// Aggregate metrics across every session since `since`, grouped by day, model, recipe
+/// or working directory, and print them as a table, JSON or CSV
+pub async fn handle_metrics_trend(
+    group_by: MetricsGrouping,
+    since: &str,
+    format: &str,
+) -> Result<()> {
+    let since_time = crate::commands::session::parse_date_arg(since)?;
+    let groups = SessionManager::get_metrics(group_by.into(), Some(since_time)).await?;
+
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&groups)?),
+        "csv" => print!("{}", metrics_csv(&groups)),
+        "table" => display_metrics_trend(&groups, group_by, since),
+        _ => {
+            return Err(anyhow::anyhow!(
+                "Unsupported format '{}', expected table, json or csv",
+                format
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+fn display_metrics_trend(groups: &[MetricsGroup], group_by: MetricsGrouping, since: &str) {
+    let label = match group_by {
+        MetricsGrouping::Day => "Day",
+        MetricsGrouping::Model => "Model",
+        MetricsGrouping::Recipe => "Recipe",
+        MetricsGrouping::WorkingDir => "Working dir",
+    };
+
+    println!();
+    println!(
+        "{}",
+        style(format!(
+            "=== METRICS BY {} (since {}) ===",
+            label.to_uppercase(),
+            since
+        ))
+        .cyan()
+        .bold()
+    );
+    println!();
+
+    if groups.is_empty() {
+        println!("  No sessions recorded in this period.");
+        println!();
+        return;
+    }
+
+    println!(
+        "  {:<40} {:>8} {:>12} {:>10} {:>7} {:>7} {:>8} {:>8}",
+        label, "Sessions", "Tokens", "Cost", "Tools", "Errors", "Created", "Modified"
+    );
+    for group in groups {
+        println!(
+            "  {:<40} {:>8} {:>12} {:>10} {:>7} {:>7} {:>8} {:>8}",
+            truncate_key(&group.key, 40),
+            group.sessions,
+            group.total_tokens,
+            format!("${:.4}", group.cost),
+            group.tool_calls,
+            format!("{:.1}%", group.error_rate * 100.0),
+            group.files_created,
+            group.files_modified
+        );
+    }
+
+    println!();
+    println!("{}", style("Tool calls by operation:").bold());
+    for group in groups.iter().filter(|g| !g.calls_by_operation.is_empty()) {
+        let operations: Vec<String> = group
+            .calls_by_operation
+            .iter()
+            .map(|(operation, calls)| format!("{}={}", operation, calls))
+            .collect();
+        println!(
+            "  {:<40} {}",
+            truncate_key(&group.key, 40),
+            operations.join(" ")
+        );
+    }
+    println!();
+}
+
+/// Keep the end of long keys, which is the distinctive part of paths and model names
+fn truncate_key(key: &str, width: usize) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= width {
+        key.to_string()
+    } else {
+        let tail: String = chars[chars.len() - (width - 3)..].iter().collect();
+        format!("...{}", tail)
+    }
+}
+
+fn metrics_csv(groups: &[MetricsGroup]) -> String {
+    let operations: std::collections::BTreeSet<&String> = groups
+        .iter()
+        .flat_map(|g| g.calls_by_operation.keys())
+        .collect();
+
+    let mut header = vec![
+        "key".to_string(),
+        "sessions".to_string(),
+        "input_tokens".to_string(),
+        "output_tokens".to_string(),
+        "total_tokens".to_string(),
+        "cost".to_string(),
+        "tool_calls".to_string(),
+        "failed_tool_calls".to_string(),
+        "error_rate".to_string(),
+        "files_created".to_string(),
+        "files_modified".to_string(),
+    ];
+    header.extend(operations.iter().map(|op| format!("calls_{}", op)));
+
+    let mut out = header.join(",");
+    out.push('\n');
+    for group in groups {
+        let mut row = vec![
+            csv_field(&group.key),
+            group.sessions.to_string(),
+            group.input_tokens.to_string(),
+            group.output_tokens.to_string(),
+            group.total_tokens.to_string(),
+            format!("{:.6}", group.cost),
+            group.tool_calls.to_string(),
+            group.failed_tool_calls.to_string(),
+            format!("{:.4}", group.error_rate),
+            group.files_created.to_string(),
+            group.files_modified.to_string(),
+        ];
+        row.extend(operations.iter().map(|op| {
+            group
+                .calls_by_operation
+                .get(*op)
+                .copied()
+                .unwrap_or(0)
+                .to_string()
+        }));
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_csv_quotes_keys_and_fills_operations() {
+        let groups = vec![
+            MetricsGroup {
+                key: "/home/me/a,b".to_string(),
+                sessions: 2,
+                tool_calls: 3,
+                calls_by_operation: BTreeMap::from([
+                    ("file_edit".to_string(), 2),
+                    ("search".to_string(), 1),
+                ]),
+                ..Default::default()
+            },
+            MetricsGroup {
+                key: "/home/me/\"c\"".to_string(),
+                calls_by_operation: BTreeMap::from([("command_execute".to_string(), 4)]),
+                ..Default::default()
+            },
+        ];
+
+        let csv = metrics_csv(&groups);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].ends_with(",calls_command_execute,calls_file_edit,calls_search"));
+        assert!(lines[1].starts_with("\"/home/me/a,b\",2,"));
+        assert!(lines[1].ends_with(",0,2,1"));
+        assert!(lines[2].starts_with("\"/home/me/\"\"c\"\"\",0,"));
+        assert!(lines[2].ends_with(",4,0,0"));
+    }
+
+    #[test]
+    fn test_truncate_key_keeps_tail() {
+        assert_eq!(truncate_key("short", 10), "short");
+        assert_eq!(truncate_key("/very/long/path/name", 10), "...th/name");
+    }
+}
@@ -1,8 +1,10 @@
 pub mod acp;
+pub mod audit;
 pub mod bench;
 pub mod configure;
 pub mod info;
 pub mod metrics;
+pub mod policy;
 pub mod project;
 pub mod recipe;
 pub mod schedule;
@@ -0,0 +1,58 @@
+use anyhow::{Context, Result};
+use console::style;
+use goose::config::PermissionManager;
+use goose::permission::{PolicyAction, ToolPolicy};
+use rmcp::model::JsonObject;
+use std::path::PathBuf;
+
+/// Dry-run the policy file against a single tool call and print the decision
+pub fn handle_policy_test(
+    tool: &str,
+    args_json: &str,
+    policy_file: Option<PathBuf>,
+    working_dir: Option<PathBuf>,
+) -> Result<()> {
+    let arguments: JsonObject = serde_json::from_str(args_json)
+        .context("Tool arguments must be a JSON object, e.g. '{\"command\": \"ls\"}'")?;
+    let policy_path = policy_file.unwrap_or_else(ToolPolicy::default_path);
+    let policy = ToolPolicy::load(&policy_path)?;
+    let working_dir = match working_dir {
+        Some(dir) => dir,
+        None => std::env::current_dir()?,
+    };
+
+    println!("{} {}", style("Policy file:").bold(), policy_path.display());
+    println!("{} {}", style("Working dir:").bold(), working_dir.display());
+    println!();
+
+    match policy.evaluate(tool, Some(&arguments), &working_dir) {
+        Some(decision) => {
+            let action = match decision.action {
+                PolicyAction::Allow => style("allow").green(),
+                PolicyAction::Ask => style("ask").yellow(),
+                PolicyAction::Deny => style("deny").red(),
+            };
+            println!("{} {}", style("Decision:").bold(), action.bold());
+            println!("{} {}", style("Rule:").bold(), decision.rule);
+            if let Some(reason) = decision.reason {
+                println!("{} {}", style("Reason:").bold(), reason);
+            }
+        }
+        None => {
+            let fallback = match PermissionManager::default().get_user_permission(tool) {
+                Some(level) => serde_json::to_value(level)?
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                None => "the current goose mode".to_string(),
+            };
+            println!(
+                "{} no rule matched, falls back to {}",
+                style("Decision:").bold(),
+                fallback
+            );
+        }
+    }
+
+    Ok(())
+}
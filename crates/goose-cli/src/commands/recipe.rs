@@ -1,8 +1,11 @@
 use anyhow::Result;
 use console::style;
 
+use crate::recipes::cassette::{run_recipe_test, RecipeTestOptions};
 use crate::recipes::github_recipe::RecipeSource;
+use crate::recipes::lockfile::RECIPE_LOCKFILE_NAME;
 use crate::recipes::recipe::load_recipe_for_validation;
+use crate::recipes::registry::lock_recipe_packages;
 use crate::recipes::search_recipe::list_available_recipes;
 use goose::recipe_deeplink;
 
@@ -11,15 +14,19 @@ use goose::recipe_deeplink;
 /// # Arguments
 ///
 /// * `file_path` - Path to the recipe file to validate
+/// * `resolved` - Whether to print the recipe with its `extends` chain resolved
 ///
 /// # Returns
 ///
 /// Result indicating success or failure
-pub fn handle_validate(recipe_name: &str) -> Result<()> {
+pub fn handle_validate(recipe_name: &str, resolved: bool) -> Result<()> {
     // Load and validate the recipe file
     match load_recipe_for_validation(recipe_name) {
-        Ok(_) => {
+        Ok(recipe) => {
             println!("{} recipe file is valid", style("✓").green().bold());
+            if resolved {
+                print!("{}", serde_yaml::to_string(&recipe)?);
+            }
             Ok(())
         }
         Err(err) => {
@@ -29,6 +36,51 @@ pub fn handle_validate(recipe_name: &str) -> Result<()> {
     }
 }
 
+/// Runs a recipe against recorded cassettes and checks its expectations
+///
+/// # Arguments
+///
+/// * `recipe_name` - Name or path of the recipe to test
+/// * `params` - Parameters to render the recipe with
+/// * `options` - Cassette directory, record mode and working directory
+///
+/// # Returns
+///
+/// Result indicating whether the recipe met its expectations without cassette misses
+pub async fn handle_test(
+    recipe_name: &str,
+    params: Vec<(String, String)>,
+    options: RecipeTestOptions,
+) -> Result<()> {
+    let record = options.record;
+    let report = run_recipe_test(recipe_name, params, options).await?;
+
+    if record {
+        println!("{} recorded cassettes", style("✓").green().bold());
+    }
+    if !report.tools_called.is_empty() {
+        println!("Tools called: {}", report.tools_called.join(", "));
+    }
+
+    for miss in &report.cassette_misses {
+        println!("{} cassette miss: {}", style("✗").red().bold(), miss);
+    }
+    for failure in &report.failures {
+        println!("{} {}", style("✗").red().bold(), failure);
+    }
+
+    if report.passed() {
+        println!("{} recipe test passed", style("✓").green().bold());
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "Recipe test failed with {} cassette miss(es) and {} failed expectation(s)",
+            report.cassette_misses.len(),
+            report.failures.len()
+        ))
+    }
+}
+
 /// Generates a deeplink for a recipe file
 ///
 /// # Arguments
@@ -68,7 +120,7 @@ pub fn handle_deeplink(recipe_name: &str) -> Result<String> {
     }
 }
 
-/// Lists all available recipes from local paths and GitHub repositories
+/// Lists all available recipes from local paths, the recipe registry and GitHub repositories
 ///
 /// # Arguments
 ///
@@ -99,6 +151,7 @@ pub fn handle_list(format: &str, verbose: bool) -> Result<()> {
                 for recipe in recipes {
                     let source_info = match recipe.source {
                         RecipeSource::Local => format!("local: {}", recipe.path),
+                        RecipeSource::Registry => format!("registry: {}", recipe.path),
                         RecipeSource::GitHub => format!("github: {}", recipe.path),
                     };
 
@@ -129,6 +182,39 @@ pub fn handle_list(format: &str, verbose: bool) -> Result<()> {
     Ok(())
 }
 
+/// Resolves recipe packages from the registry and pins them in the lockfile
+///
+/// # Arguments
+///
+/// * `references` - Packages to lock, e.g. `code-review@^1.2`; every locked package if empty
+/// * `update` - Whether to resolve the newest matching versions instead of the locked ones
+///
+/// # Returns
+///
+/// Result indicating whether every package resolved and matched its locked checksum
+pub fn handle_lock(references: &[String], update: bool) -> Result<()> {
+    let locked_packages = lock_recipe_packages(&std::env::current_dir()?, references, update)?;
+    if locked_packages.is_empty() {
+        println!("No recipe packages to lock");
+        return Ok(());
+    }
+    for locked in &locked_packages {
+        println!(
+            "{} {}@{} ({})",
+            style("✓").green().bold(),
+            locked.name,
+            locked.version,
+            locked.source
+        );
+    }
+    println!(
+        "Locked {} package(s) in {}",
+        locked_packages.len(),
+        RECIPE_LOCKFILE_NAME
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,7 +296,7 @@ response:
         let recipe_path =
             create_test_recipe_file(&temp_dir, "test_recipe.yaml", VALID_RECIPE_CONTENT);
 
-        let result = handle_validate(&recipe_path);
+        let result = handle_validate(&recipe_path, false);
         assert!(result.is_ok());
     }
 
@@ -219,7 +305,7 @@ response:
         let temp_dir = TempDir::new().expect("Failed to create temp directory");
         let recipe_path =
             create_test_recipe_file(&temp_dir, "test_recipe.yaml", INVALID_RECIPE_CONTENT);
-        let result = handle_validate(&recipe_path);
+        let result = handle_validate(&recipe_path, false);
         assert!(result.is_err());
     }
 
@@ -232,11 +318,61 @@ response:
             RECIPE_WITH_INVALID_JSON_SCHEMA,
         );
 
-        let result = handle_validate(&recipe_path);
+        let result = handle_validate(&recipe_path, false);
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
             .to_string()
             .contains("JSON schema validation failed"));
     }
+
+    #[test]
+    fn test_handle_validation_recipe_with_extends() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        create_test_recipe_file(&temp_dir, "base.yaml", VALID_RECIPE_CONTENT);
+        let recipe_path = create_test_recipe_file(
+            &temp_dir,
+            "child.yaml",
+            r#"
+title: "Child Recipe"
+description: "A recipe extending the base recipe"
+extends: base
+instructions: "Child instructions"
+"#,
+        );
+
+        let result = handle_validate(&recipe_path, true);
+        assert!(result.is_ok());
+
+        let recipe = load_recipe_for_validation(&recipe_path).unwrap();
+        assert_eq!(recipe.title, "Child Recipe");
+        assert_eq!(
+            recipe.instructions.as_deref(),
+            Some("Test instructions\n\nChild instructions")
+        );
+        assert_eq!(recipe.prompt.as_deref(), Some("Test prompt content"));
+        assert!(recipe.response.is_some());
+    }
+
+    #[test]
+    fn test_handle_validation_recipe_extending_itself() {
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let recipe_path = create_test_recipe_file(
+            &temp_dir,
+            "looping.yaml",
+            r#"
+title: "Looping Recipe"
+description: "A recipe extending itself"
+extends: looping.yaml
+instructions: "Test instructions"
+"#,
+        );
+
+        let result = handle_validate(&recipe_path, false);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Recipe extends cycle detected"));
+    }
 }
@@ -1,13 +1,19 @@
 use anyhow::{bail, Context, Result};
 use base64::engine::{general_purpose::STANDARD as BASE64_STANDARD, Engine};
+use goose::agents::BudgetLimits;
 use goose::scheduler::{
     get_default_scheduled_recipes_dir, get_default_scheduler_storage_path, ScheduledJob,
     SchedulerError,
 };
 use goose::scheduler_factory::SchedulerFactory;
+use goose::scheduler_history::{RetryPolicy, ScheduleHook, ScheduledRunStatus};
+use goose::scheduler_policy::{BlackoutWindow, OverlapPolicy};
+use goose::scheduler_trigger::ScheduleTrigger;
 use goose::temporal_scheduler::TemporalScheduler;
 use std::path::Path;
 
+use crate::recipes::registry::{lock_recipe_from_registry, PackageReference};
+
 // Base64 decoding function - might be needed if recipe_source_arg can be base64
 // For now, handle_schedule_add will assume it's a path.
 async fn _decode_base64_recipe(source: &str) -> Result<String> {
@@ -75,31 +81,106 @@ fn validate_cron_expression(cron: &str) -> Result<()> {
     Ok(())
 }
 
+/// Builds the hook for a `--on-*-command` or `--on-*-webhook` argument, whichever was given.
+pub fn schedule_hook(command: Option<String>, webhook: Option<String>) -> Option<ScheduleHook> {
+    match (command, webhook) {
+        (Some(command), _) => Some(ScheduleHook::Command { command }),
+        (None, Some(url)) => Some(ScheduleHook::Webhook { url }),
+        (None, None) => None,
+    }
+}
+
+/// Builds the trigger of `goose schedule add` from its mutually exclusive trigger flags
+pub fn schedule_trigger(
+    cron: Option<String>,
+    on_file_change: Option<String>,
+    on_git_ref: Option<String>,
+    git_repo: String,
+    on_webhook: bool,
+) -> ScheduleTrigger {
+    if let Some(path) = on_file_change {
+        ScheduleTrigger::FileChange { path }
+    } else if let Some(git_ref) = on_git_ref {
+        ScheduleTrigger::GitRef {
+            repo: git_repo,
+            git_ref,
+        }
+    } else if on_webhook {
+        ScheduleTrigger::Webhook
+    } else {
+        ScheduleTrigger::Cron {
+            expression: cron.unwrap_or_default(),
+        }
+    }
+}
+
+/// Optional settings of a job created with `goose schedule add`
+#[derive(Default)]
+pub struct ScheduleAddOptions {
+    pub budget: BudgetLimits,
+    pub retry: Option<RetryPolicy>,
+    pub on_success: Option<ScheduleHook>,
+    pub on_failure: Option<ScheduleHook>,
+    pub parameters: Vec<(String, String)>,
+    pub overlap_policy: Option<OverlapPolicy>,
+    pub timezone: Option<String>,
+    pub blackout_windows: Vec<BlackoutWindow>,
+    pub jitter_seconds: Option<u64>,
+    pub debounce_seconds: Option<u64>,
+}
+
 pub async fn handle_schedule_add(
     id: String,
-    cron: String,
+    trigger: ScheduleTrigger,
     recipe_source_arg: String, // This is expected to be a file path by the Scheduler
+    options: ScheduleAddOptions,
 ) -> Result<()> {
     println!(
-        "[CLI Debug] Scheduling job ID: {}, Cron: {}, Recipe Source Path: {}",
-        id, cron, recipe_source_arg
+        "[CLI Debug] Scheduling job ID: {}, Trigger: {}, Recipe Source Path: {}",
+        id, trigger, recipe_source_arg
     );
 
     // Validate cron expression and provide helpful feedback
-    validate_cron_expression(&cron)?;
+    if let Some(cron) = trigger.cron_expression() {
+        validate_cron_expression(cron)?;
+    }
+
+    // Registry packages are scheduled at their locked version, and the scheduler copies the
+    // whole package so every run uses the same recipe, sub-recipes and files
+    let recipe_source_arg = if PackageReference::is_versioned(&recipe_source_arg)
+        && !Path::new(&recipe_source_arg).exists()
+    {
+        let package = lock_recipe_from_registry(&recipe_source_arg)?;
+        package.dir.to_string_lossy().into_owned()
+    } else {
+        recipe_source_arg
+    };
 
     // The Scheduler's add_scheduled_job will handle copying the recipe from recipe_source_arg
     // to its internal storage and validating the path.
     let job = ScheduledJob {
         id: id.clone(),
         source: recipe_source_arg.clone(), // Pass the original user-provided path
-        cron,
+        trigger,
         last_run: None,
         currently_running: false,
         paused: false,
         current_session_id: None,
         process_start_time: None,
         execution_mode: Some("background".to_string()), // Default to background for CLI
+        budget: (options.budget != BudgetLimits::default()).then_some(options.budget),
+        retry: options.retry,
+        on_success: options.on_success,
+        on_failure: options.on_failure,
+        parameters: (!options.parameters.is_empty())
+            .then(|| options.parameters.into_iter().collect()),
+        overlap_policy: options.overlap_policy,
+        timezone: options.timezone,
+        blackout_windows: (!options.blackout_windows.is_empty())
+            .then_some(options.blackout_windows),
+        jitter_seconds: options.jitter_seconds,
+        debounce_seconds: options.debounce_seconds,
+        recipe_dir: None,
     };
 
     let scheduler_storage_path =
@@ -114,11 +195,16 @@ pub async fn handle_schedule_add(
             // We can reconstruct the likely path for display if needed, or adjust success message.
             let scheduled_recipes_dir = get_default_scheduled_recipes_dir()
                 .unwrap_or_else(|_| Path::new("./.goose_scheduled_recipes").to_path_buf()); // Fallback for display
-            let extension = Path::new(&recipe_source_arg)
-                .extension()
-                .and_then(|ext| ext.to_str())
-                .unwrap_or("yaml");
-            let final_recipe_path = scheduled_recipes_dir.join(format!("{}.{}", id, extension));
+            let final_recipe_path = if Path::new(&recipe_source_arg).is_dir() {
+                // Recipe packages are copied whole into a directory named after the job
+                scheduled_recipes_dir.join(&id)
+            } else {
+                let extension = Path::new(&recipe_source_arg)
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .unwrap_or("yaml");
+                scheduled_recipes_dir.join(format!("{}.{}", id, extension))
+            };
 
             println!(
                 "Scheduled job '{}' added. Recipe expected at {:?}",
@@ -168,10 +254,10 @@ pub async fn handle_schedule_list() -> Result<()> {
             };
 
             println!(
-                "- ID: {}\n  Status: {}\n  Cron: {}\n  Recipe Source (in store): {}\n  Last Run: {}",
+                "- ID: {}\n  Status: {}\n  Trigger: {}\n  Recipe Source (in store): {}\n  Last Run: {}",
                 job.id,
                 status,
-                job.cron,
+                job.trigger,
                 job.source, // This source is now the path within scheduled_recipes_dir
                 job.last_run
                     .map_or_else(|| "Never".to_string(), |dt| dt.to_rfc3339())
@@ -235,6 +321,57 @@ pub async fn handle_schedule_sessions(id: String, limit: Option<usize>) -> Resul
     Ok(())
 }
 
+pub async fn handle_schedule_history(id: String, limit: Option<usize>) -> Result<()> {
+    let scheduler_storage_path =
+        get_default_scheduler_storage_path().context("Failed to get scheduler storage path")?;
+    let scheduler = SchedulerFactory::create(scheduler_storage_path)
+        .await
+        .context("Failed to initialize scheduler")?;
+
+    let runs = match scheduler.runs(&id, limit.unwrap_or(20)).await {
+        Ok(runs) => runs,
+        Err(SchedulerError::JobNotFound(job_id)) => {
+            bail!("Error: Job with ID '{}' not found.", job_id);
+        }
+        Err(e) => bail!("Failed to get runs for schedule '{}': {:?}", id, e),
+    };
+    if runs.is_empty() {
+        println!("No runs recorded for schedule ID '{}'.", id);
+        return Ok(());
+    }
+
+    println!("Runs for schedule ID '{}':", id);
+    for run in runs {
+        let status = match run.status {
+            ScheduledRunStatus::Succeeded => "✅ SUCCEEDED",
+            ScheduledRunStatus::Failed => "❌ FAILED",
+            ScheduledRunStatus::Cancelled => "⏹️  CANCELLED",
+            ScheduledRunStatus::Skipped => "⏭️  SKIPPED",
+        };
+        let duration = run.ended_at.signed_duration_since(run.started_at);
+        println!(
+            "- {} (attempt {})\n  Started: {}\n  Duration: {}s\n  Session ID: {}\n  Tokens: {}",
+            status,
+            run.attempt,
+            run.started_at.to_rfc3339(),
+            duration.num_seconds(),
+            run.session_id.as_deref().unwrap_or("N/A"),
+            run.total_tokens
+                .map_or_else(|| "N/A".to_string(), |tokens| tokens.to_string())
+        );
+        if let Some(checks_passed) = run.checks_passed {
+            println!(
+                "  Success checks: {}",
+                if checks_passed { "passed" } else { "failed" }
+            );
+        }
+        if let Some(error) = run.error {
+            println!("  Error: {}", error);
+        }
+    }
+    Ok(())
+}
+
 pub async fn handle_schedule_run_now(id: String) -> Result<()> {
     let scheduler_storage_path =
         get_default_scheduler_storage_path().context("Failed to get scheduler storage path")?;
@@ -1,8 +1,10 @@
 use crate::session::message_to_markdown;
 use anyhow::{Context, Result};
 
+use chrono::{DateTime, NaiveDate, Utc};
 use cliclack::{confirm, multiselect, select};
-use goose::session::{Session, SessionManager};
+use goose::session::session_manager::{SEARCH_HIGHLIGHT_END, SEARCH_HIGHLIGHT_START};
+use goose::session::{MessageSearchFilters, Session, SessionManager};
 use goose::utils::safe_truncate;
 use regex::Regex;
 use std::fs;
@@ -119,6 +121,7 @@ pub async fn handle_session_list(
     ascending: bool,
     working_dir: Option<PathBuf>,
     limit: Option<usize>,
+    show_cost: bool,
 ) -> Result<()> {
     let mut sessions = SessionManager::list_sessions().await?;
 
@@ -153,18 +156,148 @@ pub async fn handle_session_list(
             }
 
             println!("Available sessions:");
+            let mut total_cost = 0.0;
             for session in sessions {
-                let output = format!(
+                let mut output = format!(
                     "{} - {} - {}",
                     session.id, session.description, session.updated_at
                 );
+                if let Some(parent_id) = &session.parent_session_id {
+                    output.push_str(&format!(
+                        " (forked from {} at message {})",
+                        parent_id,
+                        session.fork_message_index.unwrap_or(0)
+                    ));
+                }
+                if show_cost {
+                    output.push_str(&format!(" - {}", format_cost(session.accumulated_cost)));
+                    total_cost += session.accumulated_cost.unwrap_or(0.0);
+                }
                 println!("{}", output);
+                if show_cost {
+                    for (model, usage) in &session.model_costs {
+                        println!(
+                            "    {} ({}): {} calls, {} in / {} out tokens, {}",
+                            model,
+                            usage.provider,
+                            usage.calls,
+                            usage.input_tokens,
+                            usage.output_tokens,
+                            format_cost(usage.cost)
+                        );
+                    }
+                }
+            }
+            if show_cost {
+                println!("Total: {}", format_cost(Some(total_cost)));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Format an estimated USD cost, noting when no pricing was available
+pub fn format_cost(cost: Option<f64>) -> String {
+    match cost {
+        Some(cost) => format!("${:.4}", cost),
+        None => "cost unknown".to_string(),
+    }
+}
+
+/// Parse a date argument given as `YYYY-MM-DD`, RFC 3339, or a relative duration
+/// such as `30d`, `12h` or `2w` counted back from now.
+pub fn parse_date_arg(value: &str) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+    }
+
+    let invalid = || anyhow::anyhow!("Invalid date '{}'", value);
+    let mut chars = value.chars();
+    let unit = chars.next_back().ok_or_else(invalid)?;
+    let amount: i64 = chars.as_str().parse().map_err(|_| invalid())?;
+    let duration = match unit {
+        'h' => chrono::Duration::try_hours(amount),
+        'd' => chrono::Duration::try_days(amount),
+        'w' => chrono::Duration::try_weeks(amount),
+        _ => return Err(invalid()),
+    }
+    .ok_or_else(invalid)?;
+
+    Utc::now().checked_sub_signed(duration).ok_or_else(invalid)
+}
+
+pub async fn handle_session_search(
+    query: String,
+    working_dir: Option<PathBuf>,
+    since: Option<String>,
+    until: Option<String>,
+    recipe: Option<String>,
+    limit: Option<usize>,
+    format: String,
+) -> Result<()> {
+    let filters = MessageSearchFilters {
+        working_dir: working_dir.map(|p| p.to_string_lossy().to_string()),
+        since: since.as_deref().map(parse_date_arg).transpose()?,
+        until: until.as_deref().map(parse_date_arg).transpose()?,
+        recipe,
+        limit,
+    };
+
+    let hits = SessionManager::search_messages(&query, &filters).await?;
+
+    match format.as_str() {
+        "json" => {
+            println!("{}", serde_json::to_string(&hits)?);
+        }
+        _ => {
+            if hits.is_empty() {
+                println!("No matches found");
+                return Ok(());
+            }
+
+            for hit in hits {
+                println!(
+                    "{} #{} ({}) - {}",
+                    console::style(&hit.session_id).cyan(),
+                    hit.message_index,
+                    hit.role,
+                    hit.session_description
+                );
+                println!("    {}", highlight_snippet(&hit.snippet));
             }
         }
     }
     Ok(())
 }
 
+fn highlight_snippet(snippet: &str) -> String {
+    let mut output = String::new();
+    let mut rest = snippet.replace('\n', " ");
+
+    while let Some(start) = rest.find(SEARCH_HIGHLIGHT_START) {
+        let after_start = &rest[start + SEARCH_HIGHLIGHT_START.len()..];
+        let Some(end) = after_start.find(SEARCH_HIGHLIGHT_END) else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        output.push_str(
+            &console::style(&after_start[..end])
+                .yellow()
+                .bold()
+                .to_string(),
+        );
+        rest = after_start[end + SEARCH_HIGHLIGHT_END.len()..].to_string();
+    }
+    output.push_str(&rest);
+    output
+}
+
 pub async fn handle_session_export(
     session_id: String,
     output_path: Option<PathBuf>,
@@ -328,3 +461,33 @@ pub async fn prompt_interactive_session_selection() -> Result<String> {
         Err(anyhow::anyhow!("Invalid selection"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_date_arg() {
+        let date = parse_date_arg("2025-03-01").unwrap();
+        assert_eq!(date.to_rfc3339(), "2025-03-01T00:00:00+00:00");
+
+        let rfc = parse_date_arg("2025-03-01T12:30:00Z").unwrap();
+        assert_eq!(rfc.to_rfc3339(), "2025-03-01T12:30:00+00:00");
+
+        let relative = parse_date_arg("7d").unwrap();
+        let expected = Utc::now() - chrono::Duration::days(7);
+        assert!((relative - expected).num_seconds().abs() < 5);
+
+        assert!(parse_date_arg("yesterday").is_err());
+        assert!(parse_date_arg("7y").is_err());
+        assert!(parse_date_arg("").is_err());
+    }
+
+    #[test]
+    fn test_parse_date_arg_rejects_multibyte_and_overflowing_input() {
+        assert!(parse_date_arg("3日").is_err());
+        assert!(parse_date_arg("日").is_err());
+        assert!(parse_date_arg("9223372036854775807d").is_err());
+        assert!(parse_date_arg("-9223372036854775808w").is_err());
+    }
+}
@@ -482,7 +482,11 @@ async fn process_message_streaming(
         schedule_id: None,
         execution_mode: None,
         max_turns: None,
+        goose_mode: None,
         retry_config: None,
+        budget: None,
+        schedule_budget: None,
+        context_strategy: None,
     };
 
     match agent
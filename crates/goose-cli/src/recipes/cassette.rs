@@ -0,0 +1,468 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use futures::StreamExt;
+use goose::agents::{Agent, AgentEvent, ExtensionConfig, SessionConfig};
+use goose::config::Config;
+use goose::conversation::message::{Message, MessageContent};
+use goose::conversation::Conversation;
+use goose::model::ModelConfig;
+use goose::providers::base::Provider;
+use goose::providers::create;
+use goose::providers::testprovider::TestProvider;
+use goose::recipe::build_recipe::build_recipe_from_template;
+use goose::recipe::{Recipe, RecipeParameter};
+use goose::session::SessionManager;
+use rmcp::model::Role;
+use serde::Deserialize;
+use serde_json::Value;
+use tempfile::TempDir;
+
+use crate::recipes::search_recipe::retrieve_recipe_file;
+
+/// File in a cassette directory holding the recorded provider completions
+pub const PROVIDER_CASSETTE_FILE: &str = "provider.json";
+/// Directory in a cassette directory holding one recorded log per stdio MCP server
+pub const MCP_CASSETTE_DIR: &str = "mcp";
+/// File in a cassette directory declaring the expected outcome of the recipe
+pub const EXPECTATIONS_FILE: &str = "expectations.yaml";
+
+/// Expected outcome of a recipe test run, read from `expectations.yaml`
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecipeExpectations {
+    #[serde(default)]
+    pub final_output: Option<FinalOutputExpectation>,
+    #[serde(default)]
+    pub files: Vec<FileExpectation>,
+    #[serde(default)]
+    pub tools_called: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FinalOutputExpectation {
+    /// JSON value the final output must equal, for recipes with a response schema
+    pub equals: Option<Value>,
+    /// Text the final output must contain
+    pub contains: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FileExpectation {
+    /// Path relative to the working directory the recipe ran in
+    pub path: String,
+    /// Text the file must contain
+    pub contains: Option<String>,
+}
+
+pub struct RecipeTestOptions {
+    pub cassette_dir: PathBuf,
+    pub record: bool,
+    pub working_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+pub struct RecipeTestReport {
+    pub final_output: Option<String>,
+    pub tools_called: Vec<String>,
+    pub failures: Vec<String>,
+    pub cassette_misses: Vec<String>,
+}
+
+impl RecipeTestReport {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty() && self.cassette_misses.is_empty()
+    }
+}
+
+/// Runs a recipe against the provider and stdio MCP cassettes in a cassette directory,
+/// or records new cassettes when `record` is set, and checks the declared expectations.
+///
+/// Builtin and remote extensions run for real; only stdio MCP servers are replayed.
+pub async fn run_recipe_test(
+    recipe_name: &str,
+    params: Vec<(String, String)>,
+    options: RecipeTestOptions,
+) -> Result<RecipeTestReport> {
+    let recipe = build_recipe_from_template(
+        retrieve_recipe_file(recipe_name)?,
+        params,
+        None::<fn(&RecipeParameter) -> Result<String>>,
+    )?;
+    let prompt = recipe
+        .prompt
+        .clone()
+        .filter(|p| !p.trim().is_empty())
+        .ok_or_else(|| anyhow!("Recipe '{}' has no prompt to test", recipe.title))?;
+
+    let cassette_dir = std::path::absolute(&options.cassette_dir)?;
+    let provider_cassette = cassette_dir.join(PROVIDER_CASSETTE_FILE);
+    let mcp_cassette_dir = cassette_dir.join(MCP_CASSETTE_DIR);
+    if options.record {
+        fs::create_dir_all(&mcp_cassette_dir)?;
+    } else if !provider_cassette.exists() {
+        return Err(anyhow!(
+            "No provider cassette found at {}; record one with --record",
+            provider_cassette.display()
+        ));
+    }
+
+    let temp_dir = TempDir::new()?;
+    let working_dir = match options.working_dir {
+        Some(dir) => std::path::absolute(dir)?,
+        None => temp_dir.path().to_path_buf(),
+    };
+    fs::create_dir_all(&working_dir)?;
+    // Extensions are spawned in the current directory, so switch before adding them
+    let _current_dir = CurrentDirGuard::enter(&working_dir)?;
+    SessionManager::use_session_dir(temp_dir.path().join("sessions")).await?;
+
+    let provider = Arc::new(if options.record {
+        TestProvider::new_recording(
+            create_recording_provider(&recipe)?,
+            provider_cassette.to_string_lossy(),
+        )
+    } else {
+        TestProvider::new_replaying(provider_cassette.to_string_lossy())?
+    });
+
+    let agent = Agent::new();
+    agent.update_provider(provider.clone()).await?;
+    if let Some(instructions) = &recipe.instructions {
+        agent.extend_system_prompt(instructions.clone()).await;
+    }
+    if let Some(sub_recipes) = recipe.sub_recipes.clone() {
+        agent.add_sub_recipes(sub_recipes).await;
+    }
+    if let Some(response) = recipe.response.clone() {
+        agent.add_final_output_tool(response).await;
+    }
+
+    let mut mcp_cassettes = Vec::new();
+    for mut extension in recipe.extensions.clone().unwrap_or_default() {
+        if let ExtensionConfig::Stdio {
+            name, cmd, args, ..
+        } = &mut extension
+        {
+            let cassette = mcp_cassette_dir.join(format!("{}.log", cassette_file_name(name)));
+            let errors = errors_file(&cassette);
+            if !options.record {
+                if !cassette.exists() {
+                    return Err(anyhow!(
+                        "No MCP cassette for extension '{}' found at {}; record one with --record",
+                        name,
+                        cassette.display()
+                    ));
+                }
+                let _ = fs::remove_file(&errors);
+            }
+
+            let mut capture_args = vec![
+                "recipe".to_string(),
+                "mcp-cassette".to_string(),
+                cassette.to_string_lossy().to_string(),
+            ];
+            if options.record {
+                capture_args.push("--record".to_string());
+                capture_args.push("--".to_string());
+                capture_args.push(cmd.clone());
+                capture_args.extend(args.iter().cloned());
+            }
+            *cmd = env::current_exe()?.to_string_lossy().to_string();
+            *args = capture_args;
+            mcp_cassettes.push((name.clone(), errors));
+        }
+
+        agent
+            .add_extension(extension.clone())
+            .await
+            .map_err(|e| anyhow!("Failed to add extension '{}': {}", extension.name(), e))?;
+    }
+
+    let session = SessionManager::create_session(
+        working_dir.clone(),
+        format!("Recipe test: {}", recipe.title),
+    )
+    .await?;
+    let session_config = SessionConfig {
+        id: session.id,
+        working_dir: working_dir.clone(),
+        schedule_id: None,
+        execution_mode: None,
+        max_turns: None,
+        goose_mode: Some("auto".to_string()),
+        retry_config: recipe.retry.clone(),
+        budget: recipe.settings.as_ref().and_then(|s| s.budget.clone()),
+        schedule_budget: None,
+        context_strategy: recipe.settings.as_ref().and_then(|s| s.context_strategy),
+    };
+
+    let mut report = RecipeTestReport::default();
+    let mut messages = Vec::new();
+    let conversation = Conversation::new_unvalidated(vec![Message::user().with_text(prompt)]);
+    let mut stream = agent
+        .reply(conversation, Some(session_config), None)
+        .await?;
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(AgentEvent::Message(message)) => messages.push(message),
+            Ok(_) => {}
+            Err(e) => {
+                report.failures.push(format!("Agent error: {}", e));
+                break;
+            }
+        }
+    }
+    drop(stream);
+
+    if options.record {
+        provider.save_records()?;
+    }
+
+    report.final_output = messages
+        .iter()
+        .rev()
+        .filter(|m| m.role == Role::Assistant)
+        .map(|m| m.as_concat_text())
+        .find(|text| !text.trim().is_empty());
+    report.tools_called = tools_called(&messages);
+
+    report.cassette_misses = provider.misses();
+    for (name, errors) in &mcp_cassettes {
+        if let Ok(errors) = fs::read_to_string(errors) {
+            if !errors.trim().is_empty() {
+                report.cassette_misses.push(format!(
+                    "MCP cassette for extension '{}' diverged:\n{}",
+                    name,
+                    errors.trim_end()
+                ));
+            }
+        }
+    }
+
+    let expectations_path = cassette_dir.join(EXPECTATIONS_FILE);
+    if expectations_path.exists() {
+        let expectations: RecipeExpectations =
+            serde_yaml::from_str(&fs::read_to_string(&expectations_path)?).with_context(|| {
+                format!("Invalid expectations in {}", expectations_path.display())
+            })?;
+        report.failures.extend(check_expectations(
+            &expectations,
+            report.final_output.as_deref(),
+            &report.tools_called,
+            &working_dir,
+        ));
+    }
+
+    Ok(report)
+}
+
+/// Switches the process into a directory and switches back when dropped
+struct CurrentDirGuard {
+    previous: PathBuf,
+}
+
+impl CurrentDirGuard {
+    fn enter(dir: &Path) -> Result<Self> {
+        let previous = env::current_dir()?;
+        env::set_current_dir(dir)?;
+        Ok(Self { previous })
+    }
+}
+
+impl Drop for CurrentDirGuard {
+    fn drop(&mut self) {
+        let _ = env::set_current_dir(&self.previous);
+    }
+}
+
+fn create_recording_provider(recipe: &Recipe) -> Result<Arc<dyn Provider>> {
+    let config = Config::global();
+    let settings = recipe.settings.as_ref();
+    let provider_name = settings
+        .and_then(|s| s.goose_provider.clone())
+        .or_else(|| config.get_param("GOOSE_PROVIDER").ok())
+        .ok_or_else(|| anyhow!("No provider configured. Run 'goose configure' first"))?;
+    let model_name: String = settings
+        .and_then(|s| s.goose_model.clone())
+        .or_else(|| config.get_param("GOOSE_MODEL").ok())
+        .ok_or_else(|| anyhow!("No model configured. Run 'goose configure' first"))?;
+    let model_config =
+        ModelConfig::new(&model_name)?.with_temperature(settings.and_then(|s| s.temperature));
+    create(&provider_name, model_config)
+}
+
+fn cassette_file_name(extension_name: &str) -> String {
+    extension_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn errors_file(cassette: &Path) -> PathBuf {
+    PathBuf::from(format!("{}.errors.txt", cassette.display()))
+}
+
+fn tools_called(messages: &[Message]) -> Vec<String> {
+    messages
+        .iter()
+        .filter(|m| m.role == Role::Assistant)
+        .flat_map(|m| m.content.iter())
+        .filter_map(|content| match content {
+            MessageContent::ToolRequest(request) => request
+                .tool_call
+                .as_ref()
+                .ok()
+                .map(|call| call.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn check_expectations(
+    expectations: &RecipeExpectations,
+    final_output: Option<&str>,
+    tools_called: &[String],
+    working_dir: &Path,
+) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    if let Some(expected) = &expectations.final_output {
+        if let Some(equals) = &expected.equals {
+            let actual = final_output.map(|output| {
+                serde_json::from_str::<Value>(output)
+                    .unwrap_or_else(|_| Value::String(output.to_string()))
+            });
+            if actual.as_ref() != Some(equals) {
+                failures.push(format!(
+                    "Final output {} does not equal {}",
+                    actual.map_or("(none)".to_string(), |v| v.to_string()),
+                    equals
+                ));
+            }
+        }
+        if let Some(contains) = &expected.contains {
+            if !final_output.is_some_and(|output| output.contains(contains.as_str())) {
+                failures.push(format!("Final output does not contain '{}'", contains));
+            }
+        }
+    }
+
+    for tool in &expectations.tools_called {
+        if !tools_called.contains(tool) {
+            let called = if tools_called.is_empty() {
+                "none".to_string()
+            } else {
+                tools_called.join(", ")
+            };
+            failures.push(format!(
+                "Tool '{}' was not called (called: {})",
+                tool, called
+            ));
+        }
+    }
+
+    for file in &expectations.files {
+        match fs::read_to_string(working_dir.join(&file.path)) {
+            Ok(content) => {
+                if let Some(contains) = &file.contains {
+                    if !content.contains(contains.as_str()) {
+                        failures.push(format!(
+                            "File '{}' does not contain '{}'",
+                            file.path, contains
+                        ));
+                    }
+                }
+            }
+            Err(_) => failures.push(format!("File '{}' was not produced", file.path)),
+        }
+    }
+
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expectations(yaml: &str) -> RecipeExpectations {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_check_expectations_passes() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("report.md"), "# Weekly report\n").unwrap();
+        let expectations = expectations(
+            r#"
+final_output:
+  equals: {"status": "done"}
+files:
+  - path: report.md
+    contains: Weekly report
+tools_called:
+  - developer__text_editor
+"#,
+        );
+
+        let failures = check_expectations(
+            &expectations,
+            Some(r#"{"status":"done"}"#),
+            &["developer__text_editor".to_string()],
+            temp_dir.path(),
+        );
+        assert!(failures.is_empty(), "unexpected failures: {:?}", failures);
+    }
+
+    #[test]
+    fn test_check_expectations_reports_each_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let expectations = expectations(
+            r#"
+final_output:
+  contains: all tests pass
+files:
+  - path: missing.txt
+tools_called:
+  - developer__shell
+"#,
+        );
+
+        let failures = check_expectations(
+            &expectations,
+            Some("Some tests failed"),
+            &[],
+            temp_dir.path(),
+        );
+        assert_eq!(
+            failures,
+            vec![
+                "Final output does not contain 'all tests pass'",
+                "Tool 'developer__shell' was not called (called: none)",
+                "File 'missing.txt' was not produced",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expectations_reject_unknown_fields() {
+        assert!(serde_yaml::from_str::<RecipeExpectations>("tools: [shell]").is_err());
+    }
+
+    #[test]
+    fn test_cassette_file_name() {
+        assert_eq!(cassette_file_name("github mcp/server"), "github_mcp_server");
+        assert_eq!(cassette_file_name("fetch-v2_1"), "fetch-v2_1");
+    }
+}
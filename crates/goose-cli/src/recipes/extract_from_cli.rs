@@ -5,7 +5,7 @@ use goose::recipe::SubRecipe;
 
 use crate::recipes::print_recipe::print_recipe_info;
 use crate::recipes::recipe::load_recipe;
-use crate::recipes::search_recipe::retrieve_recipe_file;
+use crate::recipes::search_recipe::{lock_registry_recipe, retrieve_recipe_file};
 use crate::{
     cli::{InputConfig, RecipeInfo},
     session::SessionSettings,
@@ -16,6 +16,7 @@ pub fn extract_recipe_info_from_cli(
     params: Vec<(String, String)>,
     additional_sub_recipes: Vec<String>,
 ) -> Result<(InputConfig, RecipeInfo)> {
+    lock_registry_recipe(&recipe_name)?;
     let recipe = load_recipe(&recipe_name, params.clone()).unwrap_or_else(|err| {
         eprintln!("{}: {}", console::style("Error").red().bold(), err);
         std::process::exit(1);
@@ -34,6 +35,7 @@ pub fn extract_recipe_info_from_cli(
                         values: None,
                         sequential_when_repeated: true,
                         description: None,
+                        depends_on: None,
                     };
                     all_sub_recipes.push(additional_sub_recipe);
                 }
@@ -58,6 +60,8 @@ pub fn extract_recipe_info_from_cli(
             goose_provider: s.goose_provider,
             goose_model: s.goose_model,
             temperature: s.temperature,
+            budget: s.budget,
+            context_strategy: s.context_strategy,
         }),
         sub_recipes: Some(all_sub_recipes),
         final_output_response: recipe.response,
@@ -25,6 +25,7 @@ pub struct RecipeInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RecipeSource {
     Local,
+    Registry,
     GitHub,
 }
 
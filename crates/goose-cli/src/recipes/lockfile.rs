@@ -0,0 +1,168 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const RECIPE_LOCKFILE_NAME: &str = "goose-recipes.lock";
+const RECIPE_LOCKFILE_VERSION: u32 = 1;
+const RECIPE_LOCKFILE_HEADER: &str = "# This file is generated by goose. Do not edit it by hand.\n";
+
+/// Recipe package versions pinned for a project, so that everyone running a recipe
+/// by name, including scheduled jobs, gets exactly the same files.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecipeLockfile {
+    pub version: u32,
+    #[serde(default)]
+    pub packages: Vec<LockedPackage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    /// Version requirement the package was resolved from, e.g. `^1.2`
+    pub requirement: String,
+    /// Registry the package was resolved from, a directory or a git URL
+    pub source: String,
+    /// Commit of a git registry the package was resolved at, fetched again when restoring
+    /// the package from the lockfile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// `sha256:` digest of every file in the package
+    pub checksum: String,
+}
+
+impl Default for RecipeLockfile {
+    fn default() -> Self {
+        Self {
+            version: RECIPE_LOCKFILE_VERSION,
+            packages: Vec::new(),
+        }
+    }
+}
+
+impl RecipeLockfile {
+    pub fn path(dir: &Path) -> PathBuf {
+        dir.join(RECIPE_LOCKFILE_NAME)
+    }
+
+    /// Loads the lockfile in the directory, or an empty one when there is none yet.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = Self::path(dir);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)?;
+        let lockfile: Self = serde_yaml::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse {}: {}", path.display(), e))?;
+        if lockfile.version != RECIPE_LOCKFILE_VERSION {
+            return Err(anyhow!(
+                "Unsupported {} version {}",
+                RECIPE_LOCKFILE_NAME,
+                lockfile.version
+            ));
+        }
+        Ok(lockfile)
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let content = serde_yaml::to_string(self)?;
+        fs::write(
+            Self::path(dir),
+            format!("{}{}", RECIPE_LOCKFILE_HEADER, content),
+        )?;
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&LockedPackage> {
+        self.packages.iter().find(|package| package.name == name)
+    }
+
+    /// Adds the package, replacing any version of it that was locked before.
+    pub fn insert(&mut self, package: LockedPackage) {
+        self.packages.retain(|locked| locked.name != package.name);
+        self.packages.push(package);
+        self.packages.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn locked_package(name: &str, version: &str) -> LockedPackage {
+        LockedPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            requirement: "*".to_string(),
+            source: "/registry".to_string(),
+            commit: None,
+            checksum: "sha256:abc".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_load_missing_lockfile() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let lockfile = RecipeLockfile::load(temp_dir.path()).unwrap();
+
+        assert_eq!(lockfile, RecipeLockfile::default());
+    }
+
+    #[test]
+    fn test_save_and_load_lockfile() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut lockfile = RecipeLockfile::default();
+        lockfile.insert(locked_package("review", "1.0.0"));
+        lockfile.insert(locked_package("deploy", "2.1.0"));
+        lockfile.insert(locked_package("review", "1.1.0"));
+
+        lockfile.save(temp_dir.path()).unwrap();
+        let loaded = RecipeLockfile::load(temp_dir.path()).unwrap();
+
+        let names: Vec<&str> = loaded.packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["deploy", "review"]);
+        assert_eq!(loaded.get("review").unwrap().version, "1.1.0");
+        let content = fs::read_to_string(RecipeLockfile::path(temp_dir.path())).unwrap();
+        assert!(content.starts_with(RECIPE_LOCKFILE_HEADER));
+    }
+
+    #[test]
+    fn test_lockfile_keeps_registry_commit() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut lockfile = RecipeLockfile::default();
+        lockfile.insert(LockedPackage {
+            source: "https://github.com/acme/recipes.git".to_string(),
+            commit: Some("4b825dc642cb6eb9a060e54bf8d69288fbee4904".to_string()),
+            ..locked_package("review", "1.0.0")
+        });
+        lockfile.insert(locked_package("deploy", "2.1.0"));
+
+        lockfile.save(temp_dir.path()).unwrap();
+        let content = fs::read_to_string(RecipeLockfile::path(temp_dir.path())).unwrap();
+        let loaded = RecipeLockfile::load(temp_dir.path()).unwrap();
+
+        assert_eq!(content.matches("commit:").count(), 1);
+        assert_eq!(
+            loaded.get("review").unwrap().commit.as_deref(),
+            Some("4b825dc642cb6eb9a060e54bf8d69288fbee4904")
+        );
+        assert_eq!(loaded.get("deploy").unwrap().commit, None);
+    }
+
+    #[test]
+    fn test_load_unsupported_lockfile_version() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            RecipeLockfile::path(temp_dir.path()),
+            "version: 2\npackages: []\n",
+        )
+        .unwrap();
+
+        let err = RecipeLockfile::load(temp_dir.path()).unwrap_err();
+
+        assert_eq!(err.to_string(), "Unsupported goose-recipes.lock version 2");
+    }
+}
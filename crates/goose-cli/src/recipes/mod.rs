@@ -1,6 +1,9 @@
+pub mod cassette;
 pub mod extract_from_cli;
 pub mod github_recipe;
+pub mod lockfile;
 pub mod print_recipe;
 pub mod recipe;
+pub mod registry;
 pub mod search_recipe;
 pub mod secret_discovery;
@@ -7,20 +7,46 @@ use crate::recipes::secret_discovery::{discover_recipe_secrets, SecretRequiremen
 use anyhow::Result;
 use goose::config::Config;
 use goose::recipe::build_recipe::{
-    apply_values_to_parameters, build_recipe_from_template, validate_recipe_parameters, RecipeError,
+    apply_values_to_parameters, build_recipe_from_template, load_recipe_chain, recipe_chain_title,
+    render_recipe_chain_for_preview, validate_parameter_value, validate_recipe_chain_parameters,
+    RecipeError, REDACTED_SECRET_VALUE,
 };
 use goose::recipe::read_recipe_file_content::RecipeFile;
-use goose::recipe::template_recipe::render_recipe_for_preview;
-use goose::recipe::Recipe;
+use goose::recipe::{Recipe, RecipeParameter, RecipeParameterInputType};
 use serde_json::Value;
 use std::collections::HashMap;
 
 pub const RECIPE_FILE_EXTENSIONS: &[&str] = &["yaml", "json"];
 
-fn create_user_prompt_callback() -> impl Fn(&str, &str) -> Result<String> {
-    |key: &str, description: &str| -> Result<String> {
-        let input_value =
-            cliclack::input(format!("Please enter {} ({})", key, description)).interact()?;
+fn create_user_prompt_callback() -> impl Fn(&RecipeParameter) -> Result<String> {
+    |param: &RecipeParameter| -> Result<String> {
+        let prompt = format!("Please enter {} ({})", param.key, param.description);
+        let options: Vec<(String, String, String)> = param
+            .options
+            .iter()
+            .flatten()
+            .map(|option| (option.clone(), option.clone(), String::new()))
+            .collect();
+        let validation_param = param.clone();
+        let validator = move |input: &String| validate_parameter_value(&validation_param, input);
+
+        let input_value = match param.input_type {
+            RecipeParameterInputType::Select => {
+                cliclack::select(prompt).items(&options).interact()?
+            }
+            RecipeParameterInputType::MultiSelect => {
+                let selected: Vec<String> = cliclack::multiselect(prompt)
+                    .required(false)
+                    .items(&options)
+                    .interact()?;
+                selected.join(", ")
+            }
+            RecipeParameterInputType::Secret => cliclack::password(prompt)
+                .mask('▪')
+                .validate(validator)
+                .interact()?,
+            _ => cliclack::input(prompt).validate(validator).interact()?,
+        };
         Ok(input_value)
     }
 }
@@ -134,14 +160,9 @@ pub fn render_recipe_as_yaml(recipe_name: &str, params: Vec<(String, String)>) -
 }
 
 pub fn load_recipe_for_validation(recipe_name: &str) -> Result<Recipe> {
-    let (recipe_file, recipe_dir_str) = load_recipe_file_with_dir(recipe_name)?;
-    let recipe_file_content = &recipe_file.content;
-    validate_recipe_parameters(recipe_file_content, &recipe_dir_str)?;
-    let recipe = render_recipe_for_preview(
-        recipe_file_content,
-        recipe_dir_str.to_string(),
-        &HashMap::new(),
-    )?;
+    let chain = load_recipe_chain(retrieve_recipe_file(recipe_name)?)?;
+    validate_recipe_chain_parameters(&chain)?;
+    let recipe = render_recipe_chain_for_preview(&chain, &HashMap::new())?;
 
     if let Some(response) = &recipe.response {
         if let Some(json_schema) = &response.json_schema {
@@ -154,20 +175,28 @@ pub fn load_recipe_for_validation(recipe_name: &str) -> Result<Recipe> {
 
 pub fn explain_recipe(recipe_name: &str, params: Vec<(String, String)>) -> Result<()> {
     let (recipe_file, recipe_dir_str) = load_recipe_file_with_dir(recipe_name)?;
-    let recipe_file_content = &recipe_file.content;
-    let recipe_parameters = validate_recipe_parameters(recipe_file_content, &recipe_dir_str)?;
+    let chain = load_recipe_chain(recipe_file)?;
+    let recipe_parameters = validate_recipe_chain_parameters(&chain)?;
+    let secret_keys: Vec<String> = recipe_parameters
+        .iter()
+        .flatten()
+        .filter(|param| param.is_secret())
+        .map(|param| param.key.clone())
+        .collect();
 
-    let (params_for_template, missing_params) = apply_values_to_parameters(
+    let (mut params_for_template, missing_params) = apply_values_to_parameters(
         &params,
         recipe_parameters,
         &recipe_dir_str,
-        None::<fn(&str, &str) -> Result<String>>,
-    )?;
-    let recipe = render_recipe_for_preview(
-        recipe_file_content,
-        recipe_dir_str.to_string(),
-        &params_for_template,
+        &recipe_chain_title(&chain)?,
+        None::<fn(&RecipeParameter) -> Result<String>>,
     )?;
+    for key in &secret_keys {
+        if let Some(value) = params_for_template.get_mut(key) {
+            *value = REDACTED_SECRET_VALUE.to_string();
+        }
+    }
+    let recipe = render_recipe_chain_for_preview(&chain, &params_for_template)?;
     print_recipe_explanation(&recipe);
     print_required_parameters_for_template(params_for_template, missing_params);
 
@@ -0,0 +1,672 @@
+use anyhow::{anyhow, Context, Result};
+use etcetera::{choose_app_strategy, AppStrategy};
+use goose::config::{Config, APP_STRATEGY};
+use goose::recipe::read_recipe_file_content::{read_recipe_file, RecipeFile};
+use semver::{Version, VersionReq};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::recipes::lockfile::{LockedPackage, RecipeLockfile, RECIPE_LOCKFILE_NAME};
+use crate::recipes::recipe::RECIPE_FILE_EXTENSIONS;
+
+pub const GOOSE_RECIPE_REGISTRY_CONFIG_KEY: &str = "GOOSE_RECIPE_REGISTRY";
+const PACKAGE_REFERENCE_SEPARATOR: char = '@';
+
+/// A reference to a recipe package in a registry, e.g. `code-review` or `code-review@^1.2`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageReference {
+    pub name: String,
+    pub version_req: VersionReq,
+}
+
+impl PackageReference {
+    pub fn parse(reference: &str) -> Result<Self> {
+        let (name, version_req) = match reference.split_once(PACKAGE_REFERENCE_SEPARATOR) {
+            Some((name, version_req)) => (
+                name,
+                VersionReq::parse(version_req).map_err(|e| {
+                    anyhow!("Invalid version requirement in '{}': {}", reference, e)
+                })?,
+            ),
+            None => (reference, VersionReq::STAR),
+        };
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(anyhow!("Invalid recipe package name '{}'", name));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            version_req,
+        })
+    }
+
+    /// Whether the recipe name explicitly refers to a registry package version.
+    pub fn is_versioned(recipe_name: &str) -> bool {
+        recipe_name.contains(PACKAGE_REFERENCE_SEPARATOR)
+    }
+}
+
+/// Where a registry index lives: a local directory or a git repository.
+///
+/// Either way the index holds one directory per package, with one directory per
+/// semver version holding the package's `recipe.yaml` and any files it references,
+/// e.g. `code-review/1.2.0/recipe.yaml`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistrySource {
+    Directory(PathBuf),
+    Git(String),
+}
+
+impl RegistrySource {
+    pub fn parse(location: &str) -> Self {
+        let is_git_url = ["https://", "http://", "ssh://", "git@", "file://"]
+            .iter()
+            .any(|prefix| location.starts_with(prefix))
+            || location.ends_with(".git");
+        if is_git_url {
+            Self::Git(location.to_string())
+        } else {
+            Self::Directory(PathBuf::from(location))
+        }
+    }
+
+    pub fn location(&self) -> String {
+        match self {
+            Self::Directory(dir) => dir.to_string_lossy().into_owned(),
+            Self::Git(url) => url.clone(),
+        }
+    }
+}
+
+/// A version of a recipe package: a recipe with its sub-recipes and referenced files.
+#[derive(Debug, Clone)]
+pub struct RecipePackage {
+    pub name: String,
+    pub version: Version,
+    pub dir: PathBuf,
+}
+
+impl RecipePackage {
+    pub fn recipe_file(&self) -> Result<RecipeFile> {
+        RECIPE_FILE_EXTENSIONS
+            .iter()
+            .map(|ext| self.dir.join(format!("recipe.{}", ext)))
+            .find(|path| path.is_file())
+            .ok_or_else(|| {
+                anyhow!(
+                    "Recipe package {}@{} has no recipe.yaml or recipe.json",
+                    self.name,
+                    self.version
+                )
+            })
+            .and_then(read_recipe_file)
+    }
+
+    /// Digest of every file in the package, used to detect packages that changed in the
+    /// registry after they were locked.
+    pub fn checksum(&self) -> Result<String> {
+        let mut files = Vec::new();
+        collect_files(&self.dir, &mut files)?;
+        files.sort();
+
+        let mut hasher = Sha256::new();
+        for file in files {
+            let relative_path = file
+                .strip_prefix(&self.dir)?
+                .to_string_lossy()
+                .replace('\\', "/");
+            hasher.update(relative_path.as_bytes());
+            hasher.update([0]);
+            hasher.update(fs::read(&file)?);
+            hasher.update([0]);
+        }
+        Ok(format!("sha256:{:x}", hasher.finalize()))
+    }
+
+    fn to_locked(
+        &self,
+        requirement: &VersionReq,
+        source: &RegistrySource,
+    ) -> Result<LockedPackage> {
+        let commit = match source {
+            RegistrySource::Git(_) => Some(run_git(&["rev-parse", "HEAD"], Some(&self.dir))?),
+            RegistrySource::Directory(_) => None,
+        };
+        Ok(LockedPackage {
+            name: self.name.clone(),
+            version: self.version.to_string(),
+            requirement: requirement.to_string(),
+            source: source.location(),
+            commit,
+            checksum: self.checksum()?,
+        })
+    }
+}
+
+pub struct RecipeRegistry {
+    source: RegistrySource,
+}
+
+impl RecipeRegistry {
+    pub fn new(source: RegistrySource) -> Self {
+        Self { source }
+    }
+
+    pub fn configured() -> Option<Self> {
+        match Config::global().get_param::<String>(GOOSE_RECIPE_REGISTRY_CONFIG_KEY) {
+            Ok(location) if !location.is_empty() => {
+                Some(Self::new(RegistrySource::parse(&location)))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn source(&self) -> &RegistrySource {
+        &self.source
+    }
+
+    /// Resolves the newest version of the package matching the reference.
+    pub fn resolve(&self, reference: &PackageReference) -> Result<RecipePackage> {
+        let index_dir = self.index_dir(true)?;
+        let version = package_versions(&index_dir, &reference.name)?
+            .into_iter()
+            .filter(|version| reference.version_req.matches(version))
+            .max()
+            .ok_or_else(|| {
+                anyhow!(
+                    "No version of recipe package '{}' matches '{}' in registry {}",
+                    reference.name,
+                    reference.version_req,
+                    self.source.location()
+                )
+            })?;
+        Ok(package_in(&index_dir, &reference.name, version))
+    }
+
+    /// Whether the registry's index has a package with the name, fetching git registries
+    /// again when their cached index does not have it.
+    pub fn has_package(&self, name: &str) -> Result<bool> {
+        if PackageReference::parse(name).is_err() || PackageReference::is_versioned(name) {
+            return Ok(false);
+        }
+        if self.index_dir(false)?.join(name).is_dir() {
+            return Ok(true);
+        }
+        Ok(matches!(self.source, RegistrySource::Git(_))
+            && self.index_dir(true)?.join(name).is_dir())
+    }
+
+    /// Finds the locked version of a package and checks that its files are the ones
+    /// that were locked. Git registries are checked out at the locked commit, or fetched
+    /// again for packages locked without one.
+    pub fn locked_package(&self, locked: &LockedPackage) -> Result<RecipePackage> {
+        let version = Version::parse(&locked.version)?;
+        let index_dir = match (&self.source, &locked.commit) {
+            (RegistrySource::Git(url), Some(commit)) => index_dir_at_commit(url, commit)?,
+            _ => self.index_dir(false)?,
+        };
+        let mut package = package_in(&index_dir, &locked.name, version.clone());
+        let mut checksum = package
+            .dir
+            .is_dir()
+            .then(|| package.checksum())
+            .transpose()?;
+        if checksum.as_deref() != Some(locked.checksum.as_str())
+            && matches!(self.source, RegistrySource::Git(_))
+            && locked.commit.is_none()
+        {
+            package = package_in(&self.index_dir(true)?, &locked.name, version);
+            checksum = package
+                .dir
+                .is_dir()
+                .then(|| package.checksum())
+                .transpose()?;
+        }
+
+        match checksum {
+            None => Err(anyhow!(
+                "Locked recipe package {}@{} was not found in registry {}",
+                locked.name,
+                locked.version,
+                self.source.location()
+            )),
+            Some(checksum) if checksum != locked.checksum => Err(anyhow!(
+                "Checksum mismatch for recipe package {}@{}: locked {}, found {}. The package changed in the registry after it was locked",
+                locked.name,
+                locked.version,
+                locked.checksum,
+                checksum
+            )),
+            Some(_) => Ok(package),
+        }
+    }
+
+    /// Lists the newest version of every package in the registry.
+    pub fn latest_packages(&self) -> Result<Vec<RecipePackage>> {
+        let index_dir = self.index_dir(true)?;
+        let mut packages = Vec::new();
+        for entry in fs::read_dir(&index_dir)? {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if name.starts_with('.') || !path.is_dir() {
+                continue;
+            }
+            if let Some(version) = package_versions(&index_dir, name)?.into_iter().max() {
+                packages.push(package_in(&index_dir, name, version));
+            }
+        }
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(packages)
+    }
+
+    fn index_dir(&self, refresh: bool) -> Result<PathBuf> {
+        match &self.source {
+            RegistrySource::Directory(dir) => {
+                if dir.is_dir() {
+                    Ok(dir.clone())
+                } else {
+                    Err(anyhow!("Recipe registry {} does not exist", dir.display()))
+                }
+            }
+            RegistrySource::Git(url) => {
+                let checkout_dir = registry_cache_dir()?.join(cache_key(url));
+                if !checkout_dir.join(".git").exists() {
+                    run_git(
+                        &[
+                            "clone",
+                            "--depth",
+                            "1",
+                            url,
+                            &checkout_dir.to_string_lossy(),
+                        ],
+                        None,
+                    )?;
+                } else if refresh {
+                    run_git(
+                        &["fetch", "--depth", "1", "origin", "HEAD"],
+                        Some(&checkout_dir),
+                    )?;
+                    run_git(&["reset", "--hard", "FETCH_HEAD"], Some(&checkout_dir))?;
+                }
+                Ok(checkout_dir)
+            }
+        }
+    }
+}
+
+/// Checks out a git registry at a commit, in its own cache directory so that the checkout
+/// of the registry's latest commit is left alone.
+fn index_dir_at_commit(url: &str, commit: &str) -> Result<PathBuf> {
+    if commit.is_empty() || !commit.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(anyhow!("Invalid registry commit '{}' in lockfile", commit));
+    }
+    let checkout_dir = registry_cache_dir()?.join(format!("{}-{}", cache_key(url), commit));
+    if checkout_dir.join(".git").exists() {
+        return Ok(checkout_dir);
+    }
+
+    // Check out next to the final directory, so an interrupted fetch is never mistaken
+    // for a complete checkout
+    let partial_dir = checkout_dir.with_extension("partial");
+    if partial_dir.exists() {
+        fs::remove_dir_all(&partial_dir)?;
+    }
+    fs::create_dir_all(&partial_dir)?;
+    run_git(&["init", "--quiet"], Some(&partial_dir))?;
+    run_git(&["fetch", "--depth", "1", url, commit], Some(&partial_dir))?;
+    run_git(
+        &["checkout", "--quiet", "--detach", "FETCH_HEAD"],
+        Some(&partial_dir),
+    )?;
+    fs::rename(&partial_dir, &checkout_dir)?;
+    Ok(checkout_dir)
+}
+
+/// Retrieves a recipe package by reference, using the version pinned in the current
+/// directory's lockfile. The lockfile is left untouched.
+pub fn retrieve_recipe_from_registry(reference: &str) -> Result<RecipeFile> {
+    retrieve_package(&env::current_dir()?, reference, false)?.recipe_file()
+}
+
+/// Resolves a recipe package by reference like [`retrieve_recipe_from_registry`], and pins
+/// the resolved version in the current directory's lockfile when it is not locked yet.
+pub fn lock_recipe_from_registry(reference: &str) -> Result<RecipePackage> {
+    retrieve_package(&env::current_dir()?, reference, true)
+}
+
+fn retrieve_package(lockfile_dir: &Path, reference: &str, lock: bool) -> Result<RecipePackage> {
+    let mut lockfile = RecipeLockfile::load(lockfile_dir)?;
+    let (package, locked) = resolve_package(reference, &lockfile, false)?;
+    if lock && lockfile.get(&locked.name) != Some(&locked) {
+        eprintln!(
+            "🔒 Locked recipe package {}@{} in {}",
+            locked.name, locked.version, RECIPE_LOCKFILE_NAME
+        );
+        lockfile.insert(locked);
+        lockfile.save(lockfile_dir)?;
+    }
+    Ok(package)
+}
+
+/// Whether the recipe name refers to a registry package: an explicit `name@version`, a
+/// package pinned in the current directory's lockfile, or a package in the configured
+/// registry's index. Other names are left to the GitHub and local lookups.
+pub fn is_registry_recipe(recipe_name: &str) -> bool {
+    if PackageReference::is_versioned(recipe_name) {
+        return true;
+    }
+    let is_locked = env::current_dir()
+        .ok()
+        .and_then(|dir| RecipeLockfile::load(&dir).ok())
+        .is_some_and(|lockfile| lockfile.get(recipe_name).is_some());
+    is_locked
+        || RecipeRegistry::configured().is_some_and(|registry| {
+            registry.has_package(recipe_name).unwrap_or_else(|e| {
+                eprintln!("⚠️  Could not read recipe registry: {}", e);
+                false
+            })
+        })
+}
+
+/// Resolves the packages and pins them in the lockfile in the directory. Without
+/// references, every locked package is checked again, or re-resolved when updating.
+pub fn lock_recipe_packages(
+    lockfile_dir: &Path,
+    references: &[String],
+    update: bool,
+) -> Result<Vec<LockedPackage>> {
+    let mut lockfile = RecipeLockfile::load(lockfile_dir)?;
+    let references: Vec<String> = if references.is_empty() {
+        lockfile
+            .packages
+            .iter()
+            .map(|locked| format!("{}@{}", locked.name, locked.requirement))
+            .collect()
+    } else {
+        references.to_vec()
+    };
+
+    let mut locked_packages = Vec::new();
+    for reference in &references {
+        let (_, locked) = resolve_package(reference, &lockfile, update)?;
+        lockfile.insert(locked.clone());
+        locked_packages.push(locked);
+    }
+    lockfile.save(lockfile_dir)?;
+    Ok(locked_packages)
+}
+
+fn resolve_package(
+    reference: &str,
+    lockfile: &RecipeLockfile,
+    update: bool,
+) -> Result<(RecipePackage, LockedPackage)> {
+    let reference = PackageReference::parse(reference)?;
+    let locked = lockfile.get(&reference.name);
+
+    if let Some(locked) = locked.filter(|_| !update) {
+        let version = Version::parse(&locked.version)?;
+        if reference.version_req.matches(&version) {
+            let registry = RecipeRegistry::new(RegistrySource::parse(&locked.source));
+            return Ok((registry.locked_package(locked)?, locked.clone()));
+        }
+    }
+
+    let registry = RecipeRegistry::configured()
+        .or_else(|| locked.map(|l| RecipeRegistry::new(RegistrySource::parse(&l.source))))
+        .ok_or_else(|| {
+            anyhow!(
+                "No recipe registry configured. Set {} to a directory or git URL",
+                GOOSE_RECIPE_REGISTRY_CONFIG_KEY
+            )
+        })?;
+    let package = registry.resolve(&reference)?;
+    let locked = package.to_locked(&reference.version_req, registry.source())?;
+    Ok((package, locked))
+}
+
+fn package_versions(index_dir: &Path, name: &str) -> Result<Vec<Version>> {
+    let package_dir = index_dir.join(name);
+    if !package_dir.is_dir() {
+        return Err(anyhow!("Recipe package '{}' not found in registry", name));
+    }
+    let mut versions = Vec::new();
+    for entry in fs::read_dir(&package_dir)? {
+        let path = entry?.path();
+        let version = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| Version::parse(n).ok());
+        if let Some(version) = version.filter(|_| path.is_dir()) {
+            versions.push(version);
+        }
+    }
+    Ok(versions)
+}
+
+fn package_in(index_dir: &Path, name: &str, version: Version) -> RecipePackage {
+    RecipePackage {
+        name: name.to_string(),
+        dir: index_dir.join(name).join(version.to_string()),
+        version,
+    }
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn registry_cache_dir() -> Result<PathBuf> {
+    let dir = choose_app_strategy(APP_STRATEGY.clone())
+        .context("goose requires a home dir")?
+        .in_cache_dir("recipe_registries");
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn cache_key(url: &str) -> String {
+    let digest = format!("{:x}", Sha256::digest(url.as_bytes()));
+    digest[..16].to_string()
+}
+
+/// Runs git and returns its trimmed output.
+fn run_git(args: &[&str], dir: Option<&Path>) -> Result<String> {
+    let mut command = Command::new("git");
+    command.args(args);
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+    let output = command
+        .output()
+        .map_err(|e| anyhow!("Failed to run git {}: {}", args.join(" "), e))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_package(registry: &Path, name: &str, version: &str, instructions: &str) {
+        let dir = registry.join(name).join(version);
+        fs::create_dir_all(dir.join("subrecipes")).unwrap();
+        fs::write(
+            dir.join("recipe.yaml"),
+            format!(
+                "version: 1.0.0\ntitle: {}\ndescription: A packaged recipe\ninstructions: {}\n",
+                name, instructions
+            ),
+        )
+        .unwrap();
+        fs::write(dir.join("subrecipes/check.yaml"), "title: check\n").unwrap();
+    }
+
+    #[test]
+    fn test_parse_package_reference() {
+        let reference = PackageReference::parse("code-review@^1.2").unwrap();
+        assert_eq!(reference.name, "code-review");
+        assert!(reference.version_req.matches(&Version::new(1, 4, 0)));
+        assert!(!reference.version_req.matches(&Version::new(2, 0, 0)));
+
+        let reference = PackageReference::parse("code-review").unwrap();
+        assert_eq!(reference.version_req, VersionReq::STAR);
+
+        assert!(PackageReference::parse("../review@1.0.0").is_err());
+        assert!(PackageReference::parse("review@one").is_err());
+    }
+
+    #[test]
+    fn test_parse_registry_source() {
+        assert_eq!(
+            RegistrySource::parse("https://github.com/acme/recipes.git"),
+            RegistrySource::Git("https://github.com/acme/recipes.git".to_string())
+        );
+        assert_eq!(
+            RegistrySource::parse("git@github.com:acme/recipes.git"),
+            RegistrySource::Git("git@github.com:acme/recipes.git".to_string())
+        );
+        assert_eq!(
+            RegistrySource::parse("/shared/recipes"),
+            RegistrySource::Directory(PathBuf::from("/shared/recipes"))
+        );
+    }
+
+    #[test]
+    fn test_resolve_newest_matching_version() {
+        let registry_dir = TempDir::new().unwrap();
+        write_package(registry_dir.path(), "review", "1.0.0", "v1.0");
+        write_package(registry_dir.path(), "review", "1.3.0", "v1.3");
+        write_package(registry_dir.path(), "review", "2.0.0", "v2.0");
+        let registry =
+            RecipeRegistry::new(RegistrySource::Directory(registry_dir.path().to_path_buf()));
+
+        let package = registry
+            .resolve(&PackageReference::parse("review@^1").unwrap())
+            .unwrap();
+        assert_eq!(package.version, Version::new(1, 3, 0));
+        let recipe_file = package.recipe_file().unwrap();
+        assert!(recipe_file.content.contains("instructions: v1.3"));
+        assert_eq!(recipe_file.parent_dir, package.dir);
+
+        let err = registry
+            .resolve(&PackageReference::parse("review@^3").unwrap())
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("No version of recipe package 'review' matches '^3'"));
+    }
+
+    #[test]
+    fn test_has_package_only_matches_packages_in_index() {
+        let registry_dir = TempDir::new().unwrap();
+        write_package(registry_dir.path(), "review", "1.0.0", "v1.0");
+        let source = RegistrySource::Directory(registry_dir.path().to_path_buf());
+        let registry = RecipeRegistry::new(source.clone());
+
+        assert!(registry.has_package("review").unwrap());
+        assert!(!registry.has_package("deploy").unwrap());
+        assert!(!registry.has_package("../review").unwrap());
+
+        let package = registry
+            .resolve(&PackageReference::parse("review").unwrap())
+            .unwrap();
+        let locked = package.to_locked(&VersionReq::STAR, &source).unwrap();
+        assert_eq!(locked.commit, None);
+    }
+
+    #[test]
+    fn test_locked_package_detects_changed_files() {
+        let registry_dir = TempDir::new().unwrap();
+        write_package(registry_dir.path(), "review", "1.0.0", "original");
+        let source = RegistrySource::Directory(registry_dir.path().to_path_buf());
+        let registry = RecipeRegistry::new(source.clone());
+        let package = registry
+            .resolve(&PackageReference::parse("review").unwrap())
+            .unwrap();
+        let locked = package.to_locked(&VersionReq::STAR, &source).unwrap();
+        assert!(locked.checksum.starts_with("sha256:"));
+
+        assert!(registry.locked_package(&locked).is_ok());
+
+        fs::write(
+            package.dir.join("subrecipes/check.yaml"),
+            "title: changed\n",
+        )
+        .unwrap();
+        let err = registry.locked_package(&locked).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Checksum mismatch for recipe package review@1.0.0"));
+    }
+
+    #[test]
+    fn test_lock_recipe_packages_keeps_locked_versions() {
+        let registry_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        write_package(registry_dir.path(), "review", "1.0.0", "v1.0");
+        let mut lockfile = RecipeLockfile::default();
+        let source = RegistrySource::Directory(registry_dir.path().to_path_buf());
+        let package = RecipeRegistry::new(source.clone())
+            .resolve(&PackageReference::parse("review").unwrap())
+            .unwrap();
+        lockfile.insert(package.to_locked(&VersionReq::STAR, &source).unwrap());
+        lockfile.save(project_dir.path()).unwrap();
+        write_package(registry_dir.path(), "review", "1.1.0", "v1.1");
+
+        let locked = lock_recipe_packages(project_dir.path(), &[], false).unwrap();
+        assert_eq!(locked[0].version, "1.0.0");
+
+        let locked = lock_recipe_packages(project_dir.path(), &[], true).unwrap();
+        assert_eq!(locked[0].version, "1.1.0");
+        let lockfile = RecipeLockfile::load(project_dir.path()).unwrap();
+        assert_eq!(lockfile.get("review").unwrap().version, "1.1.0");
+    }
+
+    #[test]
+    fn test_retrieve_package_only_writes_lockfile_when_locking() {
+        let registry_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        write_package(registry_dir.path(), "review", "1.0.0", "v1.0");
+        let source = RegistrySource::Directory(registry_dir.path().to_path_buf());
+        let package = RecipeRegistry::new(source.clone())
+            .resolve(&PackageReference::parse("review").unwrap())
+            .unwrap();
+        let mut lockfile = RecipeLockfile::default();
+        lockfile.insert(package.to_locked(&VersionReq::STAR, &source).unwrap());
+        lockfile.save(project_dir.path()).unwrap();
+        write_package(registry_dir.path(), "review", "1.1.0", "v1.1");
+
+        let package = retrieve_package(project_dir.path(), "review@>=1.1", false).unwrap();
+        assert_eq!(package.version.to_string(), "1.1.0");
+        let lockfile = RecipeLockfile::load(project_dir.path()).unwrap();
+        assert_eq!(lockfile.get("review").unwrap().version, "1.0.0");
+
+        retrieve_package(project_dir.path(), "review@>=1.1", true).unwrap();
+        let lockfile = RecipeLockfile::load(project_dir.path()).unwrap();
+        assert_eq!(lockfile.get("review").unwrap().version, "1.1.0");
+    }
+}
@@ -12,6 +12,10 @@ use super::github_recipe::{
     list_github_recipes, retrieve_recipe_from_github, RecipeInfo, RecipeSource,
     GOOSE_RECIPE_GITHUB_REPO_CONFIG_KEY,
 };
+use super::registry::{
+    is_registry_recipe, lock_recipe_from_registry, retrieve_recipe_from_registry, PackageReference,
+    RecipeRegistry,
+};
 
 const GOOSE_RECIPE_PATH_ENV_VAR: &str = "GOOSE_RECIPE_PATH";
 
@@ -23,6 +27,9 @@ pub fn retrieve_recipe_file(recipe_name: &str) -> Result<RecipeFile> {
         let path = PathBuf::from(recipe_name);
         return read_recipe_file(path);
     }
+    if PackageReference::is_versioned(recipe_name) && !is_file_path(recipe_name) {
+        return retrieve_recipe_from_registry(recipe_name);
+    }
     if is_file_path(recipe_name) || is_file_name(recipe_name) {
         return Err(anyhow!(
             "Recipe file {} is not a json or yaml file",
@@ -30,7 +37,9 @@ pub fn retrieve_recipe_file(recipe_name: &str) -> Result<RecipeFile> {
         ));
     }
     retrieve_recipe_from_local_path(recipe_name).or_else(|e| {
-        if let Some(recipe_repo_full_name) = configured_github_recipe_repo() {
+        if is_registry_recipe(recipe_name) {
+            retrieve_recipe_from_registry(recipe_name)
+        } else if let Some(recipe_repo_full_name) = configured_github_recipe_repo() {
             retrieve_recipe_from_github(recipe_name, &recipe_repo_full_name)
         } else {
             Err(e)
@@ -38,6 +47,26 @@ pub fn retrieve_recipe_file(recipe_name: &str) -> Result<RecipeFile> {
     })
 }
 
+/// Pins the recipe in the current directory's lockfile when `retrieve_recipe_file` would
+/// retrieve it from the recipe registry, so later runs use the same version.
+pub fn lock_registry_recipe(recipe_name: &str) -> Result<()> {
+    let from_registry = if PackageReference::is_versioned(recipe_name) {
+        !is_file_path(recipe_name)
+            && !RECIPE_FILE_EXTENSIONS
+                .iter()
+                .any(|ext| recipe_name.ends_with(&format!(".{}", ext)))
+    } else {
+        !is_file_path(recipe_name)
+            && !is_file_name(recipe_name)
+            && retrieve_recipe_from_local_path(recipe_name).is_err()
+            && is_registry_recipe(recipe_name)
+    };
+    if from_registry {
+        lock_recipe_from_registry(recipe_name)?;
+    }
+    Ok(())
+}
+
 fn is_file_path(recipe_name: &str) -> bool {
     recipe_name.contains('/')
         || recipe_name.contains('\\')
@@ -100,7 +129,7 @@ fn configured_github_recipe_repo() -> Option<String> {
     }
 }
 
-/// Lists all available recipes from local paths and GitHub repositories
+/// Lists all available recipes from local paths, the recipe registry and GitHub repositories
 pub fn list_available_recipes() -> Result<Vec<RecipeInfo>> {
     let mut recipes = Vec::new();
 
@@ -109,6 +138,13 @@ pub fn list_available_recipes() -> Result<Vec<RecipeInfo>> {
         recipes.extend(local_recipes);
     }
 
+    // Search the recipe registry if configured
+    if let Some(registry) = RecipeRegistry::configured() {
+        if let Ok(registry_recipes) = discover_registry_recipes(&registry) {
+            recipes.extend(registry_recipes);
+        }
+    }
+
     // Search GitHub recipes if configured
     if let Some(repo) = configured_github_recipe_repo() {
         if let Ok(github_recipes) = list_github_recipes(&repo) {
@@ -192,3 +228,22 @@ fn create_local_recipe_info(path: &Path) -> Result<RecipeInfo> {
         description: Some(recipe.description),
     })
 }
+
+fn discover_registry_recipes(registry: &RecipeRegistry) -> Result<Vec<RecipeInfo>> {
+    let mut recipes = Vec::new();
+    for package in registry.latest_packages()? {
+        let Ok(recipe_file) = package.recipe_file() else {
+            continue;
+        };
+        let recipe_dir = package.dir.to_string_lossy().to_string();
+        let (recipe, _) = parse_recipe_content(&recipe_file.content, recipe_dir)?;
+        recipes.push(RecipeInfo {
+            name: format!("{}@{}", package.name, package.version),
+            source: RecipeSource::Registry,
+            path: recipe_file.file_path.to_string_lossy().to_string(),
+            title: Some(recipe.title),
+            description: Some(recipe.description),
+        });
+    }
+    Ok(recipes)
+}
@@ -173,6 +173,8 @@ mod tests {
             response: None,
             sub_recipes: None,
             retry: None,
+            extends: None,
+            instructions_merge: None,
         }
     }
 
@@ -217,6 +219,8 @@ mod tests {
             response: None,
             sub_recipes: None,
             retry: None,
+            extends: None,
+            instructions_merge: None,
         };
 
         let secrets = discover_recipe_secrets(&recipe);
@@ -262,6 +266,8 @@ mod tests {
             response: None,
             sub_recipes: None,
             retry: None,
+            extends: None,
+            instructions_merge: None,
         };
 
         let secrets = discover_recipe_secrets(&recipe);
@@ -307,6 +313,7 @@ mod tests {
                 values: None,
                 sequential_when_repeated: false,
                 description: None,
+                depends_on: None,
             }]),
             context: None,
             settings: None,
@@ -315,6 +322,8 @@ mod tests {
             parameters: None,
             response: None,
             retry: None,
+            extends: None,
+            instructions_merge: None,
         };
 
         let secrets = discover_recipe_secrets(&recipe);
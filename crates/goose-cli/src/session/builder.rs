@@ -2,10 +2,12 @@ use super::output;
 use super::CliSession;
 use console::style;
 use goose::agents::types::RetryConfig;
-use goose::agents::Agent;
+use goose::agents::{Agent, BudgetLimits};
 use goose::config::{Config, ExtensionConfig, ExtensionConfigManager};
+use goose::context_mgmt::strategy::ContextStrategy;
 use goose::providers::create;
 use goose::recipe::{Response, SubRecipe};
+use goose::scheduler::{get_default_scheduler_storage_path, read_stored_jobs};
 
 use goose::session::SessionManager;
 use rustyline::EditMode;
@@ -150,11 +152,37 @@ async fn offer_extension_debugging_help(
     Ok(())
 }
 
+/// Reads the budget of the scheduled job that started this run from the scheduler's storage
+fn scheduled_job_budget(job_id: &str) -> Option<BudgetLimits> {
+    let jobs = get_default_scheduler_storage_path()
+        .map_err(Into::into)
+        .and_then(|path| read_stored_jobs(&path));
+    match jobs {
+        Ok(jobs) => jobs
+            .into_iter()
+            .find(|job| job.id == job_id)
+            .and_then(|job| job.budget),
+        Err(e) => {
+            eprintln!(
+                "{}",
+                style(format!(
+                    "Warning: Could not read the budget of scheduled job '{}': {}",
+                    job_id, e
+                ))
+                .yellow()
+            );
+            None
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct SessionSettings {
     pub goose_model: Option<String>,
     pub goose_provider: Option<String>,
     pub temperature: Option<f32>,
+    pub budget: Option<BudgetLimits>,
+    pub context_strategy: Option<ContextStrategy>,
 }
 
 pub async fn build_session(session_config: SessionBuilderConfig) -> CliSession {
@@ -450,6 +478,18 @@ pub async fn build_session(session_config: SessionBuilderConfig) -> CliSession {
         edit_mode,
         session_config.retry_config.clone(),
     );
+    session.budget = session_config
+        .settings
+        .as_ref()
+        .and_then(|s| s.budget.clone());
+    session.context_strategy = session_config
+        .settings
+        .as_ref()
+        .and_then(|s| s.context_strategy);
+    session.schedule_budget = session_config
+        .scheduled_job_id
+        .as_deref()
+        .and_then(scheduled_job_budget);
 
     // Add extensions if provided
     for extension_str in session_config.extensions {
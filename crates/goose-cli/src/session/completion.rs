@@ -134,6 +134,7 @@ impl GooseCompleter {
             "/prompt",
             "/mode",
             "/recipe",
+            "/fork",
         ];
 
         // Find commands that match the prefix
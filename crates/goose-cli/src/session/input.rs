@@ -21,6 +21,7 @@ pub enum InputResult {
     Clear,
     Recipe(Option<String>),
     Summarize,
+    Fork(Option<usize>),
 }
 
 #[derive(Debug)]
@@ -121,6 +122,7 @@ fn handle_slash_command(input: &str) -> Option<InputResult> {
     const CMD_CLEAR: &str = "/clear";
     const CMD_RECIPE: &str = "/recipe";
     const CMD_SUMMARIZE: &str = "/summarize";
+    const CMD_FORK: &str = "/fork";
 
     match input {
         "/exit" | "/quit" => Some(InputResult::Exit),
@@ -181,6 +183,7 @@ fn handle_slash_command(input: &str) -> Option<InputResult> {
         s if s == CMD_CLEAR => Some(InputResult::Clear),
         s if s.starts_with(CMD_RECIPE) => parse_recipe_command(s),
         s if s == CMD_SUMMARIZE => Some(InputResult::Summarize),
+        s if s.starts_with(CMD_FORK) => parse_fork_command(s[CMD_FORK.len()..].trim()),
         _ => None,
     }
 }
@@ -210,6 +213,20 @@ fn parse_recipe_command(s: &str) -> Option<InputResult> {
     Some(InputResult::Recipe(Some(filepath.to_string())))
 }
 
+fn parse_fork_command(args: &str) -> Option<InputResult> {
+    if args.is_empty() {
+        return Some(InputResult::Fork(None));
+    }
+
+    match args.parse::<usize>() {
+        Ok(index) => Some(InputResult::Fork(Some(index))),
+        Err(_) => {
+            println!("{}", console::style("Usage: /fork [message_index]").red());
+            Some(InputResult::Retry)
+        }
+    }
+}
+
 fn parse_prompts_command(args: &str) -> Option<InputResult> {
     let parts: Vec<String> = shlex::split(args).unwrap_or_default();
 
@@ -306,6 +323,7 @@ fn print_help() {
 /recipe [filepath] - Generate a recipe from the current conversation and save it to the specified filepath (must end with .yaml).
                        If no filepath is provided, it will be saved to ./recipe.yaml.
 /summarize - Summarize the current conversation to reduce context length while preserving key information.
+/fork [message_index] - Fork the session into a new one that keeps the first message_index messages (all if omitted) and continue in the fork.
 /? or /help - Display this help message
 /clear - Clears the current chat history
 
@@ -552,6 +570,22 @@ mod tests {
         assert!(matches!(result, Some(InputResult::Summarize)));
     }
 
+    #[test]
+    fn test_fork_command() {
+        assert!(matches!(
+            handle_slash_command("/fork"),
+            Some(InputResult::Fork(None))
+        ));
+        assert!(matches!(
+            handle_slash_command("/fork 4"),
+            Some(InputResult::Fork(Some(4)))
+        ));
+        assert!(matches!(
+            handle_slash_command("/fork last"),
+            Some(InputResult::Retry)
+        ));
+    }
+
     #[test]
     fn test_get_input_prompt_string() {
         let prompt = get_input_prompt_string();
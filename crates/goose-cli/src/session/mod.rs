@@ -28,8 +28,10 @@ use completion::GooseCompleter;
 use etcetera::{choose_app_strategy, AppStrategy};
 use goose::agents::extension::{Envs, ExtensionConfig};
 use goose::agents::types::RetryConfig;
-use goose::agents::{Agent, SessionConfig};
+use goose::agents::{Agent, BudgetLimits, SessionConfig};
 use goose::config::Config;
+use goose::context_mgmt::auto_compact::perform_compaction;
+use goose::context_mgmt::strategy::ContextStrategy;
 use goose::providers::pricing::initialize_pricing_cache;
 use goose::session;
 use input::InputResult;
@@ -65,6 +67,9 @@ pub struct CliSession {
     max_turns: Option<u32>,
     edit_mode: Option<EditMode>,
     retry_config: Option<RetryConfig>,
+    budget: Option<BudgetLimits>,
+    schedule_budget: Option<BudgetLimits>,
+    context_strategy: Option<ContextStrategy>,
 }
 
 // Cache structure for completion data
@@ -152,6 +157,9 @@ impl CliSession {
             max_turns,
             edit_mode,
             retry_config,
+            budget: None,
+            schedule_budget: None,
+            context_strategy: None,
         }
     }
 
@@ -162,12 +170,14 @@ impl CliSession {
     async fn summarize_context_messages(
         messages: &mut Conversation,
         agent: &Agent,
+        context_strategy: Option<ContextStrategy>,
         message_suffix: &str,
     ) -> Result<()> {
-        let (summarized_messages, _, _) = agent.summarize_context(messages.messages()).await?;
+        let compact_result =
+            perform_compaction(agent, messages.messages(), context_strategy).await?;
         let msg = format!("Context maxed out\n{}\n{}", "-".repeat(50), message_suffix);
         output::render_text(&msg, Some(Color::Yellow), true);
-        *messages = summarized_messages;
+        *messages = compact_result.messages;
 
         Ok(())
     }
@@ -756,6 +766,35 @@ impl CliSession {
                     }
                     continue;
                 }
+                InputResult::Fork(message_index) => {
+                    save_history(&mut editor);
+
+                    let Some(parent_id) = self.session_id.clone() else {
+                        output::render_error("Cannot fork a session that is not being saved.");
+                        continue;
+                    };
+
+                    match SessionManager::fork_session(&parent_id, message_index).await {
+                        Ok(forked) => {
+                            self.messages = forked.conversation.unwrap_or_default();
+                            self.session_id = Some(forked.id.clone());
+                            println!(
+                                "{}",
+                                console::style(format!(
+                                    "Forked session {} into {} at message {}",
+                                    parent_id,
+                                    forked.id,
+                                    forked.fork_message_index.unwrap_or(0)
+                                ))
+                                .green()
+                            );
+                        }
+                        Err(e) => {
+                            output::render_error(&format!("Failed to fork session: {}", e));
+                        }
+                    }
+                    continue;
+                }
             }
         }
         Ok(())
@@ -862,7 +901,11 @@ impl CliSession {
             schedule_id: self.scheduled_job_id.clone(),
             execution_mode: None,
             max_turns: self.max_turns,
+            goose_mode: None,
             retry_config: self.retry_config.clone(),
+            budget: self.budget.clone(),
+            schedule_budget: self.schedule_budget.clone(),
+            context_strategy: self.context_strategy,
         });
         let mut stream = self
             .agent
@@ -1001,7 +1044,7 @@ impl CliSession {
                                         } else {
                                             "Goose automatically summarized messages to continue processing."
                                         };
-                                        Self::summarize_context_messages(&mut self.messages, &self.agent, message_suffix).await?;
+                                        Self::summarize_context_messages(&mut self.messages, &self.agent, self.context_strategy, message_suffix).await?;
                                     }
                                     _ => {
                                         unreachable!()
@@ -1198,7 +1241,7 @@ impl CliSession {
                                 );
 
                                 // Try auto-compaction first - keep the stream alive!
-                                if let Ok(compact_result) = goose::context_mgmt::auto_compact::perform_compaction(&self.agent, self.messages.messages()).await {
+                                if let Ok(compact_result) = perform_compaction(&self.agent, self.messages.messages(), self.context_strategy).await {
                                     self.messages = compact_result.messages;
                                     if let Some(session_id) = &self.session_id {
                                         SessionManager::replace_conversation(session_id, &self.messages).await?;
@@ -1264,7 +1307,7 @@ impl CliSession {
                                             "summarize" => {
                                                 // Use the helper function to summarize context
                                                 let message_suffix = "Goose summarized messages for you.";
-                                                if let Err(e) = Self::summarize_context_messages(&mut self.messages, &self.agent, message_suffix).await {
+                                                if let Err(e) = Self::summarize_context_messages(&mut self.messages, &self.agent, self.context_strategy, message_suffix).await {
                                                     output::render_error(&format!("Failed to summarize: {}", e));
                                                     output::render_text("Consider using /clear to start fresh.", Some(Color::Yellow), true);
                                                 }
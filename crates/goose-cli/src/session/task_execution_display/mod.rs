@@ -152,6 +152,7 @@ fn format_task_display(task: &TaskInfo) -> String {
         TaskStatus::Running => "🏃",
         TaskStatus::Completed => "✅",
         TaskStatus::Failed => "❌",
+        TaskStatus::Skipped => "⏭️",
     };
 
     task_display.push_str(&format!(
@@ -186,7 +187,7 @@ fn format_task_display(task: &TaskInfo) -> String {
         }
     }
 
-    if matches!(task.status, TaskStatus::Failed) {
+    if matches!(task.status, TaskStatus::Failed | TaskStatus::Skipped) {
         if let Some(error) = &task.error {
             let error_preview = safe_truncate(error, 80);
             task_display.push_str(&format!(
@@ -7,7 +7,10 @@ use goose::conversation::Conversation;
 use goose::permission::permission_confirmation::PrincipalType;
 use goose::providers::base::{ConfigKey, ModelInfo, ProviderMetadata};
 
-use goose::session::{Session, SessionInsights};
+use goose::session::{
+    AuditAction, AuditEntry, AuditKind, AuditRecord, CostRollup, MessageSearchHit, MetricsGroup,
+    MetricsGroupBy, ModelCost, Session, SessionInsights,
+};
 use rmcp::model::{
     Annotations, Content, EmbeddedResource, ImageContent, JsonObject, RawEmbeddedResource,
     RawImageContent, RawResource, RawTextContent, ResourceContents, Role, TextContent, Tool,
@@ -372,8 +375,12 @@ impl<'__s> ToSchema<'__s> for AnnotatedSchema {
         super::routes::session::list_sessions,
         super::routes::session::get_session,
         super::routes::session::get_session_insights,
+        super::routes::session::search_sessions,
+        super::routes::session::get_session_metrics,
         super::routes::session::update_session_description,
         super::routes::session::delete_session,
+        super::routes::session::fork_session,
+        super::routes::audit::get_audit_log,
         super::routes::schedule::create_schedule,
         super::routes::schedule::list_schedules,
         super::routes::schedule::delete_schedule,
@@ -384,10 +391,13 @@ impl<'__s> ToSchema<'__s> for AnnotatedSchema {
         super::routes::schedule::kill_running_job,
         super::routes::schedule::inspect_running_job,
         super::routes::schedule::sessions_handler,
+        super::routes::schedule::runs_handler,
+        super::routes::schedule::webhook_handler,
         super::routes::recipe::create_recipe,
         super::routes::recipe::encode_recipe,
         super::routes::recipe::decode_recipe,
         super::routes::recipe::scan_recipe,
+        super::routes::recipe::validate_recipe_parameters,
         super::routes::recipe::list_recipes,
         super::routes::recipe::delete_recipe,
         super::routes::setup::start_openrouter_setup,
@@ -409,6 +419,10 @@ impl<'__s> ToSchema<'__s> for AnnotatedSchema {
         super::routes::context::ContextManageResponse,
         super::routes::session::SessionListResponse,
         super::routes::session::UpdateSessionDescriptionRequest,
+        super::routes::session::ForkSessionRequest,
+        super::routes::session::SessionSearchResponse,
+        super::routes::session::SessionMetricsResponse,
+        super::routes::audit::AuditLogResponse,
         Message,
         MessageContent,
         MessageMetadata,
@@ -446,6 +460,15 @@ impl<'__s> ToSchema<'__s> for AnnotatedSchema {
         ModelInfo,
         Session,
         SessionInsights,
+        CostRollup,
+        ModelCost,
+        MessageSearchHit,
+        MetricsGroup,
+        MetricsGroupBy,
+        AuditRecord,
+        AuditEntry,
+        AuditKind,
+        AuditAction,
         Conversation,
         goose::session::extension_data::ExtensionData,
         super::routes::schedule::CreateScheduleRequest,
@@ -457,6 +480,14 @@ impl<'__s> ToSchema<'__s> for AnnotatedSchema {
         super::routes::schedule::ListSchedulesResponse,
         super::routes::schedule::SessionsQuery,
         super::routes::schedule::SessionDisplayInfo,
+        super::routes::schedule::RunsQuery,
+        goose::scheduler_history::ScheduledJobRun,
+        goose::scheduler_history::ScheduledRunStatus,
+        goose::scheduler_history::RetryPolicy,
+        goose::scheduler_history::ScheduleHook,
+        goose::scheduler_policy::OverlapPolicy,
+        goose::scheduler_policy::BlackoutWindow,
+        goose::scheduler_trigger::ScheduleTrigger,
         super::routes::recipe::CreateRecipeRequest,
         super::routes::recipe::AuthorRequest,
         super::routes::recipe::CreateRecipeResponse,
@@ -466,6 +497,8 @@ impl<'__s> ToSchema<'__s> for AnnotatedSchema {
         super::routes::recipe::DecodeRecipeResponse,
         super::routes::recipe::ScanRecipeRequest,
         super::routes::recipe::ScanRecipeResponse,
+        super::routes::recipe::ValidateRecipeParametersRequest,
+        super::routes::recipe::ValidateRecipeParametersResponse,
         super::routes::recipe::RecipeManifestResponse,
         super::routes::recipe::ListRecipeResponse,
         super::routes::recipe::DeleteRecipeRequest,
@@ -475,9 +508,13 @@ impl<'__s> ToSchema<'__s> for AnnotatedSchema {
         goose::recipe::RecipeParameter,
         goose::recipe::RecipeParameterInputType,
         goose::recipe::RecipeParameterRequirement,
+        goose::recipe::build_recipe::ParameterValueError,
         goose::recipe::Response,
         goose::recipe::SubRecipe,
+        goose::recipe::InstructionsMerge,
         goose::agents::types::RetryConfig,
+        goose::agents::BudgetLimits,
+        goose::context_mgmt::strategy::ContextStrategy,
         goose::agents::types::SuccessCheck,
         super::routes::agent::AddSubRecipesRequest,
         super::routes::agent::AddSubRecipesResponse,
@@ -0,0 +1,61 @@
+use crate::state::AppState;
+use axum::{extract::Query, http::StatusCode, routing::get, Json, Router};
+use goose::session::{AuditFilter, AuditRecord, SessionManager};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::ToSchema;
+
+const DEFAULT_AUDIT_LIMIT: usize = 1000;
+
+#[derive(Deserialize, ToSchema, utoipa::IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogQuery {
+    /// Only include entries recorded at or after this time (RFC 3339)
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only include entries for this session
+    session_id: Option<String>,
+    /// Maximum number of entries to return (default 1000)
+    limit: Option<usize>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogResponse {
+    /// Audit entries, oldest first
+    records: Vec<AuditRecord>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/audit",
+    params(AuditLogQuery),
+    responses(
+        (status = 200, description = "Permission audit log retrieved successfully", body = AuditLogResponse),
+        (status = 401, description = "Unauthorized - Invalid or missing API key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Audit"
+)]
+async fn get_audit_log(
+    Query(params): Query<AuditLogQuery>,
+) -> Result<Json<AuditLogResponse>, StatusCode> {
+    let filter = AuditFilter {
+        since: params.since,
+        session_id: params.session_id,
+        limit: Some(params.limit.unwrap_or(DEFAULT_AUDIT_LIMIT)),
+    };
+    let records = SessionManager::get_audit_records(&filter)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(AuditLogResponse { records }))
+}
+
+pub fn routes(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/audit", get(get_audit_log))
+        .with_state(state)
+}
@@ -1,5 +1,6 @@
 pub mod agent;
 pub mod audio;
+pub mod audit;
 pub mod config_management;
 pub mod context;
 pub mod extension;
@@ -22,6 +23,7 @@ pub fn configure(state: Arc<crate::state::AppState>) -> Router {
         .merge(reply::routes(state.clone()))
         .merge(agent::routes(state.clone()))
         .merge(audio::routes(state.clone()))
+        .merge(audit::routes(state.clone()))
         .merge(context::routes(state.clone()))
         .merge(extension::routes(state.clone()))
         .merge(config_management::routes(state.clone()))
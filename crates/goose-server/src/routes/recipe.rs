@@ -5,7 +5,8 @@ use std::sync::Arc;
 use axum::routing::get;
 use axum::{extract::State, http::StatusCode, routing::post, Json, Router};
 use goose::conversation::{message::Message, Conversation};
-use goose::recipe::Recipe;
+use goose::recipe::build_recipe::{validate_parameter_values, ParameterValueError};
+use goose::recipe::{Recipe, RecipeParameter};
 use goose::recipe_deeplink;
 
 use serde::{Deserialize, Serialize};
@@ -72,6 +73,17 @@ pub struct ScanRecipeResponse {
     has_security_warnings: bool,
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ValidateRecipeParametersRequest {
+    parameters: Vec<RecipeParameter>,
+    values: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ValidateRecipeParametersResponse {
+    errors: Vec<ParameterValueError>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct RecipeManifestResponse {
     name: String,
@@ -212,6 +224,23 @@ async fn scan_recipe(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/recipes/parameters/validate",
+    request_body = ValidateRecipeParametersRequest,
+    responses(
+        (status = 200, description = "Parameter values validated successfully", body = ValidateRecipeParametersResponse),
+    ),
+    tag = "Recipe Management"
+)]
+async fn validate_recipe_parameters(
+    Json(request): Json<ValidateRecipeParametersRequest>,
+) -> Result<Json<ValidateRecipeParametersResponse>, StatusCode> {
+    let errors = validate_parameter_values(&request.parameters, &request.values);
+
+    Ok(Json(ValidateRecipeParametersResponse { errors }))
+}
+
 #[utoipa::path(
     get,
     path = "/recipes/list",
@@ -284,6 +313,10 @@ pub fn routes(state: Arc<AppState>) -> Router {
         .route("/recipes/encode", post(encode_recipe))
         .route("/recipes/decode", post(decode_recipe))
         .route("/recipes/scan", post(scan_recipe))
+        .route(
+            "/recipes/parameters/validate",
+            post(validate_recipe_parameters),
+        )
         .route("/recipes/list", get(list_recipes))
         .route("/recipes/delete", post(delete_recipe))
         .with_state(state)
@@ -323,4 +356,41 @@ mod tests {
         assert!(!encoded_again.is_empty());
         assert_eq!(encoded, encoded_again);
     }
+
+    #[tokio::test]
+    async fn test_validate_recipe_parameters() {
+        let parameters: Vec<RecipeParameter> = serde_json::from_value(serde_json::json!([
+            {
+                "key": "count",
+                "input_type": "number",
+                "requirement": "required",
+                "description": "Number of posts",
+                "max": 5
+            },
+            {
+                "key": "topic",
+                "input_type": "string",
+                "requirement": "required",
+                "description": "Topic"
+            }
+        ]))
+        .unwrap();
+        let request = ValidateRecipeParametersRequest {
+            parameters,
+            values: HashMap::from([
+                ("count".to_string(), "7".to_string()),
+                ("topic".to_string(), "Rust".to_string()),
+            ]),
+        };
+
+        let errors = validate_recipe_parameters(Json(request))
+            .await
+            .unwrap()
+            .0
+            .errors;
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].key, "count");
+        assert_eq!(errors[0].message, "must be at most 5");
+    }
 }
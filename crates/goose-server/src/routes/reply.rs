@@ -248,7 +248,19 @@ async fn reply_handler(
             schedule_id: session.schedule_id.clone(),
             execution_mode: None,
             max_turns: None,
+            goose_mode: None,
             retry_config: None,
+            budget: session
+                .recipe
+                .as_ref()
+                .and_then(|r| r.settings.as_ref())
+                .and_then(|s| s.budget.clone()),
+            schedule_budget: None,
+            context_strategy: session
+                .recipe
+                .as_ref()
+                .and_then(|r| r.settings.as_ref())
+                .and_then(|s| s.context_strategy),
         };
 
         let mut stream = match agent
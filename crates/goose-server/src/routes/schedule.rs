@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use axum::{
@@ -11,15 +12,43 @@ use serde::{Deserialize, Serialize};
 use chrono::NaiveDateTime;
 
 use crate::state::AppState;
+use goose::agents::BudgetLimits;
 use goose::scheduler::ScheduledJob;
+use goose::scheduler_history::{RetryPolicy, ScheduleHook, ScheduledJobRun};
+use goose::scheduler_policy::{BlackoutWindow, OverlapPolicy};
+use goose::scheduler_trigger::{webhook_parameters, ScheduleTrigger};
 
 #[derive(Deserialize, Serialize, utoipa::ToSchema)]
 pub struct CreateScheduleRequest {
     id: String,
     recipe_source: String,
-    cron: String,
+    /// Cron expression the job runs on; give either this or `trigger`
+    #[serde(default)]
+    cron: Option<String>,
+    #[serde(default)]
+    trigger: Option<ScheduleTrigger>,
     #[serde(default)]
     execution_mode: Option<String>, // "foreground" or "background"
+    #[serde(default)]
+    budget: Option<BudgetLimits>,
+    #[serde(default)]
+    retry: Option<RetryPolicy>,
+    #[serde(default)]
+    on_success: Option<ScheduleHook>,
+    #[serde(default)]
+    on_failure: Option<ScheduleHook>,
+    #[serde(default)]
+    parameters: Option<HashMap<String, String>>,
+    #[serde(default)]
+    overlap_policy: Option<OverlapPolicy>,
+    #[serde(default)]
+    timezone: Option<String>,
+    #[serde(default)]
+    blackout_windows: Option<Vec<BlackoutWindow>>,
+    #[serde(default)]
+    jitter_seconds: Option<u64>,
+    #[serde(default)]
+    debounce_seconds: Option<u64>,
 }
 
 #[derive(Deserialize, Serialize, utoipa::ToSchema)]
@@ -64,6 +93,13 @@ fn default_limit() -> u32 {
     50 // Default limit for sessions listed
 }
 
+// Query parameters for the runs endpoint
+#[derive(Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct RunsQuery {
+    #[serde(default = "default_limit")]
+    limit: u32,
+}
+
 // Struct for the frontend session list
 #[derive(Serialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -94,7 +130,7 @@ fn parse_session_name_to_iso(session_name: &str) -> String {
     request_body = CreateScheduleRequest,
     responses(
         (status = 200, description = "Scheduled job created successfully", body = ScheduledJob),
-        (status = 400, description = "Invalid cron expression or recipe file"),
+        (status = 400, description = "Invalid cron expression, trigger, recipe file, parameters, hook, timezone or blackout window"),
         (status = 409, description = "Job ID already exists"),
         (status = 500, description = "Internal server error")
     ),
@@ -110,6 +146,12 @@ async fn create_schedule(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let trigger = match (req.cron, req.trigger) {
+        (Some(expression), None) => ScheduleTrigger::Cron { expression },
+        (None, Some(trigger)) => trigger,
+        _ => return Err(StatusCode::BAD_REQUEST),
+    };
+
     tracing::info!(
         "Server: Calling scheduler.add_scheduled_job() for job '{}'",
         req.id
@@ -117,13 +159,24 @@ async fn create_schedule(
     let job = ScheduledJob {
         id: req.id,
         source: req.recipe_source,
-        cron: req.cron,
+        trigger,
         last_run: None,
         currently_running: false,
         paused: false,
         current_session_id: None,
         process_start_time: None,
         execution_mode: req.execution_mode.or(Some("background".to_string())), // Default to background
+        budget: req.budget,
+        retry: req.retry,
+        on_success: req.on_success,
+        on_failure: req.on_failure,
+        parameters: req.parameters,
+        overlap_policy: req.overlap_policy,
+        timezone: req.timezone,
+        blackout_windows: req.blackout_windows,
+        jitter_seconds: req.jitter_seconds,
+        debounce_seconds: req.debounce_seconds,
+        recipe_dir: None,
     };
     scheduler
         .add_scheduled_job(job.clone())
@@ -135,6 +188,8 @@ async fn create_schedule(
                 goose::scheduler::SchedulerError::CronParseError(_) => StatusCode::BAD_REQUEST,
                 goose::scheduler::SchedulerError::RecipeLoadError(_) => StatusCode::BAD_REQUEST,
                 goose::scheduler::SchedulerError::JobIdExists(_) => StatusCode::CONFLICT,
+                goose::scheduler::SchedulerError::Unsupported(_) => StatusCode::BAD_REQUEST,
+                goose::scheduler::SchedulerError::InvalidJob(_) => StatusCode::BAD_REQUEST,
                 _ => StatusCode::INTERNAL_SERVER_ERROR,
             }
         })?;
@@ -348,6 +403,46 @@ async fn sessions_handler(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/schedule/{id}/runs",
+    params(
+        ("id" = String, Path, description = "ID of the schedule"),
+        RunsQuery
+    ),
+    responses(
+        (status = 200, description = "Recorded runs of the schedule, newest first", body = Vec<ScheduledJobRun>),
+        (status = 404, description = "Scheduled job not found"),
+        (status = 500, description = "Internal server error"),
+        (status = 501, description = "The configured scheduler does not record runs")
+    ),
+    tag = "schedule"
+)]
+#[axum::debug_handler]
+async fn runs_handler(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query_params): Query<RunsQuery>,
+) -> Result<Json<Vec<ScheduledJobRun>>, StatusCode> {
+    let scheduler = state
+        .scheduler()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let runs = scheduler
+        .runs(&id, query_params.limit as usize)
+        .await
+        .map_err(|e| {
+            eprintln!("Error fetching runs for schedule '{}': {:?}", id, e);
+            match e {
+                goose::scheduler::SchedulerError::JobNotFound(_) => StatusCode::NOT_FOUND,
+                goose::scheduler::SchedulerError::Unsupported(_) => StatusCode::NOT_IMPLEMENTED,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            }
+        })?;
+    Ok(Json(runs))
+}
+
 #[utoipa::path(
     post,
     path = "/schedule/{id}/pause",
@@ -550,6 +645,55 @@ pub async fn inspect_running_job(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/schedule/{id}/webhook",
+    params(
+        ("id" = String, Path, description = "ID of the webhook-triggered schedule")
+    ),
+    request_body(content = Object, description = "Values passed to the recipe as parameters"),
+    responses(
+        (status = 202, description = "Run of the scheduled job started"),
+        (status = 400, description = "Schedule is not triggered by webhooks or payload is not a JSON object"),
+        (status = 404, description = "Scheduled job not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "schedule"
+)]
+#[axum::debug_handler]
+async fn webhook_handler(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Json(payload): Json<serde_json::Value>,
+) -> Result<StatusCode, StatusCode> {
+    let scheduler = state
+        .scheduler()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    let jobs = scheduler.list_scheduled_jobs().await.map_err(|e| {
+        eprintln!("Error listing schedules: {:?}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let job = jobs
+        .into_iter()
+        .find(|job| job.id == id)
+        .ok_or(StatusCode::NOT_FOUND)?;
+    if job.trigger != ScheduleTrigger::Webhook {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let parameters = webhook_parameters(&payload).map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    scheduler.trigger_job(&id, parameters).await.map_err(|e| {
+        eprintln!("Error triggering schedule '{}': {:?}", id, e);
+        match e {
+            goose::scheduler::SchedulerError::JobNotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    })?;
+    Ok(StatusCode::ACCEPTED)
+}
+
 pub fn routes(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/schedule/create", post(create_schedule))
@@ -562,5 +706,7 @@ pub fn routes(state: Arc<AppState>) -> Router {
         .route("/schedule/{id}/kill", post(kill_running_job))
         .route("/schedule/{id}/inspect", get(inspect_running_job))
         .route("/schedule/{id}/sessions", get(sessions_handler)) // Corrected
+        .route("/schedule/{id}/runs", get(runs_handler))
+        .route("/schedule/{id}/webhook", post(webhook_handler))
         .with_state(state)
 }
@@ -1,12 +1,14 @@
 use crate::state::AppState;
 use axum::{
-    extract::Path,
+    extract::{Path, Query},
     http::StatusCode,
-    routing::{delete, get, put},
+    routing::{delete, get, post, put},
     Json, Router,
 };
 use goose::session::session_manager::SessionInsights;
-use goose::session::{Session, SessionManager};
+use goose::session::{
+    MessageSearchFilters, MessageSearchHit, MetricsGroup, MetricsGroupBy, Session, SessionManager,
+};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use utoipa::ToSchema;
@@ -25,6 +27,53 @@ pub struct UpdateSessionDescriptionRequest {
     description: String,
 }
 
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ForkSessionRequest {
+    /// Number of messages to copy from the parent session (defaults to all of them)
+    message_index: Option<usize>,
+}
+
+#[derive(Deserialize, ToSchema, utoipa::IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionSearchQuery {
+    /// Text to search for in message content, tool arguments and tool results
+    query: String,
+    /// Only match sessions whose working directory contains this string
+    working_dir: Option<String>,
+    /// Only match messages created at or after this time (RFC 3339)
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only match messages created at or before this time (RFC 3339)
+    until: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only match sessions started from a recipe with this title
+    recipe: Option<String>,
+    /// Maximum number of results to return
+    limit: Option<usize>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionSearchResponse {
+    /// Matching messages, best match first
+    results: Vec<MessageSearchHit>,
+}
+
+#[derive(Deserialize, ToSchema, utoipa::IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionMetricsQuery {
+    /// Dimension to group sessions by
+    group_by: MetricsGroupBy,
+    /// Only include sessions created at or after this time (RFC 3339), all sessions if omitted
+    since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionMetricsResponse {
+    /// Aggregated metrics, newest day first or most tokens first
+    groups: Vec<MetricsGroup>,
+}
+
 const MAX_DESCRIPTION_LENGTH: usize = 200;
 
 #[utoipa::path(
@@ -92,6 +141,62 @@ async fn get_session_insights() -> Result<Json<SessionInsights>, StatusCode> {
     Ok(Json(insights))
 }
 
+#[utoipa::path(
+    get,
+    path = "/sessions/search",
+    params(SessionSearchQuery),
+    responses(
+        (status = 200, description = "Search completed successfully", body = SessionSearchResponse),
+        (status = 401, description = "Unauthorized - Invalid or missing API key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Session Management"
+)]
+async fn search_sessions(
+    Query(params): Query<SessionSearchQuery>,
+) -> Result<Json<SessionSearchResponse>, StatusCode> {
+    let filters = MessageSearchFilters {
+        working_dir: params.working_dir,
+        since: params.since,
+        until: params.until,
+        recipe: params.recipe,
+        limit: params.limit,
+    };
+
+    let results = SessionManager::search_messages(&params.query, &filters)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(SessionSearchResponse { results }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/sessions/metrics",
+    params(SessionMetricsQuery),
+    responses(
+        (status = 200, description = "Metrics aggregated successfully", body = SessionMetricsResponse),
+        (status = 401, description = "Unauthorized - Invalid or missing API key"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Session Management"
+)]
+async fn get_session_metrics(
+    Query(params): Query<SessionMetricsQuery>,
+) -> Result<Json<SessionMetricsResponse>, StatusCode> {
+    let groups = SessionManager::get_metrics(params.group_by, params.since)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(SessionMetricsResponse { groups }))
+}
+
 #[utoipa::path(
     put,
     path = "/sessions/{session_id}/description",
@@ -159,15 +264,57 @@ async fn delete_session(Path(session_id): Path<String>) -> Result<StatusCode, St
     Ok(StatusCode::OK)
 }
 
+#[utoipa::path(
+    post,
+    path = "/sessions/{session_id}/fork",
+    request_body = ForkSessionRequest,
+    params(
+        ("session_id" = String, Path, description = "Unique identifier for the parent session")
+    ),
+    responses(
+        (status = 200, description = "Session forked successfully", body = Session),
+        (status = 400, description = "Bad request - Message index out of range"),
+        (status = 401, description = "Unauthorized - Invalid or missing API key"),
+        (status = 404, description = "Session not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Session Management"
+)]
+async fn fork_session(
+    Path(session_id): Path<String>,
+    Json(request): Json<ForkSessionRequest>,
+) -> Result<Json<Session>, StatusCode> {
+    let session = SessionManager::fork_session(&session_id, request.message_index)
+        .await
+        .map_err(|e| {
+            let msg = e.to_string();
+            if msg.contains("not found") {
+                StatusCode::NOT_FOUND
+            } else if msg.contains("out of range") {
+                StatusCode::BAD_REQUEST
+            } else {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        })?;
+
+    Ok(Json(session))
+}
+
 pub fn routes(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/sessions", get(list_sessions))
         .route("/sessions/{session_id}", get(get_session))
         .route("/sessions/{session_id}", delete(delete_session))
         .route("/sessions/insights", get(get_session_insights))
+        .route("/sessions/search", get(search_sessions))
+        .route("/sessions/metrics", get(get_session_metrics))
         .route(
             "/sessions/{session_id}/description",
             put(update_session_description),
         )
+        .route("/sessions/{session_id}/fork", post(fork_session))
         .with_state(state)
 }
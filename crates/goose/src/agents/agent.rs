@@ -8,13 +8,14 @@ use futures::stream::BoxStream;
 use futures::{stream, FutureExt, Stream, StreamExt, TryStreamExt};
 use uuid::Uuid;
 
+use crate::agents::budget::{self, BudgetStatus};
 use crate::agents::extension::{ExtensionConfig, ExtensionError, ExtensionResult, ToolInfo};
 use crate::agents::extension_manager::{get_parameter_names, ExtensionManager};
 use crate::agents::final_output_tool::{FINAL_OUTPUT_CONTINUATION_MESSAGE, FINAL_OUTPUT_TOOL_NAME};
 use crate::agents::platform_tools::{
     PLATFORM_LIST_RESOURCES_TOOL_NAME, PLATFORM_MANAGE_EXTENSIONS_TOOL_NAME,
     PLATFORM_MANAGE_SCHEDULE_TOOL_NAME, PLATFORM_READ_RESOURCE_TOOL_NAME,
-    PLATFORM_SEARCH_AVAILABLE_EXTENSIONS_TOOL_NAME,
+    PLATFORM_RECALL_MESSAGES_TOOL_NAME, PLATFORM_SEARCH_AVAILABLE_EXTENSIONS_TOOL_NAME,
 };
 use crate::agents::prompt_manager::PromptManager;
 use crate::agents::recipe_tools::dynamic_task_tools::{
@@ -32,12 +33,13 @@ use crate::agents::tool_router_index_manager::ToolRouterIndexManager;
 use crate::agents::types::SessionConfig;
 use crate::agents::types::{FrontendTool, ToolResultReceiver};
 use crate::config::{Config, ExtensionConfigManager};
-use crate::context_mgmt::auto_compact;
+use crate::context_mgmt::{auto_compact, recall};
 use crate::conversation::{debug_conversation_fix, fix_conversation, Conversation};
 use crate::mcp_utils::ToolResult;
 use crate::permission::permission_inspector::PermissionInspector;
 use crate::permission::permission_judge::PermissionCheckResult;
 use crate::permission::PermissionConfirmation;
+use crate::permission::{audit, PolicyInspector};
 use crate::providers::base::Provider;
 use crate::providers::errors::ProviderError;
 use crate::recipe::{Author, Recipe, Response, Settings, SubRecipe};
@@ -199,6 +201,9 @@ impl Agent {
             std::collections::HashSet::new(), // regular tools - will be populated from extension manager
         )));
 
+        // Add policy inspector (argument-aware rules from the user's policy file)
+        tool_inspection_manager.add_inspector(Box::new(PolicyInspector::new()));
+
         // Add repetition inspector (lower priority - basic repetition checking)
         tool_inspection_manager.add_inspector(Box::new(RepetitionInspector::new(None)));
 
@@ -229,7 +234,13 @@ impl Agent {
     ) -> Result<bool> {
         let result = self
             .retry_manager
-            .handle_retry_logic(messages, session, initial_messages, &self.final_output_tool)
+            .handle_retry_logic(
+                messages,
+                session,
+                initial_messages,
+                &self.final_output_tool,
+                self.provider().await.ok(),
+            )
             .await?;
 
         match result {
@@ -579,6 +590,22 @@ impl Agent {
             )
         } else if tool_call.name == PLATFORM_SEARCH_AVAILABLE_EXTENSIONS_TOOL_NAME {
             ToolCallResult::from(self.extension_manager.search_available_extensions().await)
+        } else if tool_call.name == PLATFORM_RECALL_MESSAGES_TOOL_NAME {
+            let result = match session {
+                Some(session_config) => {
+                    recall::recall_from_session(
+                        &session_config.id,
+                        &tool_call.arguments.clone().unwrap_or_default(),
+                    )
+                    .await
+                }
+                None => Err(ErrorData::new(
+                    ErrorCode::INTERNAL_ERROR,
+                    "Recalling messages requires an active session".to_string(),
+                    None,
+                )),
+            };
+            ToolCallResult::from(result)
         } else if self.is_frontend_tool(&tool_call.name).await {
             // For frontend tools, return an error indicating we need frontend execution
             ToolCallResult::from(Err(ErrorData::new(
@@ -924,6 +951,7 @@ impl Agent {
                 platform_tools::search_available_extensions_tool(),
                 platform_tools::manage_extensions_tool(),
                 platform_tools::manage_schedule_tool(),
+                platform_tools::recall_messages_tool(),
             ]);
 
             // Add task planner tools
@@ -1024,6 +1052,7 @@ impl Agent {
             messages,
             None,
             session_metadata.as_ref(),
+            session.as_ref().and_then(|s| s.context_strategy),
         )
         .await?;
 
@@ -1037,9 +1066,12 @@ impl Agent {
                 .unwrap_or(0.8); // Default to 80%
             let threshold_percentage = (threshold * 100.0) as u32;
 
+            let check_result = compact_result.check_result;
             let compaction_msg = format!(
-                "Exceeded auto-compact threshold of {}%. Context has been summarized and reduced.\n\n",
-                threshold_percentage
+                "Exceeded auto-compact threshold of {}%. Context has been reduced from {} to about {} tokens.\n\n",
+                threshold_percentage,
+                check_result.current_tokens,
+                check_result.tokens_after_compaction.unwrap_or_default()
             );
 
             return Ok(Some((
@@ -1166,6 +1198,7 @@ impl Agent {
         Ok(Box::pin(async_stream::try_stream! {
             let _ = reply_span.enter();
             let mut turns_taken = 0u32;
+            let mut budget_warned = false;
             let max_turns = session
                 .as_ref()
                 .and_then(|s| s.max_turns)
@@ -1196,6 +1229,41 @@ impl Agent {
                     break;
                 }
 
+                if let Some(session_config) = &session {
+                    let budget_check = budget::check_budget(session_config).await?;
+                    match budget_check.status {
+                        BudgetStatus::HardLimitReached(reason) => {
+                            warn!("Budget exhausted: {}", reason);
+                            let message = Message::assistant()
+                                .with_text(format!("Stopping here: {}. Raise the budget to continue.", reason))
+                                .with_visibility(true, false);
+                            SessionManager::add_message(&session_config.id, &message).await?;
+                            yield AgentEvent::Message(message);
+                            break;
+                        }
+                        BudgetStatus::SoftLimitReached(reason) if !budget_warned => {
+                            budget_warned = true;
+                            warn!("Budget soft limit reached: {}", reason);
+                            let mut text = format!("Budget warning: {}.", reason);
+                            match self.downgrade_for_budget(&budget_check.limits).await {
+                                Ok(Some(model)) => {
+                                    text.push_str(&format!(" Switching to {} to reduce cost.", model));
+                                    yield AgentEvent::ModelChange {
+                                        model,
+                                        mode: "budget".to_string(),
+                                    };
+                                }
+                                Ok(None) => {}
+                                Err(e) => warn!("Failed to switch to budget model: {}", e),
+                            }
+                            let message = Message::assistant().with_text(text).with_visibility(true, false);
+                            SessionManager::add_message(&session_config.id, &message).await?;
+                            yield AgentEvent::Message(message);
+                        }
+                        _ => {}
+                    }
+                }
+
                 {
                     let mut autopilot = self.autopilot.lock().await;
                     if let Some((new_provider, role, model)) = autopilot.check_for_switch(&conversation, self.provider().await?).await? {
@@ -1252,7 +1320,7 @@ impl Agent {
                             // Record usage for the session
                             if let Some(ref session_config) = &session {
                                 if let Some(ref usage) = usage {
-                                    Self::update_session_metrics(session_config, usage).await?;
+                                    Self::update_session_metrics(session_config, &provider, usage).await?;
                                 }
                             }
 
@@ -1325,6 +1393,15 @@ impl Agent {
                                             result
                                         });
 
+                                    audit::record(&audit::inspection_entries(
+                                        session.as_ref().map(|s| s.id.as_str()),
+                                        &remaining_requests,
+                                        &inspection_results,
+                                        &permission_check_result,
+                                        &audit::principal(),
+                                    ))
+                                    .await;
+
                                     // Track extension requests for special handling
                                     let mut enable_extension_request_ids = vec![];
                                     for request in &remaining_requests {
@@ -1351,6 +1428,7 @@ impl Agent {
                                         message_tool_response.clone(),
                                         cancel_token.clone(),
                                         &inspection_results,
+                                        &session,
                                     );
 
                                     while let Some(msg) = tool_approval_stream.try_next().await? {
@@ -1410,7 +1488,11 @@ impl Agent {
                         Err(ProviderError::ContextLengthExceeded(error_msg)) => {
                             info!("Context length exceeded, attempting compaction");
 
-                            match auto_compact::perform_compaction(self, conversation.messages()).await {
+                            match auto_compact::perform_compaction(
+                                self,
+                                conversation.messages(),
+                                session.as_ref().and_then(|s| s.context_strategy),
+                            ).await {
                                 Ok(compact_result) => {
                                     conversation = compact_result.messages;
 
@@ -1447,7 +1529,17 @@ impl Agent {
                 }
                 let mut exit_chat = false;
                 if no_tools_called {
-                    if let Some(final_output_tool) = self.final_output_tool.lock().await.as_ref() {
+                    if let Some(final_output_tool) = self.final_output_tool.lock().await.as_mut() {
+                        let provider = self.provider().await?;
+                        if final_output_tool.final_output.is_none() && provider.supports_structured_output() {
+                            let request: Vec<Message> = conversation.iter().chain(&messages_to_add).cloned().collect();
+                            let usage = final_output_tool
+                                .collect_structured_output(provider.as_ref(), &system_prompt, &request)
+                                .await;
+                            if let (Some(session_config), Some(usage)) = (&session, usage) {
+                                Self::update_session_metrics(session_config, &provider, &usage).await?;
+                            }
+                        }
                         if final_output_tool.final_output.is_none() {
                             warn!("Final output tool has not been called yet. Continuing agent loop.");
                             let message = Message::user().with_text(FINAL_OUTPUT_CONTINUATION_MESSAGE);
@@ -1495,6 +1587,10 @@ impl Agent {
     }
 
     fn determine_goose_mode(session: Option<&SessionConfig>, config: &Config) -> String {
+        if let Some(goose_mode) = session.and_then(|s| s.goose_mode.clone()) {
+            return goose_mode;
+        }
+
         let mode = session.and_then(|s| s.execution_mode.as_deref());
 
         match mode {
@@ -1780,6 +1876,8 @@ impl Agent {
             goose_provider: Some(provider_name.clone()),
             goose_model: Some(model_name.clone()),
             temperature: Some(model_config.temperature.unwrap_or(0.0)),
+            budget: None,
+            context_strategy: None,
         };
 
         tracing::debug!(
@@ -0,0 +1,254 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::agents::types::SessionConfig;
+use crate::agents::Agent;
+use crate::config::Config;
+use crate::model::ModelConfig;
+use crate::providers;
+use crate::session::{SessionManager, UsageTotals};
+
+/// Config key holding the default per-session budget
+pub const GOOSE_SESSION_BUDGET: &str = "GOOSE_SESSION_BUDGET";
+
+/// Config key holding the default per-schedule budget
+pub const GOOSE_SCHEDULE_BUDGET: &str = "GOOSE_SCHEDULE_BUDGET";
+
+/// Schedule budgets cover every run of the schedule within this many trailing hours
+pub const SCHEDULE_BUDGET_WINDOW_HOURS: i64 = 24;
+
+/// Token and estimated spend caps for a session or a schedule
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BudgetLimits {
+    /// Warn once this many tokens have been used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soft_token_limit: Option<i64>,
+    /// End the turn once this many tokens have been used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hard_token_limit: Option<i64>,
+    /// Warn once the estimated cost reaches this many USD
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soft_cost_limit: Option<f64>,
+    /// End the turn once the estimated cost reaches this many USD
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hard_cost_limit: Option<f64>,
+    /// Model to switch to when a soft limit is reached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downgrade_model: Option<String>,
+    /// Provider for `downgrade_model`, defaults to the configured GOOSE_PROVIDER
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downgrade_provider: Option<String>,
+}
+
+/// Outcome of comparing usage against a budget
+#[derive(Debug, Clone, PartialEq)]
+pub enum BudgetStatus {
+    WithinLimits,
+    /// A soft limit was reached; carries a human readable reason
+    SoftLimitReached(String),
+    /// A hard limit was reached; carries a human readable reason
+    HardLimitReached(String),
+}
+
+impl BudgetStatus {
+    fn severity(&self) -> u8 {
+        match self {
+            BudgetStatus::WithinLimits => 0,
+            BudgetStatus::SoftLimitReached(_) => 1,
+            BudgetStatus::HardLimitReached(_) => 2,
+        }
+    }
+}
+
+impl BudgetLimits {
+    /// Compare usage against these limits, hard limits taking precedence over soft ones
+    pub fn check(&self, scope: &str, usage: &UsageTotals) -> BudgetStatus {
+        if let Some(reason) =
+            Self::exceeded(scope, self.hard_token_limit, self.hard_cost_limit, usage)
+        {
+            return BudgetStatus::HardLimitReached(reason);
+        }
+        if let Some(reason) =
+            Self::exceeded(scope, self.soft_token_limit, self.soft_cost_limit, usage)
+        {
+            return BudgetStatus::SoftLimitReached(reason);
+        }
+        BudgetStatus::WithinLimits
+    }
+
+    fn exceeded(
+        scope: &str,
+        token_limit: Option<i64>,
+        cost_limit: Option<f64>,
+        usage: &UsageTotals,
+    ) -> Option<String> {
+        if let Some(limit) = token_limit {
+            if usage.tokens >= limit {
+                return Some(format!(
+                    "{} token limit of {} reached ({} used)",
+                    scope, limit, usage.tokens
+                ));
+            }
+        }
+        if let Some(limit) = cost_limit {
+            if usage.cost >= limit {
+                return Some(format!(
+                    "{} spend limit of ${:.2} reached (${:.2} spent)",
+                    scope, limit, usage.cost
+                ));
+            }
+        }
+        None
+    }
+}
+
+/// The most severe budget outcome for a session, along with the limits that produced it
+#[derive(Debug, Clone)]
+pub struct BudgetCheck {
+    pub status: BudgetStatus,
+    pub limits: BudgetLimits,
+}
+
+/// Check the session's budget and, for scheduled sessions, the schedule's budget.
+/// Limits on the session config take precedence over the defaults in config.yaml.
+pub async fn check_budget(session_config: &SessionConfig) -> Result<BudgetCheck> {
+    let config = Config::global();
+    let mut result = BudgetCheck {
+        status: BudgetStatus::WithinLimits,
+        limits: BudgetLimits::default(),
+    };
+
+    let session_limits = session_config
+        .budget
+        .clone()
+        .or_else(|| config.get_param::<BudgetLimits>(GOOSE_SESSION_BUDGET).ok());
+    if let Some(limits) = session_limits {
+        let session = SessionManager::get_session(&session_config.id, false).await?;
+        let usage = UsageTotals {
+            tokens: session.accumulated_total_tokens.unwrap_or(0) as i64,
+            cost: session.accumulated_cost.unwrap_or(0.0),
+        };
+        let status = limits.check("Session", &usage);
+        if status.severity() > result.status.severity() {
+            result = BudgetCheck { status, limits };
+        }
+    }
+
+    if let Some(schedule_id) = &session_config.schedule_id {
+        let schedule_limits = session_config
+            .schedule_budget
+            .clone()
+            .or_else(|| config.get_param::<BudgetLimits>(GOOSE_SCHEDULE_BUDGET).ok());
+        if let Some(limits) = schedule_limits {
+            let since = chrono::Utc::now() - chrono::Duration::hours(SCHEDULE_BUDGET_WINDOW_HOURS);
+            let usage = SessionManager::get_schedule_usage(schedule_id, since).await?;
+            let status = limits.check(&format!("Schedule '{}'", schedule_id), &usage);
+            if status.severity() > result.status.severity() {
+                result = BudgetCheck { status, limits };
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+impl Agent {
+    /// Switch to the budget's downgrade model, returning its name if a switch happened
+    pub(crate) async fn downgrade_for_budget(
+        &self,
+        limits: &BudgetLimits,
+    ) -> Result<Option<String>> {
+        let Some(model) = &limits.downgrade_model else {
+            return Ok(None);
+        };
+
+        if self.provider().await?.get_model_config().model_name == *model {
+            return Ok(None);
+        }
+
+        let provider_name = match &limits.downgrade_provider {
+            Some(name) => name.clone(),
+            None => Config::global().get_param::<String>("GOOSE_PROVIDER")?,
+        };
+        let provider = providers::create(&provider_name, ModelConfig::new(model)?)?;
+        self.update_provider(provider).await?;
+
+        Ok(Some(model.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(tokens: i64, cost: f64) -> UsageTotals {
+        UsageTotals { tokens, cost }
+    }
+
+    #[test]
+    fn test_budget_within_limits() {
+        let limits = BudgetLimits {
+            soft_token_limit: Some(1000),
+            hard_cost_limit: Some(5.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            limits.check("Session", &usage(999, 4.99)),
+            BudgetStatus::WithinLimits
+        );
+        assert_eq!(
+            BudgetLimits::default().check("Session", &usage(i64::MAX, 1e9)),
+            BudgetStatus::WithinLimits
+        );
+    }
+
+    #[test]
+    fn test_budget_hard_limit_takes_precedence() {
+        let limits = BudgetLimits {
+            soft_token_limit: Some(1000),
+            hard_token_limit: Some(2000),
+            soft_cost_limit: Some(1.0),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            limits.check("Session", &usage(1500, 0.5)),
+            BudgetStatus::SoftLimitReached(
+                "Session token limit of 1000 reached (1500 used)".to_string()
+            )
+        );
+        assert_eq!(
+            limits.check("Session", &usage(2500, 2.0)),
+            BudgetStatus::HardLimitReached(
+                "Session token limit of 2000 reached (2500 used)".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_budget_cost_limit() {
+        let limits = BudgetLimits {
+            hard_cost_limit: Some(2.5),
+            ..Default::default()
+        };
+        assert_eq!(
+            limits.check("Schedule 'nightly'", &usage(10, 3.0)),
+            BudgetStatus::HardLimitReached(
+                "Schedule 'nightly' spend limit of $2.50 reached ($3.00 spent)".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_budget_limits_deserialize_from_yaml() {
+        let limits: BudgetLimits = serde_yaml::from_str(
+            "soft_cost_limit: 1.5\nhard_cost_limit: 3\ndowngrade_model: gpt-4o-mini\n",
+        )
+        .unwrap();
+        assert_eq!(limits.soft_cost_limit, Some(1.5));
+        assert_eq!(limits.hard_cost_limit, Some(3.0));
+        assert_eq!(limits.downgrade_model.as_deref(), Some("gpt-4o-mini"));
+        assert_eq!(limits.hard_token_limit, None);
+    }
+}
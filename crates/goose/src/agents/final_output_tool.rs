@@ -1,13 +1,20 @@
 use crate::agents::tool_execution::ToolCallResult;
+use crate::conversation::message::Message;
+use crate::providers::base::{Provider, ProviderUsage};
 use crate::recipe::Response;
 use indoc::formatdoc;
 use rmcp::model::{CallToolRequestParam, Content, ErrorCode, ErrorData, Tool, ToolAnnotations};
 use serde_json::Value;
 use std::borrow::Cow;
+use tracing::warn;
 
 pub const FINAL_OUTPUT_TOOL_NAME: &str = "recipe__final_output";
 pub const FINAL_OUTPUT_CONTINUATION_MESSAGE: &str =
     "You MUST call the `final_output` tool NOW with the final output for the user.";
+pub const STRUCTURED_OUTPUT_REQUEST_MESSAGE: &str =
+    "Respond NOW with only the final output for the user as JSON matching the expected schema. Do not call any tools.";
+/// How many times a structured output that fails validation is sent back for repair
+pub const STRUCTURED_OUTPUT_REPAIR_ATTEMPTS: usize = 1;
 
 pub struct FinalOutputTool {
     pub response: Response,
@@ -142,19 +149,145 @@ impl FinalOutputTool {
         }
     }
 
+    /// Collect the final output through the provider's native structured output support
+    /// instead of waiting for the model to call the tool. The response is validated against
+    /// the schema and sent back for repair once; if it still does not validate the final
+    /// output stays unset so the caller can fall back to the tool.
+    ///
+    /// Returns the usage of all the requests made, if any succeeded.
+    pub async fn collect_structured_output(
+        &mut self,
+        provider: &dyn Provider,
+        system: &str,
+        messages: &[Message],
+    ) -> Option<ProviderUsage> {
+        let schema = self.response.json_schema.clone()?;
+        let mut messages = messages.to_vec();
+        messages.push(Message::user().with_text(STRUCTURED_OUTPUT_REQUEST_MESSAGE));
+
+        let mut total_usage: Option<ProviderUsage> = None;
+        for _ in 0..=STRUCTURED_OUTPUT_REPAIR_ATTEMPTS {
+            let (response, usage) = match provider
+                .complete_structured(system, &messages, &schema)
+                .await
+            {
+                Ok(result) => result,
+                Err(e) => {
+                    warn!("Structured output request failed: {}", e);
+                    break;
+                }
+            };
+            total_usage = Some(match total_usage {
+                Some(total) => ProviderUsage::new(usage.model, total.usage + usage.usage),
+                None => usage,
+            });
+
+            let text = response.as_concat_text();
+            let error = match serde_json::from_str::<Value>(strip_code_fence(&text)) {
+                Ok(output) => match self.validate_json_output(&output).await {
+                    Ok(parsed_value) => {
+                        self.final_output = Some(Self::parsed_final_output_string(parsed_value));
+                        break;
+                    }
+                    Err(error) => error,
+                },
+                Err(e) => format!(
+                    "Your response was not valid JSON: {}\n\nRespond with only the JSON final output.",
+                    e
+                ),
+            };
+            warn!("Structured output did not match the schema: {}", error);
+            messages.push(response);
+            messages.push(Message::user().with_text(error));
+        }
+        total_usage
+    }
+
     // Formats the parsed JSON as a single line string so its easy to extract from the output
     fn parsed_final_output_string(parsed_json: Value) -> String {
         serde_json::to_string(&parsed_json).unwrap()
     }
 }
 
+/// Some models wrap JSON in a markdown code fence even when asked not to
+fn strip_code_fence(text: &str) -> &str {
+    let trimmed = text.trim();
+    trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|rest| rest.strip_suffix("```"))
+        .map(str::trim)
+        .unwrap_or(trimmed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::ModelConfig;
+    use crate::providers::base::{ProviderMetadata, Usage};
+    use crate::providers::errors::ProviderError;
     use crate::recipe::Response;
     use rmcp::model::CallToolRequestParam;
     use rmcp::object;
     use serde_json::json;
+    use std::sync::Mutex;
+
+    /// Answers structured output requests with canned responses, in order
+    struct StructuredProvider {
+        responses: Mutex<Vec<&'static str>>,
+        requests: Mutex<Vec<Vec<Message>>>,
+    }
+
+    impl StructuredProvider {
+        fn new(responses: Vec<&'static str>) -> Self {
+            Self {
+                responses: Mutex::new(responses),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Provider for StructuredProvider {
+        fn metadata() -> ProviderMetadata {
+            ProviderMetadata::empty()
+        }
+
+        fn get_model_config(&self) -> ModelConfig {
+            ModelConfig::new_or_fail("structured")
+        }
+
+        async fn complete_with_model(
+            &self,
+            _model_config: &ModelConfig,
+            _system: &str,
+            _messages: &[Message],
+            _tools: &[Tool],
+        ) -> Result<(Message, ProviderUsage), ProviderError> {
+            unreachable!("the final output tool should not be used")
+        }
+
+        fn supports_structured_output(&self) -> bool {
+            true
+        }
+
+        async fn complete_structured(
+            &self,
+            _system: &str,
+            messages: &[Message],
+            _schema: &Value,
+        ) -> Result<(Message, ProviderUsage), ProviderError> {
+            self.requests.lock().unwrap().push(messages.to_vec());
+            let text = self.responses.lock().unwrap().remove(0);
+            Ok((
+                Message::assistant().with_text(text),
+                ProviderUsage::new(
+                    "structured".to_string(),
+                    Usage::new(Some(10), Some(5), Some(15)),
+                ),
+            ))
+        }
+    }
 
     fn create_complex_test_schema() -> Value {
         json!({
@@ -269,4 +402,49 @@ mod tests {
         assert!(serde_json::from_str::<Value>(&final_output).is_ok());
         assert!(!final_output.contains('\n'));
     }
+
+    #[tokio::test]
+    async fn test_collect_structured_output_repairs_once() {
+        let response = Response {
+            json_schema: Some(create_complex_test_schema()),
+        };
+        let mut tool = FinalOutputTool::new(response);
+        let provider = StructuredProvider::new(vec![
+            r#"{"user": {"name": "John"}, "tags": []}"#,
+            "```json\n{\"user\": {\"name\": \"John\", \"age\": 30}, \"tags\": [\"rust\"]}\n```",
+        ]);
+
+        let usage = tool
+            .collect_structured_output(&provider, "system", &[Message::user().with_text("hi")])
+            .await
+            .expect("expected usage");
+
+        assert_eq!(usage.usage.total_tokens, Some(30));
+        let final_output: Value =
+            serde_json::from_str(tool.final_output.as_ref().unwrap()).unwrap();
+        assert_eq!(final_output["user"]["age"], 30);
+
+        // The repair request carries the rejected output and the validation errors
+        let requests = provider.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        let repair = requests[1].last().unwrap().as_concat_text();
+        assert!(repair.contains("Validation failed"));
+    }
+
+    #[tokio::test]
+    async fn test_collect_structured_output_falls_back_after_failed_repair() {
+        let response = Response {
+            json_schema: Some(create_complex_test_schema()),
+        };
+        let mut tool = FinalOutputTool::new(response);
+        let provider = StructuredProvider::new(vec!["not json", r#"{"tags": []}"#]);
+
+        let usage = tool
+            .collect_structured_output(&provider, "system", &[Message::user().with_text("hi")])
+            .await;
+
+        assert!(usage.is_some());
+        assert!(tool.final_output.is_none());
+        assert_eq!(provider.requests.lock().unwrap().len(), 2);
+    }
 }
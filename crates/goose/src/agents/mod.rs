@@ -1,4 +1,5 @@
 mod agent;
+pub mod budget;
 mod context;
 pub mod extension;
 pub mod extension_malware_check;
@@ -27,6 +28,7 @@ mod tool_router_index_manager;
 pub mod types;
 
 pub use agent::{Agent, AgentEvent};
+pub use budget::BudgetLimits;
 pub use extension::ExtensionConfig;
 pub use extension_manager::ExtensionManager;
 pub use prompt_manager::PromptManager;
@@ -8,6 +8,7 @@ pub const PLATFORM_SEARCH_AVAILABLE_EXTENSIONS_TOOL_NAME: &str =
     "platform__search_available_extensions";
 pub const PLATFORM_MANAGE_EXTENSIONS_TOOL_NAME: &str = "platform__manage_extensions";
 pub const PLATFORM_MANAGE_SCHEDULE_TOOL_NAME: &str = "platform__manage_schedule";
+pub const PLATFORM_RECALL_MESSAGES_TOOL_NAME: &str = "platform__recall_messages";
 
 pub fn read_resource_tool() -> Tool {
     Tool::new(
@@ -154,3 +155,37 @@ pub fn manage_schedule_tool() -> Tool {
         open_world_hint: Some(false),
     })
 }
+
+pub fn recall_messages_tool() -> Tool {
+    Tool::new(
+        PLATFORM_RECALL_MESSAGES_TOOL_NAME.to_string(),
+        indoc! {r#"
+            Recall messages from earlier in this session that were compacted out of your context.
+
+            When the conversation nears the context limit it is summarized or reduced, and the
+            original messages are archived. Use this tool when you need the exact content of one of
+            them again, such as a file you read or an error message, instead of re-running tools.
+
+            Archived messages are numbered by their index in the conversation. Search them by
+            keywords, which must all appear in a message, or fetch an index range. Results are
+            limited to max_tokens; narrow the query or range to see more.
+        "#}
+        .to_string(),
+        object!({
+            "type": "object",
+            "properties": {
+                "query": {"type": "string", "description": "Keywords to search archived messages for, case insensitive"},
+                "start": {"type": "integer", "description": "First message index to return"},
+                "end": {"type": "integer", "description": "Last message index to return"},
+                "max_tokens": {"type": "integer", "description": "Maximum tokens to return, up to 16000", "default": 4000}
+            }
+        }),
+    )
+    .annotate(ToolAnnotations {
+        title: Some("Recall compacted messages".to_string()),
+        read_only_hint: Some(true),
+        destructive_hint: Some(false),
+        idempotent_hint: Some(true),
+        open_world_hint: Some(false),
+    })
+}
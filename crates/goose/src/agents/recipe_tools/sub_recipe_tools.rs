@@ -1,5 +1,4 @@
-use std::collections::HashSet;
-use std::fs;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -9,11 +8,14 @@ use serde_json::{json, Map, Value};
 use crate::agents::subagent_execution_tool::lib::ExecutionMode;
 use crate::agents::subagent_execution_tool::task_types::{Task, TaskType};
 use crate::agents::subagent_execution_tool::tasks_manager::TasksManager;
-use crate::recipe::{Recipe, RecipeParameter, RecipeParameterRequirement, SubRecipe};
+use crate::recipe::build_recipe::load_recipe_from_chain;
+use crate::recipe::read_recipe_file_content::read_recipe_file;
+use crate::recipe::{RecipeParameter, RecipeParameterRequirement, SubRecipe};
 
 use super::param_utils::prepare_command_params;
 
 pub const SUB_RECIPE_TASK_TOOL_NAME_PREFIX: &str = "subrecipe__create_task";
+pub const SUB_RECIPE_STEPS_TOOL_NAME: &str = "subrecipe__create_step_tasks";
 
 pub fn create_sub_recipe_task_tool(sub_recipe: &SubRecipe) -> Tool {
     let input_schema = get_input_schema(sub_recipe).unwrap();
@@ -43,6 +45,40 @@ pub fn create_sub_recipe_task_tool(sub_recipe: &SubRecipe) -> Tool {
     })
 }
 
+pub fn create_sub_recipe_steps_tool(sub_recipes: &[SubRecipe]) -> Tool {
+    let input_schema = get_steps_input_schema(sub_recipes).unwrap();
+    let steps = sub_recipes
+        .iter()
+        .map(|sub_recipe| match &sub_recipe.depends_on {
+            Some(depends_on) if !depends_on.is_empty() => {
+                format!("- {} (after {})", sub_recipe.name, depends_on.join(", "))
+            }
+            _ => format!("- {}", sub_recipe.name),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Tool::new(
+        SUB_RECIPE_STEPS_TOOL_NAME,
+        format!(
+            "Create one task for each step of this recipe's sub recipe workflow:\n{}\n\n\
+            Provide the parameters each step needs in the 'step_parameters' field, keyed by step name. \
+            Steps receive the outputs of the steps they depend on automatically. \
+            After creating the tasks, pass all of them to the task executor in a single call: \
+            independent steps run in parallel and a step is skipped when a step it depends on fails.",
+            steps
+        ),
+        Arc::new(input_schema.as_object().unwrap().clone()),
+    )
+    .annotate(ToolAnnotations {
+        title: Some("create sub recipe workflow tasks".to_string()),
+        read_only_hint: Some(false),
+        destructive_hint: Some(true),
+        idempotent_hint: Some(false),
+        open_world_hint: Some(true),
+    })
+}
+
 fn extract_task_parameters(params: &Value) -> Vec<Value> {
     params
         .get("task_parameters")
@@ -63,7 +99,8 @@ fn create_tasks_from_params(
                     "name": sub_recipe.name.clone(),
                     "command_parameters": task_command_param,
                     "recipe_path": sub_recipe.path.clone(),
-                    "sequential_when_repeated": sub_recipe.sequential_when_repeated
+                    "sequential_when_repeated": sub_recipe.sequential_when_repeated,
+                    "depends_on": sub_recipe.depends_on.clone().unwrap_or_default()
                 }
             });
             Task {
@@ -106,12 +143,44 @@ pub async fn create_sub_recipe_task(
     Ok(tasks_json)
 }
 
+pub async fn create_sub_recipe_step_tasks(
+    sub_recipes: &[SubRecipe],
+    params: Value,
+    tasks_manager: &TasksManager,
+) -> Result<String> {
+    let step_parameters = params
+        .get("step_parameters")
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
+
+    let mut tasks = Vec::new();
+    for sub_recipe in sub_recipes {
+        let params_from_tool_call = step_parameters
+            .get(&sub_recipe.name)
+            .cloned()
+            .into_iter()
+            .collect();
+        let command_params: Vec<HashMap<String, String>> =
+            prepare_command_params(sub_recipe, params_from_tool_call)?;
+        tasks.extend(create_tasks_from_params(sub_recipe, &command_params));
+    }
+    let task_ids: Vec<String> = tasks.iter().map(|task| task.id.clone()).collect();
+    let task_execution_payload = json!({
+        "task_ids": task_ids,
+        "execution_mode": ExecutionMode::Parallel,
+    });
+
+    let tasks_json = serde_json::to_string(&task_execution_payload)
+        .map_err(|e| anyhow::anyhow!("Failed to serialize task list: {}", e))?;
+    tasks_manager.save_tasks(tasks).await;
+    Ok(tasks_json)
+}
+
 fn get_sub_recipe_parameter_definition(
     sub_recipe: &SubRecipe,
 ) -> Result<Option<Vec<RecipeParameter>>> {
-    let content = fs::read_to_string(sub_recipe.path.clone())
-        .map_err(|e| anyhow::anyhow!("Failed to read recipe file {}: {}", sub_recipe.path, e))?;
-    let recipe = Recipe::from_content(&content)?;
+    let recipe = load_recipe_from_chain(read_recipe_file(&sub_recipe.path)?)?;
     Ok(recipe.parameters)
 }
 
@@ -150,7 +219,51 @@ fn create_input_schema(param_properties: Map<String, Value>, param_required: Vec
     })
 }
 
+fn get_steps_input_schema(sub_recipes: &[SubRecipe]) -> Result<Value> {
+    let mut step_properties = Map::new();
+    let mut steps_required = Vec::new();
+    for sub_recipe in sub_recipes {
+        let (param_properties, param_required) = get_param_properties(sub_recipe)?;
+        if param_properties.is_empty() {
+            continue;
+        }
+        if !param_required.is_empty() {
+            steps_required.push(sub_recipe.name.clone());
+        }
+        step_properties.insert(
+            sub_recipe.name.clone(),
+            json!({
+                "type": "object",
+                "properties": param_properties,
+                "required": param_required
+            }),
+        );
+    }
+
+    let mut properties = Map::new();
+    if !step_properties.is_empty() {
+        properties.insert(
+            "step_parameters".to_string(),
+            json!({
+                "type": "object",
+                "description": "Parameter values for the steps that need them, keyed by step name.",
+                "properties": step_properties,
+                "required": steps_required
+            }),
+        );
+    }
+    Ok(json!({
+        "type": "object",
+        "properties": properties,
+    }))
+}
+
 fn get_input_schema(sub_recipe: &SubRecipe) -> Result<Value> {
+    let (param_properties, param_required) = get_param_properties(sub_recipe)?;
+    Ok(create_input_schema(param_properties, param_required))
+}
+
+fn get_param_properties(sub_recipe: &SubRecipe) -> Result<(Map<String, Value>, Vec<String>)> {
     let sub_recipe_params_with_values = get_params_with_values(sub_recipe);
 
     let parameter_definition = get_sub_recipe_parameter_definition(sub_recipe)?;
@@ -175,7 +288,7 @@ fn get_input_schema(sub_recipe: &SubRecipe) -> Result<Value> {
             }
         }
     }
-    Ok(create_input_schema(param_properties, param_required))
+    Ok((param_properties, param_required))
 }
 
 #[cfg(test)]
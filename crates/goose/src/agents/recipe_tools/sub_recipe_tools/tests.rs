@@ -12,6 +12,7 @@ fn setup_default_sub_recipe() -> SubRecipe {
         values: Some(HashMap::from([("key1".to_string(), "value1".to_string())])),
         sequential_when_repeated: true,
         description: Some("Test subrecipe".to_string()),
+        depends_on: None,
     }
 }
 
@@ -125,4 +126,119 @@ mod get_input_schema {
             }),
         );
     }
+
+    #[test]
+    fn test_includes_params_from_extended_recipe() {
+        let (mut sub_recipe, temp_dir) = prepare_sub_recipe(
+            r#"{
+                "version": "1.0.0",
+                "title": "Child Recipe",
+                "description": "A child recipe",
+                "extends": "base.json",
+                "prompt": "Test prompt",
+                "parameters": [
+                    {
+                        "key": "key2",
+                        "input_type": "number",
+                        "requirement": "optional",
+                        "description": "An optional parameter"
+                    }
+                ]
+            }"#,
+        );
+        std::fs::write(
+            temp_dir.path().join("base.json"),
+            r#"{
+                "version": "1.0.0",
+                "title": "Base Recipe",
+                "description": "A base recipe",
+                "instructions": "Test instructions",
+                "parameters": [
+                    {
+                        "key": "key1",
+                        "input_type": "string",
+                        "requirement": "required",
+                        "description": "A test parameter"
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        sub_recipe.values = None;
+
+        let result = get_input_schema(&sub_recipe).unwrap();
+
+        verify_task_parameters(
+            result,
+            json!({
+                "type": "object",
+                "properties": {
+                    "key1": { "type": "string", "description": "A test parameter" },
+                    "key2": { "type": "number", "description": "An optional parameter" }
+                },
+                "required": ["key1"]
+            }),
+        );
+    }
+}
+
+mod create_sub_recipe_step_tasks {
+    use super::*;
+    use crate::agents::recipe_tools::sub_recipe_tools::create_sub_recipe_step_tasks;
+    use crate::agents::subagent_execution_tool::tasks_manager::TasksManager;
+
+    fn step(name: &str, depends_on: Option<Vec<&str>>, values: &[(&str, &str)]) -> SubRecipe {
+        SubRecipe {
+            name: name.to_string(),
+            path: format!("{}.yaml", name),
+            values: Some(
+                values
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            ),
+            sequential_when_repeated: false,
+            description: None,
+            depends_on: depends_on.map(|d| d.into_iter().map(String::from).collect()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_creates_one_task_per_step() {
+        let sub_recipes = vec![
+            step("research", None, &[]),
+            step(
+                "write",
+                Some(vec!["research"]),
+                &[("topic", "{{ steps.research.output.summary }}")],
+            ),
+        ];
+        let tasks_manager = TasksManager::new();
+
+        let output = create_sub_recipe_step_tasks(
+            &sub_recipes,
+            json!({ "step_parameters": { "research": { "subject": "rust" } } }),
+            &tasks_manager,
+        )
+        .await
+        .unwrap();
+
+        let payload: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(payload["execution_mode"], "parallel");
+        let task_ids: Vec<String> = serde_json::from_value(payload["task_ids"].clone()).unwrap();
+        let tasks = tasks_manager.get_tasks(&task_ids).await.unwrap();
+
+        assert_eq!(tasks[0].get_sub_recipe_name(), Some("research"));
+        assert_eq!(
+            tasks[0].get_command_parameters().unwrap()["subject"],
+            "rust"
+        );
+        assert!(tasks[0].get_depends_on().is_empty());
+        assert_eq!(tasks[1].get_sub_recipe_name(), Some("write"));
+        assert_eq!(tasks[1].get_depends_on(), vec!["research".to_string()]);
+        assert_eq!(
+            tasks[1].get_command_parameters().unwrap()["topic"],
+            "{{ steps.research.output.summary }}"
+        );
+    }
 }
@@ -6,10 +6,12 @@ use futures::stream::StreamExt;
 use tracing::debug;
 
 use super::super::agents::Agent;
+use crate::config::Config;
 use crate::conversation::message::{Message, MessageContent, ToolRequest};
 use crate::conversation::Conversation;
 use crate::providers::base::{stream_from_single_message, MessageStream, Provider, ProviderUsage};
 use crate::providers::errors::ProviderError;
+use crate::providers::pricing::estimate_cost;
 use crate::providers::toolshim::{
     augment_message_with_tool_calls, convert_tool_messages_to_text,
     modify_system_prompt_for_tool_json, OllamaInterpreter,
@@ -276,6 +278,7 @@ impl Agent {
 
     pub(crate) async fn update_session_metrics(
         session_config: &crate::agents::types::SessionConfig,
+        provider: &Arc<dyn Provider>,
         usage: &ProviderUsage,
     ) -> Result<()> {
         let session_id = session_config.id.as_str();
@@ -306,6 +309,41 @@ impl Agent {
             .apply()
             .await?;
 
+        let provider_name = provider_name_for_model(provider, &usage.model);
+        let input_tokens = usage.usage.input_tokens.unwrap_or(0).max(0) as usize;
+        let output_tokens = usage.usage.output_tokens.unwrap_or(0).max(0) as usize;
+        let cost = estimate_cost(&provider_name, &usage.model, input_tokens, output_tokens).await;
+
+        SessionManager::record_provider_usage(
+            session_id,
+            &provider_name,
+            &usage.model,
+            input_tokens as i64,
+            output_tokens as i64,
+            cost,
+        )
+        .await?;
+
         Ok(())
     }
 }
+
+/// The provider doesn't know its own name, so resolve it from config the same way the
+/// factory does, taking the lead provider into account when the lead model answered.
+fn provider_name_for_model(provider: &Arc<dyn Provider>, model: &str) -> String {
+    let config = Config::global();
+    let default_provider = config
+        .get_param::<String>("GOOSE_PROVIDER")
+        .unwrap_or_default();
+
+    if let Some(lead_worker) = provider.as_lead_worker() {
+        let (lead_model, _) = lead_worker.get_model_info();
+        if lead_model == model {
+            return config
+                .get_param::<String>("GOOSE_LEAD_PROVIDER")
+                .unwrap_or(default_provider);
+        }
+    }
+
+    default_provider
+}
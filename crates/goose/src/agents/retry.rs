@@ -1,4 +1,9 @@
 use anyhow::Result;
+use futures::future::BoxFuture;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::Arc;
 use std::time::Duration;
@@ -13,7 +18,11 @@ use crate::agents::types::{
 use crate::config::Config;
 use crate::conversation::message::Message;
 use crate::conversation::Conversation;
+use crate::providers::base::Provider;
+use crate::session::extension_data::{ExtensionState, SuccessCheckState};
+use crate::session::SessionManager;
 use crate::tool_monitor::RepetitionInspector;
+use crate::utils::safe_truncate;
 
 /// Result of a retry logic evaluation
 #[derive(Debug, Clone, PartialEq)]
@@ -28,6 +37,74 @@ pub enum RetryResult {
     Retried,
 }
 
+/// Outcome of a single success check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuccessCheckResult {
+    /// Short description of the check
+    pub check: String,
+    pub passed: bool,
+    /// Why the check passed or failed
+    pub detail: String,
+    /// Results of the nested checks of an `all_of` or `any_of` check
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<SuccessCheckResult>,
+}
+
+/// Per-check breakdown of one round of success checks
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SuccessCheckReport {
+    pub results: Vec<SuccessCheckResult>,
+}
+
+impl SuccessCheckReport {
+    /// True when every top-level check passed
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|result| result.passed)
+    }
+
+    /// List the failed checks, with nested failures indented, for telling the model what to fix
+    pub fn failure_summary(&self) -> String {
+        fn push_failures(results: &[SuccessCheckResult], depth: usize, lines: &mut Vec<String>) {
+            for result in results.iter().filter(|r| !r.passed) {
+                lines.push(format!(
+                    "{}- {}: {}",
+                    "  ".repeat(depth),
+                    result.check,
+                    result.detail
+                ));
+                push_failures(&result.children, depth + 1, lines);
+            }
+        }
+
+        let mut lines = Vec::new();
+        push_failures(&self.results, 0, &mut lines);
+        lines.join("\n")
+    }
+}
+
+/// What success checks can inspect besides the shell and the filesystem
+#[derive(Default)]
+pub struct SuccessCheckContext {
+    /// Directory that relative file paths are resolved against
+    pub working_dir: Option<PathBuf>,
+    /// The recipe's structured final output, if one was collected
+    pub final_output: Option<String>,
+    /// The conversation being checked, for `llm_judge`
+    pub messages: Vec<Message>,
+    /// Provider used by `llm_judge` checks
+    pub provider: Option<Arc<dyn Provider>>,
+}
+
+/// Maximum characters of command output or response body kept in a check result
+const CHECK_DETAIL_MAX_CHARS: usize = 500;
+
+/// Maximum characters of conversation transcript shown to an `llm_judge` check
+const JUDGE_TRANSCRIPT_MAX_CHARS: usize = 20_000;
+
+const JUDGE_SYSTEM_PROMPT: &str = "You judge whether an AI agent completed a task. \
+Read the rubric and the transcript, then answer with PASS or FAIL on the first line \
+followed by a one sentence reason on the second line.";
+
 /// Environment variable for configuring retry timeout globally
 const GOOSE_RECIPE_RETRY_TIMEOUT_SECONDS: &str = "GOOSE_RECIPE_RETRY_TIMEOUT_SECONDS";
 
@@ -93,13 +170,23 @@ impl RetryManager {
         *self.attempts.lock().await
     }
 
-    /// Reset status for retry: clear message history and final output tool state
+    /// Reset status for retry: clear message history and final output tool state.
+    /// The feedback about failed checks is added to the last user message so the next
+    /// attempt knows what to fix.
     async fn reset_status_for_retry(
         messages: &mut Conversation,
         initial_messages: &[Message],
         final_output_tool: &Arc<Mutex<Option<crate::agents::final_output_tool::FinalOutputTool>>>,
+        feedback: &str,
     ) {
-        *messages = Conversation::new_unvalidated(initial_messages.to_vec());
+        let mut reset_messages = initial_messages.to_vec();
+        match reset_messages.last_mut() {
+            Some(last) if last.role == rmcp::model::Role::User => {
+                *last = last.clone().with_text(feedback);
+            }
+            _ => reset_messages.push(Message::user().with_text(feedback)),
+        }
+        *messages = Conversation::new_unvalidated(reset_messages);
         info!("Reset message history to initial state for retry");
 
         if let Some(final_output_tool) = final_output_tool.lock().await.as_mut() {
@@ -115,6 +202,7 @@ impl RetryManager {
         session: &Option<SessionConfig>,
         initial_messages: &[Message],
         final_output_tool: &Arc<Mutex<Option<crate::agents::final_output_tool::FinalOutputTool>>>,
+        provider: Option<Arc<dyn Provider>>,
     ) -> Result<RetryResult> {
         let Some(session_config) = session else {
             return Ok(RetryResult::Skipped);
@@ -124,9 +212,21 @@ impl RetryManager {
             return Ok(RetryResult::Skipped);
         };
 
-        let success = execute_success_checks(&retry_config.checks, retry_config).await?;
+        let final_output = final_output_tool
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|tool| tool.final_output.clone());
+        let context = SuccessCheckContext {
+            working_dir: Some(session_config.working_dir.clone()),
+            final_output,
+            messages: messages.messages().clone(),
+            provider,
+        };
+        let report = execute_success_checks(&retry_config.checks, retry_config, &context).await?;
+        record_success_checks(&session_config.id, self.get_attempts().await, &report).await;
 
-        if success {
+        if report.passed() {
             info!("All success checks passed, no retry needed");
             return Ok(RetryResult::SuccessChecksPassed);
         }
@@ -150,7 +250,12 @@ impl RetryManager {
             execute_on_failure_command(on_failure_cmd, retry_config).await?;
         }
 
-        Self::reset_status_for_retry(messages, initial_messages, final_output_tool).await;
+        let feedback = format!(
+            "A previous attempt at this task did not pass these success checks:\n{}\n\nMake sure they pass this time.",
+            report.failure_summary()
+        );
+        Self::reset_status_for_retry(messages, initial_messages, final_output_tool, &feedback)
+            .await;
 
         let new_attempts = self.increment_attempts().await;
         info!("Incrementing retry attempts to {}", new_attempts);
@@ -189,34 +294,284 @@ fn get_on_failure_timeout(retry_config: &RetryConfig) -> Duration {
     Duration::from_secs(timeout_seconds)
 }
 
-/// Execute all success checks and return true if all pass
+/// Store the latest check report in the session so it can be inspected after the run
+async fn record_success_checks(session_id: &str, attempt: u32, report: &SuccessCheckReport) {
+    let mut session = match SessionManager::get_session(session_id, false).await {
+        Ok(session) => session,
+        Err(e) => {
+            warn!("Failed to load session to record success checks: {}", e);
+            return;
+        }
+    };
+    let state = SuccessCheckState {
+        attempt,
+        report: report.clone(),
+    };
+    if let Err(e) = state.to_extension_data(&mut session.extension_data) {
+        warn!("Failed to serialize success check state: {}", e);
+        return;
+    }
+    if let Err(e) = SessionManager::update_session(session_id)
+        .extension_data(session.extension_data)
+        .apply()
+        .await
+    {
+        warn!("Failed to save success check state: {}", e);
+    }
+}
+
+/// Execute all success checks and report the outcome of each one
 pub async fn execute_success_checks(
     checks: &[SuccessCheck],
     retry_config: &RetryConfig,
-) -> Result<bool> {
+    context: &SuccessCheckContext,
+) -> Result<SuccessCheckReport> {
     let timeout = get_retry_timeout(retry_config);
 
+    let mut results = Vec::with_capacity(checks.len());
     for check in checks {
+        let result = execute_success_check(check, context, timeout).await;
+        if result.passed {
+            info!("Success check passed: {}", result.check);
+        } else {
+            warn!("Success check failed: {}: {}", result.check, result.detail);
+        }
+        results.push(result);
+    }
+    Ok(SuccessCheckReport { results })
+}
+
+/// Run one check. Errors while running a check count as a failure of that check.
+fn execute_success_check<'a>(
+    check: &'a SuccessCheck,
+    context: &'a SuccessCheckContext,
+    timeout: Duration,
+) -> BoxFuture<'a, SuccessCheckResult> {
+    Box::pin(async move {
+        let result = |passed: bool, detail: String| SuccessCheckResult {
+            check: check.describe(),
+            passed,
+            detail,
+            children: Vec::new(),
+        };
+
         match check {
             SuccessCheck::Shell { command } => {
-                let result = execute_shell_command(command, timeout).await?;
-                if !result.status.success() {
-                    warn!(
-                        "Success check failed: command '{}' exited with status {}, stderr: {}",
-                        command,
-                        result.status,
-                        String::from_utf8_lossy(&result.stderr)
-                    );
-                    return Ok(false);
+                match execute_shell_command(command, timeout).await {
+                    Ok(output) if output.status.success() => {
+                        result(true, "exited successfully".to_string())
+                    }
+                    Ok(output) => {
+                        let stderr = String::from_utf8_lossy(&output.stderr);
+                        let mut detail = format!("exited with {}", output.status);
+                        if !stderr.trim().is_empty() {
+                            detail.push_str(&format!(
+                                ", stderr: {}",
+                                safe_truncate(stderr.trim(), CHECK_DETAIL_MAX_CHARS)
+                            ));
+                        }
+                        result(false, detail)
+                    }
+                    Err(e) => result(false, e.to_string()),
+                }
+            }
+            SuccessCheck::FileExists { path } => {
+                let full_path = resolve_path(context, path);
+                if full_path.exists() {
+                    result(true, "file exists".to_string())
+                } else {
+                    result(false, format!("{} does not exist", full_path.display()))
+                }
+            }
+            SuccessCheck::FileContains { path, pattern } => {
+                let full_path = resolve_path(context, path);
+                let regex = match Regex::new(pattern) {
+                    Ok(regex) => regex,
+                    Err(e) => return result(false, format!("invalid pattern: {}", e)),
+                };
+                match tokio::fs::read_to_string(&full_path).await {
+                    Ok(contents) if regex.is_match(&contents) => {
+                        result(true, "contents match".to_string())
+                    }
+                    Ok(_) => result(false, "contents do not match the pattern".to_string()),
+                    Err(e) => result(
+                        false,
+                        format!("could not read {}: {}", full_path.display(), e),
+                    ),
+                }
+            }
+            SuccessCheck::JsonSchema { schema, path } => {
+                let text = match path {
+                    Some(path) => {
+                        let full_path = resolve_path(context, path);
+                        match tokio::fs::read_to_string(&full_path).await {
+                            Ok(text) => text,
+                            Err(e) => {
+                                return result(
+                                    false,
+                                    format!("could not read {}: {}", full_path.display(), e),
+                                )
+                            }
+                        }
+                    }
+                    None => match &context.final_output {
+                        Some(output) => output.clone(),
+                        None => return result(false, "no final output was produced".to_string()),
+                    },
+                };
+                match validate_json(schema, &text) {
+                    Ok(()) => result(true, "matches the schema".to_string()),
+                    Err(errors) => result(false, errors),
+                }
+            }
+            SuccessCheck::Http { url, status, body } => {
+                match check_http(url, *status, body.as_deref(), timeout).await {
+                    Ok(()) => result(true, format!("responded with {}", status)),
+                    Err(detail) => result(false, detail),
+                }
+            }
+            SuccessCheck::LlmJudge { rubric } => match judge(rubric, context).await {
+                Ok((passed, reason)) => result(passed, reason),
+                Err(e) => result(false, format!("judge failed: {}", e)),
+            },
+            SuccessCheck::AllOf { checks } | SuccessCheck::AnyOf { checks } => {
+                let mut children = Vec::with_capacity(checks.len());
+                for nested in checks {
+                    children.push(execute_success_check(nested, context, timeout).await);
+                }
+                let passed_count = children.iter().filter(|c| c.passed).count();
+                let passed = match check {
+                    SuccessCheck::AllOf { .. } => passed_count == children.len(),
+                    _ => passed_count > 0,
+                };
+                SuccessCheckResult {
+                    check: check.describe(),
+                    passed,
+                    detail: format!("{} of {} checks passed", passed_count, children.len()),
+                    children,
                 }
-                info!(
-                    "Success check passed: command '{}' completed successfully",
-                    command
-                );
             }
         }
+    })
+}
+
+fn resolve_path(context: &SuccessCheckContext, path: &str) -> PathBuf {
+    let path = Path::new(path);
+    match &context.working_dir {
+        Some(dir) if path.is_relative() => dir.join(path),
+        _ => path.to_path_buf(),
     }
-    Ok(true)
+}
+
+/// Validate `text` as JSON against `schema`, returning the validation errors on failure
+fn validate_json(schema: &Value, text: &str) -> Result<(), String> {
+    let value: Value =
+        serde_json::from_str(text.trim()).map_err(|e| format!("not valid JSON: {}", e))?;
+    let validator =
+        jsonschema::validator_for(schema).map_err(|e| format!("invalid schema: {}", e))?;
+    let errors: Vec<String> = validator
+        .iter_errors(&value)
+        .map(|error| format!("{}: {}", error.instance_path, error))
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+async fn check_http(
+    url: &str,
+    expected_status: u16,
+    body_pattern: Option<&str>,
+    timeout: Duration,
+) -> Result<(), String> {
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("request failed: {}", e))?;
+
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if status.as_u16() != expected_status {
+        return Err(format!(
+            "expected status {} but got {}: {}",
+            expected_status,
+            status,
+            safe_truncate(body.trim(), CHECK_DETAIL_MAX_CHARS)
+        ));
+    }
+    if let Some(pattern) = body_pattern {
+        let regex = Regex::new(pattern).map_err(|e| format!("invalid pattern: {}", e))?;
+        if !regex.is_match(&body) {
+            return Err(format!(
+                "body does not match /{}/: {}",
+                pattern,
+                safe_truncate(body.trim(), CHECK_DETAIL_MAX_CHARS)
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Ask the fast model whether the run satisfies `rubric`
+async fn judge(rubric: &str, context: &SuccessCheckContext) -> Result<(bool, String)> {
+    let provider = context
+        .provider
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("no provider available"))?;
+
+    let mut transcript = String::new();
+    for message in context.messages.iter().filter(|m| m.is_agent_visible()) {
+        let text = message.as_concat_text();
+        if text.trim().is_empty() {
+            continue;
+        }
+        let role = match message.role {
+            rmcp::model::Role::User => "User",
+            rmcp::model::Role::Assistant => "Assistant",
+        };
+        transcript.push_str(&format!("{}: {}\n\n", role, text.trim()));
+    }
+    if let Some(output) = &context.final_output {
+        transcript.push_str(&format!("Final output: {}\n", output));
+    }
+    // Keep the end of the transcript, where the outcome is
+    let skip = transcript
+        .chars()
+        .count()
+        .saturating_sub(JUDGE_TRANSCRIPT_MAX_CHARS);
+    let transcript: String = transcript.chars().skip(skip).collect();
+
+    let prompt = format!(
+        "Rubric:\n{}\n\nTranscript:\n{}\n\nDoes the outcome satisfy the rubric?",
+        rubric, transcript
+    );
+    let (response, _) = provider
+        .complete_fast(
+            JUDGE_SYSTEM_PROMPT,
+            &[Message::user().with_text(prompt)],
+            &[],
+        )
+        .await?;
+    Ok(parse_verdict(&response.as_concat_text()))
+}
+
+fn parse_verdict(text: &str) -> (bool, String) {
+    let mut lines = text.trim().lines();
+    let verdict = lines.next().unwrap_or_default().trim().to_uppercase();
+    let reason = lines.collect::<Vec<_>>().join(" ").trim().to_string();
+    // Models sometimes decorate the verdict, e.g. "**PASS**"
+    let passed = verdict
+        .trim_start_matches(|c: char| !c.is_alphabetic())
+        .starts_with("PASS");
+    let reason = if reason.is_empty() { verdict } else { reason };
+    (passed, reason)
 }
 
 /// Execute a shell command with cross-platform compatibility and mandatory timeout
@@ -358,9 +713,10 @@ mod tests {
         ];
         let retry_config = create_test_retry_config();
 
-        let result = execute_success_checks(&checks, &retry_config).await;
+        let result =
+            execute_success_checks(&checks, &retry_config, &SuccessCheckContext::default()).await;
         assert!(result.is_ok());
-        assert!(result.unwrap());
+        assert!(result.unwrap().passed());
     }
 
     #[tokio::test]
@@ -375,9 +731,212 @@ mod tests {
         ];
         let retry_config = create_test_retry_config();
 
-        let result = execute_success_checks(&checks, &retry_config).await;
+        let result =
+            execute_success_checks(&checks, &retry_config, &SuccessCheckContext::default()).await;
         assert!(result.is_ok());
-        assert!(!result.unwrap());
+        let report = result.unwrap();
+        assert!(!report.passed());
+        assert!(report.results[0].passed);
+        assert!(!report.results[1].passed);
+    }
+
+    #[tokio::test]
+    async fn test_file_checks_resolve_against_working_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("out.txt"), "count: 3\n").unwrap();
+        let checks = vec![
+            SuccessCheck::FileExists {
+                path: "out.txt".to_string(),
+            },
+            SuccessCheck::FileContains {
+                path: "out.txt".to_string(),
+                pattern: r"count: [3-9]".to_string(),
+            },
+            SuccessCheck::FileExists {
+                path: "missing.txt".to_string(),
+            },
+        ];
+        let context = SuccessCheckContext {
+            working_dir: Some(dir.path().to_path_buf()),
+            ..Default::default()
+        };
+
+        let report = execute_success_checks(&checks, &create_test_retry_config(), &context)
+            .await
+            .unwrap();
+
+        let passed: Vec<bool> = report.results.iter().map(|r| r.passed).collect();
+        assert_eq!(passed, vec![true, true, false]);
+        assert!(report.failure_summary().contains("missing.txt"));
+    }
+
+    #[tokio::test]
+    async fn test_json_schema_check_uses_final_output() {
+        let checks = vec![SuccessCheck::JsonSchema {
+            schema: serde_json::json!({
+                "type": "object",
+                "properties": {"count": {"type": "number"}},
+                "required": ["count"]
+            }),
+            path: None,
+        }];
+        let retry_config = create_test_retry_config();
+
+        let valid = SuccessCheckContext {
+            final_output: Some(r#"{"count": 3}"#.to_string()),
+            ..Default::default()
+        };
+        let report = execute_success_checks(&checks, &retry_config, &valid)
+            .await
+            .unwrap();
+        assert!(report.passed());
+
+        let invalid = SuccessCheckContext {
+            final_output: Some(r#"{"total": 3}"#.to_string()),
+            ..Default::default()
+        };
+        let report = execute_success_checks(&checks, &retry_config, &invalid)
+            .await
+            .unwrap();
+        assert!(!report.passed());
+        assert!(report.results[0].detail.contains("count"));
+    }
+
+    #[tokio::test]
+    async fn test_http_check_matches_status_and_body() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/health"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"status":"ok"}"#))
+            .mount(&mock_server)
+            .await;
+        let url = format!("{}/health", mock_server.uri());
+        let checks = vec![
+            SuccessCheck::Http {
+                url: url.clone(),
+                status: 200,
+                body: Some(r#""status":"ok""#.to_string()),
+            },
+            SuccessCheck::Http {
+                url,
+                status: 200,
+                body: Some("degraded".to_string()),
+            },
+        ];
+
+        let report = execute_success_checks(
+            &checks,
+            &create_test_retry_config(),
+            &SuccessCheckContext::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(report.results[0].passed);
+        assert!(!report.results[1].passed);
+        assert!(report.results[1].detail.contains("body does not match"));
+    }
+
+    #[tokio::test]
+    async fn test_composed_checks_report_nested_results() {
+        let checks = vec![
+            SuccessCheck::AnyOf {
+                checks: vec![
+                    SuccessCheck::Shell {
+                        command: "false".to_string(),
+                    },
+                    SuccessCheck::Shell {
+                        command: "true".to_string(),
+                    },
+                ],
+            },
+            SuccessCheck::AllOf {
+                checks: vec![
+                    SuccessCheck::Shell {
+                        command: "true".to_string(),
+                    },
+                    SuccessCheck::Shell {
+                        command: "false".to_string(),
+                    },
+                ],
+            },
+        ];
+
+        let report = execute_success_checks(
+            &checks,
+            &create_test_retry_config(),
+            &SuccessCheckContext::default(),
+        )
+        .await
+        .unwrap();
+
+        assert!(report.results[0].passed);
+        assert!(!report.results[1].passed);
+        assert_eq!(report.results[1].detail, "1 of 2 checks passed");
+        let summary = report.failure_summary();
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "- all_of (2 checks): 1 of 2 checks passed");
+        assert!(lines[1].starts_with("  - shell `false`: exited with"));
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        assert_eq!(
+            parse_verdict("PASS\nThe file was created."),
+            (true, "The file was created.".to_string())
+        );
+        assert_eq!(
+            parse_verdict("**FAIL**\nThe tests still fail."),
+            (false, "The tests still fail.".to_string())
+        );
+        assert_eq!(parse_verdict("pass"), (true, "PASS".to_string()));
+    }
+
+    #[test]
+    fn test_validate_rejects_remote_http_checks() {
+        let mut retry_config = create_test_retry_config();
+        retry_config.checks = vec![SuccessCheck::Http {
+            url: "https://example.com/health".to_string(),
+            status: 200,
+            body: None,
+        }];
+        assert!(retry_config.validate().is_err());
+
+        retry_config.checks = vec![SuccessCheck::Http {
+            url: "http://127.0.0.1:8080/health".to_string(),
+            status: 200,
+            body: None,
+        }];
+        assert!(retry_config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_deserialize_new_check_kinds() {
+        let checks: Vec<SuccessCheck> = serde_yaml::from_str(
+            r#"
+- type: file_contains
+  path: out.txt
+  pattern: "done"
+- type: any_of
+  checks:
+    - type: http
+      url: http://localhost:8080/health
+    - type: llm_judge
+      rubric: The summary mentions every failing test
+"#,
+        )
+        .unwrap();
+
+        assert!(matches!(checks[0], SuccessCheck::FileContains { .. }));
+        let SuccessCheck::AnyOf { checks: nested } = &checks[1] else {
+            panic!("expected any_of");
+        };
+        assert!(matches!(nested[0], SuccessCheck::Http { status: 200, .. }));
+        assert!(matches!(nested[1], SuccessCheck::LlmJudge { .. }));
     }
 
     #[tokio::test]
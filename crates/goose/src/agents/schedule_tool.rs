@@ -11,6 +11,7 @@ use rmcp::model::{Content, ErrorCode, ErrorData};
 
 use crate::recipe::Recipe;
 use crate::scheduler_trait::SchedulerTrait;
+use crate::scheduler_trigger::ScheduleTrigger;
 
 use super::Agent;
 
@@ -180,13 +181,26 @@ impl Agent {
         let job = crate::scheduler::ScheduledJob {
             id: job_id.clone(),
             source: recipe_path.to_string(),
-            cron: cron_expression.to_string(),
+            trigger: ScheduleTrigger::Cron {
+                expression: cron_expression.to_string(),
+            },
             last_run: None,
             currently_running: false,
             paused: false,
             current_session_id: None,
             process_start_time: None,
             execution_mode: Some(execution_mode.to_string()),
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: None,
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
         };
 
         match scheduler.add_scheduled_job(job).await {
@@ -7,7 +7,9 @@ use std::collections::HashMap;
 use crate::{
     agents::{
         recipe_tools::sub_recipe_tools::{
-            create_sub_recipe_task, create_sub_recipe_task_tool, SUB_RECIPE_TASK_TOOL_NAME_PREFIX,
+            create_sub_recipe_step_tasks, create_sub_recipe_steps_tool, create_sub_recipe_task,
+            create_sub_recipe_task_tool, SUB_RECIPE_STEPS_TOOL_NAME,
+            SUB_RECIPE_TASK_TOOL_NAME_PREFIX,
         },
         subagent_execution_tool::tasks_manager::TasksManager,
         tool_execution::ToolCallResult,
@@ -19,6 +21,7 @@ use crate::{
 pub struct SubRecipeManager {
     pub sub_recipe_tools: HashMap<String, Tool>,
     pub sub_recipes: HashMap<String, SubRecipe>,
+    pub sub_recipe_steps: Vec<SubRecipe>,
 }
 
 impl Default for SubRecipeManager {
@@ -32,10 +35,14 @@ impl SubRecipeManager {
         Self {
             sub_recipe_tools: HashMap::new(),
             sub_recipes: HashMap::new(),
+            sub_recipe_steps: Vec::new(),
         }
     }
 
     pub fn add_sub_recipe_tools(&mut self, sub_recipes_to_add: Vec<SubRecipe>) {
+        if sub_recipes_to_add.iter().any(|s| s.depends_on.is_some()) {
+            self.add_sub_recipe_steps_tool(sub_recipes_to_add.clone());
+        }
         for sub_recipe in sub_recipes_to_add {
             let sub_recipe_key = format!(
                 "{}_{}",
@@ -48,6 +55,15 @@ impl SubRecipeManager {
         }
     }
 
+    // Sub-recipes that declare dependencies form a workflow, which is run as steps through a
+    // single tool, next to the tools that run one sub-recipe on its own.
+    fn add_sub_recipe_steps_tool(&mut self, steps: Vec<SubRecipe>) {
+        let tool = create_sub_recipe_steps_tool(&steps);
+        self.sub_recipe_tools
+            .insert(SUB_RECIPE_STEPS_TOOL_NAME.to_string(), tool);
+        self.sub_recipe_steps = steps;
+    }
+
     pub fn is_sub_recipe_tool(&self, tool_name: &str) -> bool {
         self.sub_recipe_tools.contains_key(tool_name)
     }
@@ -77,6 +93,17 @@ impl SubRecipeManager {
         params: Value,
         tasks_manager: &TasksManager,
     ) -> Result<Vec<Content>, ErrorData> {
+        if tool_name == SUB_RECIPE_STEPS_TOOL_NAME {
+            let output =
+                create_sub_recipe_step_tasks(&self.sub_recipe_steps, params, tasks_manager)
+                    .await
+                    .map_err(|e| ErrorData {
+                        code: ErrorCode::INTERNAL_ERROR,
+                        message: Cow::from(format!("Sub-recipe step task creation failed: {}", e)),
+                        data: None,
+                    })?;
+            return Ok(vec![Content::text(output)]);
+        }
         let sub_recipe = self.sub_recipes.get(tool_name).ok_or_else(|| {
             let sub_recipe_name = tool_name
                 .strip_prefix(SUB_RECIPE_TASK_TOOL_NAME_PREFIX)
@@ -107,3 +134,56 @@ impl SubRecipeManager {
         Ok(vec![Content::text(output)])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn sub_recipe(dir: &Path, name: &str, depends_on: Option<Vec<String>>) -> SubRecipe {
+        let path = dir.join(format!("{}.yaml", name));
+        std::fs::write(
+            &path,
+            format!(
+                "version: 1.0.0\ntitle: {}\ndescription: A step\ninstructions: Do it\n",
+                name
+            ),
+        )
+        .unwrap();
+        SubRecipe {
+            name: name.to_string(),
+            path: path.to_string_lossy().into_owned(),
+            values: None,
+            sequential_when_repeated: false,
+            description: None,
+            depends_on,
+        }
+    }
+
+    #[test]
+    fn test_steps_tool_is_added_next_to_sub_recipe_tools() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = SubRecipeManager::new();
+        manager.add_sub_recipe_tools(vec![
+            sub_recipe(temp_dir.path(), "research", None),
+            sub_recipe(temp_dir.path(), "write", Some(vec!["research".to_string()])),
+        ]);
+
+        let mut tool_names: Vec<&str> = manager
+            .sub_recipe_tools
+            .keys()
+            .map(String::as_str)
+            .collect();
+        tool_names.sort();
+        assert_eq!(
+            tool_names,
+            vec![
+                SUB_RECIPE_STEPS_TOOL_NAME,
+                "subrecipe__create_task_research",
+                "subrecipe__create_task_write",
+            ]
+        );
+        assert_eq!(manager.sub_recipe_steps.len(), 2);
+    }
+}
@@ -4,9 +4,11 @@ use crate::agents::subagent_execution_tool::lib::{
 use crate::agents::subagent_execution_tool::task_execution_tracker::{
     DisplayMode, TaskExecutionTracker,
 };
+use crate::agents::subagent_execution_tool::task_graph::TaskGraph;
 use crate::agents::subagent_execution_tool::tasks::process_task;
 use crate::agents::subagent_execution_tool::workers::spawn_worker;
 use crate::agents::subagent_task_config::TaskConfig;
+use futures::stream::{FuturesUnordered, StreamExt};
 use rmcp::model::ServerNotification;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
@@ -116,6 +118,99 @@ pub async fn execute_tasks_in_parallel(
     }
 }
 
+/// Runs tasks that depend on each other's output, starting every task as soon as the tasks
+/// it depends on have completed and skipping the tasks that depend on a failed one.
+pub async fn execute_task_graph(
+    tasks: Vec<Task>,
+    notifier: Sender<ServerNotification>,
+    task_config: TaskConfig,
+    cancellation_token: Option<CancellationToken>,
+) -> ExecutionResponse {
+    let task_execution_tracker = Arc::new(TaskExecutionTracker::new(
+        tasks.clone(),
+        DisplayMode::MultipleTasksOutput,
+        notifier,
+        cancellation_token.clone(),
+    ));
+    let start_time = Instant::now();
+
+    if tasks.is_empty() {
+        return create_empty_response();
+    }
+
+    task_execution_tracker.refresh_display().await;
+
+    let cancellation_token = cancellation_token.unwrap_or_default();
+    let mut graph = TaskGraph::new(tasks);
+    let mut running = FuturesUnordered::new();
+    let mut results = Vec::new();
+
+    loop {
+        let mut finished = graph.skip_blocked_tasks();
+        while running.len() < DEFAULT_MAX_WORKERS && !cancellation_token.is_cancelled() {
+            let Some(task) = graph.next_ready_task() else {
+                break;
+            };
+            match graph.render_step_outputs(task.clone()) {
+                Ok(task) => {
+                    task_execution_tracker.start_task(&task.id).await;
+                    let task_execution_tracker = task_execution_tracker.clone();
+                    let task_config = task_config.clone();
+                    let cancellation_token = cancellation_token.clone();
+                    running.push(async move {
+                        process_task(
+                            &task,
+                            task_execution_tracker,
+                            task_config,
+                            cancellation_token,
+                        )
+                        .await
+                    });
+                }
+                Err(error) => {
+                    let result = TaskResult {
+                        task_id: task.id,
+                        status: TaskStatus::Failed,
+                        data: None,
+                        error: Some(error),
+                    };
+                    graph.record_result(&result);
+                    finished.push(result);
+                }
+            }
+        }
+
+        if finished.is_empty() {
+            match running.next().await {
+                Some(result) => {
+                    graph.record_result(&result);
+                    finished.push(result);
+                }
+                None if graph.has_pending_tasks() => finished = graph.skip_remaining_tasks(),
+                None => break,
+            }
+        }
+
+        for result in finished {
+            task_execution_tracker
+                .complete_task(&result.task_id, result.clone())
+                .await;
+            results.push(result);
+        }
+    }
+
+    task_execution_tracker.send_tasks_complete().await;
+
+    let execution_time = start_time.elapsed().as_millis();
+    let stats = calculate_stats(&results, execution_time);
+
+    ExecutionResponse {
+        status: EXECUTION_STATUS_COMPLETED.to_string(),
+        results,
+        stats,
+    }
+}
+
 fn calculate_stats(results: &[TaskResult], execution_time_ms: u128) -> ExecutionStats {
     let completed = results
         .iter()
@@ -125,11 +220,16 @@ fn calculate_stats(results: &[TaskResult], execution_time_ms: u128) -> Execution
         .iter()
         .filter(|r| matches!(r.status, TaskStatus::Failed))
         .count();
+    let skipped = results
+        .iter()
+        .filter(|r| matches!(r.status, TaskStatus::Skipped))
+        .count();
 
     ExecutionStats {
         total_tasks: results.len(),
         completed,
         failed,
+        skipped,
         execution_time_ms,
     }
 }
@@ -183,6 +283,7 @@ fn create_empty_response() -> ExecutionResponse {
             total_tasks: 0,
             completed: 0,
             failed: 0,
+            skipped: 0,
             execution_time_ms: 0,
         },
     }
@@ -215,6 +316,7 @@ fn create_error_response(error: String) -> ExecutionResponse {
             total_tasks: 0,
             completed: 0,
             failed: 1,
+            skipped: 0,
             execution_time_ms: 0,
         },
     }
@@ -2,7 +2,7 @@ pub use crate::agents::subagent_execution_tool::task_types::{
     ExecutionMode, ExecutionResponse, ExecutionStats, SharedState, Task, TaskResult, TaskStatus,
 };
 use crate::agents::subagent_execution_tool::{
-    executor::{execute_single_task, execute_tasks_in_parallel},
+    executor::{execute_single_task, execute_task_graph, execute_tasks_in_parallel},
     tasks_manager::TasksManager,
 };
 use crate::agents::subagent_task_config::TaskConfig;
@@ -29,6 +29,11 @@ pub async fn execute_tasks(
 
     let tasks = tasks_manager.get_tasks(&task_ids).await?;
 
+    if tasks.iter().any(|task| !task.get_depends_on().is_empty()) {
+        let response = execute_task_graph(tasks, notifier, task_config, cancellation_token).await;
+        return handle_response(response);
+    }
+
     let task_count = tasks.len();
     match execution_mode {
         ExecutionMode::Sequential => {
@@ -66,7 +71,7 @@ pub async fn execute_tasks(
 fn extract_failed_tasks(results: &[TaskResult]) -> Vec<String> {
     results
         .iter()
-        .filter(|r| matches!(r.status, TaskStatus::Failed))
+        .filter(|r| matches!(r.status, TaskStatus::Failed | TaskStatus::Skipped))
         .map(format_failed_task_error)
         .collect()
 }
@@ -104,10 +109,10 @@ fn format_error_summary(
 }
 
 fn handle_response(response: ExecutionResponse) -> Result<Value, String> {
-    if response.stats.failed > 0 {
+    if response.stats.failed > 0 || response.stats.skipped > 0 {
         let failed_tasks = extract_failed_tasks(&response.results);
         let error_summary = format_error_summary(
-            response.stats.failed,
+            response.stats.failed + response.stats.skipped,
             response.stats.total_tasks,
             failed_tasks,
         );
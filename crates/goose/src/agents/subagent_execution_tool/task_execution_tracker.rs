@@ -287,7 +287,9 @@ impl TaskExecutionTracker {
 
         let failed_tasks: Vec<FailedTaskInfo> = tasks
             .values()
-            .filter(|task_info| matches!(task_info.status, TaskStatus::Failed))
+            .filter(|task_info| {
+                matches!(task_info.status, TaskStatus::Failed | TaskStatus::Skipped)
+            })
             .map(|task_info| FailedTaskInfo {
                 id: task_info.task.id.clone(),
                 name: get_task_name(task_info).to_string(),
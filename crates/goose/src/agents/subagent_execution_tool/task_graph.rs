@@ -0,0 +1,380 @@
+use minijinja::{Environment, UndefinedBehavior};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+
+use crate::agents::subagent_execution_tool::task_types::{Task, TaskResult, TaskStatus};
+use crate::recipe::STEP_OUTPUTS_TEMPLATE_VARIABLE;
+
+/// Tracks which sub-recipe steps of an execution are waiting, done or failed, so that
+/// each step can start as soon as the steps it depends on have completed. A step can run
+/// as several tasks and only completes once all of them have.
+pub struct TaskGraph {
+    pending: Vec<Task>,
+    step_names: HashMap<String, String>,
+    /// Ids of each step's tasks, in the order they were created
+    step_tasks: HashMap<String, Vec<String>>,
+    /// Output of each completed task, by task id
+    task_outputs: HashMap<String, Value>,
+    failed_steps: HashSet<String>,
+}
+
+impl TaskGraph {
+    pub fn new(tasks: Vec<Task>) -> Self {
+        let mut step_names = HashMap::new();
+        let mut step_tasks: HashMap<String, Vec<String>> = HashMap::new();
+        for task in &tasks {
+            let step = step_name(task).to_string();
+            step_tasks
+                .entry(step.clone())
+                .or_default()
+                .push(task.id.clone());
+            step_names.insert(task.id.clone(), step);
+        }
+        Self {
+            pending: tasks,
+            step_names,
+            step_tasks,
+            task_outputs: HashMap::new(),
+            failed_steps: HashSet::new(),
+        }
+    }
+
+    pub fn has_pending_tasks(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Takes the next pending task whose dependencies have all completed.
+    pub fn next_ready_task(&mut self) -> Option<Task> {
+        let index = self.pending.iter().position(|task| {
+            task.get_depends_on()
+                .iter()
+                .all(|dependency| self.is_step_completed(dependency))
+        })?;
+        Some(self.pending.remove(index))
+    }
+
+    /// Skips every pending task that can no longer run because a step it depends on
+    /// failed, was skipped or is not part of this execution.
+    pub fn skip_blocked_tasks(&mut self) -> Vec<TaskResult> {
+        let mut skipped = Vec::new();
+        while let Some((index, reason)) = self
+            .pending
+            .iter()
+            .enumerate()
+            .find_map(|(index, task)| self.blocking_reason(task).map(|reason| (index, reason)))
+        {
+            let task = self.pending.remove(index);
+            let result = skipped_result(&task, reason);
+            self.record_result(&result);
+            skipped.push(result);
+        }
+        skipped
+    }
+
+    /// Skips the tasks that are still pending once nothing else is running, which only
+    /// happens when their dependencies form a cycle.
+    pub fn skip_remaining_tasks(&mut self) -> Vec<TaskResult> {
+        let pending = std::mem::take(&mut self.pending);
+        pending
+            .iter()
+            .map(|task| {
+                let result = skipped_result(
+                    task,
+                    "Skipped because its dependencies never completed".to_string(),
+                );
+                self.record_result(&result);
+                result
+            })
+            .collect()
+    }
+
+    pub fn record_result(&mut self, result: &TaskResult) {
+        let Some(step_name) = self.step_names.get(&result.task_id).cloned() else {
+            return;
+        };
+        match result.status {
+            TaskStatus::Completed => {
+                self.task_outputs
+                    .insert(result.task_id.clone(), step_output(result.data.as_ref()));
+            }
+            _ => {
+                self.failed_steps.insert(step_name);
+            }
+        }
+    }
+
+    fn is_step_completed(&self, step: &str) -> bool {
+        self.step_tasks.get(step).is_some_and(|task_ids| {
+            task_ids
+                .iter()
+                .all(|task_id| self.task_outputs.contains_key(task_id))
+        })
+    }
+
+    /// Outputs of the completed steps. A step that ran as several tasks has the list of
+    /// their outputs, in the order the tasks were created.
+    fn step_outputs(&self) -> Map<String, Value> {
+        self.step_tasks
+            .iter()
+            .filter(|(step, _)| self.is_step_completed(step))
+            .map(|(step, task_ids)| {
+                let mut outputs: Vec<Value> = task_ids
+                    .iter()
+                    .map(|task_id| self.task_outputs[task_id].clone())
+                    .collect();
+                let output = if outputs.len() == 1 {
+                    outputs.remove(0)
+                } else {
+                    Value::Array(outputs)
+                };
+                (step.clone(), json!({ "output": output }))
+            })
+            .collect()
+    }
+
+    /// Renders the references to earlier steps' outputs in the task's command parameters,
+    /// e.g. `{{ steps.research.output.summary }}`.
+    pub fn render_step_outputs(&self, mut task: Task) -> Result<Task, String> {
+        let context = json!({ STEP_OUTPUTS_TEMPLATE_VARIABLE: self.step_outputs() });
+        let mut env = Environment::new();
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+
+        let Some(command_parameters) = task
+            .payload
+            .get_mut("sub_recipe")
+            .and_then(|sub_recipe| sub_recipe.get_mut("command_parameters"))
+            .and_then(|parameters| parameters.as_object_mut())
+        else {
+            return Ok(task);
+        };
+        for (key, value) in command_parameters.iter_mut() {
+            let Some(template) = value.as_str().filter(|v| v.contains("{{")) else {
+                continue;
+            };
+            let rendered = env.render_str(template, &context).map_err(|e| {
+                format!(
+                    "Failed to render parameter '{}' from earlier step outputs: {}",
+                    key, e
+                )
+            })?;
+            *value = Value::String(rendered);
+        }
+        Ok(task)
+    }
+
+    fn blocking_reason(&self, task: &Task) -> Option<String> {
+        task.get_depends_on().into_iter().find_map(|dependency| {
+            if self.failed_steps.contains(&dependency) {
+                Some(format!(
+                    "Skipped because step '{}' did not complete",
+                    dependency
+                ))
+            } else if !self.step_tasks.contains_key(&dependency) {
+                Some(format!(
+                    "Skipped because step '{}' is not part of this execution",
+                    dependency
+                ))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+fn step_name(task: &Task) -> &str {
+    task.get_sub_recipe_name().unwrap_or(&task.id)
+}
+
+fn skipped_result(task: &Task, reason: String) -> TaskResult {
+    TaskResult {
+        task_id: task.id.clone(),
+        status: TaskStatus::Skipped,
+        data: None,
+        error: Some(reason),
+    }
+}
+
+// Sub-recipe output is captured as text; structured output is parsed so that its
+// fields can be referenced by later steps.
+fn step_output(data: Option<&Value>) -> Value {
+    match data {
+        Some(Value::String(output)) => serde_json::from_str(output.trim())
+            .unwrap_or_else(|_| Value::String(output.trim().to_string())),
+        Some(data) => data.clone(),
+        None => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::subagent_execution_tool::task_types::TaskType;
+
+    fn create_step_task(name: &str, depends_on: &[&str], parameters: Value) -> Task {
+        Task {
+            id: format!("{}-id", name),
+            task_type: TaskType::SubRecipe,
+            payload: json!({
+                "sub_recipe": {
+                    "name": name,
+                    "command_parameters": parameters,
+                    "recipe_path": format!("/recipes/{}.yaml", name),
+                    "sequential_when_repeated": false,
+                    "depends_on": depends_on,
+                }
+            }),
+        }
+    }
+
+    fn completed(task_id: &str, output: &str) -> TaskResult {
+        TaskResult {
+            task_id: task_id.to_string(),
+            status: TaskStatus::Completed,
+            data: Some(Value::String(output.to_string())),
+            error: None,
+        }
+    }
+
+    fn failed(task_id: &str) -> TaskResult {
+        TaskResult {
+            task_id: task_id.to_string(),
+            status: TaskStatus::Failed,
+            data: None,
+            error: Some("boom".to_string()),
+        }
+    }
+
+    fn ready_step_names(graph: &mut TaskGraph) -> Vec<String> {
+        std::iter::from_fn(|| graph.next_ready_task())
+            .map(|task| step_name(&task).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_independent_steps_are_ready_together() {
+        let mut graph = TaskGraph::new(vec![
+            create_step_task("research", &[], json!({})),
+            create_step_task("outline", &[], json!({})),
+            create_step_task("write", &["research", "outline"], json!({})),
+        ]);
+
+        assert_eq!(ready_step_names(&mut graph), vec!["research", "outline"]);
+        assert!(graph.has_pending_tasks());
+
+        graph.record_result(&completed("research-id", "done"));
+        assert!(graph.next_ready_task().is_none());
+
+        graph.record_result(&completed("outline-id", "done"));
+        assert_eq!(ready_step_names(&mut graph), vec!["write"]);
+        assert!(!graph.has_pending_tasks());
+    }
+
+    #[test]
+    fn test_step_with_several_tasks_completes_after_all_of_them() {
+        let mut graph = TaskGraph::new(vec![
+            create_step_task("research", &[], json!({ "topic": "rust" })),
+            Task {
+                id: "research-2-id".to_string(),
+                ..create_step_task("research", &[], json!({ "topic": "go" }))
+            },
+            create_step_task(
+                "write",
+                &["research"],
+                json!({ "sources": "{{ steps.research.output | join(', ') }}" }),
+            ),
+        ]);
+        assert_eq!(ready_step_names(&mut graph), vec!["research", "research"]);
+
+        graph.record_result(&completed("research-2-id", "go notes"));
+        assert!(graph.next_ready_task().is_none());
+
+        graph.record_result(&completed("research-id", "rust notes"));
+        let task = graph.next_ready_task().unwrap();
+        let task = graph.render_step_outputs(task).unwrap();
+        assert_eq!(
+            task.get_command_parameters().unwrap()["sources"],
+            "rust notes, go notes"
+        );
+    }
+
+    #[test]
+    fn test_dependents_of_failed_step_are_skipped() {
+        let mut graph = TaskGraph::new(vec![
+            create_step_task("research", &[], json!({})),
+            create_step_task("write", &["research"], json!({})),
+            create_step_task("publish", &["write"], json!({})),
+            create_step_task("notify", &[], json!({})),
+        ]);
+        assert_eq!(ready_step_names(&mut graph), vec!["research", "notify"]);
+
+        graph.record_result(&failed("research-id"));
+        let skipped = graph.skip_blocked_tasks();
+
+        let skipped_ids: Vec<&str> = skipped.iter().map(|r| r.task_id.as_str()).collect();
+        assert_eq!(skipped_ids, vec!["write-id", "publish-id"]);
+        assert!(skipped
+            .iter()
+            .all(|r| matches!(r.status, TaskStatus::Skipped)));
+        assert_eq!(
+            skipped[1].error.as_deref(),
+            Some("Skipped because step 'write' did not complete")
+        );
+        assert!(!graph.has_pending_tasks());
+    }
+
+    #[test]
+    fn test_unknown_dependency_is_skipped() {
+        let mut graph = TaskGraph::new(vec![create_step_task("write", &["research"], json!({}))]);
+
+        let skipped = graph.skip_blocked_tasks();
+
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(
+            skipped[0].error.as_deref(),
+            Some("Skipped because step 'research' is not part of this execution")
+        );
+    }
+
+    #[test]
+    fn test_render_step_outputs() {
+        let mut graph = TaskGraph::new(vec![
+            create_step_task("research", &[], json!({})),
+            create_step_task("notes", &[], json!({})),
+        ]);
+        graph.record_result(&completed(
+            "research-id",
+            r#"{"summary": "Rust is fast", "sources": 3}"#,
+        ));
+        graph.record_result(&completed("notes-id", "plain text notes\n"));
+
+        let task = create_step_task(
+            "write",
+            &["research", "notes"],
+            json!({
+                "topic": "{{ steps.research.output.summary }} ({{ steps.research.output.sources }} sources)",
+                "notes": "{{ steps.notes.output }}",
+                "tone": "casual",
+            }),
+        );
+        let task = graph.render_step_outputs(task).unwrap();
+
+        let parameters = task.get_command_parameters().unwrap();
+        assert_eq!(parameters["topic"], "Rust is fast (3 sources)");
+        assert_eq!(parameters["notes"], "plain text notes");
+        assert_eq!(parameters["tone"], "casual");
+    }
+
+    #[test]
+    fn test_render_missing_step_output_fails() {
+        let graph = TaskGraph::new(vec![]);
+        let task = create_step_task(
+            "write",
+            &[],
+            json!({ "topic": "{{ steps.research.output.summary }}" }),
+        );
+
+        let err = graph.render_step_outputs(task).unwrap_err();
+
+        assert!(err.contains("Failed to render parameter 'topic'"));
+    }
+}
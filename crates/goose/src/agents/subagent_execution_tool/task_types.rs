@@ -69,6 +69,19 @@ impl Task {
             .and_then(|sr| sr.get("recipe_path"))
             .and_then(|path| path.as_str())
     }
+
+    pub fn get_depends_on(&self) -> Vec<String> {
+        self.get_sub_recipe()
+            .and_then(|sr| sr.get("depends_on"))
+            .and_then(|depends_on| depends_on.as_array())
+            .map(|steps| {
+                steps
+                    .iter()
+                    .filter_map(|step| step.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,6 +100,7 @@ pub enum TaskStatus {
     Running,
     Completed,
     Failed,
+    Skipped,
 }
 
 impl std::fmt::Display for TaskStatus {
@@ -96,6 +110,7 @@ impl std::fmt::Display for TaskStatus {
             TaskStatus::Running => write!(f, "Running"),
             TaskStatus::Completed => write!(f, "Completed"),
             TaskStatus::Failed => write!(f, "Failed"),
+            TaskStatus::Skipped => write!(f, "Skipped"),
         }
     }
 }
@@ -143,6 +158,7 @@ pub struct ExecutionStats {
     pub total_tasks: usize,
     pub completed: usize,
     pub failed: usize,
+    pub skipped: usize,
     pub execution_time_ms: u128,
 }
 
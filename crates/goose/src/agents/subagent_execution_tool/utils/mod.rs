@@ -17,7 +17,7 @@ pub fn count_by_status(tasks: &HashMap<String, TaskInfo>) -> (usize, usize, usiz
             TaskStatus::Pending => (pending + 1, running, completed, failed),
             TaskStatus::Running => (pending, running + 1, completed, failed),
             TaskStatus::Completed => (pending, running, completed + 1, failed),
-            TaskStatus::Failed => (pending, running, completed, failed + 1),
+            TaskStatus::Failed | TaskStatus::Skipped => (pending, running, completed, failed + 1),
         },
     );
     (total, pending, running, completed, failed)
@@ -7,9 +7,10 @@ use futures::{Stream, StreamExt};
 use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
 
+use crate::agents::types::SessionConfig;
 use crate::config::permission::PermissionLevel;
 use crate::mcp_utils::ToolResult;
-use crate::permission::Permission;
+use crate::permission::{audit, Permission};
 use rmcp::model::{Content, ServerNotification};
 
 // ToolCallResult combines the result of a tool call with an optional notification stream that
@@ -53,6 +54,7 @@ impl Agent {
         message_tool_response: Arc<Mutex<Message>>,
         cancellation_token: Option<CancellationToken>,
         inspection_results: &'a [crate::tool_inspection::InspectionResult],
+        session: &'a Option<SessionConfig>,
     ) -> BoxStream<'a, anyhow::Result<Message>> {
         try_stream! {
             for request in tool_requests.iter() {
@@ -79,6 +81,13 @@ impl Agent {
                     let mut rx = self.confirmation_rx.lock().await;
                     while let Some((req_id, confirmation)) = rx.recv().await {
                         if req_id == request.id {
+                            audit::record(&[audit::user_decision_entry(
+                                session.as_ref().map(|s| s.id.as_str()),
+                                request,
+                                &confirmation.permission,
+                                &audit::principal(),
+                            )]).await;
+
                             if confirmation.permission == Permission::AllowOnce || confirmation.permission == Permission::AlwaysAllow {
                                 // Clone tool_call to avoid moving it
                                 let (req_id, tool_result) = self.dispatch_tool_call(tool_call.clone(), request.id.clone(), cancellation_token.clone(), &None).await;
@@ -1,9 +1,15 @@
+use crate::agents::budget::BudgetLimits;
+use crate::context_mgmt::strategy::ContextStrategy;
 use crate::mcp_utils::ToolResult;
+use crate::utils::is_localhost_url;
+use regex::Regex;
 use rmcp::model::{Content, Tool};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::{mpsc, Mutex};
+use url::Url;
 use utoipa::ToSchema;
 
 /// Type alias for the tool result channel receiver
@@ -54,7 +60,7 @@ impl RetryConfig {
             }
         }
 
-        Ok(())
+        self.checks.iter().try_for_each(SuccessCheck::validate)
     }
 }
 
@@ -68,6 +74,129 @@ pub enum SuccessCheck {
         /// The shell command to execute
         command: String,
     },
+    /// Check that a file exists
+    #[serde(alias = "file_exists")]
+    FileExists {
+        /// Path to the file, relative to the session working directory
+        path: String,
+    },
+    /// Check that a file's contents match a regular expression
+    #[serde(alias = "file_contains")]
+    FileContains {
+        /// Path to the file, relative to the session working directory
+        path: String,
+        /// Regular expression the contents must match
+        pattern: String,
+    },
+    /// Validate JSON against a schema
+    #[serde(alias = "json_schema")]
+    JsonSchema {
+        /// The JSON schema to validate against
+        schema: Value,
+        /// File holding the JSON to validate; the recipe's final output is used when omitted
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+    },
+    /// Request a local URL and check the response
+    #[serde(alias = "http")]
+    Http {
+        /// URL to request with GET; must point at localhost
+        url: String,
+        /// Expected response status (default: 200)
+        #[serde(default = "default_http_status")]
+        status: u16,
+        /// Regular expression the response body must match
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        body: Option<String>,
+    },
+    /// Ask the model whether the outcome satisfies a rubric
+    #[serde(alias = "llm_judge")]
+    LlmJudge {
+        /// What a successful outcome looks like
+        rubric: String,
+    },
+    /// Passes when every nested check passes
+    #[serde(alias = "all_of")]
+    AllOf {
+        /// The nested checks
+        checks: Vec<SuccessCheck>,
+    },
+    /// Passes when at least one nested check passes
+    #[serde(alias = "any_of")]
+    AnyOf {
+        /// The nested checks
+        checks: Vec<SuccessCheck>,
+    },
+}
+
+fn default_http_status() -> u16 {
+    200
+}
+
+impl SuccessCheck {
+    /// Short description of the check for logs and failure reports
+    pub fn describe(&self) -> String {
+        match self {
+            SuccessCheck::Shell { command } => format!("shell `{}`", command),
+            SuccessCheck::FileExists { path } => format!("file_exists `{}`", path),
+            SuccessCheck::FileContains { path, pattern } => {
+                format!("file_contains `{}` /{}/", path, pattern)
+            }
+            SuccessCheck::JsonSchema {
+                path: Some(path), ..
+            } => {
+                format!("json_schema `{}`", path)
+            }
+            SuccessCheck::JsonSchema { path: None, .. } => "json_schema final output".to_string(),
+            SuccessCheck::Http { url, status, .. } => format!("http `{}` {}", url, status),
+            SuccessCheck::LlmJudge { .. } => "llm_judge".to_string(),
+            SuccessCheck::AllOf { checks } => format!("all_of ({} checks)", checks.len()),
+            SuccessCheck::AnyOf { checks } => format!("any_of ({} checks)", checks.len()),
+        }
+    }
+
+    /// Validates the check configuration, including any nested checks
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            SuccessCheck::Shell { .. } | SuccessCheck::FileExists { .. } => Ok(()),
+            SuccessCheck::FileContains { pattern, .. } => Regex::new(pattern)
+                .map(|_| ())
+                .map_err(|e| format!("Invalid file_contains pattern '{}': {}", pattern, e)),
+            SuccessCheck::JsonSchema { schema, .. } => jsonschema::meta::validate(schema)
+                .map_err(|e| format!("Invalid json_schema check schema: {}", e)),
+            SuccessCheck::Http { url, status, body } => {
+                let parsed = Url::parse(url)
+                    .map_err(|e| format!("Invalid http check URL '{}': {}", url, e))?;
+                if !is_localhost_url(&parsed) {
+                    return Err(format!("http check URL '{}' must point at localhost", url));
+                }
+                if reqwest::StatusCode::from_u16(*status).is_err() {
+                    return Err(format!("Invalid http check status: {}", status));
+                }
+                if let Some(body) = body {
+                    Regex::new(body).map_err(|e| {
+                        format!("Invalid http check body pattern '{}': {}", body, e)
+                    })?;
+                }
+                Ok(())
+            }
+            SuccessCheck::LlmJudge { rubric } => {
+                if rubric.trim().is_empty() {
+                    return Err("llm_judge rubric must not be empty".to_string());
+                }
+                Ok(())
+            }
+            SuccessCheck::AllOf { checks } | SuccessCheck::AnyOf { checks } => {
+                if checks.is_empty() {
+                    return Err(format!(
+                        "{} must contain at least one check",
+                        self.describe()
+                    ));
+                }
+                checks.iter().try_for_each(SuccessCheck::validate)
+            }
+        }
+    }
 }
 
 /// A frontend tool that will be executed by the frontend rather than an extension
@@ -90,7 +219,20 @@ pub struct SessionConfig {
     pub execution_mode: Option<String>,
     /// Maximum number of turns (iterations) allowed without user input
     pub max_turns: Option<u32>,
+    /// goose mode for this session (auto, approve, smart_approve, chat), overriding GOOSE_MODE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goose_mode: Option<String>,
     /// Retry configuration for automated validation and recovery
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_config: Option<RetryConfig>,
+    /// Token and spend caps for this session, overriding GOOSE_SESSION_BUDGET
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetLimits>,
+    /// Caps on the combined usage of recent runs of `schedule_id`, overriding GOOSE_SCHEDULE_BUDGET
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule_budget: Option<BudgetLimits>,
+    /// How the conversation is compacted near the context limit, overriding
+    /// GOOSE_COMPACTION_STRATEGY
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_strategy: Option<ContextStrategy>,
 }
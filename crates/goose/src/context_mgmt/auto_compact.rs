@@ -1,5 +1,9 @@
-use crate::conversation::message::Message;
+use crate::context_mgmt::strategy::{
+    rolling_summary_percent, rolling_summary_split, ContextStrategy,
+};
+use crate::conversation::message::{Message, MessageMetadata};
 use crate::conversation::Conversation;
+use crate::token_counter::AsyncTokenCounter;
 use crate::{
     agents::Agent, config::Config, context_mgmt::get_messages_token_counts_async,
     token_counter::create_async_token_counter,
@@ -17,6 +21,8 @@ pub struct AutoCompactResult {
     /// Provider usage from summarization (if compaction occurred)
     /// This contains the actual token counts after compaction
     pub summarization_usage: Option<crate::providers::base::ProviderUsage>,
+    /// Token counts before and, if compaction occurred, after compaction
+    pub check_result: CompactionCheckResult,
 }
 
 /// Result of checking if compaction is needed
@@ -24,10 +30,14 @@ pub struct AutoCompactResult {
 pub struct CompactionCheckResult {
     /// Whether compaction is needed
     pub needs_compaction: bool,
-    /// Current token count
+    /// Current token count, before any compaction
     pub current_tokens: usize,
+    /// Estimated token count of the messages the agent sees after compaction, if it occurred
+    pub tokens_after_compaction: Option<usize>,
     /// Context limit being used
     pub context_limit: usize,
+    /// Token count compaction aims to get below
+    pub threshold_tokens: usize,
     /// Current usage ratio (0.0 to 1.0)
     pub usage_ratio: f64,
     /// Remaining tokens before compaction threshold
@@ -94,11 +104,8 @@ pub async fn check_compaction_needed(
     };
 
     // Check if compaction is needed (disabled if threshold is invalid)
-    let needs_compaction = if threshold <= 0.0 || threshold >= 1.0 {
-        false
-    } else {
-        usage_ratio > threshold
-    };
+    let threshold_valid = threshold > 0.0 && threshold < 1.0;
+    let needs_compaction = threshold_valid && usage_ratio > threshold;
 
     debug!(
         "Compaction check: {} / {} tokens ({:.1}%), threshold: {:.1}%, needs compaction: {}, source: {}",
@@ -113,7 +120,13 @@ pub async fn check_compaction_needed(
     Ok(CompactionCheckResult {
         needs_compaction,
         current_tokens,
+        tokens_after_compaction: None,
         context_limit,
+        threshold_tokens: if threshold_valid {
+            threshold_tokens
+        } else {
+            context_limit
+        },
         usage_ratio,
         remaining_tokens,
         percentage_until_compaction,
@@ -129,39 +142,25 @@ pub async fn check_compaction_needed(
 /// # Arguments
 /// * `agent` - The agent to use for context management
 /// * `messages` - The current message history
+/// * `strategy_override` - Optional strategy override (defaults to GOOSE_COMPACTION_STRATEGY config)
 ///
 /// # Returns
 /// * `AutoCompactResult` containing the compacted messages and metadata
-pub async fn perform_compaction(agent: &Agent, messages: &[Message]) -> Result<AutoCompactResult> {
+pub async fn perform_compaction(
+    agent: &Agent,
+    messages: &[Message],
+    strategy_override: Option<ContextStrategy>,
+) -> Result<AutoCompactResult> {
     info!("Performing message compaction");
 
-    // Check if the most recent message is a user message
-    let (messages_to_compact, preserved_user_message) = if let Some(last_message) = messages.last()
-    {
-        if matches!(last_message.role, rmcp::model::Role::User) {
-            // Remove the last user message before compaction
-            (&messages[..messages.len() - 1], Some(last_message.clone()))
-        } else {
-            (messages, None)
-        }
-    } else {
-        (messages, None)
-    };
-
-    // Perform the compaction on messages excluding the preserved user message
-    let (mut compacted_messages, _, summarization_usage) =
-        agent.summarize_context(messages_to_compact).await?;
-
-    // Add back the preserved user message if it exists
-    if let Some(user_message) = preserved_user_message {
-        compacted_messages.push(user_message);
-    }
-
-    Ok(AutoCompactResult {
-        compacted: true,
-        messages: compacted_messages,
-        summarization_usage,
-    })
+    let check_result = check_compaction_needed(agent, messages, None, None).await?;
+    compact_messages(
+        agent,
+        messages,
+        ContextStrategy::resolve(strategy_override),
+        check_result,
+    )
+    .await
 }
 
 /// Check if messages need compaction and compact them if necessary
@@ -175,6 +174,7 @@ pub async fn perform_compaction(agent: &Agent, messages: &[Message]) -> Result<A
 /// * `messages` - The current message history
 /// * `threshold_override` - Optional threshold override (defaults to GOOSE_AUTO_COMPACT_THRESHOLD config)
 /// * `session_metadata` - Optional session metadata containing actual token counts
+/// * `strategy_override` - Optional strategy override (defaults to GOOSE_COMPACTION_STRATEGY config)
 ///
 /// # Returns
 /// * `AutoCompactResult` containing the potentially compacted messages and metadata
@@ -183,6 +183,7 @@ pub async fn check_and_compact_messages(
     messages: &[Message],
     threshold_override: Option<f64>,
     session_metadata: Option<&crate::session::Session>,
+    strategy_override: Option<ContextStrategy>,
 ) -> Result<AutoCompactResult> {
     // First check if compaction is needed
     let check_result =
@@ -199,6 +200,7 @@ pub async fn check_and_compact_messages(
             compacted: false,
             messages: Conversation::new_unvalidated(messages.to_vec()),
             summarization_usage: None,
+            check_result,
         });
     }
 
@@ -207,11 +209,28 @@ pub async fn check_and_compact_messages(
         check_result.usage_ratio * 100.0
     );
 
+    compact_messages(
+        agent,
+        messages,
+        ContextStrategy::resolve(strategy_override),
+        check_result,
+    )
+    .await
+}
+
+/// Compacts the messages with the given strategy, keeping the most recent message out of
+/// the compaction when it is a user message
+async fn compact_messages(
+    agent: &Agent,
+    messages: &[Message],
+    strategy: ContextStrategy,
+    mut check_result: CompactionCheckResult,
+) -> Result<AutoCompactResult> {
     // Check if the most recent message is a user message
     let (messages_to_compact, preserved_user_message) = if let Some(last_message) = messages.last()
     {
         if matches!(last_message.role, rmcp::model::Role::User) {
-            // Remove the last user message before auto-compaction
+            // Remove the last user message before compaction
             (&messages[..messages.len() - 1], Some(last_message.clone()))
         } else {
             (messages, None)
@@ -220,24 +239,144 @@ pub async fn check_and_compact_messages(
         (messages, None)
     };
 
-    // Perform the compaction on messages excluding the preserved user message
+    let token_counter = create_async_token_counter()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to create token counter: {}", e))?;
+
+    let reduced = match strategy {
+        ContextStrategy::CollapseToolResponses | ContextStrategy::DropSupersededReads => {
+            reduce_messages(
+                messages_to_compact,
+                strategy,
+                &token_counter,
+                check_result.threshold_tokens,
+            )
+        }
+        ContextStrategy::RollingSummary => {
+            summarize_oldest_messages(agent, messages_to_compact, &token_counter).await?
+        }
+        ContextStrategy::Summarize => None,
+    };
+
+    // Strategies that can't reduce the conversation fall back to summarizing all of it.
     // The summarize_context method already handles the visibility properly
-    let (mut summary_messages, _, summarization_usage) =
-        agent.summarize_context(messages_to_compact).await?;
+    let (mut compacted_messages, summarization_usage) = match reduced {
+        Some(reduced) => reduced,
+        None => {
+            let (summary_messages, _, summarization_usage) =
+                agent.summarize_context(messages_to_compact).await?;
+            (summary_messages, summarization_usage)
+        }
+    };
 
     // Add back the preserved user message if it exists
     // (keeps default visibility: both true)
     if let Some(user_message) = preserved_user_message {
-        summary_messages.push(user_message);
+        compacted_messages.push(user_message);
     }
 
+    let tokens_after: usize =
+        get_messages_token_counts_async(&token_counter, compacted_messages.messages())
+            .iter()
+            .sum();
+    info!(
+        "Compacted context with {}: {} tokens before, about {} after",
+        strategy, check_result.current_tokens, tokens_after
+    );
+    check_result.tokens_after_compaction = Some(tokens_after);
+
     Ok(AutoCompactResult {
         compacted: true,
-        messages: summary_messages,
+        messages: compacted_messages,
         summarization_usage,
+        check_result,
     })
 }
 
+/// Applies a strategy that doesn't summarize. The agent continues with reduced copies of the
+/// messages it saw while the user keeps seeing the originals. Returns `None` when the
+/// strategy can't bring the conversation below `target_tokens`.
+fn reduce_messages(
+    messages: &[Message],
+    strategy: ContextStrategy,
+    token_counter: &AsyncTokenCounter,
+    target_tokens: usize,
+) -> Option<(Conversation, Option<crate::providers::base::ProviderUsage>)> {
+    let agent_messages: Vec<Message> = messages
+        .iter()
+        .filter(|msg| msg.is_agent_visible())
+        .cloned()
+        .collect();
+    let reduced = strategy
+        .reduce(&agent_messages)
+        .filter(|reduced| *reduced != agent_messages)?;
+
+    let reduced_tokens: usize = get_messages_token_counts_async(token_counter, &reduced)
+        .iter()
+        .sum();
+    if reduced_tokens > target_tokens {
+        debug!(
+            "{} left {} tokens, more than the {} targeted",
+            strategy, reduced_tokens, target_tokens
+        );
+        return None;
+    }
+
+    // Original messages become user_visible only, like when summarizing
+    let mut final_messages: Vec<Message> = messages
+        .iter()
+        .cloned()
+        .map(|msg| {
+            let updated_metadata = msg.metadata.with_agent_invisible();
+            msg.with_metadata(updated_metadata)
+        })
+        .collect();
+    final_messages.push(
+        Message::assistant()
+            .with_summarization_requested(format!("Conversation compacted ({})", strategy))
+            .with_metadata(MessageMetadata::user_only()),
+    );
+    final_messages.extend(
+        reduced
+            .into_iter()
+            .map(|msg| msg.with_metadata(MessageMetadata::agent_only())),
+    );
+
+    Some((Conversation::new_unvalidated(final_messages), None))
+}
+
+/// Summarizes the oldest GOOSE_ROLLING_SUMMARY_PERCENT of the conversation and keeps the
+/// turns after it verbatim. Returns `None` when there is no later turn to keep.
+async fn summarize_oldest_messages(
+    agent: &Agent,
+    messages: &[Message],
+    token_counter: &AsyncTokenCounter,
+) -> Result<Option<(Conversation, Option<crate::providers::base::ProviderUsage>)>> {
+    // Messages the agent no longer sees don't take up context
+    let token_counts: Vec<usize> = messages
+        .iter()
+        .map(|msg| {
+            if msg.is_agent_visible() {
+                token_counter.count_chat_tokens("", std::slice::from_ref(msg), &[])
+            } else {
+                0
+            }
+        })
+        .collect();
+    let Some(split) = rolling_summary_split(messages, &token_counts, rolling_summary_percent())
+    else {
+        debug!("No turn to keep after the rolling summary, summarizing everything");
+        return Ok(None);
+    };
+
+    let (mut summary_messages, _, summarization_usage) =
+        agent.summarize_context(&messages[..split]).await?;
+    for msg in &messages[split..] {
+        summary_messages.push(msg.clone());
+    }
+    Ok(Some((summary_messages, summarization_usage)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,6 +469,10 @@ mod tests {
             extension_data: extension_data::ExtensionData::new(),
             conversation: Some(conversation),
             message_count,
+            parent_session_id: None,
+            fork_message_index: None,
+            accumulated_cost: None,
+            model_costs: Default::default(),
         }
     }
 
@@ -401,7 +544,7 @@ mod tests {
         let messages = vec![create_test_message("Hello"), create_test_message("World")];
 
         // Test with threshold 0 (disabled)
-        let result = check_and_compact_messages(&agent, &messages, Some(0.0), None)
+        let result = check_and_compact_messages(&agent, &messages, Some(0.0), None, None)
             .await
             .unwrap();
 
@@ -410,7 +553,7 @@ mod tests {
         assert!(result.summarization_usage.is_none());
 
         // Test with threshold 1.0 (disabled)
-        let result = check_and_compact_messages(&agent, &messages, Some(1.0), None)
+        let result = check_and_compact_messages(&agent, &messages, Some(1.0), None, None)
             .await
             .unwrap();
 
@@ -431,7 +574,7 @@ mod tests {
         // Create small messages that won't trigger compaction
         let messages = vec![create_test_message("Hello"), create_test_message("World")];
 
-        let result = check_and_compact_messages(&agent, &messages, Some(0.3), None)
+        let result = check_and_compact_messages(&agent, &messages, Some(0.3), None, None)
             .await
             .unwrap();
 
@@ -473,7 +616,7 @@ mod tests {
             )));
         }
 
-        let result = check_and_compact_messages(&agent, &messages, Some(0.3), None)
+        let result = check_and_compact_messages(&agent, &messages, Some(0.3), None, None)
             .await
             .unwrap();
 
@@ -532,7 +675,7 @@ mod tests {
             .unwrap();
 
         // Should use config value when no override provided
-        let result = check_and_compact_messages(&agent, &messages, None, None)
+        let result = check_and_compact_messages(&agent, &messages, None, None, None)
             .await
             .unwrap();
 
@@ -679,6 +822,7 @@ mod tests {
             &messages,
             Some(0.3), // 30% threshold
             Some(&session),
+            None,
         )
         .await
         .unwrap();
@@ -695,6 +839,86 @@ mod tests {
         assert!(result.messages.len() > messages.len());
     }
 
+    #[tokio::test]
+    async fn test_auto_compact_collapses_tool_responses() {
+        use rmcp::model::{CallToolRequestParam, Content};
+        use rmcp::object;
+
+        let mock_provider = Arc::new(MockProvider {
+            model_config: ModelConfig::new("test-model")
+                .unwrap()
+                .with_context_limit(10_000.into()),
+        });
+
+        let agent = Agent::new();
+        let _ = agent.update_provider(mock_provider).await;
+
+        let output = "lorem ipsum ".repeat(200);
+        let mut messages = vec![create_test_message("Read the project files")];
+        for i in 0..5 {
+            let id = format!("read-{}", i);
+            messages.push(Message::assistant().with_tool_request(
+                id.clone(),
+                Ok(CallToolRequestParam {
+                    name: "developer__shell".into(),
+                    arguments: Some(object!({"command": format!("cat file{}.txt", i)})),
+                }),
+            ));
+            messages.push(Message::user().with_tool_response(id, Ok(vec![Content::text(&output)])));
+        }
+        messages.push(create_test_message("Now summarize them"));
+
+        let mut session = crate::session::Session::default();
+        #[allow(clippy::field_reassign_with_default)]
+        {
+            session.total_tokens = Some(9000);
+        }
+
+        let result = check_and_compact_messages(
+            &agent,
+            &messages,
+            Some(0.3),
+            Some(&session),
+            Some(ContextStrategy::CollapseToolResponses),
+        )
+        .await
+        .unwrap();
+
+        assert!(result.compacted);
+        assert!(result.summarization_usage.is_none());
+        assert_eq!(result.check_result.current_tokens, 9000);
+        let tokens_after = result.check_result.tokens_after_compaction.unwrap();
+        assert!(tokens_after < result.check_result.threshold_tokens);
+
+        let agent_messages: Vec<&Message> = result
+            .messages
+            .iter()
+            .filter(|msg| msg.is_agent_visible())
+            .collect();
+        assert_eq!(agent_messages.len(), messages.len());
+        assert!(agent_messages[2].content[0]
+            .as_tool_response_text()
+            .unwrap()
+            .starts_with("[Tool response of"));
+        assert_eq!(
+            agent_messages[10].content[0]
+                .as_tool_response_text()
+                .unwrap(),
+            output
+        );
+
+        // The user still sees the full tool output
+        let user_messages: Vec<&Message> = result
+            .messages
+            .iter()
+            .filter(|msg| msg.is_user_visible())
+            .collect();
+        assert_eq!(
+            user_messages[2].content[0].as_tool_response_text().unwrap(),
+            output
+        );
+    }
+
     #[tokio::test]
     async fn test_auto_compact_with_comprehensive_session_metadata() {
         let mock_provider = Arc::new(MockProvider {
@@ -1,5 +1,7 @@
 pub mod auto_compact;
 mod common;
+pub mod recall;
+pub mod strategy;
 pub mod summarize;
 pub mod truncate;
 
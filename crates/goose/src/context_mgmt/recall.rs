@@ -0,0 +1,319 @@
+use rmcp::model::{Content, ErrorCode, ErrorData, JsonObject, RawContent, Role};
+
+use crate::conversation::message::{Message, MessageContent};
+use crate::mcp_utils::ToolResult;
+use crate::session::SessionManager;
+use crate::token_counter::{create_async_token_counter, AsyncTokenCounter};
+
+/// Token budget for a recall when the model doesn't ask for one
+pub const DEFAULT_RECALL_TOKENS: usize = 4_000;
+/// Upper bound on the tokens a single recall returns
+pub const MAX_RECALL_TOKENS: usize = 16_000;
+
+/// Which archived messages to recall and how much of them to return
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecallQuery {
+    /// Lowercased keywords that must all appear in a message
+    pub keywords: Vec<String>,
+    /// First conversation index to include
+    pub start: Option<usize>,
+    /// Last conversation index to include
+    pub end: Option<usize>,
+    pub max_tokens: usize,
+}
+
+impl RecallQuery {
+    pub fn from_arguments(arguments: &JsonObject) -> ToolResult<Self> {
+        let index = |key: &str| -> ToolResult<Option<usize>> {
+            match arguments.get(key) {
+                None | Some(serde_json::Value::Null) => Ok(None),
+                Some(value) => value.as_u64().map(|v| Some(v as usize)).ok_or_else(|| {
+                    ErrorData::new(
+                        ErrorCode::INVALID_PARAMS,
+                        format!("'{}' must be a non-negative integer", key),
+                        None,
+                    )
+                }),
+            }
+        };
+
+        let keywords = arguments
+            .get("query")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|keyword| keyword.to_lowercase())
+            .collect();
+        let max_tokens = index("max_tokens")?
+            .unwrap_or(DEFAULT_RECALL_TOKENS)
+            .clamp(1, MAX_RECALL_TOKENS);
+
+        Ok(Self {
+            keywords,
+            start: index("start")?,
+            end: index("end")?,
+            max_tokens,
+        })
+    }
+
+    fn matches(&self, index: usize, text: &str) -> bool {
+        if self.start.is_some_and(|start| index < start) || self.end.is_some_and(|end| index > end)
+        {
+            return false;
+        }
+        let text = text.to_lowercase();
+        self.keywords.iter().all(|keyword| text.contains(keyword))
+    }
+}
+
+/// Messages compaction took out of the agent's view, with their index in the conversation.
+/// Compaction markers are left out since they only tell the user compaction happened.
+pub fn archived_messages(messages: &[Message]) -> Vec<(usize, &Message)> {
+    messages
+        .iter()
+        .enumerate()
+        .filter(|(_, message)| !message.is_agent_visible())
+        .filter(|(_, message)| {
+            !message
+                .content
+                .iter()
+                .all(|content| matches!(content, MessageContent::SummarizationRequested(_)))
+        })
+        .collect()
+}
+
+/// Renders a message the way recall returns it, keeping tool results in full
+fn render_message(index: usize, message: &Message) -> String {
+    let role = match message.role {
+        Role::User => "user",
+        Role::Assistant => "assistant",
+    };
+    let body: Vec<String> = message
+        .content
+        .iter()
+        .map(|content| match content {
+            MessageContent::ToolRequest(request) => request.to_readable_string(),
+            MessageContent::ToolResponse(response) => match &response.tool_result {
+                Ok(result) => {
+                    let text: Vec<&str> = result
+                        .iter()
+                        .filter_map(|item| match &item.raw {
+                            RawContent::Text(text) => Some(text.text.as_str()),
+                            _ => None,
+                        })
+                        .collect();
+                    format!("Tool result:\n{}", text.join("\n"))
+                }
+                Err(e) => format!("Tool error: {}", e),
+            },
+            other => other.to_string(),
+        })
+        .collect();
+    format!("[{}] {}:\n{}", index, role, body.join("\n"))
+}
+
+/// Cuts `text` down to roughly `max_tokens`, on a character boundary
+fn truncate_to_tokens(text: &str, tokens: usize, max_tokens: usize) -> String {
+    let keep = text.chars().count() * max_tokens / tokens.max(1);
+    let truncated: String = text.chars().take(keep).collect();
+    format!("{}\n[Truncated to fit {} tokens]", truncated, max_tokens)
+}
+
+/// Finds the archived messages matching `query` and renders as many as fit in its token budget
+pub fn recall_messages(
+    messages: &[Message],
+    query: &RecallQuery,
+    token_counter: &AsyncTokenCounter,
+) -> String {
+    let archived = archived_messages(messages);
+    let (Some((first, _)), Some((last, _))) = (archived.first(), archived.last()) else {
+        return "No messages have been archived by compaction in this session.".to_string();
+    };
+
+    let matching: Vec<String> = archived
+        .iter()
+        .map(|(index, message)| (*index, render_message(*index, message)))
+        .filter(|(index, text)| query.matches(*index, text))
+        .map(|(_, text)| text)
+        .collect();
+    if matching.is_empty() {
+        return format!(
+            "No archived messages matched. Archived messages have indexes {} to {}.",
+            first, last
+        );
+    }
+
+    let mut recalled = Vec::new();
+    let mut used_tokens = 0;
+    for text in &matching {
+        let tokens = token_counter.count_tokens(text);
+        if used_tokens + tokens > query.max_tokens {
+            if recalled.is_empty() {
+                recalled.push(truncate_to_tokens(text, tokens, query.max_tokens));
+            }
+            break;
+        }
+        used_tokens += tokens;
+        recalled.push(text.clone());
+    }
+
+    let omitted = matching.len() - recalled.len();
+    let mut output = recalled.join("\n\n");
+    if omitted > 0 {
+        output.push_str(&format!(
+            "\n\n{} more matching message(s) left out to stay within {} tokens. Narrow the query or index range to see them.",
+            omitted, query.max_tokens
+        ));
+    }
+    output
+}
+
+/// Recalls archived messages from the stored conversation of a session
+pub async fn recall_from_session(
+    session_id: &str,
+    arguments: &JsonObject,
+) -> ToolResult<Vec<Content>> {
+    let query = RecallQuery::from_arguments(arguments)?;
+    let internal_error = |message: String| ErrorData::new(ErrorCode::INTERNAL_ERROR, message, None);
+
+    let session = SessionManager::get_session(session_id, true)
+        .await
+        .map_err(|e| internal_error(format!("Failed to read session: {}", e)))?;
+    let token_counter = create_async_token_counter()
+        .await
+        .map_err(|e| internal_error(format!("Failed to create token counter: {}", e)))?;
+
+    let messages = session.conversation.unwrap_or_default();
+    Ok(vec![Content::text(recall_messages(
+        messages.messages(),
+        &query,
+        &token_counter,
+    ))])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversation::message::MessageMetadata;
+    use rmcp::model::CallToolRequestParam;
+    use rmcp::object;
+
+    fn compacted_conversation() -> Vec<Message> {
+        let archived = vec![
+            Message::user().with_text("Why does the build fail?"),
+            Message::assistant().with_tool_request(
+                "1",
+                Ok(CallToolRequestParam {
+                    name: "developer__shell".into(),
+                    arguments: Some(object!({"command": "cargo build"})),
+                }),
+            ),
+            Message::user().with_tool_response(
+                "1",
+                Ok(vec![Content::text(
+                    "error[E0432]: unresolved import `crate::widgets`",
+                )]),
+            ),
+            Message::assistant().with_text("The widgets module is missing."),
+        ];
+        let mut messages: Vec<Message> = archived
+            .into_iter()
+            .map(|msg| {
+                let metadata = msg.metadata.with_agent_invisible();
+                msg.with_metadata(metadata)
+            })
+            .collect();
+        messages.push(
+            Message::assistant()
+                .with_summarization_requested("Conversation compacted")
+                .with_metadata(MessageMetadata::user_only()),
+        );
+        messages.push(
+            Message::user()
+                .with_text("Summary: the build fails on a missing module")
+                .with_metadata(MessageMetadata::agent_only()),
+        );
+        messages
+    }
+
+    fn query(arguments: JsonObject) -> RecallQuery {
+        RecallQuery::from_arguments(&arguments).unwrap()
+    }
+
+    #[test]
+    fn test_archived_messages_skip_markers_and_visible_messages() {
+        let messages = compacted_conversation();
+        let indexes: Vec<usize> = archived_messages(&messages)
+            .iter()
+            .map(|(index, _)| *index)
+            .collect();
+        assert_eq!(indexes, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_recall_query_from_arguments() {
+        let recall = query(object!({"query": "E0432 Widgets", "start": 2, "max_tokens": 100_000}));
+        assert_eq!(recall.keywords, vec!["e0432", "widgets"]);
+        assert_eq!(recall.start, Some(2));
+        assert_eq!(recall.end, None);
+        assert_eq!(recall.max_tokens, MAX_RECALL_TOKENS);
+
+        assert!(RecallQuery::from_arguments(&object!({"start": "two"})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_recall_messages_by_keyword_and_range() {
+        let token_counter = create_async_token_counter().await.unwrap();
+        let messages = compacted_conversation();
+
+        let found = recall_messages(
+            &messages,
+            &query(object!({"query": "e0432"})),
+            &token_counter,
+        );
+        assert_eq!(
+            found,
+            "[2] user:\nTool result:\nerror[E0432]: unresolved import `crate::widgets`"
+        );
+
+        let range = recall_messages(
+            &messages,
+            &query(object!({"start": 1, "end": 2})),
+            &token_counter,
+        );
+        assert!(range.starts_with("[1] assistant:\nTool: developer__shell"));
+        assert!(range.contains("[2] user:"));
+        assert!(!range.contains("[3]"));
+
+        let missing = recall_messages(
+            &messages,
+            &query(object!({"query": "summary"})),
+            &token_counter,
+        );
+        assert_eq!(
+            missing,
+            "No archived messages matched. Archived messages have indexes 0 to 3."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_recall_messages_stays_within_budget() {
+        let token_counter = create_async_token_counter().await.unwrap();
+        let messages = compacted_conversation();
+
+        let recalled = recall_messages(
+            &messages,
+            &query(object!({"max_tokens": 20})),
+            &token_counter,
+        );
+        assert!(recalled.starts_with("[0] user:\nWhy does the build fail?"));
+        assert!(recalled.contains("more matching message(s) left out to stay within 20 tokens"));
+
+        let truncated = recall_messages(
+            &messages,
+            &query(object!({"query": "e0432", "max_tokens": 5})),
+            &token_counter,
+        );
+        assert!(truncated.ends_with("[Truncated to fit 5 tokens]"));
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+
+use rmcp::model::{CallToolRequestParam, Content, RawContent, Role};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use utoipa::ToSchema;
+
+use crate::config::Config;
+use crate::conversation::message::{Message, MessageContent};
+
+/// Tool responses CollapseToolResponses leaves intact, counted from the most recent
+pub const KEEP_RECENT_TOOL_RESPONSES: usize = 3;
+/// Shorter tool responses are left as they are, which also keeps stubs from being collapsed again
+const MIN_COLLAPSIBLE_CHARS: usize = 200;
+const DEFAULT_ROLLING_SUMMARY_PERCENT: u8 = 50;
+
+/// How the conversation is reduced once it nears the context limit
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextStrategy {
+    /// Summarize the whole conversation
+    #[default]
+    Summarize,
+    /// Replace all but the most recent tool responses with one-line stubs, keeping the tool
+    /// requests
+    CollapseToolResponses,
+    /// Replace file reads that a later full read of the same file superseded with stubs
+    DropSupersededReads,
+    /// Summarize the oldest part of the conversation, GOOSE_ROLLING_SUMMARY_PERCENT of its
+    /// tokens, and keep the recent turns verbatim
+    RollingSummary,
+}
+
+impl ContextStrategy {
+    /// The session's strategy, falling back to GOOSE_COMPACTION_STRATEGY and then to
+    /// summarizing. This is a separate key from the CLI's GOOSE_CONTEXT_STRATEGY, which picks
+    /// between summarizing, truncating and clearing the session.
+    pub fn resolve(session_strategy: Option<ContextStrategy>) -> Self {
+        if let Some(strategy) = session_strategy {
+            return strategy;
+        }
+        let Ok(configured) = Config::global().get_param::<String>("GOOSE_COMPACTION_STRATEGY")
+        else {
+            return ContextStrategy::default();
+        };
+        configured.parse().unwrap_or_else(|e| {
+            warn!("Ignoring GOOSE_COMPACTION_STRATEGY: {}", e);
+            ContextStrategy::default()
+        })
+    }
+
+    /// Rewrites the messages the agent sees for the strategies that don't summarize; returns
+    /// `None` when the strategy summarizes or nothing could be reduced
+    pub fn reduce(&self, messages: &[Message]) -> Option<Vec<Message>> {
+        match self {
+            ContextStrategy::CollapseToolResponses => {
+                collapse_tool_responses(messages, KEEP_RECENT_TOOL_RESPONSES)
+            }
+            ContextStrategy::DropSupersededReads => drop_superseded_reads(messages),
+            ContextStrategy::Summarize | ContextStrategy::RollingSummary => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ContextStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContextStrategy::Summarize => write!(f, "summarize"),
+            ContextStrategy::CollapseToolResponses => write!(f, "collapse_tool_responses"),
+            ContextStrategy::DropSupersededReads => write!(f, "drop_superseded_reads"),
+            ContextStrategy::RollingSummary => write!(f, "rolling_summary"),
+        }
+    }
+}
+
+impl std::str::FromStr for ContextStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "summarize" => Ok(ContextStrategy::Summarize),
+            "collapse_tool_responses" => Ok(ContextStrategy::CollapseToolResponses),
+            "drop_superseded_reads" => Ok(ContextStrategy::DropSupersededReads),
+            "rolling_summary" => Ok(ContextStrategy::RollingSummary),
+            _ => Err(format!(
+                "Unknown context strategy '{}', expected summarize, collapse_tool_responses, drop_superseded_reads or rolling_summary",
+                s
+            )),
+        }
+    }
+}
+
+/// Share of the conversation's tokens the rolling summary summarizes, from
+/// GOOSE_ROLLING_SUMMARY_PERCENT
+pub fn rolling_summary_percent() -> u8 {
+    Config::global()
+        .get_param::<u8>("GOOSE_ROLLING_SUMMARY_PERCENT")
+        .ok()
+        .filter(|percent| (1..100).contains(percent))
+        .unwrap_or(DEFAULT_ROLLING_SUMMARY_PERCENT)
+}
+
+/// Index of the first message the rolling summary keeps verbatim: the start of the first turn
+/// after the oldest `percent` of the tokens. `None` when no such turn exists.
+pub fn rolling_summary_split(
+    messages: &[Message],
+    token_counts: &[usize],
+    percent: u8,
+) -> Option<usize> {
+    let total: usize = token_counts.iter().sum();
+    let target = total * percent as usize / 100;
+    let mut summarized = 0;
+    for (index, (message, tokens)) in messages.iter().zip(token_counts).enumerate() {
+        if summarized >= target && index > 0 && starts_turn(message) {
+            return Some(index);
+        }
+        summarized += tokens;
+    }
+    None
+}
+
+fn starts_turn(message: &Message) -> bool {
+    message.role == Role::User && message.is_agent_visible() && message.has_only_text_content()
+}
+
+fn response_text_len(content: &[Content]) -> usize {
+    content
+        .iter()
+        .map(|item| match &item.raw {
+            RawContent::Text(text) => text.text.chars().count(),
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Replaces the results of the tool responses with ids in `stubs` with the given text
+fn stub_tool_responses(
+    messages: &[Message],
+    stubs: &HashMap<String, String>,
+) -> Option<Vec<Message>> {
+    if stubs.is_empty() {
+        return None;
+    }
+    let reduced = messages
+        .iter()
+        .map(|message| {
+            let mut message = message.clone();
+            for content in &mut message.content {
+                if let MessageContent::ToolResponse(response) = content {
+                    if let Some(stub) = stubs.get(&response.id) {
+                        response.tool_result = Ok(vec![Content::text(stub.clone())]);
+                    }
+                }
+            }
+            message
+        })
+        .collect();
+    Some(reduced)
+}
+
+/// Collapses every successful tool response but the `keep_recent` most recent into a one-line
+/// stub, leaving the tool requests as they are
+pub fn collapse_tool_responses(messages: &[Message], keep_recent: usize) -> Option<Vec<Message>> {
+    let responses: Vec<(&str, usize)> = messages
+        .iter()
+        .flat_map(|message| message.content.iter())
+        .filter_map(|content| content.as_tool_response())
+        .filter_map(|response| match &response.tool_result {
+            Ok(result) => Some((response.id.as_str(), response_text_len(result))),
+            Err(_) => None,
+        })
+        .collect();
+    let collapsible = responses.len().saturating_sub(keep_recent);
+    let stubs: HashMap<String, String> = responses[..collapsible]
+        .iter()
+        .filter(|(_, len)| *len >= MIN_COLLAPSIBLE_CHARS)
+        .map(|(id, len)| {
+            (
+                id.to_string(),
+                format!("[Tool response of {} characters collapsed]", len),
+            )
+        })
+        .collect();
+    stub_tool_responses(messages, &stubs)
+}
+
+/// The path a tool call reads in full or in part, with whether it reads the whole file
+fn file_read(tool_call: &CallToolRequestParam) -> Option<(String, bool)> {
+    let arguments = tool_call.arguments.as_ref()?;
+    let path = arguments.get("path")?.as_str()?.to_string();
+    if tool_call.name.ends_with("text_editor") {
+        if arguments.get("command")?.as_str()? != "view" {
+            return None;
+        }
+        Some((path, !arguments.contains_key("view_range")))
+    } else if tool_call.name.ends_with("read_file") {
+        Some((path, true))
+    } else {
+        None
+    }
+}
+
+/// Replaces the results of file reads with a stub when the same file is read in full later
+pub fn drop_superseded_reads(messages: &[Message]) -> Option<Vec<Message>> {
+    let reads: Vec<(&str, String, bool)> = messages
+        .iter()
+        .flat_map(|message| message.content.iter())
+        .filter_map(|content| content.as_tool_request())
+        .filter_map(|request| {
+            let tool_call = request.tool_call.as_ref().ok()?;
+            let (path, full) = file_read(tool_call)?;
+            Some((request.id.as_str(), path, full))
+        })
+        .collect();
+
+    let mut stubs = HashMap::new();
+    for (index, (id, path, _)) in reads.iter().enumerate() {
+        let superseded = reads[index + 1..]
+            .iter()
+            .any(|(_, later_path, full)| *full && later_path == path);
+        if superseded {
+            stubs.insert(
+                id.to_string(),
+                format!("[Superseded by a later read of {}]", path),
+            );
+        }
+    }
+    stub_tool_responses(messages, &stubs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::object;
+
+    fn read_file(id: &str, path: &str, contents: &str) -> Vec<Message> {
+        vec![
+            Message::assistant().with_tool_request(
+                id,
+                Ok(CallToolRequestParam {
+                    name: "developer__text_editor".into(),
+                    arguments: Some(object!({"command": "view", "path": path})),
+                }),
+            ),
+            Message::user().with_tool_response(id, Ok(vec![Content::text(contents)])),
+        ]
+    }
+
+    fn response_text(message: &Message) -> String {
+        message.content[0].as_tool_response_text().unwrap()
+    }
+
+    #[test]
+    fn test_drop_superseded_reads() {
+        let mut messages = vec![Message::user().with_text("Fix the bug")];
+        messages.extend(read_file("1", "src/main.rs", "fn main() {}"));
+        messages.extend(read_file("2", "src/lib.rs", "pub mod app;"));
+        messages.extend(read_file("3", "src/main.rs", "fn main() { run() }"));
+
+        let reduced = drop_superseded_reads(&messages).unwrap();
+
+        assert_eq!(
+            response_text(&reduced[2]),
+            "[Superseded by a later read of src/main.rs]"
+        );
+        assert_eq!(response_text(&reduced[4]), "pub mod app;");
+        assert_eq!(response_text(&reduced[6]), "fn main() { run() }");
+        assert_eq!(reduced[1], messages[1]);
+        assert!(drop_superseded_reads(&messages[..5]).is_none());
+    }
+
+    #[test]
+    fn test_collapse_tool_responses_keeps_recent() {
+        let long_output = "x".repeat(500);
+        let mut messages = vec![Message::user().with_text("Look around")];
+        messages.extend(read_file("1", "a.rs", &long_output));
+        messages.extend(read_file("2", "b.rs", &long_output));
+        messages.extend(read_file("3", "c.rs", &long_output));
+
+        let reduced = collapse_tool_responses(&messages, 1).unwrap();
+
+        assert_eq!(
+            response_text(&reduced[2]),
+            "[Tool response of 500 characters collapsed]"
+        );
+        assert_eq!(
+            response_text(&reduced[4]),
+            "[Tool response of 500 characters collapsed]"
+        );
+        assert_eq!(response_text(&reduced[6]), long_output);
+        assert!(reduced[3].is_tool_call());
+        assert!(collapse_tool_responses(&messages, 3).is_none());
+    }
+
+    #[test]
+    fn test_rolling_summary_split_starts_a_turn() {
+        let mut messages = vec![Message::user().with_text("First task")];
+        messages.extend(read_file("1", "a.rs", "a"));
+        messages.push(Message::assistant().with_text("Done"));
+        messages.push(Message::user().with_text("Second task"));
+        messages.push(Message::assistant().with_text("Done too"));
+        let token_counts = vec![10, 10, 40, 10, 10, 20];
+
+        assert_eq!(rolling_summary_split(&messages, &token_counts, 20), Some(4));
+        assert_eq!(rolling_summary_split(&messages, &token_counts, 90), None);
+    }
+
+    #[test]
+    fn test_context_strategy_round_trip() {
+        for strategy in [
+            ContextStrategy::Summarize,
+            ContextStrategy::CollapseToolResponses,
+            ContextStrategy::DropSupersededReads,
+            ContextStrategy::RollingSummary,
+        ] {
+            assert_eq!(
+                strategy.to_string().parse::<ContextStrategy>(),
+                Ok(strategy)
+            );
+            assert_eq!(
+                serde_json::to_value(strategy).unwrap(),
+                serde_json::json!(strategy.to_string())
+            );
+        }
+        assert!("truncate".parse::<ContextStrategy>().is_err());
+        assert_eq!(
+            ContextStrategy::resolve(Some(ContextStrategy::DropSupersededReads)),
+            ContextStrategy::DropSupersededReads
+        );
+    }
+}
@@ -15,7 +15,10 @@ pub mod recipe;
 pub mod recipe_deeplink;
 pub mod scheduler;
 pub mod scheduler_factory;
+pub mod scheduler_history;
+pub mod scheduler_policy;
 pub mod scheduler_trait;
+pub mod scheduler_trigger;
 pub mod security;
 pub mod session;
 pub mod temporal_scheduler;
@@ -0,0 +1,257 @@
+use crate::config::Config;
+use crate::conversation::message::ToolRequest;
+use crate::permission::permission_judge::PermissionCheckResult;
+use crate::permission::Permission;
+use crate::session::{AuditAction, AuditEntry, AuditKind, SessionManager};
+use crate::tool_inspection::{InspectionAction, InspectionResult};
+
+/// Config key overriding the principal recorded in the audit log
+pub const GOOSE_AUDIT_PRINCIPAL: &str = "GOOSE_AUDIT_PRINCIPAL";
+
+/// Inspectors whose Allow verdicts can approve a call on their own, most specific first
+const APPROVING_INSPECTORS: [&str; 2] = ["policy", "permission"];
+
+/// The account tool calls are made for: GOOSE_AUDIT_PRINCIPAL if set, else the OS user
+pub fn principal() -> String {
+    Config::global()
+        .get_param::<String>(GOOSE_AUDIT_PRINCIPAL)
+        .ok()
+        .or_else(|| std::env::var("USER").ok())
+        .or_else(|| std::env::var("USERNAME").ok())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn tool_name(request: &ToolRequest) -> String {
+    request
+        .tool_call
+        .as_ref()
+        .map(|call| call.name.to_string())
+        .unwrap_or_default()
+}
+
+/// Widen an inspector confidence without exposing f32 noise (0.9 -> 0.8999999761...)
+fn confidence(value: f32) -> f64 {
+    (value as f64 * 10_000.0).round() / 10_000.0
+}
+
+fn inspection_action(action: &InspectionAction) -> AuditAction {
+    match action {
+        InspectionAction::Allow => AuditAction::Allow,
+        InspectionAction::RequireApproval(_) => AuditAction::RequireApproval,
+        InspectionAction::Deny => AuditAction::Deny,
+    }
+}
+
+/// One entry per inspector verdict, followed by the automatic decision for every request
+/// that was approved or denied without asking. Requests needing approval get their
+/// decision entry once the user answers.
+pub fn inspection_entries(
+    session_id: Option<&str>,
+    requests: &[ToolRequest],
+    results: &[InspectionResult],
+    check: &PermissionCheckResult,
+    principal: &str,
+) -> Vec<AuditEntry> {
+    let request = |id: &str| requests.iter().find(|r| r.id == id);
+    let entry = |request: &ToolRequest, kind, inspector: &str, action, reason: &str, confidence| {
+        AuditEntry {
+            session_id: session_id.map(str::to_string),
+            tool_request_id: request.id.clone(),
+            tool_name: tool_name(request),
+            kind,
+            inspector: inspector.to_string(),
+            action,
+            reason: reason.to_string(),
+            confidence,
+            principal: principal.to_string(),
+        }
+    };
+
+    let mut entries: Vec<AuditEntry> = results
+        .iter()
+        .filter_map(|result| {
+            let request = request(&result.tool_request_id)?;
+            Some(entry(
+                request,
+                AuditKind::Inspection,
+                &result.inspector_name,
+                inspection_action(&result.action),
+                &result.reason,
+                Some(confidence(result.confidence)),
+            ))
+        })
+        .collect();
+
+    for approved in &check.approved {
+        let decided_by = APPROVING_INSPECTORS.iter().find_map(|name| {
+            results.iter().find(|r| {
+                r.tool_request_id == approved.id
+                    && r.inspector_name == *name
+                    && r.action == InspectionAction::Allow
+            })
+        });
+        entries.push(match decided_by {
+            Some(result) => entry(
+                approved,
+                AuditKind::Decision,
+                &result.inspector_name,
+                AuditAction::AutoApproved,
+                &result.reason,
+                Some(confidence(result.confidence)),
+            ),
+            None => entry(
+                approved,
+                AuditKind::Decision,
+                "permission",
+                AuditAction::AutoApproved,
+                "Approved by the current goose mode",
+                None,
+            ),
+        });
+    }
+
+    for denied in &check.denied {
+        let decided_by = results
+            .iter()
+            .find(|r| r.tool_request_id == denied.id && r.action == InspectionAction::Deny);
+        entries.push(match decided_by {
+            Some(result) => entry(
+                denied,
+                AuditKind::Decision,
+                &result.inspector_name,
+                AuditAction::Blocked,
+                &result.reason,
+                Some(confidence(result.confidence)),
+            ),
+            None => entry(
+                denied,
+                AuditKind::Decision,
+                "permission",
+                AuditAction::Blocked,
+                "Denied by the current goose mode",
+                None,
+            ),
+        });
+    }
+
+    entries
+}
+
+/// The decision entry for a tool call the user was asked about
+pub fn user_decision_entry(
+    session_id: Option<&str>,
+    request: &ToolRequest,
+    permission: &Permission,
+    principal: &str,
+) -> AuditEntry {
+    let (action, reason) = match permission {
+        Permission::AllowOnce => (AuditAction::UserApproved, "User allowed this call"),
+        Permission::AlwaysAllow => (AuditAction::UserApproved, "User always allows this tool"),
+        Permission::DenyOnce => (AuditAction::UserDenied, "User denied this call"),
+        Permission::Cancel => (AuditAction::UserDenied, "User cancelled this call"),
+    };
+    AuditEntry {
+        session_id: session_id.map(str::to_string),
+        tool_request_id: request.id.clone(),
+        tool_name: tool_name(request),
+        kind: AuditKind::Decision,
+        inspector: "user".to_string(),
+        action,
+        reason: reason.to_string(),
+        confidence: None,
+        principal: principal.to_string(),
+    }
+}
+
+/// Append entries to the audit log. Failures are logged rather than interrupting the turn.
+pub async fn record(entries: &[AuditEntry]) {
+    if entries.is_empty() {
+        return;
+    }
+    if let Err(e) = SessionManager::record_audit_entries(entries).await {
+        tracing::error!("Failed to record permission audit entries: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::CallToolRequestParam;
+    use rmcp::object;
+
+    fn request(id: &str) -> ToolRequest {
+        ToolRequest {
+            id: id.to_string(),
+            tool_call: Ok(CallToolRequestParam {
+                name: "developer__shell".into(),
+                arguments: Some(object!({ "command": "ls" })),
+            }),
+        }
+    }
+
+    fn result(
+        id: &str,
+        inspector: &str,
+        action: InspectionAction,
+        reason: &str,
+    ) -> InspectionResult {
+        InspectionResult {
+            tool_request_id: id.to_string(),
+            action,
+            reason: reason.to_string(),
+            confidence: 0.5,
+            inspector_name: inspector.to_string(),
+            finding_id: None,
+        }
+    }
+
+    #[test]
+    fn test_inspection_entries_attribute_decisions() {
+        let requests = vec![request("a"), request("b"), request("c")];
+        let results = vec![
+            result(
+                "a",
+                "permission",
+                InspectionAction::Allow,
+                "Tool pre-approved",
+            ),
+            result("a", "policy", InspectionAction::Allow, "Policy rule 'ls'"),
+            result(
+                "b",
+                "permission",
+                InspectionAction::Allow,
+                "Tool pre-approved",
+            ),
+            result("b", "security", InspectionAction::Deny, "rm -rf /"),
+            result(
+                "c",
+                "permission",
+                InspectionAction::RequireApproval(None),
+                "Ask",
+            ),
+        ];
+        let check = PermissionCheckResult {
+            approved: vec![requests[0].clone()],
+            needs_approval: vec![requests[2].clone()],
+            denied: vec![requests[1].clone()],
+        };
+
+        let entries = inspection_entries(Some("s1"), &requests, &results, &check, "alice");
+        assert_eq!(entries.len(), 7);
+        assert!(entries[..5]
+            .iter()
+            .all(|e| e.kind == AuditKind::Inspection && e.principal == "alice"));
+
+        let decisions: Vec<_> = entries[5..]
+            .iter()
+            .map(|e| (e.tool_request_id.as_str(), e.inspector.as_str(), e.action))
+            .collect();
+        assert_eq!(
+            decisions,
+            vec![
+                ("a", "policy", AuditAction::AutoApproved),
+                ("b", "security", AuditAction::Blocked),
+            ]
+        );
+    }
+}
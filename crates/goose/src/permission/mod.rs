@@ -1,9 +1,14 @@
+pub mod audit;
 pub mod permission_confirmation;
 pub mod permission_inspector;
 pub mod permission_judge;
 pub mod permission_store;
+pub mod policy;
+pub mod policy_inspector;
 
 pub use permission_confirmation::{Permission, PermissionConfirmation};
 pub use permission_inspector::PermissionInspector;
 pub use permission_judge::detect_read_only_tools;
 pub use permission_store::ToolPermissionStore;
+pub use policy::{PolicyAction, PolicyDecision, ToolPolicy};
+pub use policy_inspector::PolicyInspector;
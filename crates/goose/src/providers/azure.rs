@@ -5,11 +5,15 @@ use serde_json::Value;
 
 use super::api_client::{ApiClient, AuthMethod, AuthProvider};
 use super::azureauth::{AuthError, AzureAuth};
-use super::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage, Usage};
+use super::base::{ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage, Usage};
 use super::errors::ProviderError;
 use super::formats::openai::{create_request, get_usage, response_to_message};
 use super::retry::ProviderRetry;
-use super::utils::{emit_debug_trace, get_model, handle_response_openai_compat, ImageFormat};
+use super::utils::{
+    emit_debug_trace, get_model, handle_response_openai_compat, handle_status_openai_compat,
+    ImageFormat,
+};
+use super::utils_universal_openai_stream::{enable_streaming, stream_openai_compat};
 use crate::conversation::message::Message;
 use crate::impl_provider_default;
 use crate::model::ModelConfig;
@@ -99,14 +103,18 @@ impl AzureProvider {
         })
     }
 
-    async fn post(&self, payload: &Value) -> Result<Value, ProviderError> {
-        // Build the path for Azure OpenAI
-        let path = format!(
+    fn chat_completions_path(&self) -> String {
+        format!(
             "openai/deployments/{}/chat/completions?api-version={}",
             self.deployment_name, self.api_version
-        );
+        )
+    }
 
-        let response = self.api_client.response_post(&path, payload).await?;
+    async fn post(&self, payload: &Value) -> Result<Value, ProviderError> {
+        let response = self
+            .api_client
+            .response_post(&self.chat_completions_path(), payload)
+            .await?;
         handle_response_openai_compat(response).await
     }
 }
@@ -162,4 +170,86 @@ impl Provider for AzureProvider {
         emit_debug_trace(model_config, &payload, &response, &usage);
         Ok((message, ProviderUsage::new(response_model, usage)))
     }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let mut payload =
+            create_request(&self.model, system, messages, tools, &ImageFormat::OpenAi)?;
+        enable_streaming(&mut payload);
+
+        let response = self
+            .api_client
+            .response_post(&self.chat_completions_path(), &payload)
+            .await?;
+        let response = handle_status_openai_compat(response).await?;
+
+        Ok(stream_openai_compat(response, self.model.clone(), payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::utils::stream_test_support::{
+        assert_single_message_id, collect_stream, mock_event_stream,
+    };
+
+    // Recorded from an Azure OpenAI gpt-4o deployment; Azure sends a leading
+    // content-filter chunk with no choices.
+    const STREAM_FIXTURE: &str = r#"data: {"choices":[],"created":0,"id":"","model":"","object":"","prompt_filter_results":[{"prompt_index":0,"content_filter_results":{"hate":{"filtered":false,"severity":"safe"},"self_harm":{"filtered":false,"severity":"safe"},"sexual":{"filtered":false,"severity":"safe"},"violence":{"filtered":false,"severity":"safe"}}}]}
+
+data: {"choices":[{"content_filter_results":{},"delta":{"content":"","refusal":null,"role":"assistant"},"finish_reason":null,"index":0,"logprobs":null}],"created":1747591235,"id":"chatcmpl-BYcbLazure","model":"gpt-4o-2024-11-20","object":"chat.completion.chunk","system_fingerprint":"fp_ee1d74bde0"}
+
+data: {"choices":[{"content_filter_results":{"hate":{"filtered":false,"severity":"safe"}},"delta":{"content":"Hello"},"finish_reason":null,"index":0,"logprobs":null}],"created":1747591235,"id":"chatcmpl-BYcbLazure","model":"gpt-4o-2024-11-20","object":"chat.completion.chunk","system_fingerprint":"fp_ee1d74bde0"}
+
+data: {"choices":[{"content_filter_results":{"hate":{"filtered":false,"severity":"safe"}},"delta":{"content":" from Azure"},"finish_reason":null,"index":0,"logprobs":null}],"created":1747591235,"id":"chatcmpl-BYcbLazure","model":"gpt-4o-2024-11-20","object":"chat.completion.chunk","system_fingerprint":"fp_ee1d74bde0"}
+
+data: {"choices":[{"content_filter_results":{},"delta":{},"finish_reason":"stop","index":0,"logprobs":null}],"created":1747591235,"id":"chatcmpl-BYcbLazure","model":"gpt-4o-2024-11-20","object":"chat.completion.chunk","system_fingerprint":"fp_ee1d74bde0"}
+
+data: {"choices":[],"created":1747591235,"id":"chatcmpl-BYcbLazure","model":"gpt-4o-2024-11-20","object":"chat.completion.chunk","system_fingerprint":"fp_ee1d74bde0","usage":{"completion_tokens":4,"prompt_tokens":21,"total_tokens":25}}
+
+data: [DONE]
+
+"#;
+
+    #[tokio::test]
+    async fn test_stream_recorded_fixture() {
+        let mock_server = mock_event_stream(
+            "/openai/deployments/gpt-4o/chat/completions",
+            STREAM_FIXTURE,
+        )
+        .await;
+        let provider = AzureProvider {
+            api_client: ApiClient::new(
+                mock_server.uri(),
+                AuthMethod::BearerToken("test".to_string()),
+            )
+            .unwrap(),
+            deployment_name: "gpt-4o".to_string(),
+            api_version: AZURE_DEFAULT_API_VERSION.to_string(),
+            model: ModelConfig::new_or_fail("gpt-4o"),
+        };
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Hi")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Hello from Azure");
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.model, "gpt-4o-2024-11-20");
+        assert_eq!(usage.usage.input_tokens, Some(21));
+        assert_eq!(usage.usage.output_tokens, Some(4));
+    }
 }
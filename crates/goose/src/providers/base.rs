@@ -9,6 +9,7 @@ use crate::conversation::Conversation;
 use crate::model::ModelConfig;
 use crate::utils::safe_truncate;
 use rmcp::model::Tool;
+use serde_json::Value;
 use utoipa::ToSchema;
 
 use once_cell::sync::Lazy;
@@ -423,6 +424,24 @@ pub trait Provider: Send + Sync {
         false
     }
 
+    /// Check if this provider can constrain a response to a JSON schema natively
+    fn supports_structured_output(&self) -> bool {
+        false
+    }
+
+    /// Generate a response whose text is JSON matching `schema`, using the provider's
+    /// native structured output support. Default implementation returns an error.
+    async fn complete_structured(
+        &self,
+        _system: &str,
+        _messages: &[Message],
+        _schema: &Value,
+    ) -> Result<(Message, ProviderUsage), ProviderError> {
+        Err(ProviderError::NotImplemented(
+            "structured output not implemented".to_string(),
+        ))
+    }
+
     /// Get the currently active model name
     /// For regular providers, this returns the configured model
     /// For LeadWorkerProvider, this returns the currently active model (lead or worker)
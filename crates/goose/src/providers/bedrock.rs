@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use super::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage};
+use super::base::{ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage};
 use super::errors::ProviderError;
 use super::retry::{ProviderRetry, RetryConfig};
 use crate::conversation::message::Message;
@@ -8,9 +8,13 @@ use crate::impl_provider_default;
 use crate::model::ModelConfig;
 use crate::providers::utils::emit_debug_trace;
 use anyhow::Result;
+use async_stream::try_stream;
 use async_trait::async_trait;
 use aws_sdk_bedrockruntime::config::ProvideCredentials;
 use aws_sdk_bedrockruntime::operation::converse::ConverseError;
+use aws_sdk_bedrockruntime::operation::converse_stream::ConverseStreamError;
+use aws_sdk_bedrockruntime::primitives::event_stream::EventReceiver;
+use aws_sdk_bedrockruntime::types::error::ConverseStreamOutputError;
 use aws_sdk_bedrockruntime::{types as bedrock, Client};
 use rmcp::model::Tool;
 use serde_json::Value;
@@ -18,6 +22,7 @@ use serde_json::Value;
 // Import the migrated helper functions from providers/formats/bedrock.rs
 use super::formats::bedrock::{
     from_bedrock_message, from_bedrock_usage, to_bedrock_message, to_bedrock_tool_config,
+    BedrockStreamState,
 };
 
 pub const BEDROCK_DOC_LINK: &str =
@@ -170,6 +175,65 @@ impl BedrockProvider {
             )),
         }
     }
+
+    async fn converse_stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<
+        EventReceiver<bedrock::ConverseStreamOutput, ConverseStreamOutputError>,
+        ProviderError,
+    > {
+        let mut request = self
+            .client
+            .converse_stream()
+            .system(bedrock::SystemContentBlock::Text(system.to_string()))
+            .model_id(self.model.model_name.to_string())
+            .set_messages(Some(
+                messages
+                    .iter()
+                    .filter(|m| m.is_agent_visible())
+                    .map(to_bedrock_message)
+                    .collect::<Result<_>>()?,
+            ));
+
+        if !tools.is_empty() {
+            request = request.tool_config(to_bedrock_tool_config(tools)?);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|err| match err.into_service_error() {
+                ConverseStreamError::ThrottlingException(throttle_err) => {
+                    ProviderError::RateLimitExceeded {
+                        details: format!("Bedrock throttling error: {:?}", throttle_err),
+                        retry_delay: None,
+                    }
+                }
+                ConverseStreamError::AccessDeniedException(err) => {
+                    ProviderError::Authentication(format!("Failed to call Bedrock: {:?}", err))
+                }
+                ConverseStreamError::ValidationException(err)
+                    if err
+                        .message()
+                        .unwrap_or_default()
+                        .contains("Input is too long for requested model.") =>
+                {
+                    ProviderError::ContextLengthExceeded(format!(
+                        "Failed to call Bedrock: {:?}",
+                        err
+                    ))
+                }
+                ConverseStreamError::ModelErrorException(err) => {
+                    ProviderError::ExecutionError(format!("Failed to call Bedrock: {:?}", err))
+                }
+                err => ProviderError::ServerError(format!("Failed to call Bedrock: {:?}", err)),
+            })?;
+
+        Ok(response.stream)
+    }
 }
 
 impl_provider_default!(BedrockProvider);
@@ -236,4 +300,39 @@ impl Provider for BedrockProvider {
         let provider_usage = ProviderUsage::new(model_name.to_string(), usage);
         Ok((message, provider_usage))
     }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let mut events = self
+            .with_retry(|| self.converse_stream(system, messages, tools))
+            .await?;
+
+        let debug_payload = serde_json::json!({
+            "system": system,
+            "messages": messages,
+            "tools": tools
+        });
+        let model_config = self.model.clone();
+
+        Ok(Box::pin(try_stream! {
+            let mut state = BedrockStreamState::default();
+            while let Some(event) = events.recv().await.map_err(|e| ProviderError::RequestFailed(format!("Stream decode error: {:?}", e)))? {
+                let (message, usage) = state.handle_event(&event).map_err(|e| ProviderError::RequestFailed(format!("Stream decode error: {}", e)))?;
+                if message.is_none() && usage.is_none() {
+                    continue;
+                }
+                let usage = usage.map(|usage| ProviderUsage::new(model_config.model_name.clone(), usage));
+                emit_debug_trace(&model_config, &debug_payload, &message, &usage.as_ref().map(|f| f.usage).unwrap_or_default());
+                yield (message, usage);
+            }
+        }))
+    }
 }
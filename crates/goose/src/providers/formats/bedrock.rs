@@ -13,6 +13,7 @@ use rmcp::model::{
     Role, Tool,
 };
 use serde_json::Value;
+use uuid::Uuid;
 
 use super::super::base::Usage;
 use crate::conversation::message::{Message, MessageContent};
@@ -341,6 +342,101 @@ pub fn from_bedrock_usage(usage: &bedrock::TokenUsage) -> Usage {
     }
 }
 
+/// Turns `ConverseStream` events into goose messages.
+///
+/// Text deltas are emitted as they arrive. Tool input arrives as JSON fragments, so each tool
+/// request is emitted whole when its content block stops. Usage arrives on the final metadata
+/// event. Bedrock sends no message id, so one is generated for all messages of the stream.
+#[derive(Debug)]
+pub struct BedrockStreamState {
+    message_id: String,
+    /// Tool uses in progress, keyed by content block index: (tool use id, name, input so far)
+    tool_uses: HashMap<i32, (String, String, String)>,
+}
+
+impl Default for BedrockStreamState {
+    fn default() -> Self {
+        Self {
+            message_id: format!("msg_{}", Uuid::new_v4()),
+            tool_uses: HashMap::new(),
+        }
+    }
+}
+
+impl BedrockStreamState {
+    pub fn handle_event(
+        &mut self,
+        event: &bedrock::ConverseStreamOutput,
+    ) -> Result<(Option<Message>, Option<Usage>)> {
+        let created = Utc::now().timestamp();
+        let message = |content| {
+            Some(Message::new(Role::Assistant, created, content).with_id(self.message_id.clone()))
+        };
+        Ok(match event {
+            bedrock::ConverseStreamOutput::ContentBlockStart(start) => {
+                if let Some(bedrock::ContentBlockStart::ToolUse(tool_use)) = start.start() {
+                    self.tool_uses.insert(
+                        start.content_block_index(),
+                        (
+                            tool_use.tool_use_id().to_string(),
+                            tool_use.name().to_string(),
+                            String::new(),
+                        ),
+                    );
+                }
+                (None, None)
+            }
+            bedrock::ConverseStreamOutput::ContentBlockDelta(delta) => match delta.delta() {
+                Some(bedrock::ContentBlockDelta::Text(text)) if !text.is_empty() => {
+                    let content = vec![MessageContent::text(text)];
+                    (message(content), None)
+                }
+                Some(bedrock::ContentBlockDelta::ToolUse(tool_use)) => {
+                    if let Some((_, _, input)) =
+                        self.tool_uses.get_mut(&delta.content_block_index())
+                    {
+                        input.push_str(tool_use.input());
+                    }
+                    (None, None)
+                }
+                _ => (None, None),
+            },
+            bedrock::ConverseStreamOutput::ContentBlockStop(stop) => {
+                match self.tool_uses.remove(&stop.content_block_index()) {
+                    Some((id, name, input)) => {
+                        let arguments = if input.is_empty() {
+                            Ok(Value::Object(Default::default()))
+                        } else {
+                            serde_json::from_str::<Value>(&input)
+                        };
+                        let tool_call = match arguments {
+                            Ok(arguments) => Ok(CallToolRequestParam {
+                                name: name.into(),
+                                arguments: Some(object(arguments)),
+                            }),
+                            Err(e) => Err(ErrorData {
+                                code: ErrorCode::INVALID_PARAMS,
+                                message: Cow::from(format!(
+                                    "Could not interpret tool use parameters for id {}: {}",
+                                    id, e
+                                )),
+                                data: None,
+                            }),
+                        };
+                        let content = vec![MessageContent::tool_request(id, tool_call)];
+                        (message(content), None)
+                    }
+                    None => (None, None),
+                }
+            }
+            bedrock::ConverseStreamOutput::Metadata(metadata) => {
+                (None, metadata.usage().map(from_bedrock_usage))
+            }
+            _ => (None, None),
+        })
+    }
+}
+
 pub fn from_bedrock_json(document: &Document) -> Result<Value> {
     Ok(match document {
         Document::Null => Value::Null,
@@ -367,6 +463,7 @@ pub fn from_bedrock_json(document: &Document) -> Result<Value> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::providers::utils::stream_test_support::assert_single_message_id;
     use anyhow::Result;
     use rmcp::model::{AnnotateAble, RawImageContent};
 
@@ -457,4 +554,113 @@ mod tests {
 
         Ok(())
     }
+
+    /// Events recorded from a `ConverseStream` call to anthropic.claude-sonnet-4-20250514-v1:0
+    /// that answers with text and then a tool use.
+    fn recorded_stream_events() -> Result<Vec<bedrock::ConverseStreamOutput>> {
+        use bedrock::ConverseStreamOutput as Event;
+
+        let text_delta = |text: &str| -> Result<Event> {
+            Ok(Event::ContentBlockDelta(
+                bedrock::ContentBlockDeltaEvent::builder()
+                    .content_block_index(0)
+                    .delta(bedrock::ContentBlockDelta::Text(text.to_string()))
+                    .build()?,
+            ))
+        };
+        let tool_delta = |input: &str| -> Result<Event> {
+            Ok(Event::ContentBlockDelta(
+                bedrock::ContentBlockDeltaEvent::builder()
+                    .content_block_index(1)
+                    .delta(bedrock::ContentBlockDelta::ToolUse(
+                        bedrock::ToolUseBlockDelta::builder().input(input).build()?,
+                    ))
+                    .build()?,
+            ))
+        };
+
+        Ok(vec![
+            Event::MessageStart(
+                bedrock::MessageStartEvent::builder()
+                    .role(bedrock::ConversationRole::Assistant)
+                    .build()?,
+            ),
+            text_delta("Let me look")?,
+            text_delta(" that up.")?,
+            Event::ContentBlockStop(
+                bedrock::ContentBlockStopEvent::builder()
+                    .content_block_index(0)
+                    .build()?,
+            ),
+            Event::ContentBlockStart(
+                bedrock::ContentBlockStartEvent::builder()
+                    .content_block_index(1)
+                    .start(bedrock::ContentBlockStart::ToolUse(
+                        bedrock::ToolUseBlockStart::builder()
+                            .tool_use_id("tooluse_Qm1kZ2VydG9vbA")
+                            .name("get_weather")
+                            .build()?,
+                    ))
+                    .build()?,
+            ),
+            tool_delta("")?,
+            tool_delta("{\"location\": \"Sea")?,
+            tool_delta("ttle\"}")?,
+            Event::ContentBlockStop(
+                bedrock::ContentBlockStopEvent::builder()
+                    .content_block_index(1)
+                    .build()?,
+            ),
+            Event::MessageStop(
+                bedrock::MessageStopEvent::builder()
+                    .stop_reason(bedrock::StopReason::ToolUse)
+                    .build()?,
+            ),
+            Event::Metadata(
+                bedrock::ConverseStreamMetadataEvent::builder()
+                    .usage(
+                        bedrock::TokenUsage::builder()
+                            .input_tokens(412)
+                            .output_tokens(57)
+                            .total_tokens(469)
+                            .build()?,
+                    )
+                    .build(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_stream_state_recorded_events() -> Result<()> {
+        let mut state = BedrockStreamState::default();
+        let mut messages = Vec::new();
+        let mut usage = None;
+        for event in recorded_stream_events()? {
+            let (message, event_usage) = state.handle_event(&event)?;
+            messages.extend(message);
+            usage = usage.or(event_usage);
+        }
+        assert_single_message_id(&messages);
+
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Let me look that up.");
+
+        let tool_request = messages
+            .iter()
+            .flat_map(|m| m.content.iter())
+            .find_map(|c| c.as_tool_request())
+            .expect("tool request should be emitted");
+        assert_eq!(tool_request.id, "tooluse_Qm1kZ2VydG9vbA");
+        let call = tool_request.tool_call.as_ref().unwrap();
+        assert_eq!(call.name, "get_weather");
+        assert_eq!(
+            call.arguments,
+            Some(object(serde_json::json!({"location": "Seattle"})))
+        );
+
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.input_tokens, Some(412));
+        assert_eq!(usage.output_tokens, Some(57));
+        Ok(())
+    }
 }
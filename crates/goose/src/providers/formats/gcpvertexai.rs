@@ -1,12 +1,14 @@
 use super::{anthropic, google};
 use crate::conversation::message::Message;
 use crate::model::ModelConfig;
-use crate::providers::base::Usage;
+use crate::providers::base::{ProviderUsage, Usage};
 use anyhow::{Context, Result};
+use futures::Stream;
 use rmcp::model::Tool;
 use serde_json::Value;
 
 use std::fmt;
+use std::pin::Pin;
 
 /// Sensible default values of Google Cloud Platform (GCP) locations for model deployment.
 ///
@@ -340,6 +342,31 @@ pub fn get_usage(data: &Value, request_context: &RequestContext) -> Result<Usage
     }
 }
 
+/// Converts a streaming provider response to a stream of messages.
+///
+/// # Arguments
+/// * `stream` - Lines of the server-sent event response
+/// * `request_context` - Context information about the request
+/// * `model_name` - Model reported in usage when the response does not name one
+///
+/// # Returns
+/// * A stream of partial messages and usage, in the shape of the provider's own parser
+pub fn response_to_streaming_message<S>(
+    stream: S,
+    request_context: RequestContext,
+    model_name: String,
+) -> Pin<Box<dyn Stream<Item = Result<(Option<Message>, Option<ProviderUsage>)>> + Send>>
+where
+    S: Stream<Item = Result<String>> + Unpin + Send + 'static,
+{
+    match request_context.provider() {
+        ModelProvider::Anthropic => Box::pin(anthropic::response_to_streaming_message(stream)),
+        ModelProvider::Google => {
+            Box::pin(google::response_to_streaming_message(stream, model_name))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,12 @@
 use crate::model::ModelConfig;
-use crate::providers::base::Usage;
+use crate::providers::base::{ProviderUsage, Usage};
 use crate::providers::errors::ProviderError;
-use crate::providers::utils::{is_valid_function_name, sanitize_function_name};
-use anyhow::Result;
+use crate::providers::utils::{
+    is_valid_function_name, sanitize_function_name, unescape_json_values,
+};
+use anyhow::{anyhow, Result};
+use async_stream::try_stream;
+use futures::Stream;
 use rand::{distributions::Alphanumeric, Rng};
 use rmcp::model::{
     object, AnnotateAble, CallToolRequestParam, ErrorCode, ErrorData, RawContent, Role, Tool,
@@ -12,6 +16,7 @@ use std::borrow::Cow;
 use crate::conversation::message::{Message, MessageContent};
 use serde_json::{json, Map, Value};
 use std::ops::Deref;
+use uuid::Uuid;
 
 /// Convert internal Message format to Google's API message specification
 pub fn format_messages(messages: &[Message]) -> Vec<Value> {
@@ -311,6 +316,71 @@ pub fn get_usage(data: &Value) -> Result<Usage> {
     }
 }
 
+/// Convert a `streamGenerateContent?alt=sse` response into messages.
+///
+/// Each event is a complete `GenerateContentResponse` holding the next slice of the answer;
+/// function calls always arrive whole. Usage metadata is repeated on every event, so it is
+/// only reported once the candidate has a finish reason. `model_name` is used when the
+/// response does not carry a `modelVersion`. All messages take the `responseId` of the first
+/// event, or a generated id if it has none, so that they merge into one message.
+pub fn response_to_streaming_message<S>(
+    mut stream: S,
+    model_name: String,
+) -> impl Stream<Item = Result<(Option<Message>, Option<ProviderUsage>)>> + 'static
+where
+    S: Stream<Item = Result<String>> + Unpin + Send + 'static,
+{
+    try_stream! {
+        use futures::StreamExt;
+
+        let mut message_id: Option<String> = None;
+        while let Some(line) = stream.next().await {
+            let line = line?;
+            let Some(data) = line.strip_prefix("data: ").map(str::trim) else {
+                continue;
+            };
+            if data.is_empty() {
+                continue;
+            }
+
+            let chunk: Value = serde_json::from_str(data)
+                .map_err(|e| anyhow!("Failed to parse streaming chunk: {}: {:?}", e, data))?;
+            let id = message_id
+                .get_or_insert_with(|| {
+                    chunk
+                        .get("responseId")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("msg_{}", Uuid::new_v4()))
+                })
+                .clone();
+
+            let finished = chunk
+                .pointer("/candidates/0/finishReason")
+                .is_some_and(|reason| !reason.is_null());
+            let usage = if finished {
+                let model = chunk
+                    .get("modelVersion")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or(&model_name)
+                    .to_string();
+                Some(ProviderUsage::new(model, get_usage(&chunk)?))
+            } else {
+                None
+            };
+
+            let message = response_to_message(unescape_json_values(&chunk))?;
+            if message.content.is_empty() {
+                if usage.is_some() {
+                    yield (None, usage);
+                }
+            } else {
+                yield (Some(message.with_id(id)), usage);
+            }
+        }
+    }
+}
+
 /// Create a complete request payload for Google's API
 pub fn create_request(
     model_config: &ModelConfig,
@@ -344,6 +414,21 @@ pub fn create_request(
     Ok(json!(payload))
 }
 
+/// Constrain the response to `schema` through `responseSchema`, which only accepts the same
+/// OpenAPI subset as function declarations.
+pub fn add_response_schema(payload: &mut Value, schema: &Value) {
+    let Some(payload) = payload.as_object_mut() else {
+        return;
+    };
+    let generation_config = payload
+        .entry("generationConfig")
+        .or_insert_with(|| json!({}));
+    generation_config["responseMimeType"] = json!("application/json");
+    if let Some(schema) = schema.as_object() {
+        generation_config["responseSchema"] = process_map(schema, None);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -716,6 +801,38 @@ mod tests {
         assert!(result[0]["parameters"].get("properties").is_none());
     }
 
+    #[test]
+    fn test_add_response_schema() {
+        let mut payload = json!({"contents": [], "generationConfig": {"temperature": 0.5}});
+        let schema = json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "additionalProperties": false,
+            "properties": {
+                "summary": {"type": "string", "format": "text"},
+                "tags": {"type": "array", "items": {"type": "string"}}
+            },
+            "required": ["summary"]
+        });
+
+        add_response_schema(&mut payload, &schema);
+
+        let config = &payload["generationConfig"];
+        assert_eq!(config["temperature"], 0.5);
+        assert_eq!(config["responseMimeType"], "application/json");
+        assert_eq!(
+            config["responseSchema"],
+            json!({
+                "type": "object",
+                "properties": {
+                    "summary": {"type": "string"},
+                    "tags": {"type": "array", "items": {"type": "string"}}
+                },
+                "required": ["summary"]
+            })
+        );
+    }
+
     #[test]
     fn test_response_to_message_with_no_candidates() {
         let response = json!({});
@@ -442,6 +442,7 @@ where
     try_stream! {
         use futures::StreamExt;
 
+        let mut finished = false;
         'outer: while let Some(response) = stream.next().await {
             if response.as_ref().is_ok_and(|s| s == "data: [DONE]") {
                 break 'outer;
@@ -578,9 +579,13 @@ where
                     msg = msg.with_id(id);
                 }
 
+                // Some routers (e.g. OpenRouter) report usage on a trailing chunk with an empty delta
+                // rather than on the finishing chunk. Earlier chunks may carry partial usage.
+                let trailing = finished && text.is_empty();
+                finished |= chunk.choices[0].finish_reason.is_some();
                 yield (
                     Some(msg),
-                    if chunk.choices[0].finish_reason.is_some() {
+                    if chunk.choices[0].finish_reason.is_some() || trailing {
                         usage
                     } else {
                         None
@@ -690,6 +695,19 @@ pub fn create_request(
     Ok(payload)
 }
 
+/// Constrain the response to `schema` using structured outputs. Strict mode is left off
+/// because recipe schemas rarely meet its requirements (every property required, no
+/// additional properties).
+pub fn add_response_schema(payload: &mut Value, schema: &Value) {
+    payload["response_format"] = json!({
+        "type": "json_schema",
+        "json_schema": {
+            "name": "final_output",
+            "schema": schema,
+        },
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1248,6 +1266,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_add_response_schema() -> anyhow::Result<()> {
+        let model_config = ModelConfig::new_or_fail("gpt-4o");
+        let mut request = create_request(&model_config, "system", &[], &[], &ImageFormat::OpenAi)?;
+        let schema = json!({
+            "type": "object",
+            "properties": {"summary": {"type": "string"}},
+            "required": ["summary"]
+        });
+
+        add_response_schema(&mut request, &schema);
+
+        assert_eq!(request["response_format"]["type"], "json_schema");
+        assert_eq!(
+            request["response_format"]["json_schema"]["name"],
+            "final_output"
+        );
+        assert_eq!(request["response_format"]["json_schema"]["schema"], schema);
+        Ok(())
+    }
+
     #[test]
     fn test_create_request_o1_default() -> anyhow::Result<()> {
         // Test default medium reasoning effort for O1 model
@@ -1363,4 +1402,30 @@ data: [DONE]
 
         panic!("Expected tool call message with two calls, but did not see it");
     }
+    #[tokio::test]
+    async fn test_streamed_usage_only_from_finishing_and_trailing_chunks() -> anyhow::Result<()> {
+        let response_lines = r#"
+data: {"model":"m","choices":[{"delta":{"role":"assistant","content":""},"index":0,"finish_reason":null}],"usage":{"prompt_tokens":10,"completion_tokens":0,"total_tokens":10},"id":"chatcmpl-1"}
+data: {"model":"m","choices":[{"delta":{"content":"Hi"},"index":0,"finish_reason":null}],"usage":{"prompt_tokens":10,"completion_tokens":1,"total_tokens":11},"id":"chatcmpl-1"}
+data: {"model":"m","choices":[{"delta":{"content":""},"index":0,"finish_reason":"stop"}],"id":"chatcmpl-1"}
+data: {"model":"m","choices":[{"delta":{"content":""},"index":0,"finish_reason":null}],"usage":{"prompt_tokens":10,"completion_tokens":2,"total_tokens":12},"id":"chatcmpl-1"}
+data: [DONE]
+"#;
+
+        let response_stream =
+            tokio_stream::iter(response_lines.lines().map(|line| Ok(line.to_string())));
+        let messages = response_to_streaming_message(response_stream);
+        pin!(messages);
+
+        let mut reported = Vec::new();
+        while let Some(item) = messages.next().await {
+            if let (_, Some(usage)) = item? {
+                reported.push(usage.usage.output_tokens);
+            }
+        }
+
+        // The empty first chunk's partial usage is not reported, the trailing chunk's is
+        assert_eq!(reported, vec![Some(2)]);
+        Ok(())
+    }
 }
@@ -1,12 +1,16 @@
 use crate::conversation::message::{Message, MessageContent};
 use crate::model::ModelConfig;
-use crate::providers::base::Usage;
+use crate::providers::base::{ProviderUsage, Usage};
 use crate::providers::errors::ProviderError;
 use anyhow::{anyhow, Result};
-use rmcp::model::{object, CallToolRequestParam, Role, Tool};
+use async_stream::try_stream;
+use futures::Stream;
+use rmcp::model::{object, CallToolRequestParam, ErrorCode, ErrorData, Role, Tool};
 use rmcp::object;
 use serde_json::{json, Value};
+use std::borrow::Cow;
 use std::collections::HashSet;
+use uuid::Uuid;
 
 /// Convert internal Message format to Snowflake's API message specification
 pub fn format_messages(messages: &[Message]) -> Vec<Value> {
@@ -203,6 +207,142 @@ pub fn parse_streaming_response(sse_data: &str) -> Result<Message> {
     Ok(message)
 }
 
+/// Build the tool request for a tool use whose input has been fully streamed
+fn streamed_tool_request(id: &str, name: String, input: &str) -> Message {
+    let arguments = if input.is_empty() {
+        Ok(json!({}))
+    } else {
+        serde_json::from_str::<Value>(input)
+    };
+    let tool_call = match arguments {
+        Ok(arguments) if arguments.is_object() => Ok(CallToolRequestParam {
+            name: name.into(),
+            arguments: Some(object(arguments)),
+        }),
+        _ => Err(ErrorData {
+            code: ErrorCode::INVALID_PARAMS,
+            message: Cow::from(format!(
+                "Could not interpret tool use parameters for id {}: {}",
+                id, input
+            )),
+            data: None,
+        }),
+    };
+    Message::assistant().with_tool_request(id, tool_call)
+}
+
+/// Extract usage from a streamed event, which reports OpenAI-style token counts
+fn get_streaming_usage(event: &Value) -> Option<Usage> {
+    let usage = event.get("usage")?;
+    let input_tokens = usage
+        .get("prompt_tokens")
+        .or_else(|| usage.get("input_tokens"))
+        .and_then(|v| v.as_i64())
+        .map(|v| v as i32);
+    let output_tokens = usage
+        .get("completion_tokens")
+        .or_else(|| usage.get("output_tokens"))
+        .and_then(|v| v.as_i64())
+        .map(|v| v as i32);
+    if input_tokens.is_none() && output_tokens.is_none() {
+        return None;
+    }
+    let total_tokens = usage
+        .get("total_tokens")
+        .and_then(|v| v.as_i64())
+        .map(|v| v as i32)
+        .or_else(|| Some(input_tokens.unwrap_or(0) + output_tokens.unwrap_or(0)));
+    Some(Usage::new(input_tokens, output_tokens, total_tokens))
+}
+
+/// Convert Snowflake's streaming API response into messages as it arrives.
+///
+/// Text deltas are yielded immediately. Tool use input arrives in fragments, so a tool
+/// request is only yielded once the next tool use starts or the stream ends. Usage is
+/// reported once, with the final message. All messages take the response id of the first event,
+/// or a generated one if it has none, so that they merge into one message.
+pub fn response_to_streaming_message<S>(
+    mut stream: S,
+    model_name: String,
+) -> impl Stream<Item = Result<(Option<Message>, Option<ProviderUsage>)>> + 'static
+where
+    S: Stream<Item = Result<String>> + Unpin + Send + 'static,
+{
+    try_stream! {
+        use futures::StreamExt;
+
+        let mut model = model_name;
+        let mut usage: Option<Usage> = None;
+        let mut tool_use: Option<(String, String, String)> = None;
+        let mut message_id: Option<String> = None;
+
+        while let Some(line) = stream.next().await {
+            let line = line?;
+            let Some(data) = line.strip_prefix("data: ").map(str::trim) else {
+                continue;
+            };
+            if data.is_empty() || data == "[DONE]" {
+                continue;
+            }
+
+            let event: Value = serde_json::from_str(data)
+                .map_err(|e| anyhow!("Failed to parse streaming chunk: {}: {:?}", e, data))?;
+            let id = message_id
+                .get_or_insert_with(|| {
+                    event
+                        .get("id")
+                        .and_then(|i| i.as_str())
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("msg_{}", Uuid::new_v4()))
+                })
+                .clone();
+
+            if let Some(event_model) = event.get("model").and_then(|m| m.as_str()) {
+                model = event_model.to_string();
+            }
+            if let Some(event_usage) = get_streaming_usage(&event) {
+                usage = Some(event_usage);
+            }
+
+            let Some(delta) = event.pointer("/choices/0/delta") else {
+                continue;
+            };
+            match delta.get("type").and_then(|t| t.as_str()) {
+                Some("text") => {
+                    let content = delta.get("content").and_then(|c| c.as_str()).unwrap_or("");
+                    if !content.is_empty() {
+                        yield (Some(Message::assistant().with_text(content).with_id(id)), None);
+                    }
+                }
+                Some("tool_use") => {
+                    if let Some(tool_id) = delta.get("tool_use_id").and_then(|i| i.as_str()) {
+                        if let Some((previous_id, name, input)) = tool_use.take() {
+                            let message = streamed_tool_request(&previous_id, name, &input);
+                            yield (Some(message.with_id(id.clone())), None);
+                        }
+                        let name = delta.get("name").and_then(|n| n.as_str()).unwrap_or("");
+                        tool_use = Some((tool_id.to_string(), name.to_string(), String::new()));
+                    }
+                    if let Some(input) = delta.get("input").and_then(|i| i.as_str()) {
+                        if let Some((_, _, buffered)) = tool_use.as_mut() {
+                            buffered.push_str(input);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let message = tool_use.take().map(|(id, name, input)| {
+            let mut message = streamed_tool_request(&id, name, &input);
+            message.id = message_id;
+            message
+        });
+        let usage = ProviderUsage::new(model, usage.unwrap_or_default());
+        yield (message, Some(usage));
+    }
+}
+
 /// Convert Snowflake's API response to internal Message format
 pub fn response_to_message(response: &Value) -> Result<Message> {
     let mut message = Message::assistant();
@@ -361,6 +361,27 @@ impl GcpAuth {
         })
     }
 
+    /// Creates a handler that always serves `token_value`, for tests against a mock server.
+    #[cfg(test)]
+    pub(crate) fn with_cached_token(token_value: &str) -> Self {
+        Self {
+            credentials: AdcCredentials::AuthorizedUser(AuthorizedUserCredentials {
+                client_id: String::new(),
+                client_secret: String::new(),
+                refresh_token: String::new(),
+                token_uri: String::new(),
+            }),
+            client: reqwest::Client::new(),
+            cached_token: Arc::new(RwLock::new(Some(CachedToken {
+                token: AuthToken {
+                    token_type: "Bearer".to_string(),
+                    token_value: token_value.to_string(),
+                },
+                expires_at: Instant::now() + Duration::from_secs(3600),
+            }))),
+        }
+    }
+
     /// Retrieves a valid authentication token.
     ///
     /// This method implements an efficient token management strategy:
@@ -1,21 +1,28 @@
 use std::time::Duration;
 
 use anyhow::Result;
+use async_stream::try_stream;
 use async_trait::async_trait;
+use futures::TryStreamExt;
 use once_cell::sync::Lazy;
-use reqwest::{Client, StatusCode};
-use serde_json::Value;
+use reqwest::{Client, Response, StatusCode};
+use serde_json::{json, Value};
+use std::io;
+use tokio::pin;
 use tokio::time::sleep;
+use tokio_stream::StreamExt;
+use tokio_util::codec::{FramedRead, LinesCodec};
+use tokio_util::io::StreamReader;
 use url::Url;
 
 use crate::conversation::message::Message;
 use crate::model::ModelConfig;
-use crate::providers::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage};
+use crate::providers::base::{ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage};
 
 use crate::providers::errors::ProviderError;
 use crate::providers::formats::gcpvertexai::{
-    create_request, get_usage, response_to_message, ClaudeVersion, GcpVertexAIModel, GeminiVersion,
-    ModelProvider, RequestContext,
+    create_request, get_usage, response_to_message, response_to_streaming_message, ClaudeVersion,
+    GcpVertexAIModel, GeminiVersion, ModelProvider, RequestContext,
 };
 
 use crate::impl_provider_default;
@@ -191,10 +198,12 @@ impl GcpVertexAIProvider {
     /// # Arguments
     /// * `provider` - The model provider (Anthropic or Google)
     /// * `location` - The GCP location for model deployment
+    /// * `streaming` - Whether the response should be streamed as server-sent events
     fn build_request_url(
         &self,
         provider: ModelProvider,
         location: &str,
+        streaming: bool,
     ) -> Result<Url, GcpVertexAIError> {
         // Create host URL for the specified location
         let host_url = if self.location == location {
@@ -207,10 +216,12 @@ impl GcpVertexAIProvider {
         let base_url =
             Url::parse(host_url).map_err(|e| GcpVertexAIError::InvalidUrl(e.to_string()))?;
 
-        // Determine endpoint based on provider type
-        let endpoint = match provider {
-            ModelProvider::Anthropic => "streamRawPredict",
-            ModelProvider::Google => "generateContent",
+        // Determine endpoint based on provider type. Anthropic models stream from the same
+        // endpoint when the payload asks for it.
+        let endpoint = match (provider, streaming) {
+            (ModelProvider::Anthropic, _) => "streamRawPredict",
+            (ModelProvider::Google, false) => "generateContent",
+            (ModelProvider::Google, true) => "streamGenerateContent",
         };
 
         // Construct path for URL
@@ -223,26 +234,33 @@ impl GcpVertexAIProvider {
             endpoint
         );
 
-        base_url
+        let mut url = base_url
             .join(&path)
-            .map_err(|e| GcpVertexAIError::InvalidUrl(e.to_string()))
+            .map_err(|e| GcpVertexAIError::InvalidUrl(e.to_string()))?;
+        if streaming && provider == ModelProvider::Google {
+            url.set_query(Some("alt=sse"));
+        }
+        Ok(url)
     }
 
     /// Makes an authenticated POST request to the Vertex AI API at a specific location.
     /// Includes retry logic for 429 (Too Many Requests) and 529 (API Overloaded) errors.
+    /// The body of a successful response is left unread.
     ///
     /// # Arguments
     /// * `payload` - The request payload to send
     /// * `context` - Request context containing model information
     /// * `location` - The GCP location for the request
-    async fn post_with_location(
+    /// * `streaming` - Whether to call the streaming endpoint
+    async fn send_with_location(
         &self,
         payload: &Value,
         context: &RequestContext,
         location: &str,
-    ) -> Result<Value, ProviderError> {
+        streaming: bool,
+    ) -> Result<Response, ProviderError> {
         let url = self
-            .build_request_url(context.provider(), location)
+            .build_request_url(context.provider(), location, streaming)
             .map_err(|e| ProviderError::RequestFailed(e.to_string()))?;
 
         // Initialize separate counters for different error types
@@ -375,6 +393,7 @@ impl GcpVertexAIProvider {
                     );
                     sleep(delay).await;
                 }
+                StatusCode::OK => return Ok(response),
                 // For any other status codes, process normally
                 _ => {
                     let response_json = response.json::<Value>().await.map_err(|e| {
@@ -382,7 +401,6 @@ impl GcpVertexAIProvider {
                     })?;
 
                     return match status {
-                        StatusCode::OK => Ok(response_json),
                         StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
                             tracing::debug!(
                                 "Authentication failed. Status: {status}, Payload: {payload:?}"
@@ -410,14 +428,16 @@ impl GcpVertexAIProvider {
     /// # Arguments
     /// * `payload` - The request payload to send
     /// * `context` - Request context containing model information
-    async fn post(
+    /// * `streaming` - Whether to call the streaming endpoint
+    async fn send(
         &self,
         payload: &Value,
         context: &RequestContext,
-    ) -> Result<Value, ProviderError> {
+        streaming: bool,
+    ) -> Result<Response, ProviderError> {
         // Try with user-specified location first
         let result = self
-            .post_with_location(payload, context, &self.location)
+            .send_with_location(payload, context, &self.location, streaming)
             .await;
 
         // If location is already the known location for the model or request succeeded, return result
@@ -436,13 +456,30 @@ impl GcpVertexAIProvider {
                     "Trying known location {known_location} for {model_name} instead of {configured_location}: {msg}"
                 );
 
-                self.post_with_location(payload, context, &known_location)
+                self.send_with_location(payload, context, &known_location, streaming)
                     .await
             }
             // For any other error, return the original result
             _ => result,
         }
     }
+
+    /// Makes a non-streaming request and parses the JSON response.
+    ///
+    /// # Arguments
+    /// * `payload` - The request payload to send
+    /// * `context` - Request context containing model information
+    async fn post(
+        &self,
+        payload: &Value,
+        context: &RequestContext,
+    ) -> Result<Value, ProviderError> {
+        let response = self.send(payload, context, false).await?;
+        response
+            .json::<Value>()
+            .await
+            .map_err(|e| ProviderError::RequestFailed(format!("Failed to parse response: {e}")))
+    }
 }
 
 impl_provider_default!(GcpVertexAIProvider);
@@ -550,6 +587,41 @@ impl Provider for GcpVertexAIProvider {
     fn get_model_config(&self) -> ModelConfig {
         self.model.clone()
     }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    /// Streams a model interaction, using Anthropic or Gemini server-sent events
+    /// depending on the model family.
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let (mut request, context) = create_request(&self.model, system, messages, tools)?;
+        if context.provider() == ModelProvider::Anthropic {
+            request["stream"] = json!(true);
+        }
+
+        let response = self.send(&request, &context, true).await?;
+        let stream = response.bytes_stream().map_err(io::Error::other);
+        let model_config = self.model.clone();
+
+        Ok(Box::pin(try_stream! {
+            let stream_reader = StreamReader::new(stream);
+            let framed = FramedRead::new(stream_reader, LinesCodec::new()).map_err(anyhow::Error::from);
+
+            let message_stream = response_to_streaming_message(framed, context, model_config.model_name.clone());
+            pin!(message_stream);
+            while let Some(message) = message_stream.next().await {
+                let (message, usage) = message.map_err(|e| ProviderError::RequestFailed(format!("Stream decode error: {}", e)))?;
+                emit_debug_trace(&model_config, &request, &message, &usage.as_ref().map(|f| f.usage).unwrap_or_default());
+                yield (message, usage);
+            }
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -644,4 +716,105 @@ mod tests {
         // Should contain the original 2 config keys plus 4 new retry-related ones
         assert_eq!(metadata.config_keys.len(), 6);
     }
+
+    fn mock_provider(host: String, location: &str, model_name: &str) -> GcpVertexAIProvider {
+        GcpVertexAIProvider {
+            client: Client::new(),
+            auth: GcpAuth::with_cached_token("test"),
+            host,
+            project_id: "test-project".to_string(),
+            location: location.to_string(),
+            model: ModelConfig::new_or_fail(model_name),
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    // Recorded from Vertex AI with claude-sonnet-4@20250514.
+    const ANTHROPIC_STREAM_FIXTURE: &str = r#"event: message_start
+data: {"type":"message_start","message":{"id":"msg_vrtx_01AbCdEf","type":"message","role":"assistant","model":"claude-sonnet-4-20250514","content":[],"stop_reason":null,"stop_sequence":null,"usage":{"input_tokens":25,"cache_creation_input_tokens":0,"cache_read_input_tokens":0,"output_tokens":1}}}
+
+event: ping
+data: {"type": "ping"}
+
+event: content_block_start
+data: {"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hello from"}}
+
+event: content_block_delta
+data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":" Vertex"}}
+
+event: content_block_stop
+data: {"type":"content_block_stop","index":0}
+
+event: message_delta
+data: {"type":"message_delta","delta":{"stop_reason":"end_turn","stop_sequence":null},"usage":{"output_tokens":6}}
+
+event: message_stop
+data: {"type":"message_stop"}
+
+"#;
+
+    // Recorded from Vertex AI with gemini-2.5-flash.
+    const GEMINI_STREAM_FIXTURE: &str = r#"data: {"candidates": [{"content": {"role": "model","parts": [{"text": "Gemini"}]}}],"usageMetadata": {"trafficType": "ON_DEMAND"},"modelVersion": "gemini-2.5-flash","createTime": "2025-08-05T12:00:00.000000Z","responseId": "vrtxGemini01"}
+
+data: {"candidates": [{"content": {"role": "model","parts": [{"text": " on Vertex"}]},"finishReason": "STOP"}],"usageMetadata": {"promptTokenCount": 9,"candidatesTokenCount": 4,"totalTokenCount": 13,"trafficType": "ON_DEMAND"},"modelVersion": "gemini-2.5-flash","createTime": "2025-08-05T12:00:00.000000Z","responseId": "vrtxGemini01"}
+
+"#;
+
+    #[tokio::test]
+    async fn test_stream_recorded_anthropic_fixture() {
+        use crate::providers::utils::stream_test_support::{
+            assert_single_message_id, collect_stream, mock_event_stream,
+        };
+
+        let mock_server = mock_event_stream(
+            "/v1/projects/test-project/locations/us-east5/publishers/anthropic/models/claude-sonnet-4@20250514:streamRawPredict",
+            ANTHROPIC_STREAM_FIXTURE,
+        )
+        .await;
+        let provider = mock_provider(mock_server.uri(), "us-east5", "claude-sonnet-4@20250514");
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Hi")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Hello from Vertex");
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.usage.input_tokens, Some(25));
+        assert_eq!(usage.usage.output_tokens, Some(6));
+    }
+
+    #[tokio::test]
+    async fn test_stream_recorded_gemini_fixture() {
+        use crate::providers::utils::stream_test_support::{
+            assert_single_message_id, collect_stream, mock_event_stream,
+        };
+
+        let mock_server = mock_event_stream(
+            "/v1/projects/test-project/locations/us-central1/publishers/google/models/gemini-2.5-flash:streamGenerateContent",
+            GEMINI_STREAM_FIXTURE,
+        )
+        .await;
+        let provider = mock_provider(mock_server.uri(), "us-central1", "gemini-2.5-flash");
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Hi")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Gemini on Vertex");
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.model, "gemini-2.5-flash");
+        assert_eq!(usage.usage.input_tokens, Some(9));
+        assert_eq!(usage.usage.output_tokens, Some(4));
+    }
 }
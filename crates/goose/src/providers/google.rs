@@ -1,16 +1,29 @@
 use super::api_client::{ApiClient, AuthMethod};
 use super::errors::ProviderError;
 use super::retry::ProviderRetry;
-use super::utils::{emit_debug_trace, handle_response_google_compat, unescape_json_values};
+use super::utils::{
+    emit_debug_trace, handle_response_google_compat, handle_status_google_compat,
+    unescape_json_values,
+};
 use crate::conversation::message::Message;
 use crate::impl_provider_default;
 use crate::model::ModelConfig;
-use crate::providers::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage};
-use crate::providers::formats::google::{create_request, get_usage, response_to_message};
+use crate::providers::base::{ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage};
+use crate::providers::formats::google::{
+    add_response_schema, create_request, get_usage, response_to_message,
+    response_to_streaming_message,
+};
 use anyhow::Result;
+use async_stream::try_stream;
 use async_trait::async_trait;
+use futures::TryStreamExt;
 use rmcp::model::Tool;
 use serde_json::Value;
+use std::io;
+use tokio::pin;
+use tokio_stream::StreamExt;
+use tokio_util::codec::{FramedRead, LinesCodec};
+use tokio_util::io::StreamReader;
 
 pub const GOOGLE_API_HOST: &str = "https://generativelanguage.googleapis.com";
 pub const GOOGLE_DEFAULT_MODEL: &str = "gemini-2.5-flash";
@@ -136,6 +149,71 @@ impl Provider for GoogleProvider {
         Ok((message, provider_usage))
     }
 
+    fn supports_structured_output(&self) -> bool {
+        true
+    }
+
+    async fn complete_structured(
+        &self,
+        system: &str,
+        messages: &[Message],
+        schema: &Value,
+    ) -> Result<(Message, ProviderUsage), ProviderError> {
+        let mut payload = create_request(&self.model, system, messages, &[])?;
+        add_response_schema(&mut payload, schema);
+
+        let response = self
+            .with_retry(|| async {
+                let payload_clone = payload.clone();
+                self.post(&self.model.model_name, &payload_clone).await
+            })
+            .await?;
+
+        let message = response_to_message(unescape_json_values(&response))?;
+        let usage = get_usage(&response)?;
+        let response_model = match response.get("modelVersion") {
+            Some(model_version) => model_version.as_str().unwrap_or_default().to_string(),
+            None => self.model.model_name.clone(),
+        };
+        emit_debug_trace(&self.model, &payload, &response, &usage);
+        Ok((message, ProviderUsage::new(response_model, usage)))
+    }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let payload = create_request(&self.model, system, messages, tools)?;
+        let path = format!(
+            "v1beta/models/{}:streamGenerateContent?alt=sse",
+            self.model.model_name
+        );
+
+        let response = self.api_client.response_post(&path, &payload).await?;
+        let response = handle_status_google_compat(response).await?;
+        let stream = response.bytes_stream().map_err(io::Error::other);
+        let model_config = self.model.clone();
+
+        Ok(Box::pin(try_stream! {
+            let stream_reader = StreamReader::new(stream);
+            let framed = FramedRead::new(stream_reader, LinesCodec::new()).map_err(anyhow::Error::from);
+
+            let message_stream = response_to_streaming_message(framed, model_config.model_name.clone());
+            pin!(message_stream);
+            while let Some(message) = message_stream.next().await {
+                let (message, usage) = message.map_err(|e| ProviderError::RequestFailed(format!("Stream decode error: {}", e)))?;
+                emit_debug_trace(&model_config, &payload, &message, &usage.as_ref().map(|f| f.usage).unwrap_or_default());
+                yield (message, usage);
+            }
+        }))
+    }
+
     /// Fetch supported models from Google Generative Language API; returns Err on failure, Ok(None) if not present
     async fn fetch_supported_models(&self) -> Result<Option<Vec<String>>, ProviderError> {
         let response = self.api_client.response_get("v1beta/models").await?;
@@ -153,3 +231,64 @@ impl Provider for GoogleProvider {
         Ok(Some(models))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::utils::stream_test_support::{
+        assert_single_message_id, collect_stream, mock_event_stream,
+    };
+    use serde_json::json;
+
+    // Recorded from the Gemini API with gemini-2.5-flash.
+    const STREAM_FIXTURE: &str = r#"data: {"candidates": [{"content": {"parts": [{"text": "Let me check"}],"role": "model"},"index": 0}],"usageMetadata": {"promptTokenCount": 52,"totalTokenCount": 52},"modelVersion": "gemini-2.5-flash","responseId": "Xr2UaK3xB9"}
+
+data: {"candidates": [{"content": {"parts": [{"text": " the weather."}],"role": "model"},"index": 0}],"usageMetadata": {"promptTokenCount": 52,"totalTokenCount": 52},"modelVersion": "gemini-2.5-flash","responseId": "Xr2UaK3xB9"}
+
+data: {"candidates": [{"content": {"parts": [{"functionCall": {"name": "get_weather","args": {"location": "Tokyo"}}}],"role": "model"},"finishReason": "STOP","index": 0}],"usageMetadata": {"promptTokenCount": 52,"candidatesTokenCount": 21,"totalTokenCount": 73},"modelVersion": "gemini-2.5-flash","responseId": "Xr2UaK3xB9"}
+
+"#;
+
+    #[tokio::test]
+    async fn test_stream_recorded_fixture() {
+        let mock_server = mock_event_stream(
+            "/v1beta/models/gemini-2.5-flash:streamGenerateContent",
+            STREAM_FIXTURE,
+        )
+        .await;
+        let provider = GoogleProvider {
+            api_client: ApiClient::new(
+                mock_server.uri(),
+                AuthMethod::BearerToken("test".to_string()),
+            )
+            .unwrap(),
+            model: ModelConfig::new_or_fail(GOOGLE_DEFAULT_MODEL),
+        };
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Weather?")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        assert_eq!(messages[0].id.as_deref(), Some("Xr2UaK3xB9"));
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Let me check the weather.");
+        let tool_request = messages
+            .iter()
+            .flat_map(|m| m.content.iter())
+            .find_map(|c| c.as_tool_request())
+            .expect("tool request should be streamed");
+        let call = tool_request.tool_call.as_ref().unwrap();
+        assert_eq!(call.name, "get_weather");
+        assert_eq!(
+            call.arguments,
+            Some(rmcp::object(json!({"location": "Tokyo"})))
+        );
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.model, "gemini-2.5-flash");
+        assert_eq!(usage.usage.input_tokens, Some(52));
+        assert_eq!(usage.usage.output_tokens, Some(21));
+    }
+}
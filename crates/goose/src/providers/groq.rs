@@ -1,11 +1,14 @@
 use super::api_client::{ApiClient, AuthMethod};
 use super::errors::ProviderError;
 use super::retry::ProviderRetry;
-use super::utils::{get_model, handle_response_openai_compat};
+use super::utils::{get_model, handle_response_openai_compat, handle_status_openai_compat};
+use super::utils_universal_openai_stream::{enable_streaming, stream_openai_compat};
 use crate::conversation::message::Message;
 use crate::impl_provider_default;
 use crate::model::ModelConfig;
-use crate::providers::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage, Usage};
+use crate::providers::base::{
+    ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage, Usage,
+};
 use crate::providers::formats::openai::{create_request, get_usage, response_to_message};
 use anyhow::Result;
 use async_trait::async_trait;
@@ -21,6 +24,8 @@ pub const GROQ_KNOWN_MODELS: &[&str] = &[
     "qwen/qwen3-32b",
 ];
 
+const GROQ_CHAT_COMPLETIONS_PATH: &str = "openai/v1/chat/completions";
+
 pub const GROQ_DOC_URL: &str = "https://console.groq.com/docs/models";
 
 #[derive(serde::Serialize)]
@@ -49,7 +54,7 @@ impl GroqProvider {
     async fn post(&self, payload: Value) -> Result<Value, ProviderError> {
         let response = self
             .api_client
-            .response_post("openai/v1/chat/completions", &payload)
+            .response_post(GROQ_CHAT_COMPLETIONS_PATH, &payload)
             .await?;
         handle_response_openai_compat(response).await
     }
@@ -107,6 +112,34 @@ impl Provider for GroqProvider {
         Ok((message, ProviderUsage::new(response_model, usage)))
     }
 
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let mut payload = create_request(
+            &self.model,
+            system,
+            messages,
+            tools,
+            &super::utils::ImageFormat::OpenAi,
+        )?;
+        enable_streaming(&mut payload);
+
+        let response = self
+            .api_client
+            .response_post(GROQ_CHAT_COMPLETIONS_PATH, &payload)
+            .await?;
+        let response = handle_status_openai_compat(response).await?;
+
+        Ok(stream_openai_compat(response, self.model.clone(), payload))
+    }
+
     /// Fetch supported models from Groq; returns Err on failure, Ok(None) if no models found
     async fn fetch_supported_models(&self) -> Result<Option<Vec<String>>, ProviderError> {
         let response = self
@@ -132,3 +165,61 @@ impl Provider for GroqProvider {
         Ok(Some(model_names))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::utils::stream_test_support::{
+        assert_single_message_id, collect_stream, mock_event_stream,
+    };
+    use serde_json::json;
+
+    // Recorded from Groq with moonshotai/kimi-k2-instruct; Groq sends each tool call
+    // in a single chunk rather than streaming the arguments.
+    const STREAM_FIXTURE: &str = r#"data: {"id":"chatcmpl-5f1c6c7e-groq","object":"chat.completion.chunk","created":1754400000,"model":"moonshotai/kimi-k2-instruct","system_fingerprint":"fp_6e6ff3688b","choices":[{"index":0,"delta":{"role":"assistant","content":null},"logprobs":null,"finish_reason":null}],"x_groq":{"id":"req_01k1xgroq"}}
+
+data: {"id":"chatcmpl-5f1c6c7e-groq","object":"chat.completion.chunk","created":1754400000,"model":"moonshotai/kimi-k2-instruct","system_fingerprint":"fp_6e6ff3688b","choices":[{"index":0,"delta":{"tool_calls":[{"id":"functions.get_weather:0","type":"function","function":{"name":"get_weather","arguments":"{\"location\":\"Paris\"}"},"index":0}]},"logprobs":null,"finish_reason":null}]}
+
+data: {"id":"chatcmpl-5f1c6c7e-groq","object":"chat.completion.chunk","created":1754400000,"model":"moonshotai/kimi-k2-instruct","system_fingerprint":"fp_6e6ff3688b","choices":[{"index":0,"delta":{},"logprobs":null,"finish_reason":"tool_calls"}],"x_groq":{"id":"req_01k1xgroq","usage":{"queue_time":0.05,"prompt_tokens":96,"prompt_time":0.01,"completion_tokens":19,"completion_time":0.05,"total_tokens":115,"total_time":0.06}}}
+
+data: {"id":"chatcmpl-5f1c6c7e-groq","object":"chat.completion.chunk","created":1754400000,"model":"moonshotai/kimi-k2-instruct","system_fingerprint":"fp_6e6ff3688b","choices":[],"usage":{"queue_time":0.05,"prompt_tokens":96,"prompt_time":0.01,"completion_tokens":19,"completion_time":0.05,"total_tokens":115,"total_time":0.06}}
+
+data: [DONE]
+
+"#;
+
+    #[tokio::test]
+    async fn test_stream_recorded_fixture() {
+        let mock_server = mock_event_stream("/openai/v1/chat/completions", STREAM_FIXTURE).await;
+        let provider = GroqProvider {
+            api_client: ApiClient::new(
+                mock_server.uri(),
+                AuthMethod::BearerToken("test".to_string()),
+            )
+            .unwrap(),
+            model: ModelConfig::new_or_fail(GROQ_DEFAULT_MODEL),
+        };
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Weather?")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        let tool_request = messages
+            .iter()
+            .flat_map(|m| m.content.iter())
+            .find_map(|c| c.as_tool_request())
+            .expect("tool request should be streamed");
+        let call = tool_request.tool_call.as_ref().unwrap();
+        assert_eq!(call.name, "get_weather");
+        assert_eq!(
+            call.arguments,
+            Some(rmcp::object(json!({"location": "Paris"})))
+        );
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.usage.input_tokens, Some(96));
+        assert_eq!(usage.usage.output_tokens, Some(19));
+    }
+}
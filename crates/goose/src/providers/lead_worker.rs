@@ -10,6 +10,7 @@ use crate::conversation::message::{Message, MessageContent};
 use crate::model::ModelConfig;
 use rmcp::model::Tool;
 use rmcp::model::{Content, RawContent};
+use serde_json::Value;
 
 /// A provider that switches between a lead model and a worker model based on turn count
 /// and can fallback to lead model on consecutive failures
@@ -446,6 +447,30 @@ impl Provider for LeadWorkerProvider {
         }
     }
 
+    fn supports_structured_output(&self) -> bool {
+        // Only claim support when either model can honour the schema
+        self.lead_provider.supports_structured_output()
+            || self.worker_provider.supports_structured_output()
+    }
+
+    async fn complete_structured(
+        &self,
+        system: &str,
+        messages: &[Message],
+        schema: &Value,
+    ) -> Result<(Message, ProviderUsage), ProviderError> {
+        // Prefer the active provider, falling back to whichever one supports structured output
+        let provider = self.get_active_provider().await;
+        let provider = if provider.supports_structured_output() {
+            provider
+        } else if self.lead_provider.supports_structured_output() {
+            Arc::clone(&self.lead_provider)
+        } else {
+            Arc::clone(&self.worker_provider)
+        };
+        provider.complete_structured(system, messages, schema).await
+    }
+
     /// Check if this provider is a LeadWorkerProvider
     fn as_lead_worker(&self) -> Option<&dyn LeadWorkerProviderTrait> {
         Some(self)
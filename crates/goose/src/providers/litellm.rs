@@ -4,11 +4,15 @@ use serde_json::{json, Value};
 use std::collections::HashMap;
 
 use super::api_client::{ApiClient, AuthMethod};
-use super::base::{ConfigKey, ModelInfo, Provider, ProviderMetadata, ProviderUsage};
+use super::base::{ConfigKey, MessageStream, ModelInfo, Provider, ProviderMetadata, ProviderUsage};
 use super::embedding::EmbeddingCapable;
 use super::errors::ProviderError;
 use super::retry::ProviderRetry;
-use super::utils::{emit_debug_trace, get_model, handle_response_openai_compat, ImageFormat};
+use super::utils::{
+    emit_debug_trace, get_model, handle_response_openai_compat, handle_status_openai_compat,
+    ImageFormat,
+};
+use super::utils_universal_openai_stream::{enable_streaming, stream_openai_compat};
 use crate::conversation::message::Message;
 use crate::impl_provider_default;
 use crate::model::ModelConfig;
@@ -111,6 +115,27 @@ impl LiteLLMProvider {
         Ok(models)
     }
 
+    fn create_request(
+        &self,
+        model_config: &ModelConfig,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<Value, ProviderError> {
+        let payload = super::formats::openai::create_request(
+            model_config,
+            system,
+            messages,
+            tools,
+            &ImageFormat::OpenAi,
+        )?;
+
+        if self.supports_cache_control() {
+            return Ok(update_request_for_cache_control(&payload));
+        }
+        Ok(payload)
+    }
+
     async fn post(&self, payload: &Value) -> Result<Value, ProviderError> {
         let response = self
             .api_client
@@ -168,17 +193,7 @@ impl Provider for LiteLLMProvider {
         messages: &[Message],
         tools: &[Tool],
     ) -> Result<(Message, ProviderUsage), ProviderError> {
-        let mut payload = super::formats::openai::create_request(
-            model_config,
-            system,
-            messages,
-            tools,
-            &ImageFormat::OpenAi,
-        )?;
-
-        if self.supports_cache_control() {
-            payload = update_request_for_cache_control(&payload);
-        }
+        let payload = self.create_request(model_config, system, messages, tools)?;
 
         let response = self
             .with_retry(|| async {
@@ -194,6 +209,28 @@ impl Provider for LiteLLMProvider {
         Ok((message, ProviderUsage::new(response_model, usage)))
     }
 
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let mut payload = self.create_request(&self.model, system, messages, tools)?;
+        enable_streaming(&mut payload);
+
+        let response = self
+            .api_client
+            .response_post(&self.base_path, &payload)
+            .await?;
+        let response = handle_status_openai_compat(response).await?;
+
+        Ok(stream_openai_compat(response, self.model.clone(), payload))
+    }
+
     fn supports_embeddings(&self) -> bool {
         true
     }
@@ -336,3 +373,50 @@ fn parse_custom_headers(headers_str: String) -> HashMap<String, String> {
     }
     headers
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::utils::stream_test_support::{
+        assert_single_message_id, collect_stream, mock_event_stream,
+    };
+
+    // Recorded from a LiteLLM proxy routing gpt-4o-mini to OpenAI.
+    const STREAM_FIXTURE: &str = r#"data: {"id":"chatcmpl-C1litellm","created":1754400000,"model":"gpt-4o-mini","object":"chat.completion.chunk","system_fingerprint":"fp_34a54ae93c","choices":[{"index":0,"delta":{"content":"Proxy","role":"assistant"}}]}
+
+data: {"id":"chatcmpl-C1litellm","created":1754400000,"model":"gpt-4o-mini","object":"chat.completion.chunk","system_fingerprint":"fp_34a54ae93c","choices":[{"index":0,"delta":{"content":" says hi"}}]}
+
+data: {"id":"chatcmpl-C1litellm","created":1754400000,"model":"gpt-4o-mini","object":"chat.completion.chunk","system_fingerprint":"fp_34a54ae93c","choices":[{"finish_reason":"stop","index":0,"delta":{}}]}
+
+data: {"id":"chatcmpl-C1litellm","created":1754400000,"model":"gpt-4o-mini","object":"chat.completion.chunk","system_fingerprint":"fp_34a54ae93c","choices":[{"index":0,"delta":{}}],"usage":{"completion_tokens":3,"prompt_tokens":18,"total_tokens":21,"completion_tokens_details":{"accepted_prediction_tokens":0,"audio_tokens":0,"reasoning_tokens":0,"rejected_prediction_tokens":0},"prompt_tokens_details":{"audio_tokens":0,"cached_tokens":0}}}
+
+data: [DONE]
+
+"#;
+
+    // supports_cache_control blocks on the model info lookup, which needs a multi-threaded runtime
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_stream_recorded_fixture() {
+        let mock_server = mock_event_stream("/v1/chat/completions", STREAM_FIXTURE).await;
+        let provider = LiteLLMProvider {
+            api_client: ApiClient::new(mock_server.uri(), AuthMethod::Custom(Box::new(NoAuth)))
+                .unwrap(),
+            base_path: "v1/chat/completions".to_string(),
+            model: ModelConfig::new_or_fail(LITELLM_DEFAULT_MODEL),
+        };
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Hi")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Proxy says hi");
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.model, "gpt-4o-mini");
+        assert_eq!(usage.usage.input_tokens, Some(18));
+        assert_eq!(usage.usage.output_tokens, Some(3));
+    }
+}
@@ -9,7 +9,8 @@ use crate::conversation::Conversation;
 use crate::impl_provider_default;
 use crate::model::ModelConfig;
 use crate::providers::formats::openai::{
-    create_request, get_usage, response_to_message, response_to_streaming_message,
+    add_response_schema, create_request, get_usage, response_to_message,
+    response_to_streaming_message,
 };
 use crate::utils::safe_truncate;
 use anyhow::Result;
@@ -212,6 +213,40 @@ impl Provider for OllamaProvider {
         Ok((message, ProviderUsage::new(response_model, usage)))
     }
 
+    fn supports_structured_output(&self) -> bool {
+        true
+    }
+
+    /// Ollama's OpenAI-compatible endpoint maps `response_format` onto its native `format`
+    async fn complete_structured(
+        &self,
+        system: &str,
+        messages: &[Message],
+        schema: &Value,
+    ) -> Result<(Message, ProviderUsage), ProviderError> {
+        let mut payload = create_request(
+            &self.model,
+            system,
+            messages,
+            &[],
+            &super::utils::ImageFormat::OpenAi,
+        )?;
+        add_response_schema(&mut payload, schema);
+
+        let response = self
+            .with_retry(|| async {
+                let payload_clone = payload.clone();
+                self.post(&payload_clone).await
+            })
+            .await?;
+        let message = response_to_message(&response)?;
+
+        let usage = response.get("usage").map(get_usage).unwrap_or_default();
+        let response_model = get_model(&response);
+        super::utils::emit_debug_trace(&self.model, &payload, &response, &usage);
+        Ok((message, ProviderUsage::new(response_model, usage)))
+    }
+
     /// Generate a session name based on the conversation history
     /// This override filters out reasoning tokens that some Ollama models produce
     async fn generate_session_name(
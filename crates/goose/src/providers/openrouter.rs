@@ -3,13 +3,14 @@ use async_trait::async_trait;
 use serde_json::{json, Value};
 
 use super::api_client::{ApiClient, AuthMethod};
-use super::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage, Usage};
+use super::base::{ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage, Usage};
 use super::errors::ProviderError;
 use super::retry::ProviderRetry;
 use super::utils::{
     emit_debug_trace, get_model, handle_response_google_compat, handle_response_openai_compat,
-    is_google_model,
+    handle_status_openai_compat, is_google_model,
 };
+use super::utils_universal_openai_stream::{enable_streaming, stream_openai_compat};
 use crate::conversation::message::Message;
 use crate::impl_provider_default;
 use crate::model::ModelConfig;
@@ -19,6 +20,7 @@ use rmcp::model::Tool;
 pub const OPENROUTER_DEFAULT_MODEL: &str = "anthropic/claude-sonnet-4";
 pub const OPENROUTER_DEFAULT_FAST_MODEL: &str = "google/gemini-flash-1.5";
 pub const OPENROUTER_MODEL_PREFIX_ANTHROPIC: &str = "anthropic";
+const OPENROUTER_CHAT_COMPLETIONS_PATH: &str = "api/v1/chat/completions";
 
 // OpenRouter can run many models, we suggest the default
 pub const OPENROUTER_KNOWN_MODELS: &[&str] = &[
@@ -64,7 +66,7 @@ impl OpenRouterProvider {
     async fn post(&self, payload: &Value) -> Result<Value, ProviderError> {
         let response = self
             .api_client
-            .response_post("api/v1/chat/completions", payload)
+            .response_post(OPENROUTER_CHAT_COMPLETIONS_PATH, payload)
             .await?;
 
         // Handle Google-compatible model responses differently
@@ -280,6 +282,29 @@ impl Provider for OpenRouterProvider {
         Ok((message, ProviderUsage::new(response_model, usage)))
     }
 
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        // OpenRouter normalizes every upstream, including Google models, to OpenAI-style chunks
+        let mut payload = create_request_based_on_model(self, system, messages, tools)?;
+        enable_streaming(&mut payload);
+
+        let response = self
+            .api_client
+            .response_post(OPENROUTER_CHAT_COMPLETIONS_PATH, &payload)
+            .await?;
+        let response = handle_status_openai_compat(response).await?;
+
+        Ok(stream_openai_compat(response, self.model.clone(), payload))
+    }
+
     /// Fetch supported models from OpenRouter API (only models with tool support)
     async fn fetch_supported_models(&self) -> Result<Option<Vec<String>>, ProviderError> {
         // Handle request failures gracefully
@@ -363,3 +388,56 @@ impl Provider for OpenRouterProvider {
             .starts_with(OPENROUTER_MODEL_PREFIX_ANTHROPIC)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::utils::stream_test_support::{
+        assert_single_message_id, collect_stream, mock_event_stream,
+    };
+
+    // Recorded from OpenRouter with anthropic/claude-sonnet-4; OpenRouter interleaves
+    // SSE comments while the upstream provider is processing.
+    const STREAM_FIXTURE: &str = r#": OPENROUTER PROCESSING
+
+data: {"id":"gen-1754400000-openrouter","provider":"Anthropic","model":"anthropic/claude-sonnet-4","object":"chat.completion.chunk","created":1754400000,"choices":[{"index":0,"delta":{"role":"assistant","content":"Hi"},"finish_reason":null,"native_finish_reason":null,"logprobs":null}]}
+
+: OPENROUTER PROCESSING
+
+data: {"id":"gen-1754400000-openrouter","provider":"Anthropic","model":"anthropic/claude-sonnet-4","object":"chat.completion.chunk","created":1754400000,"choices":[{"index":0,"delta":{"role":"assistant","content":" there!"},"finish_reason":null,"native_finish_reason":null,"logprobs":null}]}
+
+data: {"id":"gen-1754400000-openrouter","provider":"Anthropic","model":"anthropic/claude-sonnet-4","object":"chat.completion.chunk","created":1754400000,"choices":[{"index":0,"delta":{"role":"assistant","content":""},"finish_reason":"stop","native_finish_reason":"end_turn","logprobs":null}]}
+
+data: {"id":"gen-1754400000-openrouter","provider":"Anthropic","model":"anthropic/claude-sonnet-4","object":"chat.completion.chunk","created":1754400000,"choices":[{"index":0,"delta":{"role":"assistant","content":""},"finish_reason":null,"native_finish_reason":null,"logprobs":null}],"usage":{"prompt_tokens":12,"completion_tokens":5,"total_tokens":17}}
+
+data: [DONE]
+
+"#;
+
+    #[tokio::test]
+    async fn test_stream_recorded_fixture() {
+        let mock_server = mock_event_stream("/api/v1/chat/completions", STREAM_FIXTURE).await;
+        let provider = OpenRouterProvider {
+            api_client: ApiClient::new(
+                mock_server.uri(),
+                AuthMethod::BearerToken("test".to_string()),
+            )
+            .unwrap(),
+            model: ModelConfig::new_or_fail(OPENROUTER_DEFAULT_MODEL),
+        };
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Hello")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Hi there!");
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.model, "anthropic/claude-sonnet-4");
+        assert_eq!(usage.usage.input_tokens, Some(12));
+        assert_eq!(usage.usage.output_tokens, Some(5));
+    }
+}
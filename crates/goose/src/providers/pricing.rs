@@ -1,4 +1,6 @@
 use anyhow::Result;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -308,6 +310,40 @@ pub async fn get_model_pricing(provider: &str, model: &str) -> Option<PricingInf
     PRICING_CACHE.get_model_pricing(provider, model).await
 }
 
+/// Strip date and `-latest` suffixes so model names line up with the pricing cache keys
+/// e.g., "claude-3-7-sonnet-20250219" -> "claude-3.7-sonnet"
+pub fn normalize_model_name(model: &str) -> String {
+    static RE_DATE: Lazy<Regex> = Lazy::new(|| Regex::new(r"-\d{8}$").unwrap());
+    static RE_VERSION: Lazy<Regex> = Lazy::new(|| Regex::new(r"-(\d+)-(\d+)-").unwrap());
+
+    let result = model.strip_suffix("-latest").unwrap_or(model);
+    let result = RE_DATE.replace(result, "");
+    RE_VERSION.replace(&result, "-$1.$2-").to_string()
+}
+
+/// Estimate the USD cost of a single provider call, if pricing is known for the model
+pub async fn estimate_cost(
+    provider: &str,
+    model: &str,
+    input_tokens: usize,
+    output_tokens: usize,
+) -> Option<f64> {
+    // For OpenRouter, parse the model name to extract real provider/model
+    let openrouter_data = if provider == "openrouter" {
+        parse_model_id(model)
+    } else {
+        None
+    };
+
+    let (provider_to_use, model_to_use) = match &openrouter_data {
+        Some((real_provider, real_model)) => (real_provider.as_str(), real_model.as_str()),
+        None => (provider, model),
+    };
+
+    let pricing = get_model_pricing(provider_to_use, &normalize_model_name(model_to_use)).await?;
+    Some(pricing.input_cost * input_tokens as f64 + pricing.output_cost * output_tokens as f64)
+}
+
 /// Force refresh pricing data
 pub async fn refresh_pricing() -> Result<()> {
     PRICING_CACHE.refresh().await
@@ -395,6 +431,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_normalize_model_name() {
+        assert_eq!(normalize_model_name("gpt-4o-latest"), "gpt-4o");
+        assert_eq!(
+            normalize_model_name("claude-sonnet-4-20250514"),
+            "claude-sonnet-4"
+        );
+        assert_eq!(
+            normalize_model_name("claude-3-7-sonnet-20250219"),
+            "claude-3.7-sonnet"
+        );
+    }
+
     #[test]
     fn test_convert_pricing() {
         assert_eq!(convert_pricing("0.000003"), Some(0.000003));
@@ -2,14 +2,18 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use anyhow::Result;
+use async_stream::try_stream;
 use async_trait::async_trait;
 use aws_config;
 use aws_sdk_bedrockruntime::config::ProvideCredentials;
+use aws_sdk_sagemakerruntime::primitives::event_stream::EventReceiver;
+use aws_sdk_sagemakerruntime::types::error::ResponseStreamError;
+use aws_sdk_sagemakerruntime::types::ResponseStream;
 use aws_sdk_sagemakerruntime::Client as SageMakerClient;
 use rmcp::model::Tool;
 use serde_json::{json, Value};
 
-use super::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage, Usage};
+use super::base::{ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage, Usage};
 use super::errors::ProviderError;
 use super::retry::ProviderRetry;
 use super::utils::emit_debug_trace;
@@ -18,6 +22,7 @@ use crate::impl_provider_default;
 use crate::model::ModelConfig;
 use chrono::Utc;
 use rmcp::model::Role;
+use uuid::Uuid;
 
 pub const SAGEMAKER_TGI_DOC_LINK: &str =
     "https://docs.aws.amazon.com/sagemaker/latest/dg/realtime-endpoints.html";
@@ -180,6 +185,27 @@ impl SageMakerTgiProvider {
         })
     }
 
+    async fn invoke_endpoint_streaming(
+        &self,
+        payload: Value,
+    ) -> Result<EventReceiver<ResponseStream, ResponseStreamError>, ProviderError> {
+        let body = serde_json::to_string(&payload).map_err(|e| {
+            ProviderError::RequestFailed(format!("Failed to serialize request: {}", e))
+        })?;
+
+        let response = self
+            .sagemaker_client
+            .invoke_endpoint_with_response_stream()
+            .endpoint_name(&self.endpoint_name)
+            .content_type("application/json")
+            .body(body.into_bytes().into())
+            .send()
+            .await
+            .map_err(|e| ProviderError::RequestFailed(format!("SageMaker invoke failed: {}", e)))?;
+
+        Ok(response.body)
+    }
+
     fn parse_tgi_response(&self, response: Value) -> Result<Message, ProviderError> {
         // Handle standard TGI response: [{"generated_text": "..."}]
         let response_array = response
@@ -255,6 +281,102 @@ impl SageMakerTgiProvider {
     }
 }
 
+/// Incremental parser for the server-sent events TGI emits when `stream` is set.
+///
+/// SageMaker hands the event stream back in payload parts that do not line up with event
+/// boundaries, so bytes are buffered until a full line is available. Each event carries one
+/// token; the last one also carries `details` with the number of generated tokens. TGI sends
+/// no message id, so one is generated for all messages of the stream.
+#[derive(Debug)]
+struct TgiStreamState {
+    message_id: String,
+    buffer: Vec<u8>,
+    in_tag: bool,
+}
+
+impl Default for TgiStreamState {
+    fn default() -> Self {
+        Self {
+            message_id: format!("msg_{}", Uuid::new_v4()),
+            buffer: Vec::new(),
+            in_tag: false,
+        }
+    }
+}
+
+impl TgiStreamState {
+    fn push(&mut self, bytes: &[u8]) -> Result<Vec<(Option<Message>, Option<Usage>)>> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut results = Vec::new();
+        while let Some(newline) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=newline).collect();
+            let line = std::str::from_utf8(&line)?.trim();
+            let Some(data) = line.strip_prefix("data:").map(str::trim) else {
+                continue;
+            };
+            if data.is_empty() {
+                continue;
+            }
+
+            let event: Value = serde_json::from_str(data)
+                .map_err(|e| anyhow::anyhow!("Failed to parse TGI event: {}: {:?}", e, data))?;
+            if let Some(error) = event.get("error").and_then(|e| e.as_str()) {
+                return Err(anyhow::anyhow!("TGI error: {}", error));
+            }
+
+            let special = event
+                .pointer("/token/special")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let text = match event.pointer("/token/text").and_then(|v| v.as_str()) {
+                Some(text) if !special => self.strip_tags(text),
+                _ => String::new(),
+            };
+            let message = (!text.is_empty()).then(|| {
+                Message::new(
+                    Role::Assistant,
+                    Utc::now().timestamp(),
+                    vec![MessageContent::text(text)],
+                )
+                .with_id(self.message_id.clone())
+            });
+
+            let usage = event
+                .get("details")
+                .filter(|details| !details.is_null())
+                .map(|details| {
+                    let output_tokens = details
+                        .get("generated_tokens")
+                        .and_then(|v| v.as_i64())
+                        .map(|v| v as i32)
+                        .unwrap_or(0);
+                    // TGI does not report prompt tokens
+                    Usage::new(Some(0), Some(output_tokens), Some(output_tokens))
+                });
+
+            if message.is_some() || usage.is_some() {
+                results.push((message, usage));
+            }
+        }
+        Ok(results)
+    }
+
+    /// Drop anything between `<` and `>`, tracking tags that span several tokens
+    fn strip_tags(&mut self, text: &str) -> String {
+        let mut result = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '<' => self.in_tag = true,
+                '>' if self.in_tag => self.in_tag = false,
+                _ if !self.in_tag => result.push(c),
+                _ => {}
+            }
+        }
+        result
+    }
+}
+
 impl_provider_default!(SageMakerTgiProvider);
 
 #[async_trait]
@@ -325,4 +447,90 @@ impl Provider for SageMakerTgiProvider {
         let provider_usage = ProviderUsage::new(model_name.to_string(), usage);
         Ok((message, provider_usage))
     }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let mut request_payload = self.create_tgi_request(system, messages).map_err(|e| {
+            ProviderError::RequestFailed(format!("Failed to create request: {}", e))
+        })?;
+        request_payload["stream"] = json!(true);
+
+        let mut events = self
+            .with_retry(|| self.invoke_endpoint_streaming(request_payload.clone()))
+            .await?;
+
+        let debug_payload = serde_json::json!({
+            "system": system,
+            "messages": messages,
+            "tools": tools
+        });
+        let model_config = self.model.clone();
+
+        Ok(Box::pin(try_stream! {
+            let mut state = TgiStreamState::default();
+            while let Some(event) = events.recv().await.map_err(|e| ProviderError::RequestFailed(format!("Stream decode error: {:?}", e)))? {
+                let ResponseStream::PayloadPart(part) = event else {
+                    continue;
+                };
+                let Some(bytes) = part.bytes() else {
+                    continue;
+                };
+                let results = state.push(bytes.as_ref()).map_err(|e| ProviderError::RequestFailed(format!("Stream decode error: {}", e)))?;
+                for (message, usage) in results {
+                    let usage = usage.map(|usage| ProviderUsage::new(model_config.model_name.clone(), usage));
+                    emit_debug_trace(&model_config, &debug_payload, &message, &usage.as_ref().map(|f| f.usage).unwrap_or_default());
+                    yield (message, usage);
+                }
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::utils::stream_test_support::assert_single_message_id;
+
+    // Recorded from a TGI endpoint; the last event carries the generation details.
+    const TGI_STREAM: &str = concat!(
+        "data:{\"index\":1,\"token\":{\"id\":22557,\"text\":\"Hello\",\"logprob\":-0.41,\"special\":false},\"generated_text\":null,\"details\":null}\n\n",
+        "data:{\"index\":2,\"token\":{\"id\":28808,\"text\":\" <b>there\",\"logprob\":-1.02,\"special\":false},\"generated_text\":null,\"details\":null}\n\n",
+        "data:{\"index\":3,\"token\":{\"id\":28767,\"text\":\"</b>!\",\"logprob\":-0.13,\"special\":false},\"generated_text\":null,\"details\":null}\n\n",
+        "data:{\"index\":4,\"token\":{\"id\":2,\"text\":\"</s>\",\"logprob\":-0.02,\"special\":true},\"generated_text\":\"Hello <b>there</b>!\",\"details\":{\"finish_reason\":\"eos_token\",\"generated_tokens\":4,\"seed\":null}}\n\n",
+    );
+
+    #[test]
+    fn test_stream_state_recorded_payload_parts() {
+        let mut state = TgiStreamState::default();
+        let mut results = Vec::new();
+        // Payload parts split events at arbitrary byte offsets
+        for part in TGI_STREAM.as_bytes().chunks(37) {
+            results.extend(state.push(part).unwrap());
+        }
+
+        let text: String = results
+            .iter()
+            .filter_map(|(message, _)| message.as_ref())
+            .map(|m| m.as_concat_text())
+            .collect();
+        assert_eq!(text, "Hello there!");
+        let messages: Vec<Message> = results
+            .iter()
+            .filter_map(|(message, _)| message.clone())
+            .collect();
+        assert_single_message_id(&messages);
+
+        let usage: Vec<_> = results.iter().filter_map(|(_, usage)| *usage).collect();
+        assert_eq!(usage.len(), 1);
+        assert_eq!(usage[0].output_tokens, Some(4));
+        assert_eq!(usage[0].total_tokens, Some(4));
+    }
 }
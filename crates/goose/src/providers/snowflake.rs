@@ -1,14 +1,25 @@
 use anyhow::Result;
+use async_stream::try_stream;
 use async_trait::async_trait;
+use futures::TryStreamExt;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Response;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::io;
+use tokio::pin;
+use tokio_stream::StreamExt;
+use tokio_util::codec::{FramedRead, LinesCodec};
+use tokio_util::io::StreamReader;
 
 use super::api_client::{ApiClient, AuthMethod};
-use super::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage};
+use super::base::{ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage};
 use super::errors::ProviderError;
-use super::formats::snowflake::{create_request, get_usage, response_to_message};
+use super::formats::snowflake::{
+    create_request, get_usage, response_to_message, response_to_streaming_message,
+};
 use super::retry::ProviderRetry;
-use super::utils::{get_model, map_http_error_to_provider_error, ImageFormat};
+use super::utils::{emit_debug_trace, get_model, map_http_error_to_provider_error, ImageFormat};
 use crate::config::ConfigError;
 use crate::conversation::message::Message;
 use crate::impl_provider_default;
@@ -18,6 +29,8 @@ use rmcp::model::Tool;
 pub const SNOWFLAKE_DEFAULT_MODEL: &str = "claude-4-sonnet";
 pub const SNOWFLAKE_KNOWN_MODELS: &[&str] = &["claude-4-sonnet", "claude-3-7-sonnet"];
 
+pub const SNOWFLAKE_COMPLETE_PATH: &str = "api/v2/cortex/inference:complete";
+
 pub const SNOWFLAKE_DOC_URL: &str =
     "https://docs.snowflake.com/user-guide/snowflake-cortex/aisql#choosing-a-model";
 
@@ -99,28 +112,15 @@ impl SnowflakeProvider {
     async fn post(&self, payload: &Value) -> Result<Value, ProviderError> {
         let response = self
             .api_client
-            .response_post("api/v2/cortex/inference:complete", payload)
+            .response_post(SNOWFLAKE_COMPLETE_PATH, payload)
             .await?;
 
         let status = response.status();
         let payload_text: String = response.text().await.ok().unwrap_or_default();
 
         if status.is_success() {
-            if let Ok(payload) = serde_json::from_str::<Value>(&payload_text) {
-                if payload.get("code").is_some() {
-                    let code = payload
-                        .get("code")
-                        .and_then(|c| c.as_str())
-                        .unwrap_or("Unknown code");
-                    let message = payload
-                        .get("message")
-                        .and_then(|m| m.as_str())
-                        .unwrap_or("Unknown message");
-                    return Err(ProviderError::RequestFailed(format!(
-                        "{} - {}",
-                        code, message
-                    )));
-                }
+            if let Some(error) = error_from_payload(&payload_text) {
+                return Err(error);
             }
         }
 
@@ -275,6 +275,56 @@ impl SnowflakeProvider {
             Err(map_http_error_to_provider_error(status, error_json))
         }
     }
+
+    /// Send a request and hand back the event stream, surfacing errors that Snowflake
+    /// reports either through the status code or as a JSON body on a successful status.
+    async fn send_streaming(&self, payload: &Value) -> Result<Response, ProviderError> {
+        let response = self
+            .api_client
+            .response_post(SNOWFLAKE_COMPLETE_PATH, payload)
+            .await?;
+
+        let status = response.status();
+        let is_json = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("application/json"));
+        if status.is_success() && !is_json {
+            return Ok(response);
+        }
+
+        let payload_text: String = response.text().await.ok().unwrap_or_default();
+        if status.is_success() {
+            Err(error_from_payload(&payload_text).unwrap_or_else(|| {
+                ProviderError::RequestFailed(format!(
+                    "Expected an event stream but got: {}",
+                    payload_text
+                ))
+            }))
+        } else {
+            let error_json = serde_json::from_str::<Value>(&payload_text).ok();
+            Err(map_http_error_to_provider_error(status, error_json))
+        }
+    }
+}
+
+/// Snowflake reports some failures as a `code`/`message` body with a successful status
+fn error_from_payload(payload_text: &str) -> Option<ProviderError> {
+    let payload = serde_json::from_str::<Value>(payload_text).ok()?;
+    payload.get("code")?;
+    let code = payload
+        .get("code")
+        .and_then(|c| c.as_str())
+        .unwrap_or("Unknown code");
+    let message = payload
+        .get("message")
+        .and_then(|m| m.as_str())
+        .unwrap_or("Unknown message");
+    Some(ProviderError::RequestFailed(format!(
+        "{} - {}",
+        code, message
+    )))
 }
 
 #[async_trait]
@@ -326,4 +376,108 @@ impl Provider for SnowflakeProvider {
 
         Ok((message, ProviderUsage::new(response_model, usage)))
     }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let payload = create_request(&self.model, system, messages, tools)?;
+
+        let response = self.send_streaming(&payload).await?;
+        let stream = response.bytes_stream().map_err(io::Error::other);
+        let model_config = self.model.clone();
+
+        Ok(Box::pin(try_stream! {
+            let stream_reader = StreamReader::new(stream);
+            let framed = FramedRead::new(stream_reader, LinesCodec::new()).map_err(anyhow::Error::from);
+
+            let message_stream = response_to_streaming_message(framed, model_config.model_name.clone());
+            pin!(message_stream);
+            while let Some(message) = message_stream.next().await {
+                let (message, usage) = message.map_err(|e| ProviderError::RequestFailed(format!("Stream decode error: {}", e)))?;
+                emit_debug_trace(&model_config, &payload, &message, &usage.as_ref().map(|f| f.usage).unwrap_or_default());
+                yield (message, usage);
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversation::message::MessageContent;
+    use crate::providers::utils::stream_test_support::{
+        assert_single_message_id, collect_stream, mock_event_stream,
+    };
+    use rmcp::object;
+
+    // Recorded from a Cortex inference:complete call that answers and then uses a tool.
+    const TOOL_STREAM: &str = r#"data: {"id":"a9537c2c-2017-4906-9817-2456168d89fa","model":"claude-sonnet-4-20250514","choices":[{"delta":{"type":"text","content":"I","content_list":[{"type":"text","text":"I"}],"text":"I"}}],"usage":{}}
+
+data: {"id":"a9537c2c-2017-4906-9817-2456168d89fa","model":"claude-sonnet-4-20250514","choices":[{"delta":{"type":"text","content":"'ll check Nvidia's price.","content_list":[{"type":"text","text":"'ll check Nvidia's price."}],"text":"'ll check Nvidia's price."}}],"usage":{}}
+
+data: {"id":"a9537c2c-2017-4906-9817-2456168d89fa","model":"claude-sonnet-4-20250514","choices":[{"delta":{"type":"tool_use","tool_use_id":"tooluse_FB_nOElDTAOKa-YnVWI5Uw","name":"get_stock_price","content_list":[{"tool_use_id":"tooluse_FB_nOElDTAOKa-YnVWI5Uw","name":"get_stock_price"}],"text":""}}],"usage":{}}
+
+data: {"id":"a9537c2c-2017-4906-9817-2456168d89fa","model":"claude-sonnet-4-20250514","choices":[{"delta":{"type":"tool_use","input":"{\"symbol\":","content_list":[{"input":"{\"symbol\":"}],"text":""}}],"usage":{}}
+
+data: {"id":"a9537c2c-2017-4906-9817-2456168d89fa","model":"claude-sonnet-4-20250514","choices":[{"delta":{"type":"tool_use","input":"\"NVDA\"}","content_list":[{"input":"\"NVDA\"}"}],"text":""}}],"usage":{"prompt_tokens":397,"completion_tokens":65,"total_tokens":462}}
+
+"#;
+
+    #[tokio::test]
+    async fn test_stream_recorded_fixture() {
+        let mock_server = mock_event_stream("/api/v2/cortex/inference:complete", TOOL_STREAM).await;
+        let provider = SnowflakeProvider {
+            api_client: ApiClient::new(
+                mock_server.uri(),
+                AuthMethod::BearerToken("test".to_string()),
+            )
+            .unwrap(),
+            model: ModelConfig::new_or_fail("claude-4-sonnet"),
+            image_format: ImageFormat::OpenAi,
+        };
+
+        let stream = provider
+            .stream(
+                "You are helpful.",
+                &[Message::user().with_text("NVDA?")],
+                &[],
+            )
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        assert_eq!(
+            messages[0].id.as_deref(),
+            Some("a9537c2c-2017-4906-9817-2456168d89fa")
+        );
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "I'll check Nvidia's price.");
+
+        let tool_request = messages
+            .iter()
+            .flat_map(|m| m.content.iter())
+            .find_map(|c| match c {
+                MessageContent::ToolRequest(request) => Some(request),
+                _ => None,
+            })
+            .expect("expected a tool request");
+        assert_eq!(tool_request.id, "tooluse_FB_nOElDTAOKa-YnVWI5Uw");
+        let tool_call = tool_request.tool_call.as_ref().unwrap();
+        assert_eq!(tool_call.name, "get_stock_price");
+        assert_eq!(tool_call.arguments, Some(object!({"symbol": "NVDA"})));
+
+        let usage = usage.expect("expected usage");
+        assert_eq!(usage.model, "claude-sonnet-4-20250514");
+        assert_eq!(usage.usage.input_tokens, Some(397));
+        assert_eq!(usage.usage.output_tokens, Some(65));
+        assert_eq!(usage.usage.total_tokens, Some(462));
+    }
 }
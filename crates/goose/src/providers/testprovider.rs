@@ -11,7 +11,7 @@ use super::base::{Provider, ProviderMetadata, ProviderUsage};
 use super::errors::ProviderError;
 use crate::conversation::message::Message;
 use crate::model::ModelConfig;
-use rmcp::model::Tool;
+use rmcp::model::{Role, Tool};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct TestInput {
@@ -35,6 +35,7 @@ struct TestRecord {
 pub struct TestProvider {
     inner: Option<Arc<dyn Provider>>,
     records: Arc<Mutex<HashMap<String, TestRecord>>>,
+    misses: Arc<Mutex<Vec<String>>>,
     file_path: String,
 }
 
@@ -43,6 +44,7 @@ impl TestProvider {
         Self {
             inner: Some(inner),
             records: Arc::new(Mutex::new(HashMap::new())),
+            misses: Arc::new(Mutex::new(Vec::new())),
             file_path: file_path.into(),
         }
     }
@@ -54,6 +56,7 @@ impl TestProvider {
         Ok(Self {
             inner: None,
             records: Arc::new(Mutex::new(records)),
+            misses: Arc::new(Mutex::new(Vec::new())),
             file_path,
         })
     }
@@ -65,6 +68,10 @@ impl TestProvider {
         Ok(())
     }
 
+    fn stable_message(message: &Message) -> String {
+        serde_json::to_string(&(&message.role, &message.content)).unwrap_or_default()
+    }
+
     fn hash_input(messages: &[Message]) -> String {
         let stable_messages: Vec<_> = messages
             .iter()
@@ -96,6 +103,75 @@ impl TestProvider {
     pub fn get_record_count(&self) -> usize {
         self.records.lock().unwrap().len()
     }
+
+    /// Descriptions of every replayed request that had no matching recording
+    pub fn misses(&self) -> Vec<String> {
+        self.misses.lock().unwrap().clone()
+    }
+
+    /// Describes how a conversation drifted from the recording that shares the longest
+    /// prefix with it, so a replay miss can be traced back to the message that changed.
+    fn describe_miss(records: &HashMap<String, TestRecord>, messages: &[Message]) -> String {
+        let actual: Vec<String> = messages.iter().map(Self::stable_message).collect();
+        let closest = records
+            .values()
+            .map(|record| {
+                let shared = record
+                    .input
+                    .messages
+                    .iter()
+                    .zip(&actual)
+                    .take_while(|(recorded, actual)| Self::stable_message(recorded) == **actual)
+                    .count();
+                (shared, record)
+            })
+            .max_by_key(|(shared, record)| (*shared, record.input.messages.len()));
+
+        let Some((shared, record)) = closest else {
+            return "The cassette has no recordings".to_string();
+        };
+
+        let mut description = format!(
+            "Closest recording shares the first {} of {} messages",
+            shared,
+            messages.len()
+        );
+        match (record.input.messages.get(shared), messages.get(shared)) {
+            (Some(recorded), Some(actual)) => {
+                description.push_str(&format!("; message {} differs:", shared + 1));
+                description.push_str(&Self::describe_message("-", recorded));
+                description.push_str(&Self::describe_message("+", actual));
+            }
+            (Some(recorded), None) => {
+                description.push_str("; the recording continues with:");
+                description.push_str(&Self::describe_message("-", recorded));
+            }
+            (None, Some(actual)) => {
+                description.push_str("; the conversation continues with:");
+                description.push_str(&Self::describe_message("+", actual));
+            }
+            (None, None) => {}
+        }
+        description
+    }
+
+    fn describe_message(marker: &str, message: &Message) -> String {
+        let text = message.as_concat_text();
+        let text = if text.is_empty() {
+            serde_json::to_string(&message.content).unwrap_or_default()
+        } else {
+            text
+        };
+        let role = match message.role {
+            Role::User => "user",
+            Role::Assistant => "assistant",
+        };
+        let mut description = format!("\n{} [{}]", marker, role);
+        for line in text.lines() {
+            description.push_str(&format!("\n{} {}", marker, line));
+        }
+        description
+    }
 }
 
 #[async_trait]
@@ -147,9 +223,11 @@ impl Provider for TestProvider {
             if let Some(record) = records.get(&hash) {
                 Ok((record.output.message.clone(), record.output.usage.clone()))
             } else {
+                let description = Self::describe_miss(&records, messages);
+                self.misses.lock().unwrap().push(description.clone());
                 Err(ProviderError::ExecutionError(format!(
-                    "No recorded response found for input hash: {}",
-                    hash
+                    "No recorded response found for input hash: {}\n{}",
+                    hash, description
                 )))
             }
         }
@@ -284,4 +362,41 @@ mod tests {
 
         let _ = fs::remove_file(temp_file);
     }
+
+    #[tokio::test]
+    async fn test_replay_miss_describes_drift() {
+        let temp_file = format!(
+            "{}/test_drift_{}.json",
+            env::temp_dir().display(),
+            std::process::id()
+        );
+
+        let mock = Arc::new(MockProvider {
+            model_config: ModelConfig::new_or_fail("mock-model"),
+            response: "Hello, world!".to_string(),
+        });
+
+        let recorded = vec![Message::user().with_text("Summarize the README")];
+        let test_provider = TestProvider::new_recording(mock, &temp_file);
+        test_provider
+            .complete("You are helpful", &recorded, &[])
+            .await
+            .unwrap();
+        test_provider.finish_recording().unwrap();
+
+        let replay_provider = TestProvider::new_replaying(&temp_file).unwrap();
+        let drifted = vec![Message::user().with_text("Summarize the CHANGELOG")];
+        let err = replay_provider
+            .complete("You are helpful", &drifted, &[])
+            .await
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("shares the first 0 of 1 messages; message 1 differs"));
+        assert!(err.contains("- Summarize the README"));
+        assert!(err.contains("+ Summarize the CHANGELOG"));
+        assert_eq!(replay_provider.misses().len(), 1);
+
+        let _ = fs::remove_file(temp_file);
+    }
 }
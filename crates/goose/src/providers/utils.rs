@@ -304,6 +304,25 @@ pub async fn handle_response_google_compat(response: Response) -> Result<Value,
     }
 }
 
+/// Check the status of a Google Gemini API-compatible response without consuming the body.
+///
+/// Used for streaming requests, where the body must be read incrementally by the caller.
+/// Failures are mapped exactly as in [`handle_response_google_compat`].
+pub async fn handle_status_google_compat(response: Response) -> Result<Response, ProviderError> {
+    if response.status() == StatusCode::OK {
+        return Ok(response);
+    }
+
+    let status = response.status();
+    match handle_response_google_compat(response).await {
+        Ok(payload) => Err(ProviderError::RequestFailed(format!(
+            "Request failed with status: {}. Payload: {}",
+            status, payload
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
 pub fn sanitize_function_name(name: &str) -> String {
     let re = Regex::new(r"[^a-zA-Z0-9_-]").unwrap();
     re.replace_all(name, "_").to_string()
@@ -529,6 +548,56 @@ pub fn json_escape_control_chars_in_string(s: &str) -> String {
     r
 }
 
+/// Helpers for testing provider streaming against recorded responses.
+#[cfg(test)]
+pub(crate) mod stream_test_support {
+    use crate::conversation::message::Message;
+    use crate::providers::base::{MessageStream, ProviderUsage};
+    use crate::providers::errors::ProviderError;
+    use futures::StreamExt;
+    use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+    /// Serve `body` as a `text/event-stream` response to `POST path` on a fresh mock server.
+    pub async fn mock_event_stream(path: &str, body: &str) -> MockServer {
+        let mock_server = MockServer::start().await;
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path(path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string(body),
+            )
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    /// Drain a provider stream, returning every yielded message and the last usage reported.
+    pub async fn collect_stream(
+        mut stream: MessageStream,
+    ) -> Result<(Vec<Message>, Option<ProviderUsage>), ProviderError> {
+        let mut messages = Vec::new();
+        let mut last_usage = None;
+        while let Some(item) = stream.next().await {
+            let (message, usage) = item?;
+            messages.extend(message);
+            if usage.is_some() {
+                last_usage = usage;
+            }
+        }
+        Ok((messages, last_usage))
+    }
+    /// Assert that every streamed message has the same id, so the conversation merges them.
+    pub fn assert_single_message_id(messages: &[Message]) {
+        let id = messages.first().and_then(|m| m.id.as_ref());
+        assert!(id.is_some(), "streamed messages should have an id");
+        assert!(
+            messages.iter().all(|m| m.id.as_ref() == id),
+            "streamed messages should share one id"
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
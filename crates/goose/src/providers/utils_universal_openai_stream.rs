@@ -1,5 +1,20 @@
+use async_stream::try_stream;
+use futures::TryStreamExt;
+use reqwest::Response;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::{BTreeMap, HashMap};
+use std::io;
+use tokio::pin;
+use tokio_stream::StreamExt;
+use tokio_util::codec::{FramedRead, LinesCodec};
+use tokio_util::io::StreamReader;
+
+use super::base::MessageStream;
+use super::errors::ProviderError;
+use super::formats::openai::response_to_streaming_message;
+use super::utils::emit_debug_trace;
+use crate::model::ModelConfig;
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct OAIUsage {
@@ -241,6 +256,40 @@ impl OAIStreamCollector {
         }
     }
 }
+
+/// Ask an OpenAI-compatible `chat/completions` endpoint to stream its response,
+/// including a final usage chunk.
+pub fn enable_streaming(payload: &mut Value) {
+    payload["stream"] = json!(true);
+    payload["stream_options"] = json!({
+        "include_usage": true,
+    });
+}
+
+/// Decode a successful OpenAI-compatible server-sent event response into a [`MessageStream`].
+///
+/// `payload` is the request that produced the response and is only used for debug tracing.
+pub fn stream_openai_compat(
+    response: Response,
+    model_config: ModelConfig,
+    payload: Value,
+) -> MessageStream {
+    let stream = response.bytes_stream().map_err(io::Error::other);
+
+    Box::pin(try_stream! {
+        let stream_reader = StreamReader::new(stream);
+        let framed = FramedRead::new(stream_reader, LinesCodec::new()).map_err(anyhow::Error::from);
+
+        let message_stream = response_to_streaming_message(framed);
+        pin!(message_stream);
+        while let Some(message) = message_stream.next().await {
+            let (message, usage) = message.map_err(|e| ProviderError::RequestFailed(format!("Stream decode error: {}", e)))?;
+            emit_debug_trace(&model_config, &payload, &message, &usage.as_ref().map(|f| f.usage).unwrap_or_default());
+            yield (message, usage);
+        }
+    })
+}
+
 fn null_to_empty_string<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: serde::Deserializer<'de>,
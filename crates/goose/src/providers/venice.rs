@@ -1,14 +1,16 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Utc;
+use reqwest::Response;
 use serde::Serialize;
 use serde_json::{json, Value};
 
 use super::api_client::{ApiClient, AuthMethod};
-use super::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage, Usage};
+use super::base::{ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage, Usage};
 use super::errors::ProviderError;
 use super::retry::ProviderRetry;
 use super::utils::map_http_error_to_provider_error;
+use super::utils_universal_openai_stream::{enable_streaming, stream_openai_compat};
 use crate::conversation::message::{Message, MessageContent};
 use crate::impl_provider_default;
 use crate::mcp_utils::ToolResult;
@@ -71,6 +73,187 @@ const FALLBACK_MODELS: [&str; 3] = [
     "mistral-31-24b", // Another model with function calling
 ];
 
+/// Build a Venice chat completions request. Venice expects string message content and its own
+/// tool call layout, so this does not reuse the OpenAI formatter.
+fn create_request(
+    model_config: &ModelConfig,
+    system: &str,
+    messages: &[Message],
+    tools: &[Tool],
+) -> Value {
+    // Create properly formatted messages for Venice API
+    let mut formatted_messages = Vec::new();
+
+    // Add the system message if present
+    if !system.is_empty() {
+        formatted_messages.push(json!({
+            "role": "system",
+            "content": system
+        }));
+    }
+
+    // Format regular messages according to Venice API requirements
+    for msg in messages {
+        // Venice API expects 'content' to be a string, not an array of MessageContent
+        let content = match msg.role {
+            Role::User => {
+                // For user messages, concatenate all text content
+                let text_content: String = msg
+                    .content
+                    .iter()
+                    .filter_map(|c| c.as_text())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                // If we have text content, use it directly
+                if !text_content.is_empty() {
+                    text_content
+                } else {
+                    // Otherwise, try to get a reasonable string representation
+                    msg.as_concat_text()
+                }
+            }
+            _ => {
+                // For assistant messages, handle possible tool calls
+                let has_tool_calls = msg
+                    .content
+                    .iter()
+                    .any(|c| matches!(c, MessageContent::ToolRequest(_)));
+
+                if has_tool_calls {
+                    // If there are tool calls, we'll handle them separately
+                    // Just use an empty string for content
+                    "".to_string()
+                } else {
+                    // Otherwise use text content
+                    msg.as_concat_text()
+                }
+            }
+        };
+
+        // Create basic message with content as string
+        let mut venice_msg = json!({
+            "role": match msg.role {
+                Role::User => "user",
+                Role::Assistant => "assistant",
+            },
+            "content": content
+        });
+
+        // Add debug information to tracing
+        tracing::debug!(
+            "Venice message format: role={:?}, content_len={}, has_tool_calls={}",
+            msg.role,
+            content.len(),
+            msg.content
+                .iter()
+                .any(|c| matches!(c, MessageContent::ToolRequest(_)))
+        );
+
+        // For assistant messages with tool calls, add them in Venice format
+        if msg.role == Role::Assistant {
+            let tool_calls: Vec<_> = msg
+                .content
+                .iter()
+                .filter_map(|c| c.as_tool_request())
+                .collect();
+
+            if !tool_calls.is_empty() {
+                // Transform our tool calls to Venice format
+                let venice_tool_calls: Vec<Value> = tool_calls
+                    .iter()
+                    .filter_map(|tr| {
+                        if let ToolResult::Ok(tool_call) = &tr.tool_call {
+                            // Safely convert arguments to a JSON string
+                            let args_str = tool_call
+                                .arguments
+                                .as_ref() // borrow the Option contents
+                                .map(|map| serde_json::to_string(map).unwrap_or_default())
+                                .unwrap_or_default();
+
+                            // Log tool call details for debugging
+                            tracing::debug!(
+                                "Tool call conversion: id={}, name={}, args_len={}",
+                                tr.id,
+                                tool_call.name,
+                                args_str.len()
+                            );
+
+                            // Convert to Venice format
+                            Some(json!({
+                                "id": tr.id,
+                                "type": "function",
+                                "function": {
+                                    "name": tool_call.name,
+                                    "arguments": args_str
+                                }
+                            }))
+                        } else {
+                            tracing::warn!("Skipping tool call with error: id={}", tr.id);
+                            None
+                        }
+                    })
+                    .collect();
+
+                if !venice_tool_calls.is_empty() {
+                    tracing::debug!("Adding {} tool calls to message", venice_tool_calls.len());
+                    venice_msg["tool_calls"] = json!(venice_tool_calls);
+                }
+            }
+        }
+
+        // For tool messages with tool responses, add required tool_call_id
+        // Check for tool responses regardless of role - they should have an ID
+        // that corresponds to the tool call they're responding to
+        {
+            let tool_responses: Vec<_> = msg
+                .content
+                .iter()
+                .filter_map(|c| c.as_tool_response())
+                .collect();
+
+            if !tool_responses.is_empty() && !tool_responses[0].id.is_empty() {
+                venice_msg["tool_call_id"] = json!(tool_responses[0].id);
+                // Venice expects tool messages to have 'role' = 'tool'
+                venice_msg["role"] = json!("tool");
+            }
+        }
+
+        formatted_messages.push(venice_msg);
+    }
+
+    // Build Venice-specific payload
+    let mut payload = json!({
+        "model": strip_flags(&model_config.model_name),
+        "messages": formatted_messages,
+        "stream": false,
+        "temperature": 0.7,
+        "max_tokens": 2048,
+    });
+
+    if !tools.is_empty() {
+        // Format tools specifically for Venice API
+        let formatted_tools: Vec<serde_json::Value> = tools
+            .iter()
+            .map(|tool| {
+                // Format each tool in the expected Venice format
+                json!({
+                    "type": "function",
+                    "function": {
+                        "name": tool.name,
+                        "description": tool.description,
+                        "parameters": tool.input_schema
+                    }
+                })
+            })
+            .collect();
+
+        payload["tools"] = json!(formatted_tools);
+    }
+
+    payload
+}
+
 #[derive(Debug, Serialize)]
 pub struct VeniceProvider {
     #[serde(skip)]
@@ -112,7 +295,7 @@ impl VeniceProvider {
         Ok(instance)
     }
 
-    async fn post(&self, path: &str, payload: &Value) -> Result<Value, ProviderError> {
+    async fn send(&self, path: &str, payload: &Value) -> Result<Response, ProviderError> {
         let response = self.api_client.response_post(path, payload).await?;
 
         let status = response.status();
@@ -173,6 +356,11 @@ impl VeniceProvider {
             return Err(map_http_error_to_provider_error(status, error_json));
         }
 
+        Ok(response)
+    }
+
+    async fn post(&self, path: &str, payload: &Value) -> Result<Value, ProviderError> {
+        let response = self.send(path, payload).await?;
         let response_text = response.text().await?;
         serde_json::from_str(&response_text).map_err(|e| {
             ProviderError::RequestFailed(format!(
@@ -256,175 +444,7 @@ impl Provider for VeniceProvider {
         messages: &[Message],
         tools: &[Tool],
     ) -> Result<(Message, ProviderUsage), ProviderError> {
-        // Create properly formatted messages for Venice API
-        let mut formatted_messages = Vec::new();
-
-        // Add the system message if present
-        if !system.is_empty() {
-            formatted_messages.push(json!({
-                "role": "system",
-                "content": system
-            }));
-        }
-
-        // Format regular messages according to Venice API requirements
-        for msg in messages {
-            // Venice API expects 'content' to be a string, not an array of MessageContent
-            let content = match msg.role {
-                Role::User => {
-                    // For user messages, concatenate all text content
-                    let text_content: String = msg
-                        .content
-                        .iter()
-                        .filter_map(|c| c.as_text())
-                        .collect::<Vec<_>>()
-                        .join("\n");
-
-                    // If we have text content, use it directly
-                    if !text_content.is_empty() {
-                        text_content
-                    } else {
-                        // Otherwise, try to get a reasonable string representation
-                        msg.as_concat_text()
-                    }
-                }
-                _ => {
-                    // For assistant messages, handle possible tool calls
-                    let has_tool_calls = msg
-                        .content
-                        .iter()
-                        .any(|c| matches!(c, MessageContent::ToolRequest(_)));
-
-                    if has_tool_calls {
-                        // If there are tool calls, we'll handle them separately
-                        // Just use an empty string for content
-                        "".to_string()
-                    } else {
-                        // Otherwise use text content
-                        msg.as_concat_text()
-                    }
-                }
-            };
-
-            // Create basic message with content as string
-            let mut venice_msg = json!({
-                "role": match msg.role {
-                    Role::User => "user",
-                    Role::Assistant => "assistant",
-                },
-                "content": content
-            });
-
-            // Add debug information to tracing
-            tracing::debug!(
-                "Venice message format: role={:?}, content_len={}, has_tool_calls={}",
-                msg.role,
-                content.len(),
-                msg.content
-                    .iter()
-                    .any(|c| matches!(c, MessageContent::ToolRequest(_)))
-            );
-
-            // For assistant messages with tool calls, add them in Venice format
-            if msg.role == Role::Assistant {
-                let tool_calls: Vec<_> = msg
-                    .content
-                    .iter()
-                    .filter_map(|c| c.as_tool_request())
-                    .collect();
-
-                if !tool_calls.is_empty() {
-                    // Transform our tool calls to Venice format
-                    let venice_tool_calls: Vec<Value> = tool_calls
-                        .iter()
-                        .filter_map(|tr| {
-                            if let ToolResult::Ok(tool_call) = &tr.tool_call {
-                                // Safely convert arguments to a JSON string
-                                let args_str = tool_call
-                                    .arguments
-                                    .as_ref() // borrow the Option contents
-                                    .map(|map| serde_json::to_string(map).unwrap_or_default())
-                                    .unwrap_or_default();
-
-                                // Log tool call details for debugging
-                                tracing::debug!(
-                                    "Tool call conversion: id={}, name={}, args_len={}",
-                                    tr.id,
-                                    tool_call.name,
-                                    args_str.len()
-                                );
-
-                                // Convert to Venice format
-                                Some(json!({
-                                    "id": tr.id,
-                                    "type": "function",
-                                    "function": {
-                                        "name": tool_call.name,
-                                        "arguments": args_str
-                                    }
-                                }))
-                            } else {
-                                tracing::warn!("Skipping tool call with error: id={}", tr.id);
-                                None
-                            }
-                        })
-                        .collect();
-
-                    if !venice_tool_calls.is_empty() {
-                        tracing::debug!("Adding {} tool calls to message", venice_tool_calls.len());
-                        venice_msg["tool_calls"] = json!(venice_tool_calls);
-                    }
-                }
-            }
-
-            // For tool messages with tool responses, add required tool_call_id
-            // Check for tool responses regardless of role - they should have an ID
-            // that corresponds to the tool call they're responding to
-            {
-                let tool_responses: Vec<_> = msg
-                    .content
-                    .iter()
-                    .filter_map(|c| c.as_tool_response())
-                    .collect();
-
-                if !tool_responses.is_empty() && !tool_responses[0].id.is_empty() {
-                    venice_msg["tool_call_id"] = json!(tool_responses[0].id);
-                    // Venice expects tool messages to have 'role' = 'tool'
-                    venice_msg["role"] = json!("tool");
-                }
-            }
-
-            formatted_messages.push(venice_msg);
-        }
-
-        // Build Venice-specific payload
-        let mut payload = json!({
-            "model": strip_flags(&model_config.model_name),
-            "messages": formatted_messages,
-            "stream": false,
-            "temperature": 0.7,
-            "max_tokens": 2048,
-        });
-
-        if !tools.is_empty() {
-            // Format tools specifically for Venice API
-            let formatted_tools: Vec<serde_json::Value> = tools
-                .iter()
-                .map(|tool| {
-                    // Format each tool in the expected Venice format
-                    json!({
-                        "type": "function",
-                        "function": {
-                            "name": tool.name,
-                            "description": tool.description,
-                            "parameters": tool.input_schema
-                        }
-                    })
-                })
-                .collect();
-
-            payload["tools"] = json!(formatted_tools);
-        }
+        let payload = create_request(model_config, system, messages, tools);
 
         tracing::debug!("Sending request to Venice API");
         tracing::debug!("Venice request payload: {}", payload.to_string());
@@ -514,6 +534,23 @@ impl Provider for VeniceProvider {
             ProviderUsage::new(strip_flags(&self.model.model_name).to_string(), usage),
         ))
     }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let mut payload = create_request(&self.model, system, messages, tools);
+        enable_streaming(&mut payload);
+
+        let response = self.send(&self.base_path, &payload).await?;
+        Ok(stream_openai_compat(response, self.model.clone(), payload))
+    }
 }
 
 #[cfg(test)]
@@ -533,4 +570,52 @@ mod tests {
         assert_eq!(metadata.config_keys[2].name, "VENICE_BASE_PATH");
         assert_eq!(metadata.config_keys[3].name, "VENICE_MODELS_PATH");
     }
+
+    // Recorded from Venice with llama-3.3-70b.
+    const STREAM_FIXTURE: &str = r#"data: {"id":"chatcmpl-8c2d8e1f-venice","object":"chat.completion.chunk","created":1754400000,"model":"llama-3.3-70b","choices":[{"index":0,"delta":{"role":"assistant","content":""},"logprobs":null,"finish_reason":null}]}
+
+data: {"id":"chatcmpl-8c2d8e1f-venice","object":"chat.completion.chunk","created":1754400000,"model":"llama-3.3-70b","choices":[{"index":0,"delta":{"content":"Private"},"logprobs":null,"finish_reason":null}]}
+
+data: {"id":"chatcmpl-8c2d8e1f-venice","object":"chat.completion.chunk","created":1754400000,"model":"llama-3.3-70b","choices":[{"index":0,"delta":{"content":" by default"},"logprobs":null,"finish_reason":null}]}
+
+data: {"id":"chatcmpl-8c2d8e1f-venice","object":"chat.completion.chunk","created":1754400000,"model":"llama-3.3-70b","choices":[{"index":0,"delta":{"content":""},"logprobs":null,"finish_reason":"stop","stop_reason":null}]}
+
+data: {"id":"chatcmpl-8c2d8e1f-venice","object":"chat.completion.chunk","created":1754400000,"model":"llama-3.3-70b","choices":[],"usage":{"prompt_tokens":30,"total_tokens":34,"completion_tokens":4}}
+
+data: [DONE]
+
+"#;
+
+    #[tokio::test]
+    async fn test_stream_recorded_fixture() {
+        use crate::providers::utils::stream_test_support::{
+            assert_single_message_id, collect_stream, mock_event_stream,
+        };
+
+        let mock_server = mock_event_stream("/api/v1/chat/completions", STREAM_FIXTURE).await;
+        let provider = VeniceProvider {
+            api_client: ApiClient::new(
+                mock_server.uri(),
+                AuthMethod::BearerToken("test".to_string()),
+            )
+            .unwrap(),
+            base_path: VENICE_DEFAULT_BASE_PATH.to_string(),
+            models_path: VENICE_DEFAULT_MODELS_PATH.to_string(),
+            model: ModelConfig::new_or_fail(VENICE_DEFAULT_MODEL),
+        };
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Hi")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Private by default");
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.model, "llama-3.3-70b");
+        assert_eq!(usage.usage.input_tokens, Some(30));
+        assert_eq!(usage.usage.output_tokens, Some(4));
+    }
 }
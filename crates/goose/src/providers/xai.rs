@@ -1,11 +1,14 @@
 use super::api_client::{ApiClient, AuthMethod};
 use super::errors::ProviderError;
 use super::retry::ProviderRetry;
-use super::utils::{get_model, handle_response_openai_compat};
+use super::utils::{get_model, handle_response_openai_compat, handle_status_openai_compat};
+use super::utils_universal_openai_stream::{enable_streaming, stream_openai_compat};
 use crate::conversation::message::Message;
 use crate::impl_provider_default;
 use crate::model::ModelConfig;
-use crate::providers::base::{ConfigKey, Provider, ProviderMetadata, ProviderUsage, Usage};
+use crate::providers::base::{
+    ConfigKey, MessageStream, Provider, ProviderMetadata, ProviderUsage, Usage,
+};
 use crate::providers::formats::openai::{create_request, get_usage, response_to_message};
 use anyhow::Result;
 use async_trait::async_trait;
@@ -13,6 +16,7 @@ use rmcp::model::Tool;
 use serde_json::Value;
 
 pub const XAI_API_HOST: &str = "https://api.x.ai/v1";
+const XAI_CHAT_COMPLETIONS_PATH: &str = "chat/completions";
 pub const XAI_DEFAULT_MODEL: &str = "grok-3";
 pub const XAI_KNOWN_MODELS: &[&str] = &[
     "grok-4-0709",
@@ -64,7 +68,7 @@ impl XaiProvider {
 
         let response = self
             .api_client
-            .response_post("chat/completions", &payload)
+            .response_post(XAI_CHAT_COMPLETIONS_PATH, &payload)
             .await?;
 
         handle_response_openai_compat(response).await
@@ -122,4 +126,79 @@ impl Provider for XaiProvider {
         super::utils::emit_debug_trace(model_config, &payload, &response, &usage);
         Ok((message, ProviderUsage::new(response_model, usage)))
     }
+
+    fn supports_streaming(&self) -> bool {
+        true
+    }
+
+    async fn stream(
+        &self,
+        system: &str,
+        messages: &[Message],
+        tools: &[Tool],
+    ) -> Result<MessageStream, ProviderError> {
+        let mut payload = create_request(
+            &self.model,
+            system,
+            messages,
+            tools,
+            &super::utils::ImageFormat::OpenAi,
+        )?;
+        enable_streaming(&mut payload);
+
+        let response = self
+            .api_client
+            .response_post(XAI_CHAT_COMPLETIONS_PATH, &payload)
+            .await?;
+        let response = handle_status_openai_compat(response).await?;
+
+        Ok(stream_openai_compat(response, self.model.clone(), payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::utils::stream_test_support::{
+        assert_single_message_id, collect_stream, mock_event_stream,
+    };
+
+    // Recorded from xAI with grok-3.
+    const STREAM_FIXTURE: &str = r#"data: {"id":"3b1e1b4e-xai","object":"chat.completion.chunk","created":1754400000,"model":"grok-3","choices":[{"index":0,"delta":{"content":"Grok","role":"assistant"}}],"system_fingerprint":"fp_9ad1e2f0b4"}
+
+data: {"id":"3b1e1b4e-xai","object":"chat.completion.chunk","created":1754400000,"model":"grok-3","choices":[{"index":0,"delta":{"content":" here","role":"assistant"}}],"system_fingerprint":"fp_9ad1e2f0b4"}
+
+data: {"id":"3b1e1b4e-xai","object":"chat.completion.chunk","created":1754400000,"model":"grok-3","choices":[{"index":0,"delta":{"content":"","role":"assistant"},"finish_reason":"stop"}],"system_fingerprint":"fp_9ad1e2f0b4"}
+
+data: {"id":"3b1e1b4e-xai","object":"chat.completion.chunk","created":1754400000,"model":"grok-3","choices":[],"usage":{"prompt_tokens":14,"completion_tokens":3,"total_tokens":17,"prompt_tokens_details":{"text_tokens":14,"audio_tokens":0,"image_tokens":0,"cached_tokens":6},"completion_tokens_details":{"reasoning_tokens":0,"audio_tokens":0,"accepted_prediction_tokens":0,"rejected_prediction_tokens":0},"num_sources_used":0},"system_fingerprint":"fp_9ad1e2f0b4"}
+
+data: [DONE]
+
+"#;
+
+    #[tokio::test]
+    async fn test_stream_recorded_fixture() {
+        let mock_server = mock_event_stream("/v1/chat/completions", STREAM_FIXTURE).await;
+        let provider = XaiProvider {
+            api_client: ApiClient::new(
+                format!("{}/v1", mock_server.uri()),
+                AuthMethod::BearerToken("test".to_string()),
+            )
+            .unwrap(),
+            model: ModelConfig::new_or_fail(XAI_DEFAULT_MODEL),
+        };
+
+        let stream = provider
+            .stream("system", &[Message::user().with_text("Hi")], &[])
+            .await
+            .unwrap();
+        let (messages, usage) = collect_stream(stream).await.unwrap();
+
+        assert_single_message_id(&messages);
+        let text: String = messages.iter().map(|m| m.as_concat_text()).collect();
+        assert_eq!(text, "Grok here");
+        let usage = usage.expect("usage should be reported");
+        assert_eq!(usage.model, "grok-3");
+        assert_eq!(usage.usage.total_tokens, Some(17));
+    }
 }
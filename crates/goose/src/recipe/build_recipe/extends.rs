@@ -0,0 +1,177 @@
+use crate::recipe::build_recipe::{recipe_dir_str, RecipeError};
+use crate::recipe::read_recipe_file_content::{read_recipe_file, RecipeFile};
+use crate::recipe::template_recipe::parse_recipe_content;
+use crate::recipe::{InstructionsMerge, Recipe, Settings};
+use anyhow::anyhow;
+use std::env;
+use std::path::{Path, PathBuf};
+
+const GOOSE_RECIPE_PATH_ENV_VAR: &str = "GOOSE_RECIPE_PATH";
+const RECIPE_FILE_EXTENSIONS: &[&str] = &["yaml", "json"];
+
+/// Follows the `extends` references of a recipe file and returns every file in the
+/// inheritance chain, ordered from the root recipe to the one that was passed in.
+pub fn load_recipe_chain(recipe_file: RecipeFile) -> Result<Vec<RecipeFile>, RecipeError> {
+    let mut chain = Vec::new();
+    let mut visited: Vec<PathBuf> = Vec::new();
+    let mut current = recipe_file;
+
+    loop {
+        let path = current
+            .file_path
+            .canonicalize()
+            .unwrap_or_else(|_| current.file_path.clone());
+        if let Some(position) = visited.iter().position(|p| p == &path) {
+            let mut cycle: Vec<String> = visited[position..]
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect();
+            cycle.push(path.display().to_string());
+            return Err(RecipeError::ExtendsCycle { chain: cycle });
+        }
+        visited.push(path);
+
+        let extends = read_extends(&current)?;
+        let parent_dir = current.parent_dir.clone();
+        chain.push(current);
+
+        match extends {
+            Some(reference) => current = resolve_extends(&reference, &parent_dir)?,
+            None => break,
+        }
+    }
+
+    chain.reverse();
+    Ok(chain)
+}
+
+fn read_extends(recipe_file: &RecipeFile) -> Result<Option<String>, RecipeError> {
+    let (recipe, _) = recipe_dir_str(recipe_file)
+        .and_then(|recipe_dir| parse_recipe_content(&recipe_file.content, recipe_dir))
+        .map_err(|source| RecipeError::TemplateRendering { source })?;
+    Ok(recipe.extends)
+}
+
+fn resolve_extends(reference: &str, recipe_dir: &Path) -> Result<RecipeFile, RecipeError> {
+    let extends_error = |source: anyhow::Error| RecipeError::Extends {
+        reference: reference.to_string(),
+        source,
+    };
+
+    if is_recipe_path(reference) {
+        let path = Path::new(reference);
+        let path = if path.is_absolute() || reference.starts_with('~') {
+            path.to_path_buf()
+        } else {
+            recipe_dir.join(path)
+        };
+        return read_recipe_file(path).map_err(extends_error);
+    }
+
+    let mut search_dirs = vec![recipe_dir.to_path_buf()];
+    if let Ok(recipe_path_env) = env::var(GOOSE_RECIPE_PATH_ENV_VAR) {
+        search_dirs.extend(env::split_paths(&recipe_path_env));
+    }
+    for dir in &search_dirs {
+        for ext in RECIPE_FILE_EXTENSIONS {
+            if let Ok(recipe_file) = read_recipe_file(dir.join(format!("{}.{}", reference, ext))) {
+                return Ok(recipe_file);
+            }
+        }
+    }
+
+    let search_dirs_str = search_dirs
+        .iter()
+        .map(|p| p.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(extends_error(anyhow!(
+        "No {}.yaml or {}.json recipe file found in {}",
+        reference,
+        reference,
+        search_dirs_str
+    )))
+}
+
+fn is_recipe_path(reference: &str) -> bool {
+    reference.contains('/')
+        || reference.contains('\\')
+        || reference.starts_with('~')
+        || Path::new(reference).extension().is_some()
+}
+
+/// Merges a recipe on top of the recipe it extends.
+///
+/// Extensions are merged by name and parameters by key, with the extending recipe's
+/// entries replacing those of the same name. Instructions are concatenated unless
+/// `instructions_merge` is `override`, settings are overridden field by field, and every
+/// other field set on the extending recipe replaces the extended one.
+pub fn merge_recipe(base: Recipe, recipe: Recipe) -> Recipe {
+    let instructions = match (
+        base.instructions,
+        recipe.instructions,
+        recipe.instructions_merge.unwrap_or_default(),
+    ) {
+        (Some(base), Some(instructions), InstructionsMerge::Concatenate) => {
+            Some(format!("{}\n\n{}", base.trim_end(), instructions))
+        }
+        (base, instructions, _) => instructions.or(base),
+    };
+
+    Recipe {
+        version: recipe.version,
+        title: recipe.title,
+        description: recipe.description,
+        instructions,
+        prompt: recipe.prompt.or(base.prompt),
+        extensions: merge_by_key(base.extensions, recipe.extensions, |e| e.name()),
+        context: recipe.context.or(base.context),
+        settings: merge_settings(base.settings, recipe.settings),
+        activities: recipe.activities.or(base.activities),
+        author: recipe.author.or(base.author),
+        parameters: merge_by_key(base.parameters, recipe.parameters, |p| p.key.clone()),
+        response: recipe.response.or(base.response),
+        sub_recipes: recipe.sub_recipes.or(base.sub_recipes),
+        retry: recipe.retry.or(base.retry),
+        extends: None,
+        instructions_merge: None,
+    }
+}
+
+pub(super) fn merge_by_key<T, K, F>(
+    base: Option<Vec<T>>,
+    overrides: Option<Vec<T>>,
+    key: F,
+) -> Option<Vec<T>>
+where
+    K: PartialEq,
+    F: Fn(&T) -> K,
+{
+    let Some(overrides) = overrides else {
+        return base;
+    };
+    let mut merged = base.unwrap_or_default();
+    for item in overrides {
+        match merged
+            .iter()
+            .position(|existing| key(existing) == key(&item))
+        {
+            Some(index) => merged[index] = item,
+            None => merged.push(item),
+        }
+    }
+    Some(merged)
+}
+
+fn merge_settings(base: Option<Settings>, overrides: Option<Settings>) -> Option<Settings> {
+    match (base, overrides) {
+        (Some(base), Some(overrides)) => Some(Settings {
+            goose_provider: overrides.goose_provider.or(base.goose_provider),
+            goose_model: overrides.goose_model.or(base.goose_model),
+            temperature: overrides.temperature.or(base.temperature),
+            budget: overrides.budget.or(base.budget),
+            context_strategy: overrides.context_strategy.or(base.context_strategy),
+        }),
+        (base, overrides) => overrides.or(base),
+    }
+}
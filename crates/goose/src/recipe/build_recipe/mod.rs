@@ -1,5 +1,9 @@
+use crate::agents::ExtensionConfig;
+use crate::config::Config;
 use crate::recipe::read_recipe_file_content::{read_parameter_file_content, RecipeFile};
-use crate::recipe::template_recipe::{parse_recipe_content, render_recipe_content_with_params};
+use crate::recipe::template_recipe::{
+    parse_recipe_content, render_recipe_content_with_params, render_recipe_for_preview,
+};
 use crate::recipe::{
     Recipe, RecipeParameter, RecipeParameterInputType, RecipeParameterRequirement,
     BUILT_IN_RECIPE_DIR_PARAM,
@@ -8,14 +12,38 @@ use anyhow::Result;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
+mod extends;
+mod parameter_validation;
+mod sub_recipe_steps;
+
+use extends::merge_by_key;
+pub use extends::{load_recipe_chain, merge_recipe};
+pub use parameter_validation::{
+    read_secret_parameter, recipe_secret_key, validate_parameter_definitions,
+    validate_parameter_value, validate_parameter_values, ParameterValueError,
+    REDACTED_SECRET_VALUE,
+};
+pub use sub_recipe_steps::validate_sub_recipe_steps;
+
 #[derive(Debug, thiserror::Error)]
 pub enum RecipeError {
     #[error("Missing required parameters: {parameters:?}")]
     MissingParams { parameters: Vec<String> },
+    #[error("Invalid parameter values: {}", errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    InvalidParams { errors: Vec<ParameterValueError> },
     #[error("Template rendering failed: {source}")]
     TemplateRendering { source: anyhow::Error },
     #[error("Recipe parsing failed: {source}")]
     RecipeParsing { source: anyhow::Error },
+    #[error("Failed to resolve extended recipe '{reference}': {source}")]
+    Extends {
+        reference: String,
+        source: anyhow::Error,
+    },
+    #[error("Recipe extends cycle detected: {}", chain.join(" -> "))]
+    ExtendsCycle { chain: Vec<String> },
+    #[error("Invalid sub-recipe steps: {source}")]
+    SubRecipeSteps { source: anyhow::Error },
 }
 
 pub fn render_recipe_template<F>(
@@ -24,8 +52,9 @@ pub fn render_recipe_template<F>(
     user_prompt_fn: Option<F>,
 ) -> Result<(String, Vec<String>)>
 where
-    F: Fn(&str, &str) -> Result<String, anyhow::Error>,
+    F: Fn(&RecipeParameter) -> Result<String, anyhow::Error>,
 {
+    let recipe_title = recipe_chain_title(std::slice::from_ref(&recipe_file))?;
     let RecipeFile {
         content: recipe_file_content,
         parent_dir: recipe_parent_dir,
@@ -35,9 +64,24 @@ where
         .to_str()
         .ok_or_else(|| anyhow::anyhow!("Error getting recipe directory"))?;
     let recipe_parameters = validate_recipe_parameters(&recipe_file_content, recipe_dir_str)?;
+    let parameter_definitions = recipe_parameters.clone().unwrap_or_default();
 
-    let (params_for_template, missing_params) =
-        apply_values_to_parameters(&params, recipe_parameters, recipe_dir_str, user_prompt_fn)?;
+    let (mut params_for_template, missing_params) = apply_values_to_parameters(
+        &params,
+        recipe_parameters,
+        recipe_dir_str,
+        &recipe_title,
+        user_prompt_fn,
+    )?;
+    let errors = validate_parameter_values(&parameter_definitions, &params_for_template);
+    if !errors.is_empty() {
+        return Err(RecipeError::InvalidParams { errors }.into());
+    }
+    reference_secret_parameters(
+        &recipe_title,
+        &parameter_definitions,
+        &mut params_for_template,
+    );
 
     let rendered_content = if missing_params.is_empty() {
         render_recipe_content_with_params(&recipe_file_content, &params_for_template)?
@@ -56,22 +100,87 @@ pub fn validate_recipe_parameters(
         parse_recipe_content(recipe_file_content, recipe_dir_str.to_string())?;
     let recipe_parameters = raw_recipe.parameters;
     validate_optional_parameters(&recipe_parameters)?;
+    validate_parameter_definitions(recipe_parameters.as_deref().unwrap_or_default())?;
     validate_parameters_in_template(&recipe_parameters, &template_variables)?;
     Ok(recipe_parameters)
 }
 
+/// Validates the parameters of every recipe in an inheritance chain against the
+/// template variables used across the chain, returning the parameters merged by key.
+pub fn validate_recipe_chain_parameters(
+    chain: &[RecipeFile],
+) -> Result<Option<Vec<RecipeParameter>>> {
+    let mut recipe_parameters: Option<Vec<RecipeParameter>> = None;
+    let mut template_variables = HashSet::new();
+    for recipe_file in chain {
+        let (raw_recipe, variables) =
+            parse_recipe_content(&recipe_file.content, recipe_dir_str(recipe_file)?)?;
+        recipe_parameters =
+            merge_by_key(recipe_parameters, raw_recipe.parameters, |p| p.key.clone());
+        template_variables.extend(variables);
+    }
+    validate_optional_parameters(&recipe_parameters)?;
+    validate_parameter_definitions(recipe_parameters.as_deref().unwrap_or_default())?;
+    validate_parameters_in_template(&recipe_parameters, &template_variables)?;
+    Ok(recipe_parameters)
+}
+
+/// Title of the recipe an inheritance chain resolves to, which scopes the recipe's secrets.
+pub fn recipe_chain_title(chain: &[RecipeFile]) -> Result<String> {
+    let recipe_file = chain
+        .last()
+        .ok_or_else(|| anyhow::anyhow!("No recipe in the chain"))?;
+    let (raw_recipe, _) = parse_recipe_content(&recipe_file.content, recipe_dir_str(recipe_file)?)?;
+    Ok(raw_recipe.title)
+}
+
+/// Renders every recipe in an inheritance chain for preview, keeping unresolved
+/// template variables as is, and merges them into a single recipe.
+pub fn render_recipe_chain_for_preview(
+    chain: &[RecipeFile],
+    params: &HashMap<String, String>,
+) -> Result<Recipe> {
+    let mut resolved: Option<Recipe> = None;
+    for recipe_file in chain {
+        let recipe_dir = recipe_dir_str(recipe_file)?;
+        let mut params = params.clone();
+        if params.contains_key(BUILT_IN_RECIPE_DIR_PARAM) {
+            params.insert(BUILT_IN_RECIPE_DIR_PARAM.to_string(), recipe_dir.clone());
+        }
+        let recipe = render_recipe_for_preview(&recipe_file.content, recipe_dir, &params)?;
+        resolved = Some(match resolved {
+            Some(base) => merge_recipe(base, recipe),
+            None => recipe,
+        });
+    }
+    resolved.ok_or_else(|| anyhow::anyhow!("No recipe to render"))
+}
+
 pub fn build_recipe_from_template<F>(
     recipe_file: RecipeFile,
     params: Vec<(String, String)>,
     user_prompt_fn: Option<F>,
 ) -> Result<Recipe, RecipeError>
 where
-    F: Fn(&str, &str) -> Result<String, anyhow::Error>,
+    F: Fn(&RecipeParameter) -> Result<String, anyhow::Error>,
 {
-    let recipe_parent_dir = recipe_file.parent_dir.clone();
-    let (rendered_content, missing_params) =
-        render_recipe_template(recipe_file, params.clone(), user_prompt_fn)
-            .map_err(|source| RecipeError::TemplateRendering { source })?;
+    let recipe_dir =
+        recipe_dir_str(&recipe_file).map_err(|source| RecipeError::TemplateRendering { source })?;
+    let chain = load_recipe_chain(recipe_file)?;
+    let recipe_parameters = validate_recipe_chain_parameters(&chain)
+        .map_err(|source| RecipeError::TemplateRendering { source })?;
+    let parameter_definitions = recipe_parameters.clone().unwrap_or_default();
+    let recipe_title =
+        recipe_chain_title(&chain).map_err(|source| RecipeError::TemplateRendering { source })?;
+
+    let (params_for_template, missing_params) = apply_values_to_parameters(
+        &params,
+        recipe_parameters,
+        &recipe_dir,
+        &recipe_title,
+        user_prompt_fn,
+    )
+    .map_err(|source| RecipeError::TemplateRendering { source })?;
 
     if !missing_params.is_empty() {
         return Err(RecipeError::MissingParams {
@@ -79,19 +188,144 @@ where
         });
     }
 
+    let errors = validate_parameter_values(&parameter_definitions, &params_for_template);
+    if !errors.is_empty() {
+        return Err(RecipeError::InvalidParams { errors });
+    }
+
+    render_recipe_chain(
+        &chain,
+        params_for_template,
+        &recipe_title,
+        &parameter_definitions,
+    )
+}
+
+/// Renders every recipe in an inheritance chain with the parameter values and merges them,
+/// keeping the values of secret parameters out of the recipe.
+fn render_recipe_chain(
+    chain: &[RecipeFile],
+    mut params_for_template: HashMap<String, String>,
+    recipe_title: &str,
+    parameter_definitions: &[RecipeParameter],
+) -> Result<Recipe, RecipeError> {
+    reference_secret_parameters(
+        recipe_title,
+        parameter_definitions,
+        &mut params_for_template,
+    );
+
+    let mut resolved: Option<Recipe> = None;
+    for recipe_file in chain {
+        let recipe = build_recipe_from_file(recipe_file, &params_for_template)?;
+        resolved = Some(match resolved {
+            Some(base) => merge_recipe(base, recipe),
+            None => recipe,
+        });
+    }
+
+    let mut recipe = resolved.ok_or_else(|| RecipeError::RecipeParsing {
+        source: anyhow::anyhow!("No recipe to build"),
+    })?;
+    if let Some(sub_recipes) = &recipe.sub_recipes {
+        validate_sub_recipe_steps(sub_recipes)
+            .map_err(|source| RecipeError::SubRecipeSteps { source })?;
+    }
+    pass_secrets_to_extensions(&mut recipe, recipe_title, parameter_definitions);
+
+    Ok(recipe)
+}
+
+/// Replaces the values of the secret parameters with a reference to the environment
+/// variable the recipe's extensions receive them in, so that secrets never end up in the
+/// prompt or instructions, and so neither in the conversation nor the stored session.
+fn reference_secret_parameters(
+    recipe_title: &str,
+    parameters: &[RecipeParameter],
+    values: &mut HashMap<String, String>,
+) {
+    for param in parameters.iter().filter(|param| param.is_secret()) {
+        if let Some(value) = values.get_mut(&param.key) {
+            *value = format!("${}", recipe_secret_key(recipe_title, &param.key));
+        }
+    }
+}
+
+/// Has the recipe's own stdio extensions read its secrets from the keyring when they start,
+/// so that no other process gets them.
+fn pass_secrets_to_extensions(
+    recipe: &mut Recipe,
+    recipe_title: &str,
+    parameters: &[RecipeParameter],
+) {
+    let secret_keys: Vec<String> = parameters
+        .iter()
+        .filter(|param| param.is_secret())
+        .map(|param| recipe_secret_key(recipe_title, &param.key))
+        .collect();
+    for extension in recipe.extensions.iter_mut().flatten() {
+        if let ExtensionConfig::Stdio { env_keys, .. } = extension {
+            for key in &secret_keys {
+                if !env_keys.contains(key) {
+                    env_keys.push(key.clone());
+                }
+            }
+        }
+    }
+}
+
+fn build_recipe_from_file(
+    recipe_file: &RecipeFile,
+    params: &HashMap<String, String>,
+) -> Result<Recipe, RecipeError> {
+    let recipe_dir =
+        recipe_dir_str(recipe_file).map_err(|source| RecipeError::TemplateRendering { source })?;
+    let mut params = params.clone();
+    params.insert(BUILT_IN_RECIPE_DIR_PARAM.to_string(), recipe_dir);
+
+    let rendered_content = render_recipe_content_with_params(&recipe_file.content, &params)
+        .map_err(|source| RecipeError::TemplateRendering { source })?;
     let mut recipe = Recipe::from_content(&rendered_content)
         .map_err(|source| RecipeError::RecipeParsing { source })?;
+    resolve_sub_recipe_paths(&mut recipe, &recipe_file.parent_dir);
+
+    Ok(recipe)
+}
+
+/// Loads a recipe and the recipes it extends without rendering their templates, and merges
+/// them into a single recipe. For recipes that run without parameter values.
+pub fn load_recipe_from_chain(recipe_file: RecipeFile) -> Result<Recipe, RecipeError> {
+    let mut resolved: Option<Recipe> = None;
+    for recipe_file in load_recipe_chain(recipe_file)? {
+        let mut recipe = Recipe::from_content(&recipe_file.content)
+            .map_err(|source| RecipeError::RecipeParsing { source })?;
+        resolve_sub_recipe_paths(&mut recipe, &recipe_file.parent_dir);
+        resolved = Some(match resolved {
+            Some(base) => merge_recipe(base, recipe),
+            None => recipe,
+        });
+    }
+    resolved.ok_or_else(|| RecipeError::RecipeParsing {
+        source: anyhow::anyhow!("No recipe to load"),
+    })
+}
 
+fn resolve_sub_recipe_paths(recipe: &mut Recipe, recipe_dir: &Path) {
     if let Some(ref mut sub_recipes) = recipe.sub_recipes {
         for sub_recipe in sub_recipes {
-            if let Ok(resolved_path) = resolve_sub_recipe_path(&sub_recipe.path, &recipe_parent_dir)
-            {
+            if let Ok(resolved_path) = resolve_sub_recipe_path(&sub_recipe.path, recipe_dir) {
                 sub_recipe.path = resolved_path;
             }
         }
     }
+}
 
-    Ok(recipe)
+fn recipe_dir_str(recipe_file: &RecipeFile) -> Result<String> {
+    recipe_file
+        .parent_dir
+        .to_str()
+        .map(str::to_string)
+        .ok_or_else(|| anyhow::anyhow!("Error getting recipe directory"))
 }
 
 fn validate_parameters_in_template(
@@ -175,14 +409,18 @@ fn validate_optional_parameters(parameters: &Option<Vec<RecipeParameter>>) -> Re
     }
 }
 
+/// Fills in the value of every parameter from the values given, their defaults, the
+/// keyring for secrets, or else by prompting. Prompted secrets are stored in the keyring
+/// under the recipe's scope.
 pub fn apply_values_to_parameters<F>(
     user_params: &[(String, String)],
     recipe_parameters: Option<Vec<RecipeParameter>>,
     recipe_parent_dir: &str,
+    recipe_title: &str,
     user_prompt_fn: Option<F>,
 ) -> Result<(HashMap<String, String>, Vec<String>)>
 where
-    F: Fn(&str, &str) -> Result<String, anyhow::Error>,
+    F: Fn(&RecipeParameter) -> Result<String, anyhow::Error>,
 {
     let mut param_map: HashMap<String, String> = user_params.iter().cloned().collect();
     param_map.insert(
@@ -191,12 +429,33 @@ where
     );
     let mut missing_params: Vec<String> = Vec::new();
     for param in recipe_parameters.unwrap_or_default() {
-        if !param_map.contains_key(&param.key) {
+        if param.is_secret() {
+            if param_map.contains_key(&param.key) {
+                return Err(anyhow::anyhow!(
+                    "Secret parameter '{}' cannot be passed as a value; store it in the keyring instead",
+                    param.key
+                ));
+            }
+            match read_secret_parameter(recipe_title, &param.key) {
+                Ok(secret) => param_map.insert(param.key.clone(), secret),
+                Err(_) if user_prompt_fn.is_some() => {
+                    let input_value = user_prompt_fn.as_ref().unwrap()(&param)?;
+                    Config::global().set_secret(
+                        &recipe_secret_key(recipe_title, &param.key),
+                        serde_json::Value::String(input_value.clone()),
+                    )?;
+                    param_map.insert(param.key.clone(), input_value)
+                }
+                Err(_) => {
+                    missing_params.push(param.key.clone());
+                    None
+                }
+            };
+        } else if !param_map.contains_key(&param.key) {
             match (&param.default, &param.requirement) {
                 (Some(default), _) => param_map.insert(param.key.clone(), default.clone()),
                 (None, RecipeParameterRequirement::UserPrompt) if user_prompt_fn.is_some() => {
-                    let input_value =
-                        user_prompt_fn.as_ref().unwrap()(&param.key, &param.description)?;
+                    let input_value = user_prompt_fn.as_ref().unwrap()(&param)?;
                     param_map.insert(param.key.clone(), input_value)
                 }
                 _ => {
@@ -0,0 +1,438 @@
+use crate::config::Config;
+use crate::recipe::{RecipeParameter, RecipeParameterInputType};
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use utoipa::ToSchema;
+
+pub const REDACTED_SECRET_VALUE: &str = "********";
+
+/// A parameter value that does not satisfy its parameter's type or constraints.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct ParameterValueError {
+    pub key: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParameterValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+/// Checks that the constraints declared on the parameters are consistent and that their
+/// default values satisfy them.
+pub fn validate_parameter_definitions(parameters: &[RecipeParameter]) -> Result<()> {
+    for param in parameters {
+        if let Some(pattern) = &param.pattern {
+            Regex::new(pattern)
+                .map_err(|e| anyhow!("Parameter '{}' has an invalid pattern: {}", param.key, e))?;
+        }
+        if matches!(
+            param.input_type,
+            RecipeParameterInputType::Select | RecipeParameterInputType::MultiSelect
+        ) && param.options.as_ref().is_none_or(|o| o.is_empty())
+        {
+            return Err(anyhow!(
+                "Parameter '{}' of type {} needs options",
+                param.key,
+                param.input_type
+            ));
+        }
+        if let (Some(min), Some(max)) = (param.min, param.max) {
+            if min > max {
+                return Err(anyhow!(
+                    "Parameter '{}' has a min greater than its max",
+                    param.key
+                ));
+            }
+        }
+        if let (Some(min_length), Some(max_length)) = (param.min_length, param.max_length) {
+            if min_length > max_length {
+                return Err(anyhow!(
+                    "Parameter '{}' has a min_length greater than its max_length",
+                    param.key
+                ));
+            }
+        }
+        if param.is_secret() && param.default.is_some() {
+            return Err(anyhow!(
+                "Secret parameter '{}' cannot have a default value",
+                param.key
+            ));
+        }
+        if let Some(default) = &param.default {
+            if !matches!(param.input_type, RecipeParameterInputType::File) {
+                validate_parameter_value(param, default).map_err(|message| {
+                    anyhow!(
+                        "Default value of parameter '{}' is invalid: {}",
+                        param.key,
+                        message
+                    )
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validates the values given for the parameters, skipping parameters without a value.
+pub fn validate_parameter_values(
+    parameters: &[RecipeParameter],
+    values: &HashMap<String, String>,
+) -> Vec<ParameterValueError> {
+    parameters
+        .iter()
+        .filter(|param| !matches!(param.input_type, RecipeParameterInputType::File))
+        .filter_map(|param| {
+            let value = values.get(&param.key)?;
+            validate_parameter_value(param, value)
+                .err()
+                .map(|message| ParameterValueError {
+                    key: param.key.clone(),
+                    message,
+                })
+        })
+        .collect()
+}
+
+/// Validates a single value against its parameter's type and constraints, returning a
+/// message that never includes the value of a secret.
+pub fn validate_parameter_value(param: &RecipeParameter, value: &str) -> Result<(), String> {
+    match param.input_type {
+        RecipeParameterInputType::Number => {
+            let number: f64 = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("'{}' is not a number", value))?;
+            if let Some(min) = param.min.filter(|min| number < *min) {
+                return Err(format!("must be at least {}", min));
+            }
+            if let Some(max) = param.max.filter(|max| number > *max) {
+                return Err(format!("must be at most {}", max));
+            }
+            Ok(())
+        }
+        RecipeParameterInputType::Boolean => match value.trim().to_lowercase().as_str() {
+            "true" | "false" => Ok(()),
+            _ => Err(format!("'{}' is not true or false", value)),
+        },
+        RecipeParameterInputType::Select => {
+            check_options(param, std::slice::from_ref(&value.to_string()))
+        }
+        RecipeParameterInputType::MultiSelect => {
+            let items = parse_list_value(value)?;
+            check_options(param, &items)?;
+            check_length(param, items.len(), "items")
+        }
+        RecipeParameterInputType::List => {
+            let items = parse_list_value(value)?;
+            for item in &items {
+                check_pattern(param, item, false)?;
+            }
+            check_length(param, items.len(), "items")
+        }
+        RecipeParameterInputType::Map => {
+            let entries = parse_map_value(value)?;
+            check_length(param, entries.len(), "entries")
+        }
+        RecipeParameterInputType::Secret => {
+            check_pattern(param, value, true)?;
+            check_length(param, value.chars().count(), "characters")
+        }
+        RecipeParameterInputType::String
+        | RecipeParameterInputType::Date
+        | RecipeParameterInputType::File => {
+            check_pattern(param, value, false)?;
+            check_length(param, value.chars().count(), "characters")
+        }
+    }
+}
+
+/// Parses a list value given as a JSON array or as comma-separated values.
+pub fn parse_list_value(value: &str) -> Result<Vec<String>, String> {
+    let trimmed = value.trim();
+    if trimmed.starts_with('[') {
+        let items: Vec<Value> = serde_json::from_str(trimmed)
+            .map_err(|e| format!("is not a valid JSON array: {}", e))?;
+        return Ok(items
+            .into_iter()
+            .map(|item| match item {
+                Value::String(s) => s,
+                other => other.to_string(),
+            })
+            .collect());
+    }
+    Ok(trimmed
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Parses a map value given as a JSON object or as comma-separated `key=value` pairs.
+pub fn parse_map_value(value: &str) -> Result<Vec<(String, String)>, String> {
+    let trimmed = value.trim();
+    if trimmed.starts_with('{') {
+        let entries: serde_json::Map<String, Value> = serde_json::from_str(trimmed)
+            .map_err(|e| format!("is not a valid JSON object: {}", e))?;
+        return Ok(entries
+            .into_iter()
+            .map(|(key, value)| match value {
+                Value::String(s) => (key, s),
+                other => (key, other.to_string()),
+            })
+            .collect());
+    }
+    trimmed
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            entry
+                .split_once('=')
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .ok_or_else(|| format!("'{}' is not a key=value pair", entry))
+        })
+        .collect()
+}
+
+/// Name a secret parameter is stored under in the keyring, and passed to the recipe's
+/// extensions in. Scoped to the recipe, so that a recipe can neither read nor overwrite
+/// other secrets, such as provider API keys or the secrets of other recipes.
+pub fn recipe_secret_key(recipe_title: &str, param_key: &str) -> String {
+    let mut recipe = String::new();
+    for c in recipe_title.chars() {
+        if c.is_ascii_alphanumeric() {
+            recipe.push(c.to_ascii_uppercase());
+        } else if !recipe.is_empty() && !recipe.ends_with('_') {
+            recipe.push('_');
+        }
+    }
+    format!(
+        "GOOSE_RECIPE_{}__{}",
+        recipe.trim_end_matches('_'),
+        param_key.to_uppercase()
+    )
+}
+
+/// Reads a secret parameter from the keyring, never from the environment.
+pub fn read_secret_parameter(recipe_title: &str, param_key: &str) -> Result<String> {
+    let key = recipe_secret_key(recipe_title, param_key);
+    Config::global()
+        .load_secrets()?
+        .get(&key)
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("Secret '{}' is not stored in the keyring", key))
+}
+
+fn check_options(param: &RecipeParameter, values: &[String]) -> Result<(), String> {
+    let options = param.options.as_deref().unwrap_or_default();
+    match values.iter().find(|value| !options.contains(value)) {
+        Some(value) => Err(format!("'{}' is not one of: {}", value, options.join(", "))),
+        None => Ok(()),
+    }
+}
+
+fn check_pattern(param: &RecipeParameter, value: &str, is_secret: bool) -> Result<(), String> {
+    let Some(pattern) = &param.pattern else {
+        return Ok(());
+    };
+    let regex = Regex::new(pattern).map_err(|e| format!("invalid pattern: {}", e))?;
+    if regex.is_match(value) {
+        Ok(())
+    } else if is_secret {
+        Err(format!("does not match the pattern {}", pattern))
+    } else {
+        Err(format!(
+            "'{}' does not match the pattern {}",
+            value, pattern
+        ))
+    }
+}
+
+fn check_length(param: &RecipeParameter, length: usize, unit: &str) -> Result<(), String> {
+    if let Some(min_length) = param.min_length.filter(|min| length < *min) {
+        return Err(format!("must have at least {} {}", min_length, unit));
+    }
+    if let Some(max_length) = param.max_length.filter(|max| length > *max) {
+        return Err(format!("must have at most {} {}", max_length, unit));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::recipe::RecipeParameterRequirement;
+
+    fn param(input_type: RecipeParameterInputType) -> RecipeParameter {
+        RecipeParameter {
+            key: "value".to_string(),
+            input_type,
+            requirement: RecipeParameterRequirement::Required,
+            description: "A value".to_string(),
+            default: None,
+            options: None,
+            pattern: None,
+            min: None,
+            max: None,
+            min_length: None,
+            max_length: None,
+        }
+    }
+
+    #[test]
+    fn test_number_bounds() {
+        let mut number = param(RecipeParameterInputType::Number);
+        number.min = Some(1.0);
+        number.max = Some(10.0);
+
+        assert_eq!(validate_parameter_value(&number, "5"), Ok(()));
+        assert_eq!(
+            validate_parameter_value(&number, "0.5"),
+            Err("must be at least 1".to_string())
+        );
+        assert_eq!(
+            validate_parameter_value(&number, "11"),
+            Err("must be at most 10".to_string())
+        );
+        assert_eq!(
+            validate_parameter_value(&number, "ten"),
+            Err("'ten' is not a number".to_string())
+        );
+    }
+
+    #[test]
+    fn test_string_pattern_and_length() {
+        let mut string = param(RecipeParameterInputType::String);
+        string.pattern = Some("^[a-z]+$".to_string());
+        string.min_length = Some(2);
+        string.max_length = Some(5);
+
+        assert_eq!(validate_parameter_value(&string, "abc"), Ok(()));
+        assert_eq!(
+            validate_parameter_value(&string, "ABC"),
+            Err("'ABC' does not match the pattern ^[a-z]+$".to_string())
+        );
+        assert_eq!(
+            validate_parameter_value(&string, "a"),
+            Err("must have at least 2 characters".to_string())
+        );
+        assert_eq!(
+            validate_parameter_value(&string, "abcdef"),
+            Err("must have at most 5 characters".to_string())
+        );
+    }
+
+    #[test]
+    fn test_multi_select_list_and_map() {
+        let mut multi_select = param(RecipeParameterInputType::MultiSelect);
+        multi_select.options = Some(vec!["red".to_string(), "green".to_string()]);
+        multi_select.max_length = Some(1);
+
+        assert_eq!(validate_parameter_value(&multi_select, "red"), Ok(()));
+        assert_eq!(
+            validate_parameter_value(&multi_select, r#"["red", "blue"]"#),
+            Err("'blue' is not one of: red, green".to_string())
+        );
+        assert_eq!(
+            validate_parameter_value(&multi_select, "red, green"),
+            Err("must have at most 1 items".to_string())
+        );
+
+        let mut list = param(RecipeParameterInputType::List);
+        list.pattern = Some(r"^\d+$".to_string());
+        assert_eq!(validate_parameter_value(&list, "1, 2, 3"), Ok(()));
+        assert_eq!(
+            validate_parameter_value(&list, "1, x"),
+            Err(r"'x' does not match the pattern ^\d+$".to_string())
+        );
+
+        let map = param(RecipeParameterInputType::Map);
+        assert_eq!(
+            parse_map_value(r#"{"a": "1", "b": 2}"#),
+            Ok(vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "2".to_string())
+            ])
+        );
+        assert_eq!(validate_parameter_value(&map, "a=1, b=2"), Ok(()));
+        assert_eq!(
+            validate_parameter_value(&map, "a=1, b"),
+            Err("'b' is not a key=value pair".to_string())
+        );
+    }
+
+    #[test]
+    fn test_secret_errors_do_not_include_the_value() {
+        let mut secret = param(RecipeParameterInputType::Secret);
+        secret.pattern = Some("^ghp_".to_string());
+
+        let message = validate_parameter_value(&secret, "hunter2").unwrap_err();
+
+        assert_eq!(message, "does not match the pattern ^ghp_");
+    }
+
+    #[test]
+    fn test_validate_parameter_values_collects_errors() {
+        let mut number = param(RecipeParameterInputType::Number);
+        number.key = "count".to_string();
+        let mut boolean = param(RecipeParameterInputType::Boolean);
+        boolean.key = "enabled".to_string();
+        let values = HashMap::from([
+            ("count".to_string(), "many".to_string()),
+            ("enabled".to_string(), "TRUE".to_string()),
+        ]);
+
+        let errors = validate_parameter_values(&[number, boolean], &values);
+
+        assert_eq!(
+            errors,
+            vec![ParameterValueError {
+                key: "count".to_string(),
+                message: "'many' is not a number".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_parameter_definitions() {
+        let mut select = param(RecipeParameterInputType::Select);
+        assert_eq!(
+            validate_parameter_definitions(std::slice::from_ref(&select))
+                .unwrap_err()
+                .to_string(),
+            "Parameter 'value' of type select needs options"
+        );
+        select.options = Some(vec!["a".to_string()]);
+        select.default = Some("b".to_string());
+        assert_eq!(
+            validate_parameter_definitions(&[select])
+                .unwrap_err()
+                .to_string(),
+            "Default value of parameter 'value' is invalid: 'b' is not one of: a"
+        );
+
+        let mut secret = param(RecipeParameterInputType::Secret);
+        secret.default = Some("token".to_string());
+        assert_eq!(
+            validate_parameter_definitions(&[secret])
+                .unwrap_err()
+                .to_string(),
+            "Secret parameter 'value' cannot have a default value"
+        );
+
+        let mut string = param(RecipeParameterInputType::String);
+        string.pattern = Some("(".to_string());
+        assert!(validate_parameter_definitions(&[string])
+            .unwrap_err()
+            .to_string()
+            .starts_with("Parameter 'value' has an invalid pattern"));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::recipe::{SubRecipe, STEP_OUTPUTS_TEMPLATE_VARIABLE};
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+/// Checks that the `depends_on` references between sub-recipe steps name existing steps
+/// without forming a cycle, and that every step output a step's values refer to comes
+/// from a step it depends on, directly or indirectly.
+pub fn validate_sub_recipe_steps(sub_recipes: &[SubRecipe]) -> Result<()> {
+    if sub_recipes.iter().all(|s| s.depends_on.is_none()) {
+        return Ok(());
+    }
+
+    let mut steps: HashMap<&str, &SubRecipe> = HashMap::new();
+    for sub_recipe in sub_recipes {
+        if steps.insert(&sub_recipe.name, sub_recipe).is_some() {
+            return Err(anyhow!("Duplicate step name '{}'", sub_recipe.name));
+        }
+    }
+
+    for sub_recipe in sub_recipes {
+        for dependency in sub_recipe.depends_on.iter().flatten() {
+            if !steps.contains_key(dependency.as_str()) {
+                return Err(anyhow!(
+                    "Step '{}' depends on unknown step '{}'",
+                    sub_recipe.name,
+                    dependency
+                ));
+            }
+        }
+    }
+
+    for sub_recipe in sub_recipes {
+        find_cycle(&sub_recipe.name, &steps, &mut Vec::new())?;
+    }
+
+    let step_output_re = Regex::new(&format!(
+        r"\b{}\.([A-Za-z_][A-Za-z0-9_]*)",
+        regex::escape(STEP_OUTPUTS_TEMPLATE_VARIABLE)
+    ))
+    .unwrap();
+    for sub_recipe in sub_recipes {
+        let ancestors = ancestors(&sub_recipe.name, &steps);
+        for value in sub_recipe.values.iter().flat_map(|values| values.values()) {
+            for captures in step_output_re.captures_iter(value) {
+                let referenced = &captures[1];
+                if !ancestors.contains(referenced) {
+                    return Err(anyhow!(
+                        "Step '{}' uses the output of step '{}' without depending on it",
+                        sub_recipe.name,
+                        referenced
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn find_cycle<'a>(
+    name: &'a str,
+    steps: &HashMap<&'a str, &'a SubRecipe>,
+    path: &mut Vec<&'a str>,
+) -> Result<()> {
+    if let Some(position) = path.iter().position(|step| *step == name) {
+        let mut cycle = path[position..].to_vec();
+        cycle.push(name);
+        return Err(anyhow!("Step cycle detected: {}", cycle.join(" -> ")));
+    }
+    path.push(name);
+    let step: &'a SubRecipe = steps[name];
+    for dependency in step.depends_on.iter().flatten() {
+        find_cycle(dependency, steps, path)?;
+    }
+    path.pop();
+    Ok(())
+}
+
+fn ancestors<'a>(name: &str, steps: &HashMap<&'a str, &'a SubRecipe>) -> HashSet<&'a str> {
+    let dependencies = |step: &'a SubRecipe| step.depends_on.iter().flatten().map(String::as_str);
+    let mut ancestors = HashSet::new();
+    let mut queue: Vec<&'a str> = dependencies(steps[name]).collect();
+    while let Some(step) = queue.pop() {
+        if ancestors.insert(step) {
+            queue.extend(dependencies(steps[step]));
+        }
+    }
+    ancestors
+}
@@ -1,12 +1,16 @@
+use crate::agents::ExtensionConfig;
+use crate::conversation::message::Message;
 use crate::recipe::build_recipe::{
-    build_recipe_from_template, resolve_sub_recipe_path, RecipeError,
+    build_recipe_from_template, recipe_secret_key, render_recipe_chain, resolve_sub_recipe_path,
+    validate_recipe_chain_parameters, RecipeError,
 };
 use crate::recipe::read_recipe_file_content::RecipeFile;
-use crate::recipe::{RecipeParameterInputType, RecipeParameterRequirement};
+use crate::recipe::{RecipeParameter, RecipeParameterInputType, RecipeParameterRequirement};
+use std::collections::HashMap;
 use tempfile::TempDir;
 
 #[allow(clippy::type_complexity)]
-const NO_USER_PROMPT: Option<fn(&str, &str) -> Result<String, anyhow::Error>> = None;
+const NO_USER_PROMPT: Option<fn(&RecipeParameter) -> Result<String, anyhow::Error>> = None;
 
 fn setup_recipe_file(instructions_and_parameters: &str) -> (TempDir, RecipeFile) {
     let recipe_content = format!(
@@ -544,3 +548,481 @@ parameters:
         }
     }
 }
+
+mod extends_tests {
+    use super::*;
+    use crate::agents::extension::ExtensionConfig;
+    use crate::recipe::build_recipe::{load_recipe_chain, merge_recipe};
+    use crate::recipe::read_recipe_file_content::read_recipe_file;
+    use crate::recipe::{InstructionsMerge, Recipe};
+
+    const BASE_RECIPE: &str = r#"
+version: 1.0.0
+title: Base
+description: Base recipe
+instructions: Follow the team style guide for {{ language }}.
+prompt: Review the code
+extensions:
+  - type: builtin
+    name: developer
+    timeout: 300
+  - type: builtin
+    name: memory
+    timeout: 300
+settings:
+  goose_provider: openai
+  goose_model: gpt-4o
+  temperature: 0.2
+parameters:
+  - key: language
+    input_type: string
+    requirement: optional
+    default: rust
+    description: language to review
+"#;
+
+    fn write_recipe(temp_dir: &TempDir, filename: &str, content: &str) -> RecipeFile {
+        let path = setup_test_file(temp_dir, filename, content);
+        read_recipe_file(path).unwrap()
+    }
+
+    #[test]
+    fn test_build_recipe_with_extends_merges_fields() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_recipe(&temp_dir, "base.yaml", BASE_RECIPE);
+        let child = write_recipe(
+            &temp_dir,
+            "child.yaml",
+            r#"
+version: 1.0.0
+title: Child
+description: Child recipe
+extends: base.yaml
+instructions: Focus on {{ focus }}.
+extensions:
+  - type: builtin
+    name: memory
+    timeout: 600
+  - type: builtin
+    name: computercontroller
+    timeout: 300
+settings:
+  goose_model: gpt-4o-mini
+parameters:
+  - key: focus
+    input_type: string
+    requirement: required
+    description: area to focus on
+"#,
+        );
+
+        let params = vec![("focus".to_string(), "error handling".to_string())];
+        let recipe = build_recipe_from_template(child, params, NO_USER_PROMPT).unwrap();
+
+        assert_eq!(recipe.title, "Child");
+        assert_eq!(recipe.extends, None);
+        assert_eq!(
+            recipe.instructions.unwrap(),
+            "Follow the team style guide for rust.\n\nFocus on error handling."
+        );
+        assert_eq!(recipe.prompt.unwrap(), "Review the code");
+
+        let extensions = recipe.extensions.unwrap();
+        let names: Vec<String> = extensions.iter().map(|e| e.name()).collect();
+        assert_eq!(names, vec!["developer", "memory", "computercontroller"]);
+        assert!(matches!(
+            extensions[1],
+            ExtensionConfig::Builtin {
+                timeout: Some(600),
+                ..
+            }
+        ));
+
+        let settings = recipe.settings.unwrap();
+        assert_eq!(settings.goose_provider.as_deref(), Some("openai"));
+        assert_eq!(settings.goose_model.as_deref(), Some("gpt-4o-mini"));
+        assert_eq!(settings.temperature, Some(0.2));
+
+        let keys: Vec<&str> = recipe
+            .parameters
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|p| p.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["language", "focus"]);
+    }
+
+    #[test]
+    fn test_build_recipe_with_extends_by_name_overrides_instructions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_recipe(&temp_dir, "base.yaml", BASE_RECIPE);
+        let child = write_recipe(
+            &temp_dir,
+            "child.yaml",
+            r#"
+version: 1.0.0
+title: Child
+description: Child recipe
+extends: base
+instructions_merge: override
+instructions: Only check the {{ language }} formatting.
+parameters:
+  - key: language
+    input_type: string
+    requirement: optional
+    default: python
+    description: language to format
+"#,
+        );
+
+        let recipe = build_recipe_from_template(child, Vec::new(), NO_USER_PROMPT).unwrap();
+
+        assert_eq!(
+            recipe.instructions.unwrap(),
+            "Only check the python formatting."
+        );
+        assert_eq!(recipe.instructions_merge, None);
+        let parameters = recipe.parameters.unwrap();
+        assert_eq!(parameters.len(), 1);
+        assert_eq!(parameters[0].default.as_deref(), Some("python"));
+    }
+
+    #[test]
+    fn test_load_recipe_chain_detects_cycle() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_recipe(
+            &temp_dir,
+            "first.yaml",
+            "title: First\ndescription: First\ninstructions: First\nextends: second.yaml\n",
+        );
+        let second = write_recipe(
+            &temp_dir,
+            "second.yaml",
+            "title: Second\ndescription: Second\ninstructions: Second\nextends: first.yaml\n",
+        );
+
+        match load_recipe_chain(second) {
+            Err(RecipeError::ExtendsCycle { chain }) => {
+                assert_eq!(chain.len(), 3);
+                assert!(chain[0].ends_with("second.yaml"));
+                assert!(chain[1].ends_with("first.yaml"));
+                assert!(chain[2].ends_with("second.yaml"));
+            }
+            other => panic!(
+                "Expected ExtendsCycle error, got: {:?}",
+                other.map(|c| c.len())
+            ),
+        }
+    }
+
+    #[test]
+    fn test_load_recipe_chain_missing_parent() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let child = write_recipe(
+            &temp_dir,
+            "child.yaml",
+            "title: Child\ndescription: Child\ninstructions: Child\nextends: missing\n",
+        );
+
+        match load_recipe_chain(child) {
+            Err(RecipeError::Extends { reference, .. }) => assert_eq!(reference, "missing"),
+            other => panic!("Expected Extends error, got: {:?}", other.map(|c| c.len())),
+        }
+    }
+
+    #[test]
+    fn test_merge_recipe_keeps_base_fields_when_unset() {
+        let base = Recipe::builder()
+            .title("Base")
+            .description("Base recipe")
+            .instructions("Base instructions")
+            .activities(vec!["Base activity".to_string()])
+            .build()
+            .unwrap();
+        let recipe = Recipe::builder()
+            .title("Child")
+            .description("Child recipe")
+            .prompt("Child prompt")
+            .extends("base")
+            .instructions_merge(InstructionsMerge::Override)
+            .build()
+            .unwrap();
+
+        let merged = merge_recipe(base, recipe);
+
+        assert_eq!(merged.instructions.as_deref(), Some("Base instructions"));
+        assert_eq!(merged.prompt.as_deref(), Some("Child prompt"));
+        assert_eq!(merged.activities, Some(vec!["Base activity".to_string()]));
+        assert_eq!(merged.extends, None);
+    }
+}
+
+mod sub_recipe_steps_tests {
+    use super::*;
+
+    fn build_recipe_with_sub_recipes(
+        sub_recipes: &str,
+    ) -> Result<crate::recipe::Recipe, RecipeError> {
+        let (_temp_dir, recipe_file) = setup_yaml_recipe_file(&format!(
+            "instructions: Run the steps\nsub_recipes:\n{}",
+            sub_recipes
+        ));
+        build_recipe_from_template(recipe_file, Vec::new(), NO_USER_PROMPT)
+    }
+
+    #[test]
+    fn test_build_recipe_with_step_output_references() {
+        let recipe = build_recipe_with_sub_recipes(
+            r#"  - name: research
+    path: research.yaml
+  - name: outline
+    path: outline.yaml
+  - name: write
+    path: write.yaml
+    depends_on: [research, outline]
+    values:
+      topic: "{{ steps.research.output.summary }}"
+      outline: "{{ steps.outline.output }}"
+"#,
+        )
+        .unwrap();
+
+        let sub_recipes = recipe.sub_recipes.unwrap();
+        assert_eq!(
+            sub_recipes[2].depends_on,
+            Some(vec!["research".to_string(), "outline".to_string()])
+        );
+        let values = sub_recipes[2].values.as_ref().unwrap();
+        assert_eq!(values["topic"], "{{ steps.research.output.summary }}");
+        assert_eq!(values["outline"], "{{ steps.outline.output }}");
+    }
+
+    #[test]
+    fn test_build_recipe_with_unknown_step_dependency() {
+        let err = build_recipe_with_sub_recipes(
+            r#"  - name: write
+    path: write.yaml
+    depends_on: [research]
+"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid sub-recipe steps: Step 'write' depends on unknown step 'research'"
+        );
+    }
+
+    #[test]
+    fn test_build_recipe_with_step_cycle() {
+        let err = build_recipe_with_sub_recipes(
+            r#"  - name: research
+    path: research.yaml
+    depends_on: [write]
+  - name: write
+    path: write.yaml
+    depends_on: [research]
+"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid sub-recipe steps: Step cycle detected: research -> write -> research"
+        );
+    }
+
+    #[test]
+    fn test_build_recipe_with_output_of_unrelated_step() {
+        let err = build_recipe_with_sub_recipes(
+            r#"  - name: research
+    path: research.yaml
+  - name: outline
+    path: outline.yaml
+  - name: write
+    path: write.yaml
+    depends_on: [outline]
+    values:
+      topic: "{{ steps.research.output.summary }}"
+"#,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid sub-recipe steps: Step 'write' uses the output of step 'research' without depending on it"
+        );
+    }
+}
+
+mod parameter_validation_tests {
+    use super::*;
+
+    #[test]
+    fn test_build_recipe_with_invalid_parameter_values() {
+        let (_temp_dir, recipe_file) = setup_yaml_recipe_file(
+            r#"instructions: Write {{ count }} posts about {{ topic }}
+parameters:
+  - key: count
+    input_type: number
+    requirement: required
+    description: Number of posts
+    min: 1
+    max: 5
+  - key: topic
+    input_type: string
+    requirement: required
+    description: Topic
+    pattern: "^[a-z ]+$"
+"#,
+        );
+        let params = vec![
+            ("count".to_string(), "7".to_string()),
+            ("topic".to_string(), "Rust!".to_string()),
+        ];
+
+        let result = build_recipe_from_template(recipe_file, params, NO_USER_PROMPT);
+
+        match result {
+            Err(RecipeError::InvalidParams { errors }) => {
+                let keys: Vec<&str> = errors.iter().map(|e| e.key.as_str()).collect();
+                assert_eq!(keys, vec!["count", "topic"]);
+                assert_eq!(errors[0].message, "must be at most 5");
+            }
+            other => panic!("Expected InvalidParams error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_build_recipe_with_list_and_map_parameters() {
+        let (_temp_dir, recipe_file) = setup_yaml_recipe_file(
+            r#"instructions: Tag {{ tags }} with {{ labels }}
+parameters:
+  - key: tags
+    input_type: multi_select
+    requirement: required
+    description: Tags
+    options: [bug, feature, docs]
+  - key: labels
+    input_type: map
+    requirement: optional
+    description: Labels
+    default: "team=core"
+"#,
+        );
+        let params = vec![("tags".to_string(), "bug, docs".to_string())];
+
+        let recipe = build_recipe_from_template(recipe_file, params, NO_USER_PROMPT).unwrap();
+
+        assert_eq!(recipe.instructions.unwrap(), "Tag bug, docs with team=core");
+    }
+
+    #[test]
+    fn test_build_recipe_with_invalid_parameter_definition() {
+        let (_temp_dir, recipe_file) = setup_yaml_recipe_file(
+            r#"instructions: Use {{ color }}
+parameters:
+  - key: color
+    input_type: select
+    requirement: required
+    description: Color
+"#,
+        );
+        let params = vec![("color".to_string(), "red".to_string())];
+
+        let result = build_recipe_from_template(recipe_file, params, NO_USER_PROMPT);
+
+        match result {
+            Err(RecipeError::TemplateRendering { source }) => {
+                assert_eq!(
+                    source.to_string(),
+                    "Parameter 'color' of type select needs options"
+                );
+            }
+            other => panic!("Expected TemplateRendering error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_build_recipe_rejects_secret_passed_as_value() {
+        let (_temp_dir, recipe_file) = setup_yaml_recipe_file(
+            r#"instructions: Call the API with {{ api_token }}
+parameters:
+  - key: api_token
+    input_type: secret
+    requirement: required
+    description: API token
+"#,
+        );
+        let params = vec![("api_token".to_string(), "hunter2".to_string())];
+
+        let result = build_recipe_from_template(recipe_file, params, NO_USER_PROMPT);
+
+        match result {
+            Err(RecipeError::TemplateRendering { source }) => {
+                let message = source.to_string();
+                assert!(message.contains("Secret parameter 'api_token' cannot be passed"));
+                assert!(!message.contains("hunter2"));
+            }
+            other => panic!("Expected TemplateRendering error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_build_recipe_keeps_secret_out_of_rendered_text() {
+        let secret = "s3cr3t-deploy-token";
+        let (_temp_dir, recipe_file) = setup_yaml_recipe_file(
+            r#"instructions: Authenticate with {{ deploy_token }}
+prompt: Deploy using {{ deploy_token }}
+extensions:
+  - type: stdio
+    name: deployer
+    cmd: deploy-mcp
+    args: []
+parameters:
+  - key: deploy_token
+    input_type: secret
+    requirement: required
+    description: Deploy token
+"#,
+        );
+        let parameters = validate_recipe_chain_parameters(std::slice::from_ref(&recipe_file))
+            .unwrap()
+            .unwrap();
+        let values = HashMap::from([("deploy_token".to_string(), secret.to_string())]);
+
+        let recipe = render_recipe_chain(&[recipe_file], values, "Deploy", &parameters).unwrap();
+
+        assert_eq!(
+            recipe.prompt.as_deref(),
+            Some("Deploy using $GOOSE_RECIPE_DEPLOY__DEPLOY_TOKEN")
+        );
+        // Sessions store the recipe, and the conversation starts from its prompt
+        let first_message = Message::user().with_text(recipe.prompt.clone().unwrap());
+        let stored = format!(
+            "{}{}",
+            serde_json::to_string(&recipe).unwrap(),
+            serde_json::to_string(&first_message).unwrap()
+        );
+        assert!(!stored.contains(secret));
+        // Only the recipe's own extension reads the secret from the keyring when it starts
+        match &recipe.extensions.unwrap()[0] {
+            ExtensionConfig::Stdio { env_keys, .. } => {
+                assert_eq!(
+                    env_keys,
+                    &vec!["GOOSE_RECIPE_DEPLOY__DEPLOY_TOKEN".to_string()]
+                )
+            }
+            other => panic!("Expected stdio extension, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_recipe_secret_key_is_scoped_to_recipe() {
+        assert_eq!(
+            recipe_secret_key("Deploy the service!", "openai_api_key"),
+            "GOOSE_RECIPE_DEPLOY_THE_SERVICE__OPENAI_API_KEY"
+        );
+    }
+}
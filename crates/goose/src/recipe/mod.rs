@@ -3,8 +3,10 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt;
 
+use crate::agents::budget::BudgetLimits;
 use crate::agents::extension::ExtensionConfig;
 use crate::agents::types::RetryConfig;
+use crate::context_mgmt::strategy::ContextStrategy;
 use crate::utils::contains_unicode_tags;
 use serde::de::Deserializer;
 use serde::{Deserialize, Serialize};
@@ -15,6 +17,7 @@ pub mod read_recipe_file_content;
 pub mod template_recipe;
 
 pub const BUILT_IN_RECIPE_DIR_PARAM: &str = "recipe_dir";
+pub const STEP_OUTPUTS_TEMPLATE_VARIABLE: &str = "steps";
 
 fn default_version() -> String {
     "1.0.0".to_string()
@@ -40,6 +43,8 @@ fn default_version() -> String {
 /// * `parameters` - Additional parameters for the Recipe
 /// * `response` - Response configuration including JSON schema validation
 /// * `retry` - Retry configuration for automated validation and recovery
+/// * `extends` - Path or name of a recipe this Recipe inherits from
+/// * `instructions_merge` - Whether instructions are concatenated with or override the extended Recipe's
 /// # Example
 ///
 ///
@@ -69,6 +74,8 @@ fn default_version() -> String {
 ///     response: None,
 ///     sub_recipes: None,
 ///     retry: None,
+///     extends: None,
+///     instructions_merge: None,
 /// };
 ///
 #[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
@@ -115,6 +122,12 @@ pub struct Recipe {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retry: Option<RetryConfig>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>, // path or name of the recipe this one inherits from
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions_merge: Option<InstructionsMerge>, // how instructions combine with the extended recipe
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
@@ -136,6 +149,23 @@ pub struct Settings {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetLimits>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_strategy: Option<ContextStrategy>,
+}
+
+/// How a recipe's instructions combine with those of the recipe it extends
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InstructionsMerge {
+    /// Append the recipe's instructions after the extended recipe's instructions
+    #[default]
+    Concatenate,
+    /// Replace the extended recipe's instructions entirely
+    Override,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
@@ -154,6 +184,8 @@ pub struct SubRecipe {
     pub sequential_when_repeated: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<Vec<String>>,
 }
 
 fn deserialize_value_map_as_string<'de, D>(
@@ -210,6 +242,14 @@ pub enum RecipeParameterInputType {
     /// Cannot have default values to prevent importing sensitive user files.
     File,
     Select,
+    /// Any number of the `options`, given as a JSON array or comma-separated values.
+    MultiSelect,
+    /// List of values, given as a JSON array or comma-separated values.
+    List,
+    /// Key-value map, given as a JSON object or comma-separated `key=value` pairs.
+    Map,
+    /// Secret read from the keyring. Never passed as a value, rendered into logs or exported.
+    Secret,
 }
 
 impl fmt::Display for RecipeParameterInputType {
@@ -232,6 +272,27 @@ pub struct RecipeParameter {
     pub default: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<String>>,
+    /// Regular expression that string values, or every item of a list, must match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Smallest allowed value of a number parameter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    /// Largest allowed value of a number parameter
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// Minimum length of a string value, or minimum number of items of a list, map or multi-select
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+    /// Maximum length of a string value, or maximum number of items of a list, map or multi-select
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+}
+
+impl RecipeParameter {
+    pub fn is_secret(&self) -> bool {
+        matches!(self.input_type, RecipeParameterInputType::Secret)
+    }
 }
 
 /// Builder for creating Recipe instances
@@ -253,6 +314,8 @@ pub struct RecipeBuilder {
     response: Option<Response>,
     sub_recipes: Option<Vec<SubRecipe>>,
     retry: Option<RetryConfig>,
+    extends: Option<String>,
+    instructions_merge: Option<InstructionsMerge>,
 }
 
 impl Recipe {
@@ -305,6 +368,8 @@ impl Recipe {
             response: None,
             sub_recipes: None,
             retry: None,
+            extends: None,
+            instructions_merge: None,
         }
     }
     pub fn from_content(content: &str) -> Result<Self> {
@@ -421,6 +486,18 @@ impl RecipeBuilder {
         self
     }
 
+    /// Sets the recipe this Recipe extends
+    pub fn extends(mut self, extends: impl Into<String>) -> Self {
+        self.extends = Some(extends.into());
+        self
+    }
+
+    /// Sets how instructions combine with the extended Recipe's instructions
+    pub fn instructions_merge(mut self, instructions_merge: InstructionsMerge) -> Self {
+        self.instructions_merge = Some(instructions_merge);
+        self
+    }
+
     /// Builds the Recipe instance
     ///
     /// Returns an error if any required fields are missing
@@ -447,6 +524,8 @@ impl RecipeBuilder {
             response: self.response,
             sub_recipes: self.sub_recipes,
             retry: self.retry,
+            extends: self.extends,
+            instructions_merge: self.instructions_merge,
         })
     }
 }
@@ -786,6 +865,8 @@ isGlobal: true"#;
             response: None,
             sub_recipes: None,
             retry: None,
+            extends: None,
+            instructions_merge: None,
         };
 
         assert!(!recipe.check_for_security_warnings());
@@ -3,7 +3,7 @@ use std::{
     path::Path,
 };
 
-use crate::recipe::{Recipe, BUILT_IN_RECIPE_DIR_PARAM};
+use crate::recipe::{Recipe, BUILT_IN_RECIPE_DIR_PARAM, STEP_OUTPUTS_TEMPLATE_VARIABLE};
 use anyhow::Result;
 use minijinja::{Environment, UndefinedBehavior};
 use regex::Regex;
@@ -15,8 +15,13 @@ const CLOSE_BRACE: &str = "}}";
 fn preprocess_template_variables(content: &str) -> Result<String> {
     let all_template_variables = extract_template_variables(content);
     let complex_template_variables = filter_complex_variables(&all_template_variables);
-    let unparsable_template_variables = filter_unparseable_variables(&complex_template_variables)?;
-    replace_unparseable_vars_with_raw(content, &unparsable_template_variables)
+    let mut raw_template_variables = filter_unparseable_variables(&complex_template_variables)?;
+    for var in filter_step_output_variables(&complex_template_variables) {
+        if !raw_template_variables.contains(&var) {
+            raw_template_variables.push(var);
+        }
+    }
+    replace_unparseable_vars_with_raw(content, &raw_template_variables)
 }
 
 fn extract_template_variables(content: &str) -> Vec<String> {
@@ -37,6 +42,20 @@ fn filter_complex_variables(template_variables: &[String]) -> Vec<String> {
         .collect()
 }
 
+// references to sub-recipe step outputs are only rendered when the steps run
+fn filter_step_output_variables(template_variables: &[String]) -> Vec<String> {
+    let step_output_re = Regex::new(&format!(
+        r"^\s*{}\.",
+        regex::escape(STEP_OUTPUTS_TEMPLATE_VARIABLE)
+    ))
+    .unwrap();
+    template_variables
+        .iter()
+        .filter(|var| step_output_re.is_match(var))
+        .cloned()
+        .collect()
+}
+
 fn filter_unparseable_variables(template_variables: &[String]) -> Result<Vec<String>> {
     let mut vars_to_convert = Vec::new();
 
@@ -280,6 +299,20 @@ mod tests {
             assert_eq!(result, "Hello and {{invalid var}}");
         }
 
+        #[test]
+        fn test_render_content_keeps_step_output_references() {
+            let content = "topic: {{ steps.research.output.summary }}\nnotes: {{ steps.research.output.summary }} by {{ name }}";
+            let params = HashMap::from([
+                ("recipe_dir".to_string(), "some_dir".to_string()),
+                ("name".to_string(), "Alice".to_string()),
+            ]);
+            let result = render_recipe_content_with_params(content, &params).unwrap();
+            assert_eq!(
+                result,
+                "topic: {{ steps.research.output.summary }}\nnotes: {{ steps.research.output.summary }} by Alice"
+            );
+        }
+
         #[test]
         fn test_empty_prompt() {
             let content = r#"
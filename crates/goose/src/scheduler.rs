@@ -7,25 +7,44 @@ use std::sync::Arc;
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use etcetera::{choose_app_strategy, AppStrategy};
 use serde::{Deserialize, Serialize};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, MutexGuard};
 use tokio_cron_scheduler::{job::JobId, Job, JobScheduler as TokioJobScheduler};
 
 use crate::agents::AgentEvent;
-use crate::agents::{Agent, SessionConfig};
+use crate::agents::{Agent, BudgetLimits, SessionConfig};
 use crate::config::{self, Config};
 use crate::conversation::message::Message;
 use crate::conversation::Conversation;
 use crate::providers::base::Provider as GooseProvider; // Alias to avoid conflict in test section
 use crate::providers::create;
-use crate::recipe::Recipe;
+use crate::recipe::build_recipe::{
+    build_recipe_from_template, load_recipe_chain, load_recipe_from_chain,
+};
+use crate::recipe::read_recipe_file_content::{read_recipe_file, RecipeFile};
+use crate::recipe::{Recipe, RecipeParameter};
+use crate::scheduler_history::{
+    RetryPolicy, RunHistory, ScheduleHook, ScheduledJobRun, ScheduledRunStatus,
+};
+use crate::scheduler_policy::{
+    active_blackout_window, jitter_delay, parse_timezone, BlackoutWindow, OverlapPolicy,
+};
 use crate::scheduler_trait::SchedulerTrait;
+use crate::scheduler_trigger::{deserialize_trigger, spawn_trigger_watcher, ScheduleTrigger};
+use crate::session::extension_data::{ExtensionState, SuccessCheckState};
 use crate::session::{Session, SessionManager};
 
 // Track running tasks with their abort handles
 type RunningTasksMap = HashMap<String, tokio::task::AbortHandle>;
-type JobsMap = HashMap<String, (JobId, ScheduledJob)>;
+// Only cron jobs have a tokio-cron-scheduler job
+type JobsMap = HashMap<String, (Option<JobId>, ScheduledJob)>;
+type RunGatesMap = HashMap<String, Arc<RunGate>>;
+type TriggerWatchersMap = HashMap<String, tokio::task::AbortHandle>;
+
+/// Files a recipe package directory holds its recipe in
+const PACKAGE_RECIPE_FILE_NAMES: [&str; 2] = ["recipe.yaml", "recipe.json"];
 
 /// Normalize a cron string so that:
 /// 1. It is always in **quartz 7-field format** expected by Temporal
@@ -94,6 +113,10 @@ pub enum SchedulerError {
     PersistError(String),
     CronParseError(String),
     SchedulerInternalError(String),
+    /// The configured scheduler can't do what was asked, e.g. a job option it has no support for
+    Unsupported(String),
+    /// A job setting such as a hook, timezone, blackout window or trigger is invalid
+    InvalidJob(String),
     AnyhowError(anyhow::Error),
 }
 
@@ -110,6 +133,8 @@ impl std::fmt::Display for SchedulerError {
             SchedulerError::SchedulerInternalError(e) => {
                 write!(f, "Scheduler internal error: {}", e)
             }
+            SchedulerError::Unsupported(e) => write!(f, "Not supported by this scheduler: {}", e),
+            SchedulerError::InvalidJob(e) => write!(f, "Invalid job: {}", e),
             SchedulerError::AnyhowError(e) => write!(f, "Scheduler operation failed: {}", e),
         }
     }
@@ -147,7 +172,10 @@ impl From<anyhow::Error> for SchedulerError {
 pub struct ScheduledJob {
     pub id: String,
     pub source: String,
-    pub cron: String,
+    /// What starts the job's runs; schedules stored before triggers existed have a plain
+    /// `cron` expression instead
+    #[serde(alias = "cron", deserialize_with = "deserialize_trigger")]
+    pub trigger: ScheduleTrigger,
     pub last_run: Option<DateTime<Utc>>,
     #[serde(default)]
     pub currently_running: bool,
@@ -159,6 +187,68 @@ pub struct ScheduledJob {
     pub process_start_time: Option<DateTime<Utc>>,
     #[serde(default)]
     pub execution_mode: Option<String>, // "foreground" or "background"
+    /// Caps on the combined usage of this job's runs over the trailing 24 hours
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<BudgetLimits>,
+    /// Retry failed runs with backoff before giving up
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+    /// Fired once a run succeeds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_success: Option<ScheduleHook>,
+    /// Fired once a run has failed and has no retries left
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<ScheduleHook>,
+    /// Values for the recipe's parameters, so that one recipe can serve several schedules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<HashMap<String, String>>,
+    /// What to do when the schedule fires while its previous run is still going; skips by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overlap_policy: Option<OverlapPolicy>,
+    /// IANA timezone the cron expression is evaluated in; UTC by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Periods in which the schedule does not start runs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blackout_windows: Option<Vec<BlackoutWindow>>,
+    /// Delay every scheduled run by a random amount of up to this many seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jitter_seconds: Option<u64>,
+    /// File and git triggers start a run once their source has been quiet for this many
+    /// seconds; 2 by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debounce_seconds: Option<u64>,
+    /// Directory the recipe was scheduled from. The stored copy of the recipe resolves the
+    /// recipes it extends, its sub-recipes and `recipe_dir` against it, as the original did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipe_dir: Option<String>,
+}
+
+/// Serializes the runs of one job according to its overlap policy
+#[derive(Default)]
+struct RunGate {
+    running: Mutex<()>,
+    queued: Mutex<()>,
+}
+
+/// Everything a firing trigger needs to run a job and track its state
+#[derive(Clone)]
+struct TriggerContext {
+    jobs: Arc<Mutex<JobsMap>>,
+    storage_path: PathBuf,
+    running_tasks: Arc<Mutex<RunningTasksMap>>,
+    run_gates: Arc<Mutex<RunGatesMap>>,
+}
+
+impl TriggerContext {
+    async fn run_gate(&self, job_id: &str) -> Arc<RunGate> {
+        self.run_gates
+            .lock()
+            .await
+            .entry(job_id.to_string())
+            .or_default()
+            .clone()
+    }
 }
 
 async fn persist_jobs_from_arc(
@@ -175,11 +265,28 @@ async fn persist_jobs_from_arc(
     Ok(())
 }
 
+/// Reads the jobs saved in the scheduler's storage file
+pub fn read_stored_jobs(storage_path: &Path) -> Result<Vec<ScheduledJob>, SchedulerError> {
+    if !storage_path.exists() {
+        return Ok(Vec::new());
+    }
+    let data = fs::read_to_string(storage_path)?;
+    if data.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    serde_json::from_str(&data).map_err(|e| {
+        SchedulerError::PersistError(format!("Failed to deserialize schedules.json: {}", e))
+    })
+}
+
 pub struct Scheduler {
     internal_scheduler: TokioJobScheduler,
     jobs: Arc<Mutex<JobsMap>>,
     storage_path: PathBuf,
     running_tasks: Arc<Mutex<RunningTasksMap>>,
+    run_gates: Arc<Mutex<RunGatesMap>>,
+    trigger_watchers: Arc<Mutex<TriggerWatchersMap>>,
 }
 
 impl Scheduler {
@@ -190,12 +297,16 @@ impl Scheduler {
 
         let jobs = Arc::new(Mutex::new(HashMap::new()));
         let running_tasks = Arc::new(Mutex::new(HashMap::new()));
+        let run_gates = Arc::new(Mutex::new(HashMap::new()));
+        let trigger_watchers = Arc::new(Mutex::new(HashMap::new()));
 
         let arc_self = Arc::new(Self {
             internal_scheduler,
             jobs,
             storage_path,
             running_tasks,
+            run_gates,
+            trigger_watchers,
         });
 
         arc_self.load_jobs_from_storage().await?;
@@ -208,6 +319,66 @@ impl Scheduler {
         Ok(arc_self)
     }
 
+    fn trigger_context(&self) -> TriggerContext {
+        TriggerContext {
+            jobs: self.jobs.clone(),
+            storage_path: self.storage_path.clone(),
+            running_tasks: self.running_tasks.clone(),
+            run_gates: self.run_gates.clone(),
+        }
+    }
+
+    /// Starts listening for the job's trigger and returns the tokio-cron-scheduler job of a
+    /// cron trigger. File and git triggers get a watcher; webhooks are started by goose-server.
+    async fn start_trigger(&self, job: &ScheduledJob) -> Result<Option<JobId>, SchedulerError> {
+        match &job.trigger {
+            ScheduleTrigger::Cron { expression } => {
+                tracing::info!("Attempting to parse cron expression: '{}'", expression);
+                let cron_task = create_cron_task(
+                    expression,
+                    job.timezone.as_deref(),
+                    job.id.clone(),
+                    self.trigger_context(),
+                )?;
+                let job_uuid = self
+                    .internal_scheduler
+                    .add(cron_task)
+                    .await
+                    .map_err(|e| SchedulerError::SchedulerInternalError(e.to_string()))?;
+                Ok(Some(job_uuid))
+            }
+            trigger => {
+                let job_id = job.id.clone();
+                let context = self.trigger_context();
+                let watcher =
+                    spawn_trigger_watcher(&job.id, trigger, job.debounce_seconds, move |params| {
+                        run_triggered_job(job_id.clone(), Some(params), context.clone())
+                    })
+                    .map_err(|e| SchedulerError::InvalidJob(e.to_string()))?;
+                if let Some(abort_handle) = watcher {
+                    self.trigger_watchers
+                        .lock()
+                        .await
+                        .insert(job.id.clone(), abort_handle);
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    async fn stop_trigger(&self, id: &str, job_uuid: Option<JobId>) -> Result<(), SchedulerError> {
+        if let Some(job_uuid) = job_uuid {
+            self.internal_scheduler
+                .remove(&job_uuid)
+                .await
+                .map_err(|e| SchedulerError::SchedulerInternalError(e.to_string()))?;
+        }
+        if let Some(abort_handle) = self.trigger_watchers.lock().await.remove(id) {
+            abort_handle.abort();
+        }
+        Ok(())
+    }
+
     pub async fn add_scheduled_job(
         &self,
         original_job_spec: ScheduledJob,
@@ -217,199 +388,63 @@ impl Scheduler {
             return Err(SchedulerError::JobIdExists(original_job_spec.id.clone()));
         }
 
-        let original_recipe_path = Path::new(&original_job_spec.source);
-        if !original_recipe_path.exists() {
+        for hook in [&original_job_spec.on_success, &original_job_spec.on_failure]
+            .into_iter()
+            .flatten()
+        {
+            hook.validate().map_err(SchedulerError::InvalidJob)?;
+        }
+        for window in original_job_spec.blackout_windows.iter().flatten() {
+            window.validate().map_err(SchedulerError::InvalidJob)?;
+        }
+        if let Some(timezone) = &original_job_spec.timezone {
+            parse_timezone(timezone).map_err(|e| SchedulerError::InvalidJob(e.to_string()))?;
+        }
+
+        let source_path = Path::new(&original_job_spec.source);
+        if !source_path.exists() {
             return Err(SchedulerError::RecipeLoadError(format!(
                 "Original recipe file not found: {}",
                 original_job_spec.source
             )));
         }
-        if !original_recipe_path.is_file() {
-            return Err(SchedulerError::RecipeLoadError(format!(
-                "Original recipe source is not a file: {}",
-                original_job_spec.source
-            )));
+        let original_recipe_path = resolve_recipe_source(source_path)?;
+        // Event-triggered runs add the event's values, so only cron jobs can be checked up front
+        if let (Some(parameters), ScheduleTrigger::Cron { .. }) =
+            (&original_job_spec.parameters, &original_job_spec.trigger)
+        {
+            read_recipe_file(&original_recipe_path)
+                .and_then(|recipe_file| render_scheduled_recipe(recipe_file, parameters))
+                .map_err(|e| {
+                    SchedulerError::RecipeLoadError(format!(
+                        "Schedule parameters don't fit recipe {}: {}",
+                        original_job_spec.source, e
+                    ))
+                })?;
         }
-
-        let scheduled_recipes_dir = get_default_scheduled_recipes_dir()?;
-        let original_extension = original_recipe_path
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .unwrap_or("yaml");
-
-        let destination_filename = format!("{}.{}", original_job_spec.id, original_extension);
-        let destination_recipe_path = scheduled_recipes_dir.join(destination_filename);
-
-        tracing::info!(
-            "Copying recipe from {} to {}",
-            original_recipe_path.display(),
-            destination_recipe_path.display()
-        );
-        fs::copy(original_recipe_path, &destination_recipe_path).map_err(|e| {
-            SchedulerError::StorageError(io::Error::new(
-                e.kind(),
-                format!(
-                    "Failed to copy recipe from {} to {}: {}",
-                    original_job_spec.source,
-                    destination_recipe_path.display(),
-                    e
-                ),
-            ))
-        })?;
+        // The recipes it extends must resolve, as they are looked up again on every run
+        read_recipe_file(&original_recipe_path)
+            .and_then(|recipe_file| Ok(load_recipe_chain(recipe_file)?))
+            .map_err(|e| {
+                SchedulerError::RecipeLoadError(format!(
+                    "Failed to load recipe {}: {}",
+                    original_job_spec.source, e
+                ))
+            })?;
+        let (destination_recipe_path, original_recipe_dir) = store_scheduled_recipe(
+            &original_job_spec,
+            &original_recipe_path,
+            &get_default_scheduled_recipes_dir()?,
+        )?;
 
         let mut stored_job = original_job_spec.clone();
         stored_job.source = destination_recipe_path.to_string_lossy().into_owned();
+        stored_job.recipe_dir = original_recipe_dir;
         stored_job.current_session_id = None;
         stored_job.process_start_time = None;
         tracing::info!("Updated job source path to: {}", stored_job.source);
 
-        let job_for_task = stored_job.clone();
-        let jobs_arc_for_task = self.jobs.clone();
-        let storage_path_for_task = self.storage_path.clone();
-        let running_tasks_for_task = self.running_tasks.clone();
-
-        tracing::info!("Attempting to parse cron expression: '{}'", stored_job.cron);
-        let normalized_cron = normalize_cron_expression(&stored_job.cron);
-        // Convert from 7-field (Temporal format) to 6-field (tokio-cron-scheduler format)
-        let tokio_cron = {
-            let parts: Vec<&str> = normalized_cron.split_whitespace().collect();
-            if parts.len() == 7 {
-                parts[..6].join(" ")
-            } else {
-                normalized_cron.clone()
-            }
-        };
-        if tokio_cron != stored_job.cron {
-            tracing::info!(
-                "Converted cron expression from '{}' to '{}' for tokio-cron-scheduler",
-                stored_job.cron,
-                tokio_cron
-            );
-        }
-        let cron_task = Job::new_async(&tokio_cron, move |_uuid, _l| {
-            let task_job_id = job_for_task.id.clone();
-            let current_jobs_arc = jobs_arc_for_task.clone();
-            let local_storage_path = storage_path_for_task.clone();
-            let job_to_execute = job_for_task.clone(); // Clone for run_scheduled_job_internal
-            let running_tasks_arc = running_tasks_for_task.clone();
-
-            Box::pin(async move {
-                // Check if the job is paused before executing
-                let should_execute = {
-                    let jobs_map_guard = current_jobs_arc.lock().await;
-                    if let Some((_, current_job_in_map)) = jobs_map_guard.get(&task_job_id) {
-                        !current_job_in_map.paused
-                    } else {
-                        false
-                    }
-                };
-
-                if !should_execute {
-                    tracing::info!("Skipping execution of paused job '{}'", &task_job_id);
-                    return;
-                }
-
-                let current_time = Utc::now();
-                let mut needs_persist = false;
-                {
-                    let mut jobs_map_guard = current_jobs_arc.lock().await;
-                    if let Some((_, current_job_in_map)) = jobs_map_guard.get_mut(&task_job_id) {
-                        current_job_in_map.last_run = Some(current_time);
-                        current_job_in_map.currently_running = true;
-                        current_job_in_map.process_start_time = Some(current_time);
-                        needs_persist = true;
-                    }
-                }
-
-                if needs_persist {
-                    if let Err(e) =
-                        persist_jobs_from_arc(&local_storage_path, &current_jobs_arc).await
-                    {
-                        tracing::error!(
-                            "Failed to persist last_run update for job {}: {}",
-                            &task_job_id,
-                            e
-                        );
-                    }
-                }
-
-                // Spawn the job execution as an abortable task
-                let job_task = tokio::spawn(run_scheduled_job_internal(
-                    job_to_execute.clone(),
-                    None,
-                    Some(current_jobs_arc.clone()),
-                    Some(task_job_id.clone()),
-                ));
-
-                // Store the abort handle at the scheduler level
-                {
-                    let mut running_tasks_guard = running_tasks_arc.lock().await;
-                    running_tasks_guard.insert(task_job_id.clone(), job_task.abort_handle());
-                }
-
-                // Wait for the job to complete or be aborted
-                let result = job_task.await;
-
-                // Remove the abort handle
-                {
-                    let mut running_tasks_guard = running_tasks_arc.lock().await;
-                    running_tasks_guard.remove(&task_job_id);
-                }
-
-                // Update the job status after execution
-                {
-                    let mut jobs_map_guard = current_jobs_arc.lock().await;
-                    if let Some((_, current_job_in_map)) = jobs_map_guard.get_mut(&task_job_id) {
-                        current_job_in_map.currently_running = false;
-                        current_job_in_map.current_session_id = None;
-                        current_job_in_map.process_start_time = None;
-                        needs_persist = true;
-                    }
-                }
-
-                if needs_persist {
-                    if let Err(e) =
-                        persist_jobs_from_arc(&local_storage_path, &current_jobs_arc).await
-                    {
-                        tracing::error!(
-                            "Failed to persist running status update for job {}: {}",
-                            &task_job_id,
-                            e
-                        );
-                    }
-                }
-
-                match result {
-                    Ok(Ok(_session_id)) => {
-                        tracing::info!("Scheduled job '{}' completed successfully", &task_job_id);
-                    }
-                    Ok(Err(e)) => {
-                        tracing::error!(
-                            "Scheduled job '{}' execution failed: {}",
-                            &e.job_id,
-                            e.error
-                        );
-                    }
-                    Err(join_error) if join_error.is_cancelled() => {
-                        tracing::info!("Scheduled job '{}' was cancelled/killed", &task_job_id);
-                    }
-                    Err(join_error) => {
-                        tracing::error!(
-                            "Scheduled job '{}' task failed: {}",
-                            &task_job_id,
-                            join_error
-                        );
-                    }
-                }
-            })
-        })
-        .map_err(|e| SchedulerError::CronParseError(e.to_string()))?;
-
-        let job_uuid = self
-            .internal_scheduler
-            .add(cron_task)
-            .await
-            .map_err(|e| SchedulerError::SchedulerInternalError(e.to_string()))?;
+        let job_uuid = self.start_trigger(&stored_job).await?;
 
         jobs_guard.insert(stored_job.id.clone(), (job_uuid, stored_job));
         // Pass the jobs_guard by reference for the initial persist after adding a job
@@ -418,17 +453,7 @@ impl Scheduler {
     }
 
     async fn load_jobs_from_storage(self: &Arc<Self>) -> Result<(), SchedulerError> {
-        if !self.storage_path.exists() {
-            return Ok(());
-        }
-        let data = fs::read_to_string(&self.storage_path)?;
-        if data.trim().is_empty() {
-            return Ok(());
-        }
-
-        let list: Vec<ScheduledJob> = serde_json::from_str(&data).map_err(|e| {
-            SchedulerError::PersistError(format!("Failed to deserialize schedules.json: {}", e))
-        })?;
+        let list = read_stored_jobs(&self.storage_path)?;
 
         let mut jobs_guard = self.jobs.lock().await;
         for job_to_load in list {
@@ -437,160 +462,23 @@ impl Scheduler {
                 continue;
             }
 
-            let job_for_task = job_to_load.clone();
-            let jobs_arc_for_task = self.jobs.clone();
-            let storage_path_for_task = self.storage_path.clone();
-            let running_tasks_for_task = self.running_tasks.clone();
-
             tracing::info!(
-                "Loading job '{}' with cron expression: '{}'",
+                "Loading job '{}' triggered by {}",
                 job_to_load.id,
-                job_to_load.cron
+                job_to_load.trigger
             );
-            let normalized_cron = normalize_cron_expression(&job_to_load.cron);
-            // Convert from 7-field (Temporal format) to 6-field (tokio-cron-scheduler format)
-            let tokio_cron = {
-                let parts: Vec<&str> = normalized_cron.split_whitespace().collect();
-                if parts.len() == 7 {
-                    parts[..6].join(" ")
-                } else {
-                    normalized_cron.clone()
+            let job_uuid = match self.start_trigger(&job_to_load).await {
+                Ok(job_uuid) => job_uuid,
+                Err(e) if job_to_load.trigger.cron_expression().is_none() => {
+                    tracing::warn!(
+                        "Could not watch the trigger of scheduled job {}: {}",
+                        job_to_load.id,
+                        e
+                    );
+                    None
                 }
+                Err(e) => return Err(e),
             };
-            if tokio_cron != job_to_load.cron {
-                tracing::info!(
-                    "Converted cron expression from '{}' to '{}' for tokio-cron-scheduler",
-                    job_to_load.cron,
-                    tokio_cron
-                );
-            }
-            let cron_task = Job::new_async(&tokio_cron, move |_uuid, _l| {
-                let task_job_id = job_for_task.id.clone();
-                let current_jobs_arc = jobs_arc_for_task.clone();
-                let local_storage_path = storage_path_for_task.clone();
-                let job_to_execute = job_for_task.clone(); // Clone for run_scheduled_job_internal
-                let running_tasks_arc = running_tasks_for_task.clone();
-
-                Box::pin(async move {
-                    // Check if the job is paused before executing
-                    let should_execute = {
-                        let jobs_map_guard = current_jobs_arc.lock().await;
-                        if let Some((_, stored_job)) = jobs_map_guard.get(&task_job_id) {
-                            !stored_job.paused
-                        } else {
-                            false
-                        }
-                    };
-
-                    if !should_execute {
-                        tracing::info!("Skipping execution of paused job '{}'", &task_job_id);
-                        return;
-                    }
-
-                    let current_time = Utc::now();
-                    let mut needs_persist = false;
-                    {
-                        let mut jobs_map_guard = current_jobs_arc.lock().await;
-                        if let Some((_, stored_job)) = jobs_map_guard.get_mut(&task_job_id) {
-                            stored_job.last_run = Some(current_time);
-                            stored_job.currently_running = true;
-                            stored_job.process_start_time = Some(current_time);
-                            needs_persist = true;
-                        }
-                    }
-
-                    if needs_persist {
-                        if let Err(e) =
-                            persist_jobs_from_arc(&local_storage_path, &current_jobs_arc).await
-                        {
-                            tracing::error!(
-                                "Failed to persist last_run update for loaded job {}: {}",
-                                &task_job_id,
-                                e
-                            );
-                        }
-                    }
-
-                    // Spawn the job execution as an abortable task
-                    let job_task = tokio::spawn(run_scheduled_job_internal(
-                        job_to_execute,
-                        None,
-                        Some(current_jobs_arc.clone()),
-                        Some(task_job_id.clone()),
-                    ));
-
-                    // Store the abort handle at the scheduler level
-                    {
-                        let mut running_tasks_guard = running_tasks_arc.lock().await;
-                        running_tasks_guard.insert(task_job_id.clone(), job_task.abort_handle());
-                    }
-
-                    // Wait for the job to complete or be aborted
-                    let result = job_task.await;
-
-                    // Remove the abort handle
-                    {
-                        let mut running_tasks_guard = running_tasks_arc.lock().await;
-                        running_tasks_guard.remove(&task_job_id);
-                    }
-
-                    // Update the job status after execution
-                    {
-                        let mut jobs_map_guard = current_jobs_arc.lock().await;
-                        if let Some((_, stored_job)) = jobs_map_guard.get_mut(&task_job_id) {
-                            stored_job.currently_running = false;
-                            stored_job.current_session_id = None;
-                            stored_job.process_start_time = None;
-                            needs_persist = true;
-                        }
-                    }
-
-                    if needs_persist {
-                        if let Err(e) =
-                            persist_jobs_from_arc(&local_storage_path, &current_jobs_arc).await
-                        {
-                            tracing::error!(
-                                "Failed to persist running status update for job {}: {}",
-                                &task_job_id,
-                                e
-                            );
-                        }
-                    }
-
-                    match result {
-                        Ok(Ok(_session_id)) => {
-                            tracing::info!(
-                                "Scheduled job '{}' completed successfully",
-                                &task_job_id
-                            );
-                        }
-                        Ok(Err(e)) => {
-                            tracing::error!(
-                                "Scheduled job '{}' execution failed: {}",
-                                &e.job_id,
-                                e.error
-                            );
-                        }
-                        Err(join_error) if join_error.is_cancelled() => {
-                            tracing::info!("Scheduled job '{}' was cancelled/killed", &task_job_id);
-                        }
-                        Err(join_error) => {
-                            tracing::error!(
-                                "Scheduled job '{}' task failed: {}",
-                                &task_job_id,
-                                join_error
-                            );
-                        }
-                    }
-                })
-            })
-            .map_err(|e| SchedulerError::CronParseError(e.to_string()))?;
-
-            let job_uuid = self
-                .internal_scheduler
-                .add(cron_task)
-                .await
-                .map_err(|e| SchedulerError::SchedulerInternalError(e.to_string()))?;
             jobs_guard.insert(job_to_load.id.clone(), (job_uuid, job_to_load));
         }
         Ok(())
@@ -627,15 +515,17 @@ impl Scheduler {
     pub async fn remove_scheduled_job(&self, id: &str) -> Result<(), SchedulerError> {
         let mut jobs_guard = self.jobs.lock().await;
         if let Some((job_uuid, scheduled_job)) = jobs_guard.remove(id) {
-            self.internal_scheduler
-                .remove(&job_uuid)
-                .await
-                .map_err(|e| SchedulerError::SchedulerInternalError(e.to_string()))?;
+            self.stop_trigger(id, job_uuid).await?;
 
             let recipe_path = Path::new(&scheduled_job.source);
-            if recipe_path.exists() {
+            let package_dir = get_default_scheduled_recipes_dir()?.join(id);
+            if recipe_path.parent() == Some(package_dir.as_path()) {
+                fs::remove_dir_all(&package_dir).map_err(SchedulerError::StorageError)?;
+            } else if recipe_path.exists() {
                 fs::remove_file(recipe_path).map_err(SchedulerError::StorageError)?;
             }
+            RunHistory::for_storage_path(&self.storage_path).remove(id)?;
+            self.run_gates.lock().await.remove(id);
 
             self.persist_jobs_to_storage_with_guard(&jobs_guard).await?;
             Ok(())
@@ -668,13 +558,42 @@ impl Scheduler {
         Ok(result_sessions)
     }
 
+    pub async fn runs(
+        &self,
+        sched_id: &str,
+        limit: usize,
+    ) -> Result<Vec<ScheduledJobRun>, SchedulerError> {
+        if !self.jobs.lock().await.contains_key(sched_id) {
+            return Err(SchedulerError::JobNotFound(sched_id.to_string()));
+        }
+        RunHistory::for_storage_path(&self.storage_path).list(sched_id, limit)
+    }
+
+    /// Runs the job immediately. Manual runs ignore blackout windows and jitter but wait for,
+    /// skip or cancel a run in progress like any other run, following the job's overlap policy.
     pub async fn run_now(&self, sched_id: &str) -> Result<String, SchedulerError> {
+        let context = self.trigger_context();
+        let history = RunHistory::for_storage_path(&self.storage_path);
+        let job_spec = match self.jobs.lock().await.get(sched_id) {
+            Some((_, job_def)) => job_def.clone(),
+            None => return Err(SchedulerError::JobNotFound(sched_id.to_string())),
+        };
+        let gate = context.run_gate(sched_id).await;
+        let Some(_running_guard) = acquire_run_slot(&gate, &job_spec, &context, &history).await
+        else {
+            return Err(SchedulerError::AnyhowError(anyhow!(
+                "Job '{}' was not run, its overlap policy skipped the run",
+                sched_id
+            )));
+        };
+
         let job_to_run: ScheduledJob = {
             let mut jobs_guard = self.jobs.lock().await;
             match jobs_guard.get_mut(sched_id) {
                 Some((_, job_def)) => {
                     // Set the currently_running flag before executing
                     job_def.currently_running = true;
+                    job_def.process_start_time = Some(Utc::now());
                     let job_clone = job_def.clone();
                     // Drop the guard before persisting to avoid borrow issues
                     drop(jobs_guard);
@@ -688,11 +607,11 @@ impl Scheduler {
         };
 
         // Spawn the job execution as an abortable task for run_now
-        let job_task = tokio::spawn(run_scheduled_job_internal(
+        let job_task = tokio::spawn(run_scheduled_job_with_retries(
             job_to_run.clone(),
             None,
             Some(self.jobs.clone()),
-            Some(sched_id.to_string()),
+            history,
         ));
 
         // Store the abort handle for run_now jobs
@@ -791,175 +710,38 @@ impl Scheduler {
                     )));
                 }
 
-                if new_cron == job_def.cron {
+                let Some(current_cron) = job_def.trigger.cron_expression() else {
+                    return Err(SchedulerError::AnyhowError(anyhow!(
+                        "Schedule '{}' is triggered by {}, not a cron expression",
+                        sched_id,
+                        job_def.trigger
+                    )));
+                };
+                if new_cron == current_cron {
                     // No change needed
                     return Ok(());
                 }
 
                 // Remove the old job from the scheduler
-                self.internal_scheduler
-                    .remove(job_uuid)
-                    .await
-                    .map_err(|e| SchedulerError::SchedulerInternalError(e.to_string()))?;
+                if let Some(old_job_uuid) = job_uuid {
+                    self.internal_scheduler
+                        .remove(old_job_uuid)
+                        .await
+                        .map_err(|e| SchedulerError::SchedulerInternalError(e.to_string()))?;
+                }
 
                 // Create new job with updated cron
-                let job_for_task = job_def.clone();
-                let jobs_arc_for_task = self.jobs.clone();
-                let storage_path_for_task = self.storage_path.clone();
-                let running_tasks_for_task = self.running_tasks.clone();
-
                 tracing::info!(
                     "Updating job '{}' with new cron expression: '{}'",
                     sched_id,
                     new_cron
                 );
-                let normalized_cron = normalize_cron_expression(&new_cron);
-                // Convert from 7-field (Temporal format) to 6-field (tokio-cron-scheduler format)
-                let tokio_cron = {
-                    let parts: Vec<&str> = normalized_cron.split_whitespace().collect();
-                    if parts.len() == 7 {
-                        parts[..6].join(" ")
-                    } else {
-                        normalized_cron.clone()
-                    }
-                };
-                if tokio_cron != new_cron {
-                    tracing::info!(
-                        "Converted cron expression from '{}' to '{}' for tokio-cron-scheduler",
-                        new_cron,
-                        tokio_cron
-                    );
-                }
-                let cron_task = Job::new_async(&tokio_cron, move |_uuid, _l| {
-                    let task_job_id = job_for_task.id.clone();
-                    let current_jobs_arc = jobs_arc_for_task.clone();
-                    let local_storage_path = storage_path_for_task.clone();
-                    let job_to_execute = job_for_task.clone();
-                    let running_tasks_arc = running_tasks_for_task.clone();
-
-                    Box::pin(async move {
-                        // Check if the job is paused before executing
-                        let should_execute = {
-                            let jobs_map_guard = current_jobs_arc.lock().await;
-                            if let Some((_, current_job_in_map)) = jobs_map_guard.get(&task_job_id)
-                            {
-                                !current_job_in_map.paused
-                            } else {
-                                false
-                            }
-                        };
-
-                        if !should_execute {
-                            tracing::info!("Skipping execution of paused job '{}'", &task_job_id);
-                            return;
-                        }
-
-                        let current_time = Utc::now();
-                        let mut needs_persist = false;
-                        {
-                            let mut jobs_map_guard = current_jobs_arc.lock().await;
-                            if let Some((_, current_job_in_map)) =
-                                jobs_map_guard.get_mut(&task_job_id)
-                            {
-                                current_job_in_map.last_run = Some(current_time);
-                                current_job_in_map.currently_running = true;
-                                current_job_in_map.process_start_time = Some(current_time);
-                                needs_persist = true;
-                            }
-                        }
-
-                        if needs_persist {
-                            if let Err(e) =
-                                persist_jobs_from_arc(&local_storage_path, &current_jobs_arc).await
-                            {
-                                tracing::error!(
-                                    "Failed to persist last_run update for job {}: {}",
-                                    &task_job_id,
-                                    e
-                                );
-                            }
-                        }
-
-                        // Spawn the job execution as an abortable task
-                        let job_task = tokio::spawn(run_scheduled_job_internal(
-                            job_to_execute,
-                            None,
-                            Some(current_jobs_arc.clone()),
-                            Some(task_job_id.clone()),
-                        ));
-
-                        // Store the abort handle at the scheduler level
-                        {
-                            let mut running_tasks_guard = running_tasks_arc.lock().await;
-                            running_tasks_guard
-                                .insert(task_job_id.clone(), job_task.abort_handle());
-                        }
-
-                        // Wait for the job to complete or be aborted
-                        let result = job_task.await;
-
-                        // Remove the abort handle
-                        {
-                            let mut running_tasks_guard = running_tasks_arc.lock().await;
-                            running_tasks_guard.remove(&task_job_id);
-                        }
-
-                        // Update the job status after execution
-                        {
-                            let mut jobs_map_guard = current_jobs_arc.lock().await;
-                            if let Some((_, current_job_in_map)) =
-                                jobs_map_guard.get_mut(&task_job_id)
-                            {
-                                current_job_in_map.currently_running = false;
-                                current_job_in_map.current_session_id = None;
-                                current_job_in_map.process_start_time = None;
-                                needs_persist = true;
-                            }
-                        }
-
-                        if needs_persist {
-                            if let Err(e) =
-                                persist_jobs_from_arc(&local_storage_path, &current_jobs_arc).await
-                            {
-                                tracing::error!(
-                                    "Failed to persist running status update for job {}: {}",
-                                    &task_job_id,
-                                    e
-                                );
-                            }
-                        }
-
-                        match result {
-                            Ok(Ok(_session_id)) => {
-                                tracing::info!(
-                                    "Scheduled job '{}' completed successfully",
-                                    &task_job_id
-                                );
-                            }
-                            Ok(Err(e)) => {
-                                tracing::error!(
-                                    "Scheduled job '{}' execution failed: {}",
-                                    &e.job_id,
-                                    e.error
-                                );
-                            }
-                            Err(join_error) if join_error.is_cancelled() => {
-                                tracing::info!(
-                                    "Scheduled job '{}' was cancelled/killed",
-                                    &task_job_id
-                                );
-                            }
-                            Err(join_error) => {
-                                tracing::error!(
-                                    "Scheduled job '{}' task failed: {}",
-                                    &task_job_id,
-                                    join_error
-                                );
-                            }
-                        }
-                    })
-                })
-                .map_err(|e| SchedulerError::CronParseError(e.to_string()))?;
+                let cron_task = create_cron_task(
+                    &new_cron,
+                    job_def.timezone.as_deref(),
+                    sched_id.to_string(),
+                    self.trigger_context(),
+                )?;
 
                 let new_job_uuid = self
                     .internal_scheduler
@@ -968,8 +750,10 @@ impl Scheduler {
                     .map_err(|e| SchedulerError::SchedulerInternalError(e.to_string()))?;
 
                 // Update the job UUID and cron expression
-                *job_uuid = new_job_uuid;
-                job_def.cron = new_cron;
+                *job_uuid = Some(new_job_uuid);
+                job_def.trigger = ScheduleTrigger::Cron {
+                    expression: new_cron,
+                };
 
                 self.persist_jobs_to_storage_with_guard(&jobs_guard).await?;
                 Ok(())
@@ -978,6 +762,24 @@ impl Scheduler {
         }
     }
 
+    /// Starts a run of the job for an event, with the event's values as recipe parameters.
+    /// Like a cron firing, the run honors the job's blackout windows and overlap policy.
+    pub async fn trigger_job(
+        &self,
+        sched_id: &str,
+        event_parameters: HashMap<String, String>,
+    ) -> Result<(), SchedulerError> {
+        if !self.jobs.lock().await.contains_key(sched_id) {
+            return Err(SchedulerError::JobNotFound(sched_id.to_string()));
+        }
+        tokio::spawn(run_triggered_job(
+            sched_id.to_string(),
+            Some(event_parameters),
+            self.trigger_context(),
+        ));
+        Ok(())
+    }
+
     pub async fn kill_running_job(&self, sched_id: &str) -> Result<(), SchedulerError> {
         let mut jobs_guard = self.jobs.lock().await;
         match jobs_guard.get_mut(sched_id) {
@@ -1005,6 +807,8 @@ impl Scheduler {
                     }
                 }
 
+                record_cancelled_run(&RunHistory::for_storage_path(&self.storage_path), job_def);
+
                 // Mark the job as no longer running
                 job_def.currently_running = false;
                 job_def.current_session_id = None;
@@ -1043,61 +847,451 @@ impl Scheduler {
     }
 }
 
-#[derive(Debug)]
-struct JobExecutionError {
+/// Builds the tokio-cron-scheduler job that fires the scheduled job on its cron expression
+fn create_cron_task(
+    cron: &str,
+    timezone: Option<&str>,
     job_id: String,
-    error: String,
+    context: TriggerContext,
+) -> Result<Job, SchedulerError> {
+    let normalized_cron = normalize_cron_expression(cron);
+    // Convert from 7-field (Temporal format) to 6-field (tokio-cron-scheduler format)
+    let tokio_cron = {
+        let parts: Vec<&str> = normalized_cron.split_whitespace().collect();
+        if parts.len() == 7 {
+            parts[..6].join(" ")
+        } else {
+            normalized_cron.clone()
+        }
+    };
+    if tokio_cron != cron {
+        tracing::info!(
+            "Converted cron expression from '{}' to '{}' for tokio-cron-scheduler",
+            cron,
+            tokio_cron
+        );
+    }
+    let timezone = match timezone {
+        Some(name) => parse_timezone(name)?,
+        None => Tz::UTC,
+    };
+
+    Job::new_async_tz(&tokio_cron, timezone, move |_uuid, _l| {
+        Box::pin(run_triggered_job(job_id.clone(), None, context.clone()))
+    })
+    .map_err(|e| SchedulerError::CronParseError(e.to_string()))
 }
 
-async fn run_scheduled_job_internal(
-    job: ScheduledJob,
-    provider_override: Option<Arc<dyn GooseProvider>>,
-    jobs_arc: Option<Arc<Mutex<JobsMap>>>,
-    job_id: Option<String>,
-) -> std::result::Result<String, JobExecutionError> {
-    tracing::info!("Executing job: {} (Source: {})", job.id, job.source);
+/// Handles one firing of a job's trigger, honoring its pause state, blackout windows, jitter
+/// and overlap policy. Event triggers pass the event's values, which take precedence over
+/// the job's own recipe parameters.
+/// Waits for the job's turn to run according to its overlap policy. Returns `None`, after
+/// recording the skipped run, when the policy drops the run.
+async fn acquire_run_slot<'a>(
+    gate: &'a RunGate,
+    job: &ScheduledJob,
+    context: &TriggerContext,
+    history: &RunHistory,
+) -> Option<MutexGuard<'a, ()>> {
+    match job.overlap_policy.unwrap_or_default() {
+        OverlapPolicy::Skip => match gate.running.try_lock() {
+            Ok(guard) => Some(guard),
+            Err(_) => {
+                tracing::info!(
+                    "Skipping execution of job '{}', its previous run is still in progress",
+                    &job.id
+                );
+                record_skipped_run(
+                    history,
+                    &job.id,
+                    "Previous run was still in progress".to_string(),
+                );
+                None
+            }
+        },
+        OverlapPolicy::Queue => {
+            let Ok(_queued_guard) = gate.queued.try_lock() else {
+                tracing::info!(
+                    "Skipping execution of job '{}', a run is already queued",
+                    &job.id
+                );
+                record_skipped_run(
+                    history,
+                    &job.id,
+                    "Another run was already queued behind the one in progress".to_string(),
+                );
+                return None;
+            };
+            Some(gate.running.lock().await)
+        }
+        OverlapPolicy::KillPrevious => {
+            let previous_task = context.running_tasks.lock().await.remove(&job.id);
+            if let Some(abort_handle) = previous_task {
+                tracing::info!("Cancelling previous run of job '{}'", &job.id);
+                abort_handle.abort();
+                if let Some((_, previous_job)) = context.jobs.lock().await.get(&job.id) {
+                    record_cancelled_run(history, previous_job);
+                }
+            }
+            Some(gate.running.lock().await)
+        }
+    }
+}
 
-    let recipe_path = Path::new(&job.source);
+async fn run_triggered_job(
+    task_job_id: String,
+    event_parameters: Option<HashMap<String, String>>,
+    context: TriggerContext,
+) {
+    let mut job_to_execute = {
+        let jobs_map_guard = context.jobs.lock().await;
+        match jobs_map_guard.get(&task_job_id) {
+            Some((_, current_job_in_map)) => current_job_in_map.clone(),
+            None => return,
+        }
+    };
+
+    if job_to_execute.paused {
+        tracing::info!("Skipping execution of paused job '{}'", &task_job_id);
+        return;
+    }
+    if let Some(event_parameters) = event_parameters {
+        job_to_execute
+            .parameters
+            .get_or_insert_with(HashMap::new)
+            .extend(event_parameters);
+    }
+
+    let history = RunHistory::for_storage_path(&context.storage_path);
+    let blackout_windows = job_to_execute
+        .blackout_windows
+        .as_deref()
+        .unwrap_or_default();
+    if let Some(window) = active_blackout_window(blackout_windows, Utc::now()) {
+        tracing::info!(
+            "Skipping execution of job '{}' during blackout window {}",
+            &task_job_id,
+            window
+        );
+        record_skipped_run(
+            &history,
+            &task_job_id,
+            format!("Inside blackout window {}", window),
+        );
+        return;
+    }
 
-    let recipe_content = match fs::read_to_string(recipe_path) {
-        Ok(content) => content,
+    if let Some(jitter_seconds) = job_to_execute.jitter_seconds {
+        tokio::time::sleep(jitter_delay(jitter_seconds)).await;
+    }
+
+    let gate = context.run_gate(&task_job_id).await;
+    let Some(_running_guard) = acquire_run_slot(&gate, &job_to_execute, &context, &history).await
+    else {
+        return;
+    };
+
+    let current_time = Utc::now();
+    let mut needs_persist = false;
+    {
+        let mut jobs_map_guard = context.jobs.lock().await;
+        if let Some((_, current_job_in_map)) = jobs_map_guard.get_mut(&task_job_id) {
+            current_job_in_map.last_run = Some(current_time);
+            current_job_in_map.currently_running = true;
+            current_job_in_map.process_start_time = Some(current_time);
+            needs_persist = true;
+        }
+    }
+
+    if needs_persist {
+        if let Err(e) = persist_jobs_from_arc(&context.storage_path, &context.jobs).await {
+            tracing::error!(
+                "Failed to persist last_run update for job {}: {}",
+                &task_job_id,
+                e
+            );
+        }
+    }
+
+    // Spawn the job execution as an abortable task
+    let job_task = tokio::spawn(run_scheduled_job_with_retries(
+        job_to_execute,
+        None,
+        Some(context.jobs.clone()),
+        history,
+    ));
+
+    // Store the abort handle at the scheduler level
+    {
+        let mut running_tasks_guard = context.running_tasks.lock().await;
+        running_tasks_guard.insert(task_job_id.clone(), job_task.abort_handle());
+    }
+
+    // Wait for the job to complete or be aborted
+    let result = job_task.await;
+
+    // Remove the abort handle
+    {
+        let mut running_tasks_guard = context.running_tasks.lock().await;
+        running_tasks_guard.remove(&task_job_id);
+    }
+
+    // Update the job status after execution
+    {
+        let mut jobs_map_guard = context.jobs.lock().await;
+        if let Some((_, current_job_in_map)) = jobs_map_guard.get_mut(&task_job_id) {
+            current_job_in_map.currently_running = false;
+            current_job_in_map.current_session_id = None;
+            current_job_in_map.process_start_time = None;
+            needs_persist = true;
+        }
+    }
+
+    if needs_persist {
+        if let Err(e) = persist_jobs_from_arc(&context.storage_path, &context.jobs).await {
+            tracing::error!(
+                "Failed to persist running status update for job {}: {}",
+                &task_job_id,
+                e
+            );
+        }
+    }
+
+    match result {
+        Ok(Ok(_session_id)) => {
+            tracing::info!("Scheduled job '{}' completed successfully", &task_job_id);
+        }
+        Ok(Err(e)) => {
+            tracing::error!(
+                "Scheduled job '{}' execution failed: {}",
+                &e.job_id,
+                e.error
+            );
+        }
+        Err(join_error) if join_error.is_cancelled() => {
+            tracing::info!("Scheduled job '{}' was cancelled/killed", &task_job_id);
+        }
+        Err(join_error) => {
+            tracing::error!(
+                "Scheduled job '{}' task failed: {}",
+                &task_job_id,
+                join_error
+            );
+        }
+    }
+}
+
+/// Records the run that was in progress when the job was killed. Attempts that already
+/// finished are in the history; this one never will be.
+fn record_cancelled_run(history: &RunHistory, job: &ScheduledJob) {
+    let started_at = job.process_start_time.unwrap_or_else(Utc::now);
+    let finished_attempts = match history.list(&job.id, usize::MAX) {
+        Ok(runs) => runs
+            .iter()
+            .filter(|run| run.status != ScheduledRunStatus::Skipped)
+            .take_while(|run| run.started_at >= started_at)
+            .count(),
         Err(e) => {
-            return Err(JobExecutionError {
-                job_id: job.id.clone(),
-                error: format!("Failed to load recipe file '{}': {}", job.source, e),
-            });
+            tracing::error!("Failed to read run history of job '{}': {}", job.id, e);
+            0
         }
     };
+    let cancelled_run = ScheduledJobRun {
+        schedule_id: job.id.clone(),
+        attempt: finished_attempts as u32 + 1,
+        started_at,
+        ended_at: Utc::now(),
+        status: ScheduledRunStatus::Cancelled,
+        error: None,
+        session_id: job.current_session_id.clone(),
+        input_tokens: None,
+        output_tokens: None,
+        total_tokens: None,
+        checks_passed: None,
+    };
+    if let Err(e) = history.record(&cancelled_run) {
+        tracing::error!("Failed to record cancelled run of job '{}': {}", job.id, e);
+    }
+}
+
+fn record_skipped_run(history: &RunHistory, job_id: &str, reason: String) {
+    let now = Utc::now();
+    let skipped_run = ScheduledJobRun {
+        schedule_id: job_id.to_string(),
+        attempt: 1,
+        started_at: now,
+        ended_at: now,
+        status: ScheduledRunStatus::Skipped,
+        error: Some(reason),
+        session_id: None,
+        input_tokens: None,
+        output_tokens: None,
+        total_tokens: None,
+        checks_passed: None,
+    };
+    if let Err(e) = history.record(&skipped_run) {
+        tracing::error!("Failed to record skipped run of job '{}': {}", job_id, e);
+    }
+}
 
-    let recipe: Recipe = {
-        let extension = recipe_path
+/// Copies a job's recipe into the scheduled recipes directory, returning the path of the copy
+/// and the directory the copy resolves relative references against. A recipe package is
+/// copied whole, with its sub-recipes and files, so the copy resolves them itself.
+fn store_scheduled_recipe(
+    job: &ScheduledJob,
+    original_recipe_path: &Path,
+    scheduled_recipes_dir: &Path,
+) -> Result<(PathBuf, Option<String>), SchedulerError> {
+    let source_path = Path::new(&job.source);
+    if source_path.is_dir() {
+        let destination_dir = scheduled_recipes_dir.join(&job.id);
+        tracing::info!(
+            "Copying recipe package from {} to {}",
+            source_path.display(),
+            destination_dir.display()
+        );
+        if destination_dir.exists() {
+            fs::remove_dir_all(&destination_dir)?;
+        }
+        copy_dir(source_path, &destination_dir).map_err(|e| {
+            SchedulerError::StorageError(io::Error::new(
+                e.kind(),
+                format!(
+                    "Failed to copy recipe package from {} to {}: {}",
+                    job.source,
+                    destination_dir.display(),
+                    e
+                ),
+            ))
+        })?;
+        let recipe_file_name = original_recipe_path.file_name().unwrap_or_default();
+        (destination_dir.join(recipe_file_name), None)
+    } else {
+        let original_recipe_dir = fs::canonicalize(original_recipe_path)
+            .map_err(SchedulerError::StorageError)?
+            .parent()
+            .map(|dir| dir.to_string_lossy().into_owned());
+        let original_extension = original_recipe_path
             .extension()
-            .and_then(|os_str| os_str.to_str())
-            .unwrap_or("yaml")
-            .to_lowercase();
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("yaml");
 
-        match extension.as_str() {
-            "json" | "jsonl" => {
-                serde_json::from_str::<Recipe>(&recipe_content).map_err(|e| JobExecutionError {
-                    job_id: job.id.clone(),
-                    error: format!("Failed to parse JSON recipe '{}': {}", job.source, e),
-                })
-            }
-            "yaml" | "yml" => {
-                serde_yaml::from_str::<Recipe>(&recipe_content).map_err(|e| JobExecutionError {
-                    job_id: job.id.clone(),
-                    error: format!("Failed to parse YAML recipe '{}': {}", job.source, e),
-                })
-            }
-            _ => Err(JobExecutionError {
-                job_id: job.id.clone(),
-                error: format!(
-                    "Unsupported recipe file extension '{}' for: {}",
-                    extension, job.source
+        let destination_filename = format!("{}.{}", job.id, original_extension);
+        let destination_recipe_path = scheduled_recipes_dir.join(destination_filename);
+
+        tracing::info!(
+            "Copying recipe from {} to {}",
+            original_recipe_path.display(),
+            destination_recipe_path.display()
+        );
+        fs::copy(original_recipe_path, &destination_recipe_path).map_err(|e| {
+            SchedulerError::StorageError(io::Error::new(
+                e.kind(),
+                format!(
+                    "Failed to copy recipe from {} to {}: {}",
+                    job.source,
+                    destination_recipe_path.display(),
+                    e
                 ),
-            }),
+            ))
+        })?;
+        (destination_recipe_path, original_recipe_dir)
+    }
+}
+
+/// Finds the recipe a job's source refers to: the source itself when it is a file, or the
+/// `recipe.yaml` or `recipe.json` of a recipe package directory.
+pub fn resolve_recipe_source(source: &Path) -> Result<PathBuf, SchedulerError> {
+    if source.is_file() {
+        return Ok(source.to_path_buf());
+    }
+    PACKAGE_RECIPE_FILE_NAMES
+        .iter()
+        .map(|name| source.join(name))
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            SchedulerError::RecipeLoadError(format!(
+                "Original recipe source is neither a file nor a recipe package: {}",
+                source.display()
+            ))
+        })
+}
+
+fn copy_dir(source: &Path, destination: &Path) -> io::Result<()> {
+    fs::create_dir_all(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let destination_path = destination.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &destination_path)?;
+        } else {
+            fs::copy(entry.path(), destination_path)?;
         }
-    }?;
+    }
+    Ok(())
+}
+
+/// Reads the stored copy of a job's recipe, placed in the directory it was scheduled from
+fn read_scheduled_recipe_file(job: &ScheduledJob) -> Result<RecipeFile> {
+    let mut recipe_file = read_recipe_file(&job.source)?;
+    if let Some(recipe_dir) = &job.recipe_dir {
+        recipe_file.parent_dir = PathBuf::from(recipe_dir);
+    }
+    Ok(recipe_file)
+}
+
+/// Builds the recipe with the schedule's parameter values filled into its template
+fn render_scheduled_recipe(
+    recipe_file: RecipeFile,
+    parameters: &HashMap<String, String>,
+) -> Result<Recipe> {
+    let params = parameters
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    Ok(build_recipe_from_template(
+        recipe_file,
+        params,
+        None::<fn(&RecipeParameter) -> Result<String>>,
+    )?)
+}
+
+#[derive(Debug)]
+struct JobExecutionError {
+    job_id: String,
+    /// Session the run was recorded in, when it failed after one was created
+    session_id: Option<String>,
+    error: String,
+}
+
+fn parse_scheduled_recipe(job: &ScheduledJob) -> std::result::Result<Recipe, JobExecutionError> {
+    let load_error = |e: String| JobExecutionError {
+        job_id: job.id.clone(),
+        session_id: None,
+        error: format!("Failed to load recipe file '{}': {}", job.source, e),
+    };
+    let recipe_file = read_scheduled_recipe_file(job).map_err(|e| load_error(e.to_string()))?;
+    load_recipe_from_chain(recipe_file).map_err(|e| load_error(e.to_string()))
+}
+
+async fn run_scheduled_job_internal(
+    job: ScheduledJob,
+    provider_override: Option<Arc<dyn GooseProvider>>,
+    jobs_arc: Option<Arc<Mutex<JobsMap>>>,
+    job_id: Option<String>,
+) -> std::result::Result<String, JobExecutionError> {
+    tracing::info!("Executing job: {} (Source: {})", job.id, job.source);
+
+    let recipe = match &job.parameters {
+        Some(parameters) => read_scheduled_recipe_file(&job)
+            .and_then(|recipe_file| render_scheduled_recipe(recipe_file, parameters))
+            .map_err(|e| JobExecutionError {
+                job_id: job.id.clone(),
+                session_id: None,
+                error: format!("Failed to render recipe '{}': {}", job.source, e),
+            })?,
+        None => parse_scheduled_recipe(&job)?,
+    };
 
     let agent: Agent = Agent::new();
 
@@ -1111,6 +1305,7 @@ async fn run_scheduled_job_internal(
             Ok(name) => name,
             Err(_) => return Err(JobExecutionError {
                 job_id: job.id.clone(),
+                session_id: None,
                 error:
                     "GOOSE_PROVIDER not configured globally. Run 'goose configure' or set env var."
                         .to_string(),
@@ -1121,6 +1316,7 @@ async fn run_scheduled_job_internal(
                 Ok(name) => name,
                 Err(_) => return Err(JobExecutionError {
                     job_id: job.id.clone(),
+                    session_id: None,
                     error:
                         "GOOSE_MODEL not configured globally. Run 'goose configure' or set env var."
                             .to_string(),
@@ -1129,11 +1325,13 @@ async fn run_scheduled_job_internal(
         let model_config =
             crate::model::ModelConfig::new(model_name.as_str()).map_err(|e| JobExecutionError {
                 job_id: job.id.clone(),
+                session_id: None,
                 error: format!("Model config error: {}", e),
             })?;
 
         agent_provider = create(&provider_name, model_config).map_err(|e| JobExecutionError {
             job_id: job.id.clone(),
+            session_id: None,
             error: format!(
                 "Failed to create provider instance '{}': {}",
                 provider_name, e
@@ -1148,6 +1346,7 @@ async fn run_scheduled_job_internal(
                 .await
                 .map_err(|e| JobExecutionError {
                     job_id: job.id.clone(),
+                    session_id: None,
                     error: format!("Failed to add extension '{}': {}", extension.name(), e),
                 })?;
         }
@@ -1156,6 +1355,7 @@ async fn run_scheduled_job_internal(
     if let Err(e) = agent.update_provider(agent_provider).await {
         return Err(JobExecutionError {
             job_id: job.id.clone(),
+            session_id: None,
             error: format!("Failed to set provider on agent: {}", e),
         });
     }
@@ -1168,6 +1368,7 @@ async fn run_scheduled_job_internal(
         Err(e) => {
             return Err(JobExecutionError {
                 job_id: job.id.clone(),
+                session_id: None,
                 error: format!("Failed to get current directory for job execution: {}", e),
             });
         }
@@ -1188,6 +1389,7 @@ async fn run_scheduled_job_internal(
         Err(e) => {
             return Err(JobExecutionError {
                 job_id: job.id.clone(),
+                session_id: None,
                 error: format!("Failed to create session: {}", e),
             });
         }
@@ -1201,6 +1403,7 @@ async fn run_scheduled_job_internal(
         }
     }
 
+    let mut agent_error = None;
     if let Some(ref prompt_text) = recipe.prompt {
         let mut all_session_messages =
             Conversation::new_unvalidated(vec![Message::user().with_text(prompt_text.clone())]);
@@ -1211,7 +1414,11 @@ async fn run_scheduled_job_internal(
             schedule_id: Some(job.id.clone()),
             execution_mode: job.execution_mode.clone(),
             max_turns: None,
-            retry_config: None,
+            goose_mode: None,
+            retry_config: recipe.retry.clone(),
+            budget: recipe.settings.as_ref().and_then(|s| s.budget.clone()),
+            schedule_budget: job.budget.clone(),
+            context_strategy: recipe.settings.as_ref().and_then(|s| s.context_strategy),
         };
 
         match agent
@@ -1244,6 +1451,7 @@ async fn run_scheduled_job_internal(
                                 job.id,
                                 e
                             );
+                            agent_error = Some(format!("Agent error: {}", e));
                             break;
                         }
                     }
@@ -1252,6 +1460,7 @@ async fn run_scheduled_job_internal(
             Err(e) => {
                 return Err(JobExecutionError {
                     job_id: job.id.clone(),
+                    session_id: Some(session.id.clone()),
                     error: format!("Agent failed to reply for recipe '{}': {}", job.source, e),
                 });
             }
@@ -1273,10 +1482,125 @@ async fn run_scheduled_job_internal(
         tracing::error!("[Job {}] Failed to update session metadata: {}", job.id, e);
     }
 
+    if let Some(error) = agent_error {
+        return Err(JobExecutionError {
+            job_id: job.id.clone(),
+            session_id: Some(session.id),
+            error,
+        });
+    }
+
     tracing::info!("Finished job: {}", job.id);
     Ok(session.id)
 }
 
+/// Runs the job, retrying failed attempts according to its retry policy, records every
+/// attempt in the run history and then fires the job's success or failure hook.
+async fn run_scheduled_job_with_retries(
+    job: ScheduledJob,
+    provider_override: Option<Arc<dyn GooseProvider>>,
+    jobs_arc: Option<Arc<Mutex<JobsMap>>>,
+    history: RunHistory,
+) -> std::result::Result<String, JobExecutionError> {
+    let max_retries = job.retry.as_ref().map_or(0, |policy| policy.max_retries);
+    let mut attempt = 1;
+    loop {
+        let started_at = Utc::now();
+        let result = run_scheduled_job_internal(
+            job.clone(),
+            provider_override.clone(),
+            jobs_arc.clone(),
+            jobs_arc.as_ref().map(|_| job.id.clone()),
+        )
+        .await;
+        let (run, result) = finish_run(&job.id, attempt, started_at, result).await;
+        if let Err(e) = history.record(&run) {
+            tracing::error!("Failed to record run of scheduled job '{}': {}", job.id, e);
+        }
+
+        match (&result, &job.retry) {
+            (Err(e), Some(policy)) if attempt <= max_retries => {
+                let delay = policy.delay_before_retry(attempt);
+                tracing::warn!(
+                    "Scheduled job '{}' attempt {} failed: {}. Retrying in {:?}",
+                    job.id,
+                    attempt,
+                    e.error,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            _ => {
+                let hook = if result.is_ok() {
+                    &job.on_success
+                } else {
+                    &job.on_failure
+                };
+                if let Some(hook) = hook {
+                    if let Err(e) = hook.fire(&run).await {
+                        tracing::error!("Hook for scheduled job '{}' failed: {}", job.id, e);
+                    }
+                }
+                return result;
+            }
+        }
+    }
+}
+
+/// Builds the history record of one attempt. A run whose recipe success checks did not
+/// pass counts as failed, so that it can be retried.
+async fn finish_run(
+    job_id: &str,
+    attempt: u32,
+    started_at: DateTime<Utc>,
+    result: std::result::Result<String, JobExecutionError>,
+) -> (
+    ScheduledJobRun,
+    std::result::Result<String, JobExecutionError>,
+) {
+    let session_id = match &result {
+        Ok(session_id) => Some(session_id.clone()),
+        Err(e) => e.session_id.clone(),
+    };
+    let session = match &session_id {
+        Some(session_id) => SessionManager::get_session(session_id, false).await.ok(),
+        None => None,
+    };
+    let checks_passed = session
+        .as_ref()
+        .and_then(|s| SuccessCheckState::from_extension_data(&s.extension_data))
+        .map(|state| state.report.passed());
+
+    let result = match result {
+        Ok(session_id) if checks_passed == Some(false) => Err(JobExecutionError {
+            job_id: job_id.to_string(),
+            session_id: Some(session_id),
+            error: "Recipe success checks did not pass".to_string(),
+        }),
+        result => result,
+    };
+
+    let run = ScheduledJobRun {
+        schedule_id: job_id.to_string(),
+        attempt,
+        started_at,
+        ended_at: Utc::now(),
+        status: if result.is_ok() {
+            ScheduledRunStatus::Succeeded
+        } else {
+            ScheduledRunStatus::Failed
+        },
+        error: result.as_ref().err().map(|e| e.error.clone()),
+        session_id,
+        input_tokens: session.as_ref().and_then(|s| s.accumulated_input_tokens),
+        output_tokens: session.as_ref().and_then(|s| s.accumulated_output_tokens),
+        total_tokens: session.as_ref().and_then(|s| s.accumulated_total_tokens),
+        checks_passed,
+    };
+    (run, result)
+}
+
 #[async_trait]
 impl SchedulerTrait for Scheduler {
     async fn add_scheduled_job(&self, job: ScheduledJob) -> Result<(), SchedulerError> {
@@ -1311,6 +1635,14 @@ impl SchedulerTrait for Scheduler {
         self.sessions(sched_id, limit).await
     }
 
+    async fn runs(
+        &self,
+        sched_id: &str,
+        limit: usize,
+    ) -> Result<Vec<ScheduledJobRun>, SchedulerError> {
+        self.runs(sched_id, limit).await
+    }
+
     async fn update_schedule(
         &self,
         sched_id: &str,
@@ -1319,6 +1651,14 @@ impl SchedulerTrait for Scheduler {
         self.update_schedule(sched_id, new_cron).await
     }
 
+    async fn trigger_job(
+        &self,
+        sched_id: &str,
+        event_parameters: HashMap<String, String>,
+    ) -> Result<(), SchedulerError> {
+        self.trigger_job(sched_id, event_parameters).await
+    }
+
     async fn kill_running_job(&self, sched_id: &str) -> Result<(), SchedulerError> {
         self.kill_running_job(sched_id).await
     }
@@ -1435,6 +1775,8 @@ mod tests {
             response: None,
             sub_recipes: None,
             retry: None,
+            extends: None,
+            instructions_merge: None,
         };
         let mut recipe_file = File::create(&recipe_filename)?;
         writeln!(
@@ -1448,13 +1790,26 @@ mod tests {
         let dummy_job = ScheduledJob {
             id: schedule_id_str.clone(),
             source: recipe_filename.to_string_lossy().into_owned(),
-            cron: "* * * * * * ".to_string(), // Runs every second for quick testing
+            trigger: ScheduleTrigger::Cron {
+                expression: "* * * * * * ".to_string(), // Runs every second for quick testing
+            },
             last_run: None,
             currently_running: false,
             paused: false,
             current_session_id: None,
             process_start_time: None,
             execution_mode: Some("background".to_string()), // Default for test
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: None,
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
         };
 
         let mock_model_config = ModelConfig::new_or_fail("test_model");
@@ -1499,4 +1854,397 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[cfg(not(target_os = "windows"))]
+    async fn test_failed_job_is_retried_and_recorded() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempdir()?;
+        let hook_output = temp_dir.path().join("on_failure.txt");
+        let history = RunHistory::new(temp_dir.path().join("runs"));
+
+        let job = ScheduledJob {
+            id: "test_failing_schedule".to_string(),
+            source: temp_dir
+                .path()
+                .join("missing.yaml")
+                .to_string_lossy()
+                .into_owned(),
+            trigger: ScheduleTrigger::Cron {
+                expression: "0 0 * * * *".to_string(),
+            },
+            last_run: None,
+            currently_running: false,
+            paused: false,
+            current_session_id: None,
+            process_start_time: None,
+            execution_mode: Some("background".to_string()),
+            budget: None,
+            retry: Some(RetryPolicy {
+                max_retries: 2,
+                backoff_seconds: 0,
+                max_backoff_seconds: 0,
+            }),
+            on_success: None,
+            on_failure: Some(ScheduleHook::Command {
+                command: format!("echo \"$GOOSE_RUN_STATUS\" >> {}", hook_output.display()),
+            }),
+            parameters: None,
+            overlap_policy: None,
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
+        };
+
+        let result = run_scheduled_job_with_retries(
+            job,
+            None,
+            None,
+            RunHistory::new(temp_dir.path().join("runs")),
+        )
+        .await;
+
+        assert!(result.is_err());
+        let runs = history.list("test_failing_schedule", 10)?;
+        let attempts: Vec<u32> = runs.iter().map(|run| run.attempt).collect();
+        assert_eq!(attempts, vec![3, 2, 1]);
+        assert!(runs
+            .iter()
+            .all(|run| run.status == ScheduledRunStatus::Failed));
+        assert!(runs[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("Failed to load recipe file"));
+        // The hook fires once, after the last attempt
+        assert_eq!(fs::read_to_string(&hook_output)?.trim(), "failed");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_triggered_run_skips_overlapping_and_blacked_out_runs(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempdir()?;
+        let storage_path = temp_dir.path().join("schedules.json");
+        let job = ScheduledJob {
+            id: "test_overlapping_schedule".to_string(),
+            source: temp_dir
+                .path()
+                .join("missing.yaml")
+                .to_string_lossy()
+                .into_owned(),
+            trigger: ScheduleTrigger::Cron {
+                expression: "0 0 * * * *".to_string(),
+            },
+            last_run: None,
+            currently_running: false,
+            paused: false,
+            current_session_id: None,
+            process_start_time: None,
+            execution_mode: Some("background".to_string()),
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: Some(OverlapPolicy::Skip),
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
+        };
+        let context = TriggerContext {
+            jobs: Arc::new(Mutex::new(HashMap::from([(
+                job.id.clone(),
+                (Some(uuid::Uuid::new_v4()), job.clone()),
+            )]))),
+            storage_path: storage_path.clone(),
+            running_tasks: Arc::new(Mutex::new(HashMap::new())),
+            run_gates: Arc::new(Mutex::new(HashMap::new())),
+        };
+
+        // A run of the job is still in progress
+        let gate = context.run_gate(&job.id).await;
+        let running_guard = gate.running.lock().await;
+        run_triggered_job(job.id.clone(), None, context.clone()).await;
+        drop(running_guard);
+
+        if let Some((_, job_in_map)) = context.jobs.lock().await.get_mut(&job.id) {
+            job_in_map.blackout_windows = Some(vec![BlackoutWindow {
+                start: Utc::now() - chrono::Duration::hours(1),
+                end: Utc::now() + chrono::Duration::hours(1),
+                reason: Some("deploy freeze".to_string()),
+            }]);
+        }
+        run_triggered_job(job.id.clone(), None, context.clone()).await;
+
+        let runs = RunHistory::for_storage_path(&storage_path).list(&job.id, 10)?;
+        assert_eq!(runs.len(), 2);
+        assert!(runs
+            .iter()
+            .all(|run| run.status == ScheduledRunStatus::Skipped));
+        assert!(runs[0].error.as_deref().unwrap().contains("deploy freeze"));
+        assert_eq!(
+            runs[1].error.as_deref(),
+            Some("Previous run was still in progress")
+        );
+        assert!(context.jobs.lock().await[&job.id].1.last_run.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalid_job_settings_are_reported_as_invalid_job(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempdir()?;
+        let scheduler = Scheduler::new(temp_dir.path().join("schedules.json")).await?;
+        let job = ScheduledJob {
+            id: "test_invalid_timezone".to_string(),
+            source: temp_dir
+                .path()
+                .join("missing.yaml")
+                .to_string_lossy()
+                .into_owned(),
+            trigger: ScheduleTrigger::Cron {
+                expression: "0 0 * * * *".to_string(),
+            },
+            last_run: None,
+            currently_running: false,
+            paused: false,
+            current_session_id: None,
+            process_start_time: None,
+            execution_mode: Some("background".to_string()),
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: None,
+            timezone: Some("Mars/Olympus".to_string()),
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
+        };
+
+        let err = scheduler.add_scheduled_job(job).await.unwrap_err();
+
+        assert!(matches!(err, SchedulerError::InvalidJob(_)));
+        assert_eq!(
+            err.to_string(),
+            "Invalid job: Unknown timezone 'Mars/Olympus'"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_run_now_follows_overlap_policy() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempdir()?;
+        let storage_path = temp_dir.path().join("schedules.json");
+        let scheduler = Scheduler::new(storage_path.clone()).await?;
+        let job = ScheduledJob {
+            id: "test_manual_overlap".to_string(),
+            source: temp_dir
+                .path()
+                .join("missing.yaml")
+                .to_string_lossy()
+                .into_owned(),
+            trigger: ScheduleTrigger::Webhook,
+            last_run: None,
+            currently_running: false,
+            paused: false,
+            current_session_id: None,
+            process_start_time: None,
+            execution_mode: Some("background".to_string()),
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: Some(OverlapPolicy::Skip),
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
+        };
+        scheduler
+            .jobs
+            .lock()
+            .await
+            .insert(job.id.clone(), (None, job.clone()));
+
+        // A triggered run of the job is still in progress
+        let gate = scheduler.trigger_context().run_gate(&job.id).await;
+        let running_guard = gate.running.lock().await;
+        let err = scheduler.run_now(&job.id).await.unwrap_err();
+        drop(running_guard);
+
+        assert!(err.to_string().contains("overlap policy"));
+        let runs = RunHistory::for_storage_path(&storage_path).list(&job.id, 10)?;
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].status, ScheduledRunStatus::Skipped);
+        assert!(!scheduler.jobs.lock().await[&job.id].1.currently_running);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_scheduled_recipe_with_parameters() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempdir()?;
+        let recipe_path = temp_dir.path().join("report.yaml");
+        fs::write(
+            &recipe_path,
+            r#"version: 1.0.0
+title: Report
+description: Write a report
+prompt: Write the {{ team }} report
+parameters:
+  - key: team
+    input_type: string
+    requirement: required
+    description: Team to report on
+"#,
+        )?;
+        let parameters = HashMap::from([("team".to_string(), "platform".to_string())]);
+
+        let recipe = render_scheduled_recipe(read_recipe_file(&recipe_path)?, &parameters)?;
+
+        assert_eq!(recipe.prompt.as_deref(), Some("Write the platform report"));
+        assert!(render_scheduled_recipe(read_recipe_file(&recipe_path)?, &HashMap::new()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scheduled_copy_resolves_extended_recipe() -> Result<(), Box<dyn std::error::Error>> {
+        let original_dir = tempdir()?;
+        fs::write(
+            original_dir.path().join("base.yaml"),
+            r#"version: 1.0.0
+title: Base
+description: Shared instructions
+instructions: Follow the team style guide
+"#,
+        )?;
+        let child_content = r#"version: 1.0.0
+title: Child
+description: Write a report
+extends: base.yaml
+prompt: Write the weekly report
+"#;
+        fs::write(original_dir.path().join("child.yaml"), child_content)?;
+        let scheduled_dir = tempdir()?;
+        let scheduled_copy = scheduled_dir.path().join("weekly.yaml");
+        fs::write(&scheduled_copy, child_content)?;
+
+        let job = ScheduledJob {
+            id: "weekly".to_string(),
+            source: scheduled_copy.to_string_lossy().into_owned(),
+            trigger: ScheduleTrigger::Cron {
+                expression: "0 0 9 * * 1".to_string(),
+            },
+            last_run: None,
+            currently_running: false,
+            paused: false,
+            current_session_id: None,
+            process_start_time: None,
+            execution_mode: None,
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: None,
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: Some(original_dir.path().to_string_lossy().into_owned()),
+        };
+
+        let recipe = parse_scheduled_recipe(&job).expect("scheduled child recipe should load");
+
+        assert_eq!(
+            recipe.instructions.as_deref(),
+            Some("Follow the team style guide")
+        );
+        assert_eq!(recipe.prompt.as_deref(), Some("Write the weekly report"));
+        assert!(parse_scheduled_recipe(&ScheduledJob {
+            recipe_dir: None,
+            ..job
+        })
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scheduled_package_keeps_its_sub_recipes() -> Result<(), Box<dyn std::error::Error>> {
+        let package_dir = tempdir()?;
+        fs::create_dir_all(package_dir.path().join("subrecipes"))?;
+        fs::write(
+            package_dir.path().join("recipe.yaml"),
+            r#"version: 1.0.0
+title: Review
+description: Review a change
+prompt: Review the change
+sub_recipes:
+  - name: check
+    path: ./subrecipes/check.yaml
+"#,
+        )?;
+        fs::write(
+            package_dir.path().join("subrecipes/check.yaml"),
+            r#"version: 1.0.0
+title: Check
+description: Check a change
+prompt: Check the change
+"#,
+        )?;
+        let scheduled_recipes_dir = tempdir()?;
+        let job = ScheduledJob {
+            id: "review".to_string(),
+            source: package_dir.path().to_string_lossy().into_owned(),
+            trigger: ScheduleTrigger::Cron {
+                expression: "0 0 9 * * 1".to_string(),
+            },
+            last_run: None,
+            currently_running: false,
+            paused: false,
+            current_session_id: None,
+            process_start_time: None,
+            execution_mode: None,
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: None,
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
+        };
+
+        let recipe_path = resolve_recipe_source(package_dir.path())?;
+        let (stored_path, recipe_dir) =
+            store_scheduled_recipe(&job, &recipe_path, scheduled_recipes_dir.path())?;
+        // Runs must not depend on the package the job was scheduled from
+        drop(package_dir);
+        let recipe = parse_scheduled_recipe(&ScheduledJob {
+            source: stored_path.to_string_lossy().into_owned(),
+            recipe_dir,
+            ..job
+        })
+        .expect("scheduled package should load");
+
+        assert_eq!(recipe.prompt.as_deref(), Some("Review the change"));
+        let sub_recipes = recipe.sub_recipes.expect("package sub-recipe");
+        let sub_recipe_file = read_recipe_file(&sub_recipes[0].path)?;
+        assert!(sub_recipe_file.content.contains("Check the change"));
+        Ok(())
+    }
 }
@@ -0,0 +1,394 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::process::Command;
+use url::Url;
+use utoipa::ToSchema;
+
+use crate::scheduler::SchedulerError;
+use crate::utils::is_localhost_url;
+
+/// Runs kept per schedule; older runs are dropped when a new one is recorded
+const MAX_RUNS_PER_SCHEDULE: usize = 200;
+const RUN_HISTORY_DIR: &str = "schedule_runs";
+const HOOK_TIMEOUT: Duration = Duration::from_secs(60);
+
+const DEFAULT_BACKOFF_SECONDS: u64 = 60;
+const DEFAULT_MAX_BACKOFF_SECONDS: u64 = 3600;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledRunStatus {
+    Succeeded,
+    Failed,
+    Cancelled,
+    /// The schedule fired but did not start a run, see the run's error for why
+    Skipped,
+}
+
+impl std::fmt::Display for ScheduledRunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduledRunStatus::Succeeded => write!(f, "succeeded"),
+            ScheduledRunStatus::Failed => write!(f, "failed"),
+            ScheduledRunStatus::Cancelled => write!(f, "cancelled"),
+            ScheduledRunStatus::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+/// One attempt at executing a scheduled job
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ScheduledJobRun {
+    pub schedule_id: String,
+    /// Attempt number within one execution, starting at 1; retries count up from there
+    pub attempt: u32,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub status: ScheduledRunStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_tokens: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_tokens: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_tokens: Option<i32>,
+    /// Whether the recipe's retry success checks passed; absent when the recipe has none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checks_passed: Option<bool>,
+}
+
+/// Retry a failed run, waiting `backoff_seconds` before the first retry and twice as long
+/// before each one after it, up to `max_backoff_seconds`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    #[serde(default = "default_backoff_seconds")]
+    pub backoff_seconds: u64,
+    #[serde(default = "default_max_backoff_seconds")]
+    pub max_backoff_seconds: u64,
+}
+
+fn default_backoff_seconds() -> u64 {
+    DEFAULT_BACKOFF_SECONDS
+}
+
+fn default_max_backoff_seconds() -> u64 {
+    DEFAULT_MAX_BACKOFF_SECONDS
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            backoff_seconds: DEFAULT_BACKOFF_SECONDS,
+            max_backoff_seconds: DEFAULT_MAX_BACKOFF_SECONDS,
+        }
+    }
+
+    /// How long to wait before the given retry, counting from 1
+    pub fn delay_before_retry(&self, retry: u32) -> Duration {
+        let factor = 2u64.saturating_pow(retry.saturating_sub(1));
+        let seconds = self
+            .backoff_seconds
+            .saturating_mul(factor)
+            .min(self.max_backoff_seconds);
+        Duration::from_secs(seconds)
+    }
+}
+
+/// Alert fired when a scheduled job finishes, after any retries
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduleHook {
+    /// Shell command, run with GOOSE_SCHEDULE_ID, GOOSE_RUN_STATUS, GOOSE_RUN_ERROR and
+    /// GOOSE_SESSION_ID set
+    Command { command: String },
+    /// URL the run record is POSTed to as JSON; must point at localhost
+    Webhook { url: String },
+}
+
+impl ScheduleHook {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ScheduleHook::Command { command } => {
+                if command.trim().is_empty() {
+                    return Err("Hook command cannot be empty".to_string());
+                }
+                Ok(())
+            }
+            ScheduleHook::Webhook { url } => {
+                let parsed =
+                    Url::parse(url).map_err(|e| format!("Invalid webhook URL '{}': {}", url, e))?;
+                if !is_localhost_url(&parsed) {
+                    return Err(format!("Webhook URL '{}' must point at localhost", url));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub async fn fire(&self, run: &ScheduledJobRun) -> Result<()> {
+        match self {
+            ScheduleHook::Command { command } => run_hook_command(command, run).await,
+            ScheduleHook::Webhook { url } => post_hook_webhook(url, run).await,
+        }
+    }
+}
+
+async fn run_hook_command(command: &str, run: &ScheduledJobRun) -> Result<()> {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    };
+    cmd.env("GOOSE_SCHEDULE_ID", &run.schedule_id)
+        .env("GOOSE_RUN_STATUS", run.status.to_string())
+        .env("GOOSE_RUN_ERROR", run.error.as_deref().unwrap_or_default())
+        .env(
+            "GOOSE_SESSION_ID",
+            run.session_id.as_deref().unwrap_or_default(),
+        )
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .stdin(Stdio::null())
+        .kill_on_drop(true);
+
+    let output = tokio::time::timeout(HOOK_TIMEOUT, cmd.output())
+        .await
+        .map_err(|_| anyhow!("Hook command timed out after {:?}", HOOK_TIMEOUT))??;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Hook command exited with status {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+async fn post_hook_webhook(url: &str, run: &ScheduledJobRun) -> Result<()> {
+    let response = reqwest::Client::builder()
+        .timeout(HOOK_TIMEOUT)
+        .build()?
+        .post(url)
+        .json(run)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Webhook returned status {}", response.status()));
+    }
+    Ok(())
+}
+
+/// Append-only record of every run of every schedule, one JSON lines file per schedule
+pub struct RunHistory {
+    dir: PathBuf,
+}
+
+impl RunHistory {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// History kept next to the scheduler's `schedules.json`
+    pub fn for_storage_path(storage_path: &Path) -> Self {
+        let parent = storage_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::new(parent.join(RUN_HISTORY_DIR))
+    }
+
+    fn path(&self, schedule_id: &str) -> PathBuf {
+        self.dir.join(format!("{}.jsonl", schedule_id))
+    }
+
+    pub fn record(&self, run: &ScheduledJobRun) -> Result<(), SchedulerError> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(&run.schedule_id);
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        writeln!(file, "{}", serde_json::to_string(run)?)?;
+        drop(file);
+
+        let runs = self.read(&run.schedule_id)?;
+        if runs.len() > MAX_RUNS_PER_SCHEDULE {
+            let kept = &runs[runs.len() - MAX_RUNS_PER_SCHEDULE..];
+            let mut content = String::new();
+            for run in kept {
+                content.push_str(&serde_json::to_string(run)?);
+                content.push('\n');
+            }
+            fs::write(&path, content)?;
+        }
+        Ok(())
+    }
+
+    /// Most recent runs of the schedule, newest first
+    pub fn list(
+        &self,
+        schedule_id: &str,
+        limit: usize,
+    ) -> Result<Vec<ScheduledJobRun>, SchedulerError> {
+        let mut runs = self.read(schedule_id)?;
+        runs.reverse();
+        runs.truncate(limit);
+        Ok(runs)
+    }
+
+    pub fn remove(&self, schedule_id: &str) -> Result<(), SchedulerError> {
+        match fs::remove_file(self.path(schedule_id)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn read(&self, schedule_id: &str) -> Result<Vec<ScheduledJobRun>, SchedulerError> {
+        let path = self.path(schedule_id);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&path)?;
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(run) => Some(run),
+                Err(e) => {
+                    tracing::warn!("Skipping unreadable run in {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_run(schedule_id: &str, attempt: u32, status: ScheduledRunStatus) -> ScheduledJobRun {
+        let now = Utc::now();
+        ScheduledJobRun {
+            schedule_id: schedule_id.to_string(),
+            attempt,
+            started_at: now,
+            ended_at: now,
+            status,
+            error: None,
+            session_id: None,
+            input_tokens: None,
+            output_tokens: None,
+            total_tokens: None,
+            checks_passed: None,
+        }
+    }
+
+    #[test]
+    fn test_run_history_lists_newest_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = RunHistory::new(temp_dir.path().to_path_buf());
+
+        history
+            .record(&create_run("nightly", 1, ScheduledRunStatus::Failed))
+            .unwrap();
+        history
+            .record(&create_run("nightly", 2, ScheduledRunStatus::Succeeded))
+            .unwrap();
+        history
+            .record(&create_run("other", 1, ScheduledRunStatus::Succeeded))
+            .unwrap();
+
+        let runs = history.list("nightly", 10).unwrap();
+        let attempts: Vec<u32> = runs.iter().map(|run| run.attempt).collect();
+        assert_eq!(attempts, vec![2, 1]);
+        assert_eq!(history.list("nightly", 1).unwrap().len(), 1);
+        assert!(history.list("missing", 10).unwrap().is_empty());
+
+        history.remove("nightly").unwrap();
+        assert!(history.list("nightly", 10).unwrap().is_empty());
+        history.remove("nightly").unwrap();
+    }
+
+    #[test]
+    fn test_run_history_keeps_most_recent_runs() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = RunHistory::new(temp_dir.path().to_path_buf());
+
+        for attempt in 1..=(MAX_RUNS_PER_SCHEDULE as u32 + 5) {
+            history
+                .record(&create_run(
+                    "nightly",
+                    attempt,
+                    ScheduledRunStatus::Succeeded,
+                ))
+                .unwrap();
+        }
+
+        let runs = history.list("nightly", usize::MAX).unwrap();
+        assert_eq!(runs.len(), MAX_RUNS_PER_SCHEDULE);
+        assert_eq!(runs[0].attempt, MAX_RUNS_PER_SCHEDULE as u32 + 5);
+        assert_eq!(runs.last().unwrap().attempt, 6);
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            backoff_seconds: 30,
+            max_backoff_seconds: 100,
+        };
+
+        assert_eq!(policy.delay_before_retry(1), Duration::from_secs(30));
+        assert_eq!(policy.delay_before_retry(2), Duration::from_secs(60));
+        assert_eq!(policy.delay_before_retry(3), Duration::from_secs(100));
+        assert_eq!(policy.delay_before_retry(40), Duration::from_secs(100));
+    }
+
+    #[test]
+    fn test_webhook_hook_must_be_local() {
+        let local = ScheduleHook::Webhook {
+            url: "http://127.0.0.1:9000/alerts".to_string(),
+        };
+        let remote = ScheduleHook::Webhook {
+            url: "https://example.com/alerts".to_string(),
+        };
+
+        assert!(local.validate().is_ok());
+        assert_eq!(
+            remote.validate().unwrap_err(),
+            "Webhook URL 'https://example.com/alerts' must point at localhost"
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(not(target_os = "windows"))]
+    async fn test_command_hook_receives_run_details() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("hook.txt");
+        let hook = ScheduleHook::Command {
+            command: format!(
+                "echo \"$GOOSE_SCHEDULE_ID $GOOSE_RUN_STATUS\" > {}",
+                output_path.display()
+            ),
+        };
+
+        hook.fire(&create_run("nightly", 1, ScheduledRunStatus::Failed))
+            .await
+            .unwrap();
+
+        let output = fs::read_to_string(&output_path).unwrap();
+        assert_eq!(output.trim(), "nightly failed");
+    }
+}
@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// What to do when a schedule fires while its previous run is still going
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlapPolicy {
+    /// Drop the new run
+    #[default]
+    Skip,
+    /// Start the new run once the previous one finishes; at most one run waits
+    Queue,
+    /// Cancel the previous run and start the new one
+    KillPrevious,
+}
+
+impl std::fmt::Display for OverlapPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OverlapPolicy::Skip => write!(f, "skip"),
+            OverlapPolicy::Queue => write!(f, "queue"),
+            OverlapPolicy::KillPrevious => write!(f, "kill_previous"),
+        }
+    }
+}
+
+impl std::str::FromStr for OverlapPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(OverlapPolicy::Skip),
+            "queue" => Ok(OverlapPolicy::Queue),
+            "kill_previous" => Ok(OverlapPolicy::KillPrevious),
+            _ => Err(format!(
+                "Unknown overlap policy '{}', expected skip, queue or kill_previous",
+                s
+            )),
+        }
+    }
+}
+
+/// A period in which the schedule does not start runs, e.g. a deploy freeze
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct BlackoutWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl BlackoutWindow {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.end <= self.start {
+            return Err(format!(
+                "Blackout window ending at {} must end after it starts at {}",
+                self.end.to_rfc3339(),
+                self.start.to_rfc3339()
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        self.start <= at && at < self.end
+    }
+}
+
+impl std::fmt::Display for BlackoutWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} to {}",
+            self.start.to_rfc3339(),
+            self.end.to_rfc3339()
+        )?;
+        if let Some(reason) = &self.reason {
+            write!(f, " ({})", reason)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn active_blackout_window(
+    windows: &[BlackoutWindow],
+    at: DateTime<Utc>,
+) -> Option<&BlackoutWindow> {
+    windows.iter().find(|window| window.contains(at))
+}
+
+/// Parses an IANA timezone name such as `Europe/Berlin`
+pub fn parse_timezone(name: &str) -> Result<Tz> {
+    name.parse::<Tz>()
+        .map_err(|_| anyhow!("Unknown timezone '{}'", name))
+}
+
+/// A random delay of up to `max_seconds`, so that schedules sharing a cron expression
+/// don't all start at the same moment
+pub fn jitter_delay(max_seconds: u64) -> Duration {
+    if max_seconds == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_millis(rand::thread_rng().gen_range(0..=max_seconds * 1000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn window(start_hour: u32, end_hour: u32) -> BlackoutWindow {
+        BlackoutWindow {
+            start: Utc.with_ymd_and_hms(2025, 6, 1, start_hour, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2025, 6, 1, end_hour, 0, 0).unwrap(),
+            reason: Some("deploy freeze".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_active_blackout_window() {
+        let windows = vec![window(2, 4), window(10, 12)];
+
+        let inside = Utc.with_ymd_and_hms(2025, 6, 1, 11, 30, 0).unwrap();
+        let at_end = Utc.with_ymd_and_hms(2025, 6, 1, 4, 0, 0).unwrap();
+
+        assert_eq!(active_blackout_window(&windows, inside), Some(&windows[1]));
+        assert_eq!(active_blackout_window(&windows, at_end), None);
+    }
+
+    #[test]
+    fn test_blackout_window_must_end_after_start() {
+        assert!(window(2, 4).validate().is_ok());
+        assert!(window(4, 4).validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(parse_timezone("Europe/Berlin").unwrap(), Tz::Europe__Berlin);
+        assert_eq!(
+            parse_timezone("Mars/Olympus").unwrap_err().to_string(),
+            "Unknown timezone 'Mars/Olympus'"
+        );
+    }
+
+    #[test]
+    fn test_jitter_delay_is_bounded() {
+        assert_eq!(jitter_delay(0), Duration::ZERO);
+        for _ in 0..100 {
+            assert!(jitter_delay(5) <= Duration::from_secs(5));
+        }
+    }
+
+    #[test]
+    fn test_overlap_policy_round_trip() {
+        for policy in [
+            OverlapPolicy::Skip,
+            OverlapPolicy::Queue,
+            OverlapPolicy::KillPrevious,
+        ] {
+            assert_eq!(policy.to_string().parse::<OverlapPolicy>(), Ok(policy));
+            assert_eq!(
+                serde_json::to_value(policy).unwrap(),
+                serde_json::json!(policy.to_string())
+            );
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 
 use crate::scheduler::{ScheduledJob, SchedulerError};
+use crate::scheduler_history::ScheduledJobRun;
 use crate::session::Session;
 
 /// Common trait for all scheduler implementations
@@ -32,10 +35,25 @@ pub trait SchedulerTrait: Send + Sync {
         limit: usize,
     ) -> Result<Vec<(String, Session)>, SchedulerError>;
 
+    /// Get the recorded runs of a scheduled job, newest first
+    async fn runs(
+        &self,
+        sched_id: &str,
+        limit: usize,
+    ) -> Result<Vec<ScheduledJobRun>, SchedulerError>;
+
     /// Update a schedule's cron expression
     async fn update_schedule(&self, sched_id: &str, new_cron: String)
         -> Result<(), SchedulerError>;
 
+    /// Start a run of a job for an event such as a webhook call, passing the event's
+    /// values as recipe parameters
+    async fn trigger_job(
+        &self,
+        sched_id: &str,
+        event_parameters: HashMap<String, String>,
+    ) -> Result<(), SchedulerError>;
+
     /// Kill a running job
     async fn kill_running_job(&self, sched_id: &str) -> Result<(), SchedulerError>;
 
@@ -0,0 +1,487 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::ToSchema;
+
+/// How often file and git triggers check their source for changes
+const TRIGGER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_DEBOUNCE_SECONDS: u64 = 2;
+
+pub const CHANGED_PATHS_PARAM: &str = "changed_paths";
+pub const GIT_REF_PARAM: &str = "ref";
+pub const PREVIOUS_COMMIT_PARAM: &str = "previous_commit";
+pub const COMMIT_PARAM: &str = "commit";
+
+/// What starts the runs of a scheduled job
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduleTrigger {
+    /// Runs on a cron expression
+    Cron { expression: String },
+    /// Runs when a file under the path is created, modified or removed; the recipe gets the
+    /// changed paths, one per line, as `changed_paths`
+    FileChange { path: String },
+    /// Runs when a ref of a local git repository moves; the recipe gets `ref`,
+    /// `previous_commit` and `commit`
+    GitRef {
+        repo: String,
+        #[serde(rename = "ref")]
+        git_ref: String,
+    },
+    /// Runs when a JSON payload is POSTed to the schedule's webhook on goose-server; the
+    /// payload's fields are passed as recipe parameters
+    Webhook,
+}
+
+impl ScheduleTrigger {
+    pub fn cron_expression(&self) -> Option<&str> {
+        match self {
+            ScheduleTrigger::Cron { expression } => Some(expression),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ScheduleTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleTrigger::Cron { expression } => write!(f, "cron {}", expression),
+            ScheduleTrigger::FileChange { path } => write!(f, "changes under {}", path),
+            ScheduleTrigger::GitRef { repo, git_ref } => {
+                write!(f, "updates to {} in {}", git_ref, repo)
+            }
+            ScheduleTrigger::Webhook => write!(f, "webhook"),
+        }
+    }
+}
+
+/// Reads a job's trigger, accepting the bare cron expression that schedules stored before
+/// triggers existed
+pub fn deserialize_trigger<'de, D>(
+    deserializer: D,
+) -> std::result::Result<ScheduleTrigger, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredTrigger {
+        Cron(String),
+        Trigger(ScheduleTrigger),
+    }
+
+    Ok(match StoredTrigger::deserialize(deserializer)? {
+        StoredTrigger::Cron(expression) => ScheduleTrigger::Cron { expression },
+        StoredTrigger::Trigger(trigger) => trigger,
+    })
+}
+
+/// Turns a webhook's JSON payload into recipe parameters. String fields are passed as they
+/// are, other fields as JSON.
+pub fn webhook_parameters(payload: &serde_json::Value) -> Result<HashMap<String, String>> {
+    match payload {
+        serde_json::Value::Null => Ok(HashMap::new()),
+        serde_json::Value::Object(fields) => Ok(fields
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                (key.clone(), value)
+            })
+            .collect()),
+        _ => Err(anyhow!("Webhook payload must be a JSON object")),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl From<&fs::Metadata> for FileStamp {
+    fn from(metadata: &fs::Metadata) -> Self {
+        Self {
+            modified: metadata.modified().ok(),
+            len: metadata.len(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum SourceState {
+    Files(BTreeMap<PathBuf, FileStamp>),
+    Commit(String),
+}
+
+enum WatchedSource {
+    Files(PathBuf),
+    GitRef { repo: PathBuf, git_ref: String },
+}
+
+impl WatchedSource {
+    fn read_state(&self) -> Result<SourceState> {
+        match self {
+            WatchedSource::Files(root) => {
+                let mut files = BTreeMap::new();
+                snapshot_files(root, &mut files)?;
+                Ok(SourceState::Files(files))
+            }
+            WatchedSource::GitRef { repo, git_ref } => {
+                resolve_git_ref(repo, git_ref).map(SourceState::Commit)
+            }
+        }
+    }
+}
+
+/// Directories that hold version control data, dependencies or build output, which change
+/// too often and are too large to watch
+const IGNORED_DIRECTORIES: &[&str] = &[
+    ".git",
+    ".hg",
+    ".svn",
+    "target",
+    "node_modules",
+    ".venv",
+    "__pycache__",
+    "dist",
+    "build",
+];
+
+fn snapshot_files(root: &Path, files: &mut BTreeMap<PathBuf, FileStamp>) -> Result<()> {
+    let metadata =
+        fs::metadata(root).map_err(|e| anyhow!("Cannot watch {}: {}", root.display(), e))?;
+    if !metadata.is_dir() {
+        files.insert(root.to_path_buf(), FileStamp::from(&metadata));
+        return Ok(());
+    }
+
+    // Symlinks below the root are recorded but not followed, so links pointing back up the
+    // tree cannot loop. Entries removed while walking are expected and skipped.
+    let mut directories = vec![root.to_path_buf()];
+    while let Some(directory) = directories.pop() {
+        let entries = match fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound && directory != root => continue,
+            Err(e) => return Err(anyhow!("Cannot watch {}: {}", directory.display(), e)),
+        };
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let path = entry.path();
+            let metadata = match fs::symlink_metadata(&path) {
+                Ok(metadata) => metadata,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            if metadata.is_dir() {
+                let ignored = entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| IGNORED_DIRECTORIES.contains(&name));
+                if !ignored {
+                    directories.push(path);
+                }
+            } else {
+                files.insert(path, FileStamp::from(&metadata));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn resolve_git_ref(repo: &Path, git_ref: &str) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{}^{{commit}}", git_ref))
+        .output()
+        .map_err(|e| anyhow!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "Cannot resolve ref '{}' in {}",
+            git_ref,
+            repo.display()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Polls the source of a file or git trigger and reports a change once the source has been
+/// quiet for the debounce period, so that a burst of changes starts a single run
+pub struct TriggerWatcher {
+    source: WatchedSource,
+    debounce: Duration,
+    /// State the last reported change ended in
+    baseline: SourceState,
+    latest: SourceState,
+    latest_changed_at: Option<Instant>,
+}
+
+impl TriggerWatcher {
+    /// Creates a watcher for triggers that watch a local source, `None` for the others
+    pub fn new(trigger: &ScheduleTrigger, debounce_seconds: Option<u64>) -> Result<Option<Self>> {
+        let source = match trigger {
+            ScheduleTrigger::FileChange { path } => WatchedSource::Files(PathBuf::from(path)),
+            ScheduleTrigger::GitRef { repo, git_ref } => WatchedSource::GitRef {
+                repo: PathBuf::from(repo),
+                git_ref: git_ref.clone(),
+            },
+            ScheduleTrigger::Cron { .. } | ScheduleTrigger::Webhook => return Ok(None),
+        };
+        let baseline = source.read_state()?;
+        Ok(Some(Self {
+            source,
+            debounce: Duration::from_secs(debounce_seconds.unwrap_or(DEFAULT_DEBOUNCE_SECONDS)),
+            latest: baseline.clone(),
+            baseline,
+            latest_changed_at: None,
+        }))
+    }
+
+    /// Checks the source once, returning the recipe parameters describing the change when
+    /// one is due
+    pub fn poll(&mut self, now: Instant) -> Result<Option<HashMap<String, String>>> {
+        let state = self.source.read_state()?;
+        if state != self.latest {
+            self.latest = state;
+            self.latest_changed_at = Some(now);
+        }
+        match self.latest_changed_at {
+            Some(changed_at) if now.duration_since(changed_at) >= self.debounce => {
+                self.latest_changed_at = None;
+                let parameters = self.change_parameters();
+                self.baseline = self.latest.clone();
+                Ok(parameters)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn change_parameters(&self) -> Option<HashMap<String, String>> {
+        match (&self.baseline, &self.latest, &self.source) {
+            (SourceState::Files(before), SourceState::Files(after), _) => {
+                let changed: BTreeSet<&PathBuf> = before
+                    .keys()
+                    .chain(after.keys())
+                    .filter(|path| before.get(*path) != after.get(*path))
+                    .collect();
+                if changed.is_empty() {
+                    return None;
+                }
+                let changed_paths = changed
+                    .iter()
+                    .map(|path| path.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Some(HashMap::from([(
+                    CHANGED_PATHS_PARAM.to_string(),
+                    changed_paths,
+                )]))
+            }
+            (
+                SourceState::Commit(previous),
+                SourceState::Commit(commit),
+                WatchedSource::GitRef { git_ref, .. },
+            ) if previous != commit => Some(HashMap::from([
+                (GIT_REF_PARAM.to_string(), git_ref.clone()),
+                (PREVIOUS_COMMIT_PARAM.to_string(), previous.clone()),
+                (COMMIT_PARAM.to_string(), commit.clone()),
+            ])),
+            _ => None,
+        }
+    }
+}
+
+/// Watches the source of a file or git trigger in the background and calls `on_event` with
+/// the change's recipe parameters. Returns `None` for triggers that have nothing to watch.
+pub fn spawn_trigger_watcher<F, Fut>(
+    job_id: &str,
+    trigger: &ScheduleTrigger,
+    debounce_seconds: Option<u64>,
+    on_event: F,
+) -> Result<Option<tokio::task::AbortHandle>>
+where
+    F: Fn(HashMap<String, String>) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let Some(mut watcher) = TriggerWatcher::new(trigger, debounce_seconds)? else {
+        return Ok(None);
+    };
+    let job_id = job_id.to_string();
+    let task = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TRIGGER_POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+            let polled = tokio::task::spawn_blocking(move || {
+                let result = watcher.poll(Instant::now());
+                (watcher, result)
+            })
+            .await;
+            let Ok((returned_watcher, result)) = polled else {
+                tracing::error!("Trigger watcher of job '{}' stopped", job_id);
+                return;
+            };
+            watcher = returned_watcher;
+            match result {
+                Ok(Some(parameters)) => {
+                    tracing::info!("Trigger of job '{}' fired", job_id);
+                    tokio::spawn(on_event(parameters));
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Failed to check trigger of job '{}': {}", job_id, e),
+            }
+        }
+    });
+    Ok(Some(task.abort_handle()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_file_change_is_debounced() {
+        let temp_dir = TempDir::new().unwrap();
+        let notes = temp_dir.path().join("notes.md");
+        fs::write(&notes, "first").unwrap();
+        let trigger = ScheduleTrigger::FileChange {
+            path: temp_dir.path().to_string_lossy().into_owned(),
+        };
+        let mut watcher = TriggerWatcher::new(&trigger, Some(5)).unwrap().unwrap();
+        let start = Instant::now();
+
+        assert_eq!(watcher.poll(start).unwrap(), None);
+        fs::write(&notes, "second draft").unwrap();
+        assert_eq!(watcher.poll(start + Duration::from_secs(1)).unwrap(), None);
+        let todo = temp_dir.path().join("todo.md");
+        fs::write(&todo, "ship it").unwrap();
+        // Still changing, so the quiet period starts over
+        assert_eq!(watcher.poll(start + Duration::from_secs(3)).unwrap(), None);
+        assert_eq!(watcher.poll(start + Duration::from_secs(7)).unwrap(), None);
+
+        let parameters = watcher
+            .poll(start + Duration::from_secs(8))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            parameters[CHANGED_PATHS_PARAM],
+            format!("{}\n{}", notes.display(), todo.display())
+        );
+        assert_eq!(watcher.poll(start + Duration::from_secs(20)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_snapshot_skips_ignored_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let notes = temp_dir.path().join("notes.md");
+        fs::write(&notes, "notes").unwrap();
+        for ignored in ["target", "node_modules", ".git"] {
+            fs::create_dir(temp_dir.path().join(ignored)).unwrap();
+            fs::write(temp_dir.path().join(ignored).join("output"), "ignored").unwrap();
+        }
+        let nested = temp_dir.path().join("src");
+        fs::create_dir(&nested).unwrap();
+        fs::write(nested.join("main.rs"), "fn main() {}").unwrap();
+
+        let mut files = BTreeMap::new();
+        snapshot_files(temp_dir.path(), &mut files).unwrap();
+
+        assert_eq!(
+            files.keys().cloned().collect::<Vec<_>>(),
+            vec![notes, nested.join("main.rs")]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_snapshot_does_not_follow_symlinks() {
+        let temp_dir = TempDir::new().unwrap();
+        let nested = temp_dir.path().join("nested");
+        fs::create_dir(&nested).unwrap();
+        let link = nested.join("loop");
+        std::os::unix::fs::symlink(temp_dir.path(), &link).unwrap();
+
+        let mut files = BTreeMap::new();
+        snapshot_files(temp_dir.path(), &mut files).unwrap();
+
+        assert_eq!(files.keys().cloned().collect::<Vec<_>>(), vec![link]);
+    }
+
+    #[test]
+    fn test_watcher_requires_existing_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let trigger = ScheduleTrigger::FileChange {
+            path: temp_dir
+                .path()
+                .join("missing")
+                .to_string_lossy()
+                .into_owned(),
+        };
+
+        assert!(TriggerWatcher::new(&trigger, None).is_err());
+        assert!(TriggerWatcher::new(&ScheduleTrigger::Webhook, None)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_deserialize_trigger_accepts_cron_string() {
+        #[derive(Deserialize)]
+        struct Job {
+            #[serde(alias = "cron", deserialize_with = "deserialize_trigger")]
+            trigger: ScheduleTrigger,
+        }
+
+        let stored: Job = serde_json::from_str(r#"{"cron": "0 0 * * * *"}"#).unwrap();
+        assert_eq!(
+            stored.trigger,
+            ScheduleTrigger::Cron {
+                expression: "0 0 * * * *".to_string()
+            }
+        );
+
+        let git: Job =
+            serde_json::from_str(r#"{"trigger": {"type": "git_ref", "repo": ".", "ref": "main"}}"#)
+                .unwrap();
+        assert_eq!(
+            git.trigger,
+            ScheduleTrigger::GitRef {
+                repo: ".".to_string(),
+                git_ref: "main".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_webhook_parameters() {
+        let parameters = webhook_parameters(&serde_json::json!({
+            "branch": "main",
+            "pr": 42,
+            "labels": ["bug"]
+        }))
+        .unwrap();
+
+        assert_eq!(parameters["branch"], "main");
+        assert_eq!(parameters["pr"], "42");
+        assert_eq!(parameters["labels"], r#"["bug"]"#);
+        assert!(webhook_parameters(&serde_json::json!("main")).is_err());
+        assert!(webhook_parameters(&serde_json::Value::Null)
+            .unwrap()
+            .is_empty());
+    }
+}
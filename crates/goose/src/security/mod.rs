@@ -1,4 +1,5 @@
 pub mod patterns;
+pub mod rules;
 pub mod scanner;
 pub mod security_inspector;
 
@@ -100,10 +101,10 @@ impl SecurityManager {
                     "🔍 Starting security analysis for current tool call"
                 );
 
-                // Analyze only the current tool call content, not the entire conversation history
-                // This prevents re-analyzing and re-flagging historical malicious content
+                // Score the current tool call against the conversation so far; earlier tool
+                // responses are untrusted and may be steering this call
                 let analysis_result = scanner
-                    .analyze_tool_call_with_context(tool_call, &[]) // Pass empty messages to avoid historical analysis
+                    .analyze_tool_call_with_context(tool_call, messages)
                     .await?;
 
                 // Get threshold from config - only flag things above threshold
@@ -146,6 +147,8 @@ impl SecurityManager {
                         explanation = %analysis_result.explanation,
                         finding_id = %finding_id,
                         threshold = config_threshold,
+                        rule_files = ?analysis_result.rule_files,
+                        influenced_by = ?analysis_result.influenced_by,
                         "🔒 Current tool call flagged as malicious after security analysis (above threshold)"
                     );
 
@@ -0,0 +1,213 @@
+use crate::config::{get_config_dir, Config};
+use crate::security::patterns::{RiskLevel, ThreatCategory};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Name of the rule file read from the config dir and from `.goose/` in the project
+pub const SECURITY_RULES_FILENAME: &str = "security_rules.yaml";
+
+/// Extra threat patterns and allowlist entries loaded from a YAML rule file
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RuleFile {
+    #[serde(default)]
+    pub patterns: Vec<CustomThreatPattern>,
+    #[serde(default)]
+    pub allowlist: Vec<AllowlistEntry>,
+    /// Path the rules were read from
+    #[serde(skip)]
+    pub source: PathBuf,
+}
+
+/// A user-defined threat pattern, matched case-insensitively like the built-in ones
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomThreatPattern {
+    pub name: String,
+    pub pattern: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub risk_level: RiskLevel,
+    pub category: ThreatCategory,
+    /// Tool names the pattern applies to; empty means every tool
+    #[serde(default)]
+    pub tools: Vec<String>,
+}
+
+/// Suppresses threat matches on lines that are known to be safe
+#[derive(Debug, Clone, Deserialize)]
+pub struct AllowlistEntry {
+    /// Regex that must match the whole line containing the threat
+    pub pattern: String,
+    /// Threat pattern names to suppress; empty means any pattern
+    #[serde(default)]
+    pub rules: Vec<String>,
+    /// Threat categories to suppress; empty means any category
+    #[serde(default)]
+    pub categories: Vec<ThreatCategory>,
+    /// Tool names the entry applies to; empty means every tool
+    #[serde(default)]
+    pub tools: Vec<String>,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// The global rule file followed by the project rule file. Allowlist entries in the project
+/// file are dropped unless the project is trusted in the user config, so a cloned repository
+/// can add threat patterns but can't switch any off.
+pub fn load_security_rules() -> Vec<RuleFile> {
+    let mut files = load_rule_files(&[get_config_dir().join(SECURITY_RULES_FILENAME)]);
+    if let Ok(cwd) = std::env::current_dir() {
+        let trusted = is_trusted_project(&cwd, &trusted_project_dirs());
+        files.extend(load_project_rule_file(&cwd, trusted));
+    }
+    files
+}
+
+/// Project dirs listed under `security.trusted_project_dirs` in the user config
+fn trusted_project_dirs() -> Vec<PathBuf> {
+    Config::global()
+        .get_param::<serde_json::Value>("security")
+        .ok()
+        .and_then(|security| security.get("trusted_project_dirs").cloned())
+        .and_then(|dirs| serde_json::from_value(dirs).ok())
+        .unwrap_or_default()
+}
+
+fn is_trusted_project(project_dir: &Path, trusted_dirs: &[PathBuf]) -> bool {
+    let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let project_dir = canonical(project_dir);
+    trusted_dirs.iter().any(|dir| canonical(dir) == project_dir)
+}
+
+fn load_project_rule_file(project_dir: &Path, trusted: bool) -> Vec<RuleFile> {
+    let path = project_dir.join(".goose").join(SECURITY_RULES_FILENAME);
+    let mut files = load_rule_files(&[path]);
+    for file in files.iter_mut().filter(|file| !file.allowlist.is_empty()) {
+        if !trusted {
+            tracing::warn!(
+                path = %file.source.display(),
+                "Ignoring allowlist from untrusted project security rules; add the project to security.trusted_project_dirs to apply it"
+            );
+            file.allowlist.clear();
+        }
+    }
+    files
+}
+
+/// Load every rule file that exists. Unreadable or invalid files are logged and skipped
+/// so a typo never disables the built-in patterns.
+pub fn load_rule_files(paths: &[PathBuf]) -> Vec<RuleFile> {
+    paths
+        .iter()
+        .filter(|path| path.is_file())
+        .filter_map(|path| match load_rule_file(path) {
+            Ok(rules) => Some(rules),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), "Ignoring security rule file: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
+fn load_rule_file(path: &Path) -> anyhow::Result<RuleFile> {
+    let content = std::fs::read_to_string(path)?;
+    let mut rules: RuleFile = serde_yaml::from_str(&content)?;
+    rules.source = path.to_path_buf();
+    Ok(rules)
+}
+
+/// Whether a rule scoped to `tools` applies to a call of `tool_name`. Scoped rules match
+/// either the full prefixed name (`developer__shell`) or the bare tool name (`shell`).
+pub fn applies_to_tool(tools: &[String], tool_name: Option<&str>) -> bool {
+    if tools.is_empty() {
+        return true;
+    }
+    let Some(tool_name) = tool_name else {
+        return false;
+    };
+    let base_name = tool_name.rsplit("__").next().unwrap_or(tool_name);
+    tools.iter().any(|t| t == tool_name || t == base_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_rule_files_skips_missing_and_invalid() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let valid = temp_dir.path().join("valid.yaml");
+        let invalid = temp_dir.path().join("invalid.yaml");
+        std::fs::write(
+            &valid,
+            r#"
+patterns:
+  - name: internal_secrets
+    pattern: 'curl\s+.*secrets\.internal'
+    risk_level: critical
+    category: data_exfiltration
+    tools: [shell]
+allowlist:
+  - pattern: 'rm -rf \./target'
+    rules: [rm_rf_root]
+    reason: cargo build output
+"#,
+        )
+        .unwrap();
+        std::fs::write(&invalid, "patterns: [{name: broken}]").unwrap();
+
+        let files =
+            load_rule_files(&[valid.clone(), invalid, temp_dir.path().join("missing.yaml")]);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].source, valid);
+        assert_eq!(files[0].patterns[0].risk_level, RiskLevel::Critical);
+        assert_eq!(
+            files[0].patterns[0].category,
+            ThreatCategory::DataExfiltration
+        );
+        assert_eq!(files[0].allowlist[0].rules, vec!["rm_rf_root"]);
+    }
+
+    #[test]
+    fn test_project_allowlist_requires_trust() {
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir(project.path().join(".goose")).unwrap();
+        std::fs::write(
+            project.path().join(".goose").join(SECURITY_RULES_FILENAME),
+            r#"
+patterns:
+  - name: internal_secrets
+    pattern: 'secrets\.internal'
+    risk_level: high
+    category: data_exfiltration
+allowlist:
+  - pattern: '.*'
+    reason: switch everything off
+"#,
+        )
+        .unwrap();
+
+        let untrusted = load_project_rule_file(project.path(), false);
+        assert_eq!(untrusted[0].patterns.len(), 1);
+        assert!(untrusted[0].allowlist.is_empty());
+
+        let trusted = load_project_rule_file(project.path(), true);
+        assert_eq!(trusted[0].allowlist.len(), 1);
+
+        assert!(is_trusted_project(
+            project.path(),
+            &[project.path().join(".goose").join("..")]
+        ));
+        assert!(!is_trusted_project(project.path(), &[]));
+    }
+
+    #[test]
+    fn test_applies_to_tool() {
+        let tools = vec!["shell".to_string()];
+        assert!(applies_to_tool(&[], None));
+        assert!(applies_to_tool(&tools, Some("developer__shell")));
+        assert!(applies_to_tool(&tools, Some("shell")));
+        assert!(!applies_to_tool(&tools, Some("developer__text_editor")));
+        assert!(!applies_to_tool(&tools, None));
+    }
+}
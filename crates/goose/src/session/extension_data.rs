@@ -1,6 +1,7 @@
 // Extension data management for sessions
 // Provides a simple way to store extension-specific data with versioned keys
 
+use crate::agents::retry::SuccessCheckReport;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -95,6 +96,19 @@ impl TodoState {
     }
 }
 
+/// Outcome of the most recent round of recipe success checks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuccessCheckState {
+    /// Retry attempt the checks ran after, starting at 0 for the first run
+    pub attempt: u32,
+    pub report: SuccessCheckReport,
+}
+
+impl ExtensionState for SuccessCheckState {
+    const EXTENSION_NAME: &'static str = "success_checks";
+    const VERSION: &'static str = "v0";
+}
+
 /// File tracking state for metrics
 /// Tracks which files have been created in the current session
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -3,5 +3,9 @@ pub mod legacy;
 pub mod session_manager;
 pub mod tool_classifier;
 
-pub use session_manager::{ensure_session_dir, Session, SessionInsights, SessionManager, ToolStats};
+pub use session_manager::{
+    ensure_session_dir, AuditAction, AuditEntry, AuditFilter, AuditKind, AuditRecord, CostPeriod,
+    CostRollup, MessageSearchFilters, MessageSearchHit, MetricsGroup, MetricsGroupBy, ModelCost,
+    Session, SessionInsights, SessionManager, ToolStats, UsageTotals,
+};
 pub use tool_classifier::{classify_tool, ToolOperation};
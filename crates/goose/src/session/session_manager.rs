@@ -1,5 +1,5 @@
 use crate::config::APP_STRATEGY;
-use crate::conversation::message::Message;
+use crate::conversation::message::{Message, MessageContent};
 use crate::conversation::Conversation;
 use crate::providers::base::{Provider, MSG_COUNT_FOR_SESSION_NAME_GENERATION};
 use crate::recipe::Recipe;
@@ -9,7 +9,8 @@ use etcetera::{choose_app_strategy, AppStrategy};
 use rmcp::model::Role;
 use serde::{Deserialize, Serialize};
 use sqlx::sqlite::SqliteConnectOptions;
-use sqlx::{Pool, Sqlite};
+use sqlx::{Pool, Sqlite, SqliteConnection};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -17,7 +18,12 @@ use tokio::sync::OnceCell;
 use tracing::{info, warn};
 use utoipa::ToSchema;
 
-const CURRENT_SCHEMA_VERSION: i32 = 3;
+const CURRENT_SCHEMA_VERSION: i32 = 8;
+
+/// Markers wrapped around matched terms in search snippets
+pub const SEARCH_HIGHLIGHT_START: &str = "<<";
+pub const SEARCH_HIGHLIGHT_END: &str = ">>";
+const DEFAULT_SEARCH_LIMIT: usize = 50;
 
 static SESSION_STORAGE: OnceCell<Arc<SessionStorage>> = OnceCell::const_new();
 
@@ -40,6 +46,178 @@ pub struct Session {
     pub recipe: Option<Recipe>,
     pub conversation: Option<Conversation>,
     pub message_count: usize,
+    /// Session this one was forked from, if any
+    pub parent_session_id: Option<String>,
+    /// Number of parent messages copied into this session when it was forked
+    pub fork_message_index: Option<usize>,
+    /// Estimated USD cost of all priced provider calls in this session
+    pub accumulated_cost: Option<f64>,
+    /// Usage and cost broken down by model name
+    #[serde(default)]
+    pub model_costs: BTreeMap<String, ModelCost>,
+}
+
+/// Token usage and estimated cost attributed to one model within a session
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ModelCost {
+    pub provider: String,
+    pub calls: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    /// Estimated USD cost, absent when no pricing is known for the model
+    pub cost: Option<f64>,
+}
+
+/// Tokens and estimated cost summed over some set of provider calls
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct UsageTotals {
+    pub tokens: i64,
+    pub cost: f64,
+}
+
+/// Granularity of a cost rollup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CostPeriod {
+    Day,
+    Week,
+}
+
+/// Provider usage aggregated over one day or week for a single model
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CostRollup {
+    /// First day of the period (YYYY-MM-DD); weeks start on Monday
+    pub period_start: String,
+    pub provider: String,
+    pub model: String,
+    pub calls: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    /// Estimated USD cost of the priced calls in this period
+    pub cost: f64,
+}
+
+/// Dimension that cross-session metrics are grouped by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricsGroupBy {
+    Day,
+    Model,
+    Recipe,
+    WorkingDir,
+}
+
+/// Token, cost and tool usage aggregated over the sessions in one group
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsGroup {
+    /// Day (YYYY-MM-DD), model name, recipe title or working directory
+    pub key: String,
+    pub sessions: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub total_tokens: i64,
+    /// Estimated USD cost of the priced calls in this group
+    pub cost: f64,
+    pub tool_calls: i64,
+    pub failed_tool_calls: i64,
+    /// Fraction of tool calls that failed, between 0 and 1
+    pub error_rate: f64,
+    /// Distinct files created by tool calls
+    pub files_created: i64,
+    /// Distinct files modified by tool calls
+    pub files_modified: i64,
+    /// Tool calls per operation type (file_create, command_execute, ...)
+    pub calls_by_operation: BTreeMap<String, i64>,
+}
+
+/// Whether an audit entry is one inspector's verdict or the decision that was acted on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditKind {
+    Inspection,
+    Decision,
+}
+
+impl AuditKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditKind::Inspection => "inspection",
+            AuditKind::Decision => "decision",
+        }
+    }
+}
+
+/// Verdict of an inspector, or the final decision taken for a tool call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Allow,
+    RequireApproval,
+    Deny,
+    /// Run without asking because the inspectors allowed it
+    AutoApproved,
+    /// Run after the user approved it
+    UserApproved,
+    /// Not run because the user declined it
+    UserDenied,
+    /// Not run because an inspector denied it
+    Blocked,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Allow => "allow",
+            AuditAction::RequireApproval => "require_approval",
+            AuditAction::Deny => "deny",
+            AuditAction::AutoApproved => "auto_approved",
+            AuditAction::UserApproved => "user_approved",
+            AuditAction::UserDenied => "user_denied",
+            AuditAction::Blocked => "blocked",
+        }
+    }
+}
+
+/// A permission audit entry as recorded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub session_id: Option<String>,
+    pub tool_request_id: String,
+    pub tool_name: String,
+    pub kind: AuditKind,
+    /// Inspector that produced the verdict, or "user" for decisions made by the user
+    pub inspector: String,
+    pub action: AuditAction,
+    pub reason: String,
+    /// Inspector confidence between 0 and 1; absent for user decisions
+    pub confidence: Option<f64>,
+    /// Account the agent was acting for
+    pub principal: String,
+}
+
+/// An entry read back from the append-only permission audit log
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecord {
+    pub id: i64,
+    /// UTC time the entry was recorded (YYYY-MM-DD HH:MM:SS)
+    pub created_at: String,
+    #[serde(flatten)]
+    pub entry: AuditEntry,
+}
+
+/// Optional constraints applied when reading the audit log
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct AuditFilter {
+    /// Only return entries recorded at or after this time
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only return entries for this session
+    pub session_id: Option<String>,
+    /// Maximum number of entries to return, oldest first
+    pub limit: Option<usize>,
 }
 
 pub struct SessionUpdateBuilder {
@@ -64,6 +242,41 @@ pub struct SessionInsights {
     total_sessions: usize,
     /// Total tokens used across all sessions
     total_tokens: i64,
+    /// Estimated USD cost across all sessions
+    total_cost: f64,
+    /// Cost per day and model over the last 30 days
+    daily_costs: Vec<CostRollup>,
+    /// Cost per week and model over the last 12 weeks
+    weekly_costs: Vec<CostRollup>,
+}
+
+/// Optional constraints applied to a message search
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct MessageSearchFilters {
+    /// Only match sessions whose working directory contains this string
+    pub working_dir: Option<String>,
+    /// Only match messages created at or after this time
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only match messages created at or before this time
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only match sessions started from a recipe with this title
+    pub recipe: Option<String>,
+    /// Maximum number of hits to return
+    pub limit: Option<usize>,
+}
+
+/// A single message matching a search query
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MessageSearchHit {
+    pub session_id: String,
+    pub session_description: String,
+    /// Position of the message within its session's conversation
+    pub message_index: usize,
+    pub role: String,
+    /// Unix timestamp of the message
+    pub created: i64,
+    /// Excerpt around the match with matched terms wrapped in `<<` and `>>`
+    pub snippet: String,
 }
 
 impl SessionUpdateBuilder {
@@ -154,21 +367,21 @@ impl SessionManager {
             .map(Arc::clone)
     }
 
+    /// Keeps sessions in `session_dir` instead of the user's data directory for the rest
+    /// of the process. Fails if session storage has already been opened.
+    pub async fn use_session_dir(session_dir: PathBuf) -> Result<()> {
+        let storage = SessionStorage::in_dir(session_dir).await?;
+        SESSION_STORAGE
+            .set(Arc::new(storage))
+            .map_err(|_| anyhow::anyhow!("Session storage is already open"))
+    }
+
     pub async fn create_session(working_dir: PathBuf, description: String) -> Result<Session> {
-        let today = chrono::Utc::now().format("%Y%m%d").to_string();
         let storage = Self::instance().await?;
 
         let mut tx = storage.pool.begin().await?;
 
-        let max_idx = sqlx::query_scalar::<_, Option<i32>>(
-            "SELECT MAX(CAST(SUBSTR(id, 10) AS INTEGER)) FROM sessions WHERE id LIKE ?",
-        )
-        .bind(format!("{}_%", today))
-        .fetch_one(&mut *tx)
-        .await?
-        .unwrap_or(0);
-
-        let session_id = format!("{}_{}", today, max_idx + 1);
+        let session_id = next_session_id(&mut tx).await?;
 
         sqlx::query(
             r#"
@@ -217,10 +430,81 @@ impl SessionManager {
         Self::instance().await?.list_sessions().await
     }
 
+    /// Full-text search over message text, tool request arguments and tool responses
+    pub async fn search_messages(
+        query: &str,
+        filters: &MessageSearchFilters,
+    ) -> Result<Vec<MessageSearchHit>> {
+        Self::instance()
+            .await?
+            .search_messages(query, filters)
+            .await
+    }
+
+    /// Create a child session containing the first `message_index` messages of the parent.
+    /// When no index is given the whole conversation is copied.
+    pub async fn fork_session(parent_id: &str, message_index: Option<usize>) -> Result<Session> {
+        let session_id = Self::instance()
+            .await?
+            .fork_session(parent_id, message_index)
+            .await?;
+        Self::get_session(&session_id, true).await
+    }
+
     pub async fn delete_session(id: &str) -> Result<()> {
         Self::instance().await?.delete_session(id).await
     }
 
+    /// Record one provider call against a session, adding its cost to the per-model breakdown.
+    /// `cost` is None when the model has no known pricing; tokens are still tracked.
+    pub async fn record_provider_usage(
+        session_id: &str,
+        provider: &str,
+        model: &str,
+        input_tokens: i64,
+        output_tokens: i64,
+        cost: Option<f64>,
+    ) -> Result<()> {
+        Self::instance()
+            .await?
+            .record_provider_usage(
+                session_id,
+                provider,
+                model,
+                input_tokens,
+                output_tokens,
+                cost,
+            )
+            .await
+    }
+
+    /// Total usage of every session started by a schedule since the given time
+    pub async fn get_schedule_usage(
+        schedule_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<UsageTotals> {
+        Self::instance()
+            .await?
+            .get_schedule_usage(schedule_id, since)
+            .await
+    }
+
+    /// Aggregate provider usage per day or week and model, newest period first
+    pub async fn get_cost_rollup(
+        period: CostPeriod,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<CostRollup>> {
+        Self::instance().await?.get_cost_rollup(period, since).await
+    }
+
+    /// Aggregate usage across sessions, grouped by day, model, recipe or working directory
+    pub async fn get_metrics(
+        group_by: MetricsGroupBy,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<MetricsGroup>> {
+        Self::instance().await?.get_metrics(group_by, since).await
+    }
+
     pub async fn get_insights() -> Result<SessionInsights> {
         Self::instance().await?.get_insights().await
     }
@@ -273,6 +557,16 @@ impl SessionManager {
             .await
     }
 
+    /// Append entries to the permission audit log
+    pub async fn record_audit_entries(entries: &[AuditEntry]) -> Result<()> {
+        Self::instance().await?.record_audit_entries(entries).await
+    }
+
+    /// Read the permission audit log, oldest entry first
+    pub async fn get_audit_records(filter: &AuditFilter) -> Result<Vec<AuditRecord>> {
+        Self::instance().await?.get_audit_records(filter).await
+    }
+
     /// Get tool usage statistics for a session
     pub async fn get_tool_stats(session_id: &str) -> Result<ToolStats> {
         Self::instance().await?.get_tool_stats(session_id).await
@@ -315,6 +609,185 @@ pub fn ensure_session_dir() -> Result<PathBuf> {
     Ok(data_dir)
 }
 
+async fn next_session_id(tx: &mut sqlx::Transaction<'_, Sqlite>) -> Result<String> {
+    let today = chrono::Utc::now().format("%Y%m%d").to_string();
+
+    let max_idx = sqlx::query_scalar::<_, Option<i32>>(
+        "SELECT MAX(CAST(SUBSTR(id, 10) AS INTEGER)) FROM sessions WHERE id LIKE ?",
+    )
+    .bind(format!("{}_%", today))
+    .fetch_one(&mut **tx)
+    .await?
+    .unwrap_or(0);
+
+    Ok(format!("{}_{}", today, max_idx + 1))
+}
+
+/// Flatten the parts of a message worth searching into a single document
+fn searchable_text(message: &Message) -> String {
+    let mut parts = Vec::new();
+    for content in &message.content {
+        match content {
+            MessageContent::Text(text) => parts.push(text.text.clone()),
+            MessageContent::ToolRequest(request) => {
+                if let Ok(call) = &request.tool_call {
+                    parts.push(call.name.to_string());
+                    if let Some(args) = &call.arguments {
+                        parts.push(serde_json::Value::Object(args.clone()).to_string());
+                    }
+                }
+            }
+            MessageContent::ToolResponse(response) => {
+                if let Ok(contents) = &response.tool_result {
+                    parts.extend(
+                        contents
+                            .iter()
+                            .filter_map(|c| c.as_text())
+                            .map(|t| t.text.clone()),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+    parts.join("\n")
+}
+
+/// Turn free-form user input into an FTS5 query that ANDs every term together.
+/// Each term is quoted so punctuation such as `-` or `:` is not read as query syntax.
+fn to_fts_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+async fn insert_message(
+    conn: &mut SqliteConnection,
+    session_id: &str,
+    message: &Message,
+) -> Result<()> {
+    let message_id = sqlx::query(
+        r#"
+            INSERT INTO messages (session_id, role, content_json, created_timestamp, metadata_json)
+            VALUES (?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(session_id)
+    .bind(role_to_string(&message.role))
+    .bind(serde_json::to_string(&message.content)?)
+    .bind(message.created)
+    .bind(serde_json::to_string(&message.metadata)?)
+    .execute(&mut *conn)
+    .await?
+    .last_insert_rowid();
+
+    sqlx::query("INSERT INTO messages_fts (rowid, content) VALUES (?, ?)")
+        .bind(message_id)
+        .bind(searchable_text(message))
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_message_search_index(conn: &mut SqliteConnection) -> Result<()> {
+    sqlx::query(
+        "CREATE VIRTUAL TABLE messages_fts USING fts5(content, tokenize = 'porter unicode61')",
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        r#"
+            CREATE TRIGGER messages_fts_delete AFTER DELETE ON messages BEGIN
+                DELETE FROM messages_fts WHERE rowid = old.id;
+            END
+        "#,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn create_provider_usage_table(conn: &mut SqliteConnection) -> Result<()> {
+    sqlx::query(
+        r#"
+            CREATE TABLE provider_usage (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                session_id TEXT NOT NULL REFERENCES sessions(id),
+                provider TEXT NOT NULL,
+                model TEXT NOT NULL,
+                input_tokens INTEGER NOT NULL DEFAULT 0,
+                output_tokens INTEGER NOT NULL DEFAULT 0,
+                cost REAL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+        "#,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query("CREATE INDEX idx_provider_usage_session ON provider_usage(session_id)")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("CREATE INDEX idx_provider_usage_created ON provider_usage(created_at)")
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_permission_audit_table(conn: &mut SqliteConnection) -> Result<()> {
+    // Not tied to sessions so deleting a session keeps its audit trail
+    sqlx::query(
+        r#"
+            CREATE TABLE permission_audit (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                session_id TEXT,
+                tool_request_id TEXT NOT NULL,
+                tool_name TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                inspector TEXT NOT NULL,
+                action TEXT NOT NULL,
+                reason TEXT NOT NULL,
+                confidence REAL,
+                principal TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+        "#,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query("CREATE INDEX idx_permission_audit_created ON permission_audit(created_at)")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("CREATE INDEX idx_permission_audit_session ON permission_audit(session_id)")
+        .execute(&mut *conn)
+        .await?;
+
+    for (name, event) in [
+        ("permission_audit_no_update", "UPDATE"),
+        ("permission_audit_no_delete", "DELETE"),
+    ] {
+        sqlx::query(&format!(
+            r#"
+            CREATE TRIGGER {} BEFORE {} ON permission_audit BEGIN
+                SELECT RAISE(ABORT, 'permission_audit is append-only');
+            END
+            "#,
+            name, event
+        ))
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
 fn role_to_string(role: &Role) -> &'static str {
     match role {
         Role::User => "user",
@@ -341,6 +814,10 @@ impl Default for Session {
             recipe: None,
             conversation: None,
             message_count: 0,
+            parent_session_id: None,
+            fork_message_index: None,
+            accumulated_cost: None,
+            model_costs: BTreeMap::new(),
         }
     }
 }
@@ -359,6 +836,11 @@ impl sqlx::FromRow<'_, sqlx::sqlite::SqliteRow> for Session {
         let recipe_json: Option<String> = row.try_get("recipe_json")?;
         let recipe = recipe_json.and_then(|json| serde_json::from_str(&json).ok());
 
+        let model_costs_json: Option<String> = row.try_get("model_costs")?;
+        let model_costs = model_costs_json
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+
         Ok(Session {
             id: row.try_get("id")?,
             working_dir: PathBuf::from(row.try_get::<String, _>("working_dir")?),
@@ -377,13 +859,23 @@ impl sqlx::FromRow<'_, sqlx::sqlite::SqliteRow> for Session {
             recipe,
             conversation: None,
             message_count: row.try_get("message_count").unwrap_or(0) as usize,
+            parent_session_id: row.try_get("parent_session_id")?,
+            fork_message_index: row
+                .try_get::<Option<i64>, _>("fork_message_index")?
+                .map(|idx| idx as usize),
+            accumulated_cost: row.try_get("accumulated_cost")?,
+            model_costs,
         })
     }
 }
 
 impl SessionStorage {
     async fn new() -> Result<Self> {
-        let session_dir = ensure_session_dir()?;
+        Self::in_dir(ensure_session_dir()?).await
+    }
+
+    async fn in_dir(session_dir: PathBuf) -> Result<Self> {
+        fs::create_dir_all(&session_dir)?;
         let db_path = session_dir.join("sessions.db");
 
         let storage = if db_path.exists() {
@@ -460,7 +952,11 @@ impl SessionStorage {
                 accumulated_input_tokens INTEGER,
                 accumulated_output_tokens INTEGER,
                 schedule_id TEXT,
-                recipe_json TEXT
+                recipe_json TEXT,
+                parent_session_id TEXT,
+                fork_message_index INTEGER,
+                accumulated_cost REAL,
+                model_costs TEXT
             )
         "#,
         )
@@ -476,7 +972,8 @@ impl SessionStorage {
                 content_json TEXT NOT NULL,
                 created_timestamp INTEGER NOT NULL,
                 timestamp TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-                tokens INTEGER
+                tokens INTEGER,
+                metadata_json TEXT
             )
         "#,
         )
@@ -492,6 +989,49 @@ impl SessionStorage {
         sqlx::query("CREATE INDEX idx_sessions_updated ON sessions(updated_at DESC)")
             .execute(&pool)
             .await?;
+        sqlx::query("CREATE INDEX idx_sessions_parent ON sessions(parent_session_id)")
+            .execute(&pool)
+            .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE tool_events (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                session_id TEXT NOT NULL,
+                tool_name TEXT NOT NULL,
+                status TEXT NOT NULL,
+                error_message TEXT,
+                started_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                completed_at TIMESTAMP,
+                duration_ms INTEGER,
+                operation_type TEXT,
+                file_path TEXT,
+                FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE
+            )
+        "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query("CREATE INDEX idx_tool_events_session ON tool_events(session_id)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX idx_tool_events_tool_name ON tool_events(tool_name)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX idx_tool_events_status ON tool_events(status)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX idx_tool_events_operation ON tool_events(operation_type)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("CREATE INDEX idx_tool_events_file_path ON tool_events(file_path)")
+            .execute(&pool)
+            .await?;
+
+        create_message_search_index(&mut *pool.acquire().await?).await?;
+        create_provider_usage_table(&mut *pool.acquire().await?).await?;
+        create_permission_audit_table(&mut *pool.acquire().await?).await?;
 
         Ok(Self { pool })
     }
@@ -705,6 +1245,74 @@ impl SessionStorage {
                     .execute(&self.pool)
                     .await?;
             }
+            4 => {
+                // Track session lineage so forks can point back at their parent
+                sqlx::query("ALTER TABLE sessions ADD COLUMN parent_session_id TEXT")
+                    .execute(&self.pool)
+                    .await?;
+
+                sqlx::query("ALTER TABLE sessions ADD COLUMN fork_message_index INTEGER")
+                    .execute(&self.pool)
+                    .await?;
+
+                sqlx::query("CREATE INDEX idx_sessions_parent ON sessions(parent_session_id)")
+                    .execute(&self.pool)
+                    .await?;
+            }
+            5 => {
+                // Full-text index over message content, keyed by messages.id
+                let mut tx = self.pool.begin().await?;
+                create_message_search_index(&mut tx).await?;
+
+                let rows = sqlx::query_as::<_, (i64, String, String, i64)>(
+                    "SELECT id, role, content_json, created_timestamp FROM messages",
+                )
+                .fetch_all(&mut *tx)
+                .await?;
+
+                for (id, role, content_json, created) in rows {
+                    let role = if role == "assistant" {
+                        Role::Assistant
+                    } else {
+                        Role::User
+                    };
+                    let Ok(content) = serde_json::from_str(&content_json) else {
+                        continue;
+                    };
+                    let message = Message::new(role, created, content);
+
+                    sqlx::query("INSERT INTO messages_fts (rowid, content) VALUES (?, ?)")
+                        .bind(id)
+                        .bind(searchable_text(&message))
+                        .execute(&mut *tx)
+                        .await?;
+                }
+
+                tx.commit().await?;
+            }
+            6 => {
+                // Per-call cost accounting, rolled up onto the session row
+                sqlx::query("ALTER TABLE sessions ADD COLUMN accumulated_cost REAL")
+                    .execute(&self.pool)
+                    .await?;
+
+                sqlx::query("ALTER TABLE sessions ADD COLUMN model_costs TEXT")
+                    .execute(&self.pool)
+                    .await?;
+
+                create_provider_usage_table(&mut *self.pool.acquire().await?).await?;
+            }
+            7 => {
+                // Append-only record of every tool permission decision
+                create_permission_audit_table(&mut *self.pool.acquire().await?).await?;
+            }
+            8 => {
+                // Keep message visibility so messages archived by compaction stay hidden from
+                // the agent when a session is reloaded
+                sqlx::query("ALTER TABLE messages ADD COLUMN metadata_json TEXT")
+                    .execute(&self.pool)
+                    .await?;
+            }
             _ => {
                 anyhow::bail!("Unknown migration version: {}", version);
             }
@@ -719,7 +1327,8 @@ impl SessionStorage {
         SELECT id, working_dir, description, created_at, updated_at, extension_data,
                total_tokens, input_tokens, output_tokens,
                accumulated_total_tokens, accumulated_input_tokens, accumulated_output_tokens,
-               schedule_id, recipe_json
+               schedule_id, recipe_json, parent_session_id, fork_message_index,
+               accumulated_cost, model_costs
         FROM sessions
         WHERE id = ?
     "#,
@@ -830,15 +1439,15 @@ impl SessionStorage {
     }
 
     async fn get_conversation(&self, session_id: &str) -> Result<Conversation> {
-        let rows = sqlx::query_as::<_, (String, String, i64)>(
-            "SELECT role, content_json, created_timestamp FROM messages WHERE session_id = ? ORDER BY timestamp",
+        let rows = sqlx::query_as::<_, (String, String, i64, Option<String>)>(
+            "SELECT role, content_json, created_timestamp, metadata_json FROM messages WHERE session_id = ? ORDER BY timestamp",
         )
             .bind(session_id)
             .fetch_all(&self.pool)
             .await?;
 
         let mut messages = Vec::new();
-        for (role_str, content_json, created_timestamp) in rows {
+        for (role_str, content_json, created_timestamp, metadata_json) in rows {
             let role = match role_str.as_str() {
                 "user" => Role::User,
                 "assistant" => Role::Assistant,
@@ -846,7 +1455,11 @@ impl SessionStorage {
             };
 
             let content = serde_json::from_str(&content_json)?;
-            let message = Message::new(role, created_timestamp, content);
+            let mut message = Message::new(role, created_timestamp, content);
+            // Messages stored before metadata_json existed are visible to both
+            if let Some(metadata) = metadata_json.and_then(|m| serde_json::from_str(&m).ok()) {
+                message = message.with_metadata(metadata);
+            }
             messages.push(message);
         }
 
@@ -854,24 +1467,16 @@ impl SessionStorage {
     }
 
     async fn add_message(&self, session_id: &str, message: &Message) -> Result<()> {
-        sqlx::query(
-            r#"
-            INSERT INTO messages (session_id, role, content_json, created_timestamp)
-            VALUES (?, ?, ?, ?)
-        "#,
-        )
-        .bind(session_id)
-        .bind(role_to_string(&message.role))
-        .bind(serde_json::to_string(&message.content)?)
-        .bind(message.created)
-        .execute(&self.pool)
-        .await?;
+        let mut tx = self.pool.begin().await?;
+
+        insert_message(&mut tx, session_id, message).await?;
 
         sqlx::query("UPDATE sessions SET updated_at = datetime('now') WHERE id = ?")
             .bind(session_id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
 
+        tx.commit().await?;
         Ok(())
     }
 
@@ -888,35 +1493,27 @@ impl SessionStorage {
             .await?;
 
         for message in conversation.messages() {
-            sqlx::query(
-                r#"
-            INSERT INTO messages (session_id, role, content_json, created_timestamp)
-            VALUES (?, ?, ?, ?)
-        "#,
-            )
-            .bind(session_id)
-            .bind(role_to_string(&message.role))
-            .bind(serde_json::to_string(&message.content)?)
-            .bind(message.created)
-            .execute(&mut *tx)
-            .await?;
+            insert_message(&mut tx, session_id, message).await?;
         }
 
         tx.commit().await?;
         Ok(())
     }
 
+    /// Sessions without messages are left out unless they are forks, which can start empty
     async fn list_sessions(&self) -> Result<Vec<Session>> {
         sqlx::query_as::<_, Session>(
             r#"
         SELECT s.id, s.working_dir, s.description, s.created_at, s.updated_at, s.extension_data,
                s.total_tokens, s.input_tokens, s.output_tokens,
                s.accumulated_total_tokens, s.accumulated_input_tokens, s.accumulated_output_tokens,
-               s.schedule_id, s.recipe_json,
+               s.schedule_id, s.recipe_json, s.parent_session_id, s.fork_message_index,
+               s.accumulated_cost, s.model_costs,
                COUNT(m.id) as message_count
         FROM sessions s
-        INNER JOIN messages m ON s.id = m.session_id
+        LEFT JOIN messages m ON s.id = m.session_id
         GROUP BY s.id
+        HAVING COUNT(m.id) > 0 OR s.parent_session_id IS NOT NULL
         ORDER BY s.updated_at DESC
     "#,
         )
@@ -925,6 +1522,129 @@ impl SessionStorage {
         .map_err(Into::into)
     }
 
+    async fn fork_session(&self, parent_id: &str, message_index: Option<usize>) -> Result<String> {
+        let parent = self.get_session(parent_id, true).await?;
+        let conversation = parent.conversation.unwrap_or_default();
+        let messages = conversation.messages();
+
+        let fork_index = message_index.unwrap_or(messages.len());
+        if fork_index > messages.len() {
+            return Err(anyhow::anyhow!(
+                "Message index {} is out of range (session has {} messages)",
+                fork_index,
+                messages.len()
+            ));
+        }
+
+        let recipe_json = match &parent.recipe {
+            Some(recipe) => Some(serde_json::to_string(recipe)?),
+            None => None,
+        };
+
+        let mut tx = self.pool.begin().await?;
+
+        let session_id = next_session_id(&mut tx).await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO sessions (
+                id, description, working_dir, extension_data, recipe_json,
+                parent_session_id, fork_message_index
+            ) VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+        )
+        .bind(&session_id)
+        .bind(&parent.description)
+        .bind(parent.working_dir.to_string_lossy().as_ref())
+        .bind(serde_json::to_string(&parent.extension_data)?)
+        .bind(recipe_json)
+        .bind(parent_id)
+        .bind(fork_index as i64)
+        .execute(&mut *tx)
+        .await?;
+
+        for message in &messages[..fork_index] {
+            insert_message(&mut tx, &session_id, message).await?;
+        }
+
+        tx.commit().await?;
+        Ok(session_id)
+    }
+
+    async fn search_messages(
+        &self,
+        query: &str,
+        filters: &MessageSearchFilters,
+    ) -> Result<Vec<MessageSearchHit>> {
+        let fts_query = to_fts_query(query);
+        if fts_query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut sql = format!(
+            r#"
+            SELECT m.session_id, s.description, m.role, m.created_timestamp,
+                   (SELECT COUNT(*) FROM messages p
+                    WHERE p.session_id = m.session_id AND p.id < m.id) AS message_index,
+                   snippet(messages_fts, 0, '{}', '{}', '...', 16) AS snippet
+            FROM messages_fts
+            JOIN messages m ON m.id = messages_fts.rowid
+            JOIN sessions s ON s.id = m.session_id
+            WHERE messages_fts MATCH ?
+            "#,
+            SEARCH_HIGHLIGHT_START, SEARCH_HIGHLIGHT_END
+        );
+
+        if filters.working_dir.is_some() {
+            sql.push_str(" AND s.working_dir LIKE ?");
+        }
+        if filters.since.is_some() {
+            sql.push_str(" AND m.created_timestamp >= ?");
+        }
+        if filters.until.is_some() {
+            sql.push_str(" AND m.created_timestamp <= ?");
+        }
+        if filters.recipe.is_some() {
+            sql.push_str(" AND json_extract(s.recipe_json, '$.title') = ? COLLATE NOCASE");
+        }
+        sql.push_str(" ORDER BY rank LIMIT ?");
+
+        let mut q =
+            sqlx::query_as::<_, (String, String, String, i64, i64, String)>(&sql).bind(fts_query);
+
+        if let Some(working_dir) = &filters.working_dir {
+            q = q.bind(format!("%{}%", working_dir));
+        }
+        if let Some(since) = filters.since {
+            q = q.bind(since.timestamp());
+        }
+        if let Some(until) = filters.until {
+            q = q.bind(until.timestamp());
+        }
+        if let Some(recipe) = &filters.recipe {
+            q = q.bind(recipe);
+        }
+        q = q.bind(filters.limit.unwrap_or(DEFAULT_SEARCH_LIMIT) as i64);
+
+        let rows = q.fetch_all(&self.pool).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(session_id, session_description, role, created, message_index, snippet)| {
+                    MessageSearchHit {
+                        session_id,
+                        session_description,
+                        message_index: message_index as usize,
+                        role,
+                        created,
+                        snippet,
+                    }
+                },
+            )
+            .collect())
+    }
+
     async fn delete_session(&self, session_id: &str) -> Result<()> {
         let exists =
             sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM sessions WHERE id = ?)")
@@ -941,6 +1661,11 @@ impl SessionStorage {
             .execute(&self.pool)
             .await?;
 
+        sqlx::query("DELETE FROM provider_usage WHERE session_id = ?")
+            .bind(session_id)
+            .execute(&self.pool)
+            .await?;
+
         sqlx::query("DELETE FROM sessions WHERE id = ?")
             .bind(session_id)
             .execute(&self.pool)
@@ -960,12 +1685,417 @@ impl SessionStorage {
             .fetch_one(&self.pool)
             .await?;
 
+        let total_cost: Option<f64> =
+            sqlx::query_scalar("SELECT SUM(accumulated_cost) FROM sessions")
+                .fetch_one(&self.pool)
+                .await?;
+
+        let now = chrono::Utc::now();
+        let daily_costs = self
+            .get_cost_rollup(CostPeriod::Day, Some(now - chrono::Duration::days(30)))
+            .await?;
+        let weekly_costs = self
+            .get_cost_rollup(CostPeriod::Week, Some(now - chrono::Duration::weeks(12)))
+            .await?;
+
         Ok(SessionInsights {
             total_sessions: row.0 as usize,
             total_tokens: row.1.unwrap_or(0),
+            total_cost: total_cost.unwrap_or(0.0),
+            daily_costs,
+            weekly_costs,
         })
     }
 
+    async fn record_provider_usage(
+        &self,
+        session_id: &str,
+        provider: &str,
+        model: &str,
+        input_tokens: i64,
+        output_tokens: i64,
+        cost: Option<f64>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let model_costs_json = sqlx::query_scalar::<_, Option<String>>(
+            "SELECT model_costs FROM sessions WHERE id = ?",
+        )
+        .bind(session_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Session not found"))?;
+
+        let mut model_costs: BTreeMap<String, ModelCost> = model_costs_json
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+
+        let entry = model_costs
+            .entry(model.to_string())
+            .or_insert_with(|| ModelCost {
+                provider: provider.to_string(),
+                ..Default::default()
+            });
+        entry.calls += 1;
+        entry.input_tokens += input_tokens;
+        entry.output_tokens += output_tokens;
+        if let Some(cost) = cost {
+            entry.cost = Some(entry.cost.unwrap_or(0.0) + cost);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO provider_usage (session_id, provider, model, input_tokens, output_tokens, cost)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(session_id)
+        .bind(provider)
+        .bind(model)
+        .bind(input_tokens)
+        .bind(output_tokens)
+        .bind(cost)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE sessions
+            SET model_costs = ?,
+                accumulated_cost = CASE WHEN ? IS NULL THEN accumulated_cost
+                                        ELSE COALESCE(accumulated_cost, 0) + ? END
+            WHERE id = ?
+            "#,
+        )
+        .bind(serde_json::to_string(&model_costs)?)
+        .bind(cost)
+        .bind(cost)
+        .bind(session_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_schedule_usage(
+        &self,
+        schedule_id: &str,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<UsageTotals> {
+        let (tokens, cost) = sqlx::query_as::<_, (i64, f64)>(
+            r#"
+            SELECT COALESCE(SUM(p.input_tokens + p.output_tokens), 0), TOTAL(p.cost)
+            FROM provider_usage p
+            JOIN sessions s ON s.id = p.session_id
+            WHERE s.schedule_id = ? AND p.created_at >= ?
+            "#,
+        )
+        .bind(schedule_id)
+        .bind(since.format("%Y-%m-%d %H:%M:%S").to_string())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(UsageTotals { tokens, cost })
+    }
+
+    async fn get_cost_rollup(
+        &self,
+        period: CostPeriod,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<CostRollup>> {
+        let period_expr = match period {
+            CostPeriod::Day => "date(created_at)",
+            // Move forward to Sunday, then back to the Monday that starts the week
+            CostPeriod::Week => "date(created_at, 'weekday 0', '-6 days')",
+        };
+
+        let mut sql = format!(
+            r#"
+            SELECT {} AS period_start, provider, model,
+                   COUNT(*), SUM(input_tokens), SUM(output_tokens), TOTAL(cost)
+            FROM provider_usage
+            "#,
+            period_expr
+        );
+        if since.is_some() {
+            sql.push_str(" WHERE created_at >= ?");
+        }
+        sql.push_str(" GROUP BY period_start, provider, model ORDER BY period_start DESC, 7 DESC");
+
+        let mut q = sqlx::query_as::<_, (String, String, String, i64, i64, i64, f64)>(&sql);
+        if let Some(since) = since {
+            q = q.bind(since.format("%Y-%m-%d %H:%M:%S").to_string());
+        }
+
+        let rows = q.fetch_all(&self.pool).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(period_start, provider, model, calls, input_tokens, output_tokens, cost)| {
+                    CostRollup {
+                        period_start,
+                        provider,
+                        model,
+                        calls,
+                        input_tokens,
+                        output_tokens,
+                        cost,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    async fn get_metrics(
+        &self,
+        group_by: MetricsGroupBy,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<MetricsGroup>> {
+        let since = since.map(|s| s.format("%Y-%m-%d %H:%M:%S").to_string());
+
+        // Token usage comes from the session rows, except per model where only
+        // provider_usage knows which model answered
+        let (usage_sql, tool_key, tool_time) = match group_by {
+            MetricsGroupBy::Model => (
+                r#"
+                SELECT model AS key, COUNT(DISTINCT session_id),
+                       SUM(input_tokens), SUM(output_tokens),
+                       SUM(input_tokens + output_tokens), TOTAL(cost)
+                FROM provider_usage
+                WHERE ? IS NULL OR created_at >= ?
+                GROUP BY key
+                "#
+                .to_string(),
+                // Attribute each tool call to the model that most recently answered before it
+                r#"COALESCE((
+                    SELECT p.model FROM provider_usage p
+                    WHERE p.session_id = t.session_id AND p.created_at <= t.started_at
+                    ORDER BY p.id DESC LIMIT 1
+                ), 'unknown')"#,
+                "t.started_at",
+            ),
+            _ => {
+                let key = match group_by {
+                    MetricsGroupBy::Day => "date(s.created_at)",
+                    MetricsGroupBy::Recipe => {
+                        "COALESCE(json_extract(s.recipe_json, '$.title'), '(none)')"
+                    }
+                    _ => "s.working_dir",
+                };
+                (
+                    format!(
+                        r#"
+                        SELECT {} AS key, COUNT(*),
+                               COALESCE(SUM(s.accumulated_input_tokens), 0),
+                               COALESCE(SUM(s.accumulated_output_tokens), 0),
+                               COALESCE(SUM(s.accumulated_total_tokens), 0),
+                               TOTAL(s.accumulated_cost)
+                        FROM sessions s
+                        WHERE ? IS NULL OR s.created_at >= ?
+                        GROUP BY key
+                        "#,
+                        key
+                    ),
+                    key,
+                    "s.created_at",
+                )
+            }
+        };
+
+        let mut groups: BTreeMap<String, MetricsGroup> = BTreeMap::new();
+
+        let usage_rows = sqlx::query_as::<_, (String, i64, i64, i64, i64, f64)>(&usage_sql)
+            .bind(&since)
+            .bind(&since)
+            .fetch_all(&self.pool)
+            .await?;
+        for (key, sessions, input_tokens, output_tokens, total_tokens, cost) in usage_rows {
+            groups.insert(
+                key.clone(),
+                MetricsGroup {
+                    key,
+                    sessions,
+                    input_tokens,
+                    output_tokens,
+                    total_tokens,
+                    cost,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let operation_sql = format!(
+            r#"
+            SELECT {} AS key, COALESCE(t.operation_type, 'other') AS operation,
+                   COUNT(*), SUM(CASE WHEN t.status = 'error' THEN 1 ELSE 0 END)
+            FROM tool_events t
+            JOIN sessions s ON s.id = t.session_id
+            WHERE ? IS NULL OR {} >= ?
+            GROUP BY key, operation
+            "#,
+            tool_key, tool_time
+        );
+        let operation_rows = sqlx::query_as::<_, (String, String, i64, i64)>(&operation_sql)
+            .bind(&since)
+            .bind(&since)
+            .fetch_all(&self.pool)
+            .await?;
+        for (key, operation, calls, failed) in operation_rows {
+            let group = groups.entry(key.clone()).or_insert_with(|| MetricsGroup {
+                key,
+                ..Default::default()
+            });
+            group.tool_calls += calls;
+            group.failed_tool_calls += failed;
+            group.calls_by_operation.insert(operation, calls);
+        }
+
+        let file_sql = format!(
+            r#"
+            SELECT {} AS key,
+                   COUNT(DISTINCT CASE WHEN t.operation_type = 'file_create'
+                         THEN t.session_id || ':' || t.file_path END),
+                   COUNT(DISTINCT CASE WHEN t.operation_type = 'file_edit'
+                         THEN t.session_id || ':' || t.file_path END)
+            FROM tool_events t
+            JOIN sessions s ON s.id = t.session_id
+            WHERE t.file_path IS NOT NULL AND t.status != 'error'
+              AND (? IS NULL OR {} >= ?)
+            GROUP BY key
+            "#,
+            tool_key, tool_time
+        );
+        let file_rows = sqlx::query_as::<_, (String, i64, i64)>(&file_sql)
+            .bind(&since)
+            .bind(&since)
+            .fetch_all(&self.pool)
+            .await?;
+        for (key, created, modified) in file_rows {
+            if let Some(group) = groups.get_mut(&key) {
+                group.files_created = created;
+                group.files_modified = modified;
+            }
+        }
+
+        let mut groups: Vec<MetricsGroup> = groups
+            .into_values()
+            .map(|mut group| {
+                if group.tool_calls > 0 {
+                    group.error_rate = group.failed_tool_calls as f64 / group.tool_calls as f64;
+                }
+                group
+            })
+            .collect();
+
+        match group_by {
+            MetricsGroupBy::Day => groups.sort_by(|a, b| b.key.cmp(&a.key)),
+            _ => groups.sort_by(|a, b| b.total_tokens.cmp(&a.total_tokens)),
+        }
+
+        Ok(groups)
+    }
+
+    async fn record_audit_entries(&self, entries: &[AuditEntry]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for entry in entries {
+            sqlx::query(
+                r#"
+                INSERT INTO permission_audit (session_id, tool_request_id, tool_name, kind,
+                                              inspector, action, reason, confidence, principal)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&entry.session_id)
+            .bind(&entry.tool_request_id)
+            .bind(&entry.tool_name)
+            .bind(entry.kind.as_str())
+            .bind(&entry.inspector)
+            .bind(entry.action.as_str())
+            .bind(&entry.reason)
+            .bind(entry.confidence)
+            .bind(&entry.principal)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn get_audit_records(&self, filter: &AuditFilter) -> Result<Vec<AuditRecord>> {
+        let since = filter
+            .since
+            .map(|s| s.format("%Y-%m-%d %H:%M:%S").to_string());
+        let rows = sqlx::query_as::<
+            _,
+            (
+                i64,
+                String,
+                Option<String>,
+                String,
+                String,
+                String,
+                String,
+                String,
+                String,
+                Option<f64>,
+                String,
+            ),
+        >(
+            r#"
+            SELECT id, created_at, session_id, tool_request_id, tool_name, kind,
+                   inspector, action, reason, confidence, principal
+            FROM permission_audit
+            WHERE (? IS NULL OR created_at >= ?)
+              AND (? IS NULL OR session_id = ?)
+            ORDER BY id
+            LIMIT ?
+            "#,
+        )
+        .bind(&since)
+        .bind(&since)
+        .bind(&filter.session_id)
+        .bind(&filter.session_id)
+        .bind(filter.limit.map(|l| l as i64).unwrap_or(-1))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(
+                |(
+                    id,
+                    created_at,
+                    session_id,
+                    tool_request_id,
+                    tool_name,
+                    kind,
+                    inspector,
+                    action,
+                    reason,
+                    confidence,
+                    principal,
+                )| {
+                    Ok(AuditRecord {
+                        id,
+                        created_at,
+                        entry: AuditEntry {
+                            session_id,
+                            tool_request_id,
+                            tool_name,
+                            kind: serde_json::from_value(serde_json::Value::String(kind))?,
+                            inspector,
+                            action: serde_json::from_value(serde_json::Value::String(action))?,
+                            reason,
+                            confidence,
+                            principal,
+                        },
+                    })
+                },
+            )
+            .collect()
+    }
+
     /// Record the start of a tool execution with operation metadata
     async fn record_tool_start(
         &self,
@@ -1116,3 +2246,342 @@ impl SessionStorage {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversation::message::MessageMetadata;
+    use chrono::Datelike;
+    use rmcp::model::{CallToolRequestParam, Content};
+
+    #[test]
+    fn test_to_fts_query_quotes_terms() {
+        assert_eq!(to_fts_query("bedrock auth"), "\"bedrock\" \"auth\"");
+        assert_eq!(to_fts_query("rm -rf"), "\"rm\" \"-rf\"");
+        assert_eq!(to_fts_query("say \"hi\""), "\"say\" \"\"\"hi\"\"\"");
+        assert_eq!(to_fts_query("   "), "");
+    }
+
+    #[test]
+    fn test_searchable_text_includes_tool_calls_and_results() {
+        let request = Message::assistant()
+            .with_text("let me check")
+            .with_tool_request(
+                "call_1",
+                Ok(CallToolRequestParam {
+                    name: "developer__shell".into(),
+                    arguments: serde_json::json!({"command": "aws sts get-caller-identity"})
+                        .as_object()
+                        .cloned(),
+                }),
+            );
+        let text = searchable_text(&request);
+        assert!(text.contains("let me check"));
+        assert!(text.contains("developer__shell"));
+        assert!(text.contains("get-caller-identity"));
+
+        let response = Message::user().with_tool_response(
+            "call_1",
+            Ok(vec![Content::text("ExpiredToken: bedrock credentials")]),
+        );
+        assert!(searchable_text(&response).contains("ExpiredToken"));
+    }
+
+    #[tokio::test]
+    async fn test_record_provider_usage_accumulates_cost_by_model() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = SessionStorage::create(&temp_dir.path().join("sessions.db"))
+            .await
+            .unwrap();
+
+        sqlx::query("INSERT INTO sessions (id, working_dir) VALUES ('s1', '/tmp')")
+            .execute(&storage.pool)
+            .await
+            .unwrap();
+
+        storage
+            .record_provider_usage("s1", "anthropic", "claude-sonnet-4", 1000, 200, Some(0.5))
+            .await
+            .unwrap();
+        storage
+            .record_provider_usage("s1", "anthropic", "claude-sonnet-4", 500, 100, Some(0.25))
+            .await
+            .unwrap();
+        storage
+            .record_provider_usage("s1", "ollama", "qwen3", 300, 50, None)
+            .await
+            .unwrap();
+
+        let session = storage.get_session("s1", false).await.unwrap();
+        assert_eq!(session.accumulated_cost, Some(0.75));
+
+        let sonnet = &session.model_costs["claude-sonnet-4"];
+        assert_eq!(sonnet.provider, "anthropic");
+        assert_eq!(sonnet.calls, 2);
+        assert_eq!(sonnet.input_tokens, 1500);
+        assert_eq!(sonnet.output_tokens, 300);
+        assert_eq!(sonnet.cost, Some(0.75));
+        assert_eq!(session.model_costs["qwen3"].cost, None);
+
+        let daily = storage
+            .get_cost_rollup(CostPeriod::Day, None)
+            .await
+            .unwrap();
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily[0].model, "claude-sonnet-4");
+        assert_eq!(daily[0].calls, 2);
+        assert_eq!(daily[0].cost, 0.75);
+
+        sqlx::query("UPDATE sessions SET schedule_id = 'nightly' WHERE id = 's1'")
+            .execute(&storage.pool)
+            .await
+            .unwrap();
+        let schedule_usage = storage
+            .get_schedule_usage("nightly", chrono::Utc::now() - chrono::Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(schedule_usage.tokens, 2150);
+        assert_eq!(schedule_usage.cost, 0.75);
+
+        let weekly = storage
+            .get_cost_rollup(CostPeriod::Week, None)
+            .await
+            .unwrap();
+        let week_start =
+            chrono::NaiveDate::parse_from_str(&weekly[0].period_start, "%Y-%m-%d").unwrap();
+        assert_eq!(week_start.weekday(), chrono::Weekday::Mon);
+    }
+
+    #[tokio::test]
+    async fn test_get_metrics_groups_tokens_and_tool_calls() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = SessionStorage::create(&temp_dir.path().join("sessions.db"))
+            .await
+            .unwrap();
+
+        sqlx::query(
+            r#"
+            INSERT INTO sessions (id, working_dir, accumulated_input_tokens,
+                                  accumulated_output_tokens, accumulated_total_tokens, recipe_json)
+            VALUES ('s1', '/repo/a', 100, 20, 120, '{"title": "Release notes"}'),
+                   ('s2', '/repo/b', 300, 50, 350, NULL)
+            "#,
+        )
+        .execute(&storage.pool)
+        .await
+        .unwrap();
+        storage
+            .record_provider_usage("s1", "openai", "gpt-4o", 100, 20, Some(0.1))
+            .await
+            .unwrap();
+        storage
+            .record_provider_usage("s2", "openai", "gpt-4o-mini", 300, 50, Some(0.02))
+            .await
+            .unwrap();
+
+        for (session, operation, path, status) in [
+            ("s1", "file_create", Some("/repo/a/new.rs"), "success"),
+            ("s1", "file_edit", Some("/repo/a/lib.rs"), "success"),
+            ("s1", "file_edit", Some("/repo/a/lib.rs"), "success"),
+            ("s1", "command_execute", None, "error"),
+            ("s2", "command_execute", None, "success"),
+        ] {
+            let id = storage
+                .record_tool_start(session, "developer__tool", Some(operation), path)
+                .await
+                .unwrap();
+            storage
+                .record_tool_complete(id, status, None)
+                .await
+                .unwrap();
+        }
+
+        let by_dir = storage
+            .get_metrics(MetricsGroupBy::WorkingDir, None)
+            .await
+            .unwrap();
+        assert_eq!(by_dir.len(), 2);
+        assert_eq!(by_dir[0].key, "/repo/b");
+        assert_eq!(by_dir[0].total_tokens, 350);
+        let repo_a = &by_dir[1];
+        assert_eq!(repo_a.tool_calls, 4);
+        assert_eq!(repo_a.failed_tool_calls, 1);
+        assert_eq!(repo_a.error_rate, 0.25);
+        assert_eq!(repo_a.files_created, 1);
+        assert_eq!(repo_a.files_modified, 1);
+        assert_eq!(repo_a.calls_by_operation["file_edit"], 2);
+
+        let by_recipe = storage
+            .get_metrics(MetricsGroupBy::Recipe, None)
+            .await
+            .unwrap();
+        let keys: Vec<_> = by_recipe.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(keys, vec!["(none)", "Release notes"]);
+
+        let by_model = storage
+            .get_metrics(MetricsGroupBy::Model, None)
+            .await
+            .unwrap();
+        assert_eq!(by_model[0].key, "gpt-4o-mini");
+        assert_eq!(by_model[0].sessions, 1);
+        assert_eq!(by_model[0].cost, 0.02);
+        assert_eq!(by_model[1].tool_calls, 4);
+
+        let by_day = storage
+            .get_metrics(
+                MetricsGroupBy::Day,
+                Some(chrono::Utc::now() - chrono::Duration::days(1)),
+            )
+            .await
+            .unwrap();
+        assert_eq!(by_day.len(), 1);
+        assert_eq!(by_day[0].sessions, 2);
+        assert_eq!(by_day[0].tool_calls, 5);
+
+        let future = storage
+            .get_metrics(
+                MetricsGroupBy::Day,
+                Some(chrono::Utc::now() + chrono::Duration::days(1)),
+            )
+            .await
+            .unwrap();
+        assert!(future.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_permission_audit_is_append_only() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = SessionStorage::create(&temp_dir.path().join("sessions.db"))
+            .await
+            .unwrap();
+
+        let entry = |session: &str, action| AuditEntry {
+            session_id: Some(session.to_string()),
+            tool_request_id: "req_1".to_string(),
+            tool_name: "developer__shell".to_string(),
+            kind: AuditKind::Decision,
+            inspector: "permission".to_string(),
+            action,
+            reason: "Tool pre-approved".to_string(),
+            confidence: Some(1.0),
+            principal: "alice".to_string(),
+        };
+        storage
+            .record_audit_entries(&[
+                entry("s1", AuditAction::AutoApproved),
+                entry("s2", AuditAction::Blocked),
+            ])
+            .await
+            .unwrap();
+
+        let all = storage
+            .get_audit_records(&AuditFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].entry, entry("s1", AuditAction::AutoApproved));
+
+        let filtered = storage
+            .get_audit_records(&AuditFilter {
+                session_id: Some("s2".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].entry.action, AuditAction::Blocked);
+
+        assert!(sqlx::query("DELETE FROM permission_audit")
+            .execute(&storage.pool)
+            .await
+            .is_err());
+        assert!(sqlx::query("UPDATE permission_audit SET action = 'allow'")
+            .execute(&storage.pool)
+            .await
+            .is_err());
+    }
+    #[tokio::test]
+    async fn test_fork_session_lineage_and_listing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = SessionStorage::create(&temp_dir.path().join("sessions.db"))
+            .await
+            .unwrap();
+
+        sqlx::query("INSERT INTO sessions (id, working_dir) VALUES ('parent', '/tmp')")
+            .execute(&storage.pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO sessions (id, working_dir) VALUES ('empty', '/tmp')")
+            .execute(&storage.pool)
+            .await
+            .unwrap();
+        let conversation = Conversation::new_unvalidated(vec![
+            Message::user().with_text("first"),
+            Message::assistant().with_text("second"),
+        ]);
+        storage
+            .replace_conversation("parent", &conversation)
+            .await
+            .unwrap();
+
+        let partial = storage.fork_session("parent", Some(1)).await.unwrap();
+        let blank = storage.fork_session("parent", Some(0)).await.unwrap();
+        assert!(storage.fork_session("parent", Some(3)).await.is_err());
+
+        let fork = storage.get_session(&partial, true).await.unwrap();
+        assert_eq!(fork.parent_session_id.as_deref(), Some("parent"));
+        assert_eq!(fork.fork_message_index, Some(1));
+        assert_eq!(fork.message_count, 1);
+        assert_eq!(
+            fork.conversation.unwrap().messages()[0].as_concat_text(),
+            "first"
+        );
+
+        let listed: Vec<(String, usize)> = storage
+            .list_sessions()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|session| (session.id, session.message_count))
+            .collect();
+        assert_eq!(listed.len(), 3);
+        assert!(listed.contains(&("parent".to_string(), 2)));
+        assert!(listed.contains(&(partial, 1)));
+        assert!(listed.contains(&(blank, 0)));
+    }
+
+    #[tokio::test]
+    async fn test_replace_conversation_keeps_visibility() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let storage = SessionStorage::create(&temp_dir.path().join("sessions.db"))
+            .await
+            .unwrap();
+
+        sqlx::query("INSERT INTO sessions (id, working_dir) VALUES ('s1', '/tmp')")
+            .execute(&storage.pool)
+            .await
+            .unwrap();
+
+        let conversation = Conversation::new_unvalidated(vec![
+            Message::user()
+                .with_text("cat the logs")
+                .with_metadata(MessageMetadata::user_only()),
+            Message::assistant()
+                .with_text("The logs show a timeout")
+                .with_metadata(MessageMetadata::agent_only()),
+            Message::user().with_text("Fix it"),
+        ]);
+        storage
+            .replace_conversation("s1", &conversation)
+            .await
+            .unwrap();
+
+        let stored = storage.get_conversation("s1").await.unwrap();
+        let visibility: Vec<(bool, bool)> = stored
+            .messages()
+            .iter()
+            .map(|m| (m.is_user_visible(), m.is_agent_visible()))
+            .collect();
+        assert_eq!(visibility, vec![(true, false), (false, true), (true, true)]);
+    }
+}
@@ -77,8 +77,13 @@ pub fn classify_tool(tool_name: &str, arguments: &Option<serde_json::Map<String,
     // Extract extension name if present (format: "extension__tool" or "extension::tool")
     let (extension, base_name) = extract_extension_and_name(tool_name);
     
-    // Try to classify based on tool name patterns
-    let operation = classify_by_name(&base_name);
+    // Editor tools multiplex reads, writes and edits through their `command` argument,
+    // so their name (which contains "edit") must not decide the operation
+    let operation = if base_name.contains("editor") {
+        ToolOperation::Other(base_name.clone())
+    } else {
+        classify_by_name(&base_name)
+    };
     
     // If we couldn't classify by name, try arguments
     let (final_operation, metadata) = if matches!(operation, ToolOperation::Other(_)) {
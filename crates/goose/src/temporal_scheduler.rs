@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::process::Command;
 use std::sync::Arc;
 use std::time::Duration;
@@ -6,11 +7,17 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
 use tokio::time::sleep;
 use tracing::{info, warn};
 
-use crate::scheduler::{normalize_cron_expression, ScheduledJob, SchedulerError};
+use crate::scheduler::{
+    normalize_cron_expression, resolve_recipe_source, ScheduledJob, SchedulerError,
+};
+use crate::scheduler_history::ScheduledJobRun;
+use crate::scheduler_policy::{BlackoutWindow, OverlapPolicy};
 use crate::scheduler_trait::SchedulerTrait;
+use crate::scheduler_trigger::{spawn_trigger_watcher, ScheduleTrigger, TriggerWatcher};
 use crate::session::{Session, SessionManager};
 
 const TEMPORAL_SERVICE_STARTUP_TIMEOUT: Duration = Duration::from_secs(15);
@@ -20,13 +27,20 @@ const TEMPORAL_SERVICE_HEALTH_CHECK_INTERVAL: Duration = Duration::from_millis(5
 // to avoid conflicts with common services
 const DEFAULT_HTTP_PORTS: &[u16] = &[58080, 58081, 58082, 58083, 58084, 58085];
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 struct JobRequest {
     action: String,
     job_id: Option<String>,
     cron: Option<String>,
     recipe_path: Option<String>,
     execution_mode: Option<String>,
+    parameters: Option<HashMap<String, String>>,
+    overlap_policy: Option<OverlapPolicy>,
+    timezone: Option<String>,
+    blackout_windows: Option<Vec<BlackoutWindow>>,
+    jitter_seconds: Option<u64>,
+    trigger: Option<ScheduleTrigger>,
+    debounce_seconds: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -48,6 +62,20 @@ struct TemporalJobStatus {
     paused: bool,
     created_at: String,
     execution_mode: Option<String>,
+    #[serde(default)]
+    parameters: Option<HashMap<String, String>>,
+    #[serde(default)]
+    overlap_policy: Option<OverlapPolicy>,
+    #[serde(default)]
+    timezone: Option<String>,
+    #[serde(default)]
+    blackout_windows: Option<Vec<BlackoutWindow>>,
+    #[serde(default)]
+    jitter_seconds: Option<u64>,
+    #[serde(default)]
+    trigger: Option<ScheduleTrigger>,
+    #[serde(default)]
+    debounce_seconds: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -62,11 +90,47 @@ pub struct PortConfig {
     ui_port: u16,
 }
 
+/// Budgets, retries and hooks are carried out by the built-in scheduler's run loop, which
+/// Temporal runs do not go through, so jobs using them are refused rather than having them
+/// silently dropped.
+/// Runs started by a file, git or webhook trigger only honour the skip overlap policy.
+fn check_supported_options(job: &ScheduledJob) -> Result<(), SchedulerError> {
+    let triggered = job.trigger.cron_expression().is_none();
+    if let Some(policy) = job
+        .overlap_policy
+        .filter(|policy| triggered && *policy != OverlapPolicy::Skip)
+    {
+        return Err(SchedulerError::Unsupported(format!(
+            "the Temporal scheduler does not support the {} overlap policy for {} on job '{}'",
+            policy, job.trigger, job.id
+        )));
+    }
+
+    let unsupported: Vec<&str> = [
+        ("budget", job.budget.is_some()),
+        ("retry", job.retry.is_some()),
+        ("on_success", job.on_success.is_some()),
+        ("on_failure", job.on_failure.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(field, set)| set.then_some(field))
+    .collect();
+    if unsupported.is_empty() {
+        return Ok(());
+    }
+    Err(SchedulerError::Unsupported(format!(
+        "the Temporal scheduler does not support {} on job '{}'",
+        unsupported.join(", "),
+        job.id
+    )))
+}
+
 #[derive(Clone)]
 pub struct TemporalScheduler {
     http_client: Client,
     service_url: String,
     port_config: PortConfig,
+    trigger_watchers: Arc<Mutex<HashMap<String, tokio::task::AbortHandle>>>,
 }
 
 impl TemporalScheduler {
@@ -88,6 +152,7 @@ impl TemporalScheduler {
                 temporal_port: 7233, // temporary defaults
                 ui_port: 8233,
             },
+            trigger_watchers: Arc::new(Mutex::new(HashMap::new())),
         });
 
         // Start the Go service if not already running
@@ -109,6 +174,7 @@ impl TemporalScheduler {
             http_client,
             service_url,
             port_config,
+            trigger_watchers: Arc::new(Mutex::new(HashMap::new())),
         });
 
         // Start the status monitor to keep job statuses in sync
@@ -116,6 +182,10 @@ impl TemporalScheduler {
             tracing::warn!("Failed to start status monitor: {}", e);
         }
 
+        if let Err(e) = final_scheduler.start_trigger_watchers().await {
+            tracing::warn!("Failed to start trigger watchers: {}", e);
+        }
+
         info!("TemporalScheduler initialized successfully");
         Ok(final_scheduler)
     }
@@ -546,28 +616,53 @@ impl TemporalScheduler {
             job.id
         );
 
-        // Normalize the cron expression to ensure it's 6-field format
-        let normalized_cron = normalize_cron_expression(&job.cron);
-        if normalized_cron != job.cron {
-            tracing::info!(
-                "TemporalScheduler: Normalized cron expression from '{}' to '{}'",
-                job.cron,
-                normalized_cron
-            );
-        }
+        check_supported_options(&job)?;
+
+        // Temporal fires cron triggers itself; goose starts the runs of the other triggers
+        let (cron, trigger) = match &job.trigger {
+            ScheduleTrigger::Cron { expression } => {
+                // Normalize the cron expression to ensure it's 6-field format
+                let normalized_cron = normalize_cron_expression(expression);
+                if normalized_cron != *expression {
+                    tracing::info!(
+                        "TemporalScheduler: Normalized cron expression from '{}' to '{}'",
+                        expression,
+                        normalized_cron
+                    );
+                }
+                (Some(normalized_cron), None)
+            }
+            trigger => {
+                // Fail before creating the schedule when the trigger's source can't be watched
+                TriggerWatcher::new(trigger, job.debounce_seconds)?;
+                (None, Some(trigger.clone()))
+            }
+        };
 
         let request = JobRequest {
             action: "create".to_string(),
             job_id: Some(job.id.clone()),
-            cron: Some(normalized_cron.clone()),
-            recipe_path: Some(job.source.clone()),
+            cron,
+            recipe_path: Some(
+                resolve_recipe_source(std::path::Path::new(&job.source))?
+                    .to_string_lossy()
+                    .into_owned(),
+            ),
             execution_mode: job.execution_mode.clone(),
+            parameters: job.parameters.clone(),
+            overlap_policy: job.overlap_policy,
+            timezone: job.timezone.clone(),
+            blackout_windows: job.blackout_windows.clone(),
+            jitter_seconds: job.jitter_seconds,
+            trigger,
+            debounce_seconds: job.debounce_seconds,
         };
 
         let response = self.make_request(request).await?;
 
         if response.success {
             info!("Successfully created scheduled job: {}", job.id);
+            self.watch_trigger(&job).await?;
             Ok(())
         } else {
             Err(SchedulerError::SchedulerInternalError(response.message))
@@ -578,10 +673,7 @@ impl TemporalScheduler {
         tracing::info!("TemporalScheduler: list_scheduled_jobs() called");
         let request = JobRequest {
             action: "list".to_string(),
-            job_id: None,
-            cron: None,
-            recipe_path: None,
-            execution_mode: None,
+            ..Default::default()
         };
 
         let response = self.make_request(request).await?;
@@ -594,13 +686,26 @@ impl TemporalScheduler {
                     ScheduledJob {
                         id: tj.id,
                         source: tj.recipe_path,
-                        cron: tj.cron,
+                        trigger: tj.trigger.unwrap_or(ScheduleTrigger::Cron {
+                            expression: tj.cron,
+                        }),
                         last_run: tj.last_run.and_then(|s| s.parse::<DateTime<Utc>>().ok()),
                         currently_running: tj.currently_running,
                         paused: tj.paused,
                         current_session_id: None, // Not provided by Temporal service
                         process_start_time: None, // Not provided by Temporal service
                         execution_mode: tj.execution_mode,
+                        budget: None,
+                        retry: None,
+                        on_success: None,
+                        on_failure: None,
+                        parameters: tj.parameters,
+                        overlap_policy: tj.overlap_policy,
+                        timezone: tj.timezone,
+                        blackout_windows: tj.blackout_windows,
+                        jitter_seconds: tj.jitter_seconds,
+                        debounce_seconds: tj.debounce_seconds,
+                        recipe_dir: None,
                     }
                 })
                 .collect();
@@ -614,15 +719,16 @@ impl TemporalScheduler {
         let request = JobRequest {
             action: "delete".to_string(),
             job_id: Some(id.to_string()),
-            cron: None,
-            recipe_path: None,
-            execution_mode: None,
+            ..Default::default()
         };
 
         let response = self.make_request(request).await?;
 
         if response.success {
             info!("Successfully removed scheduled job: {}", id);
+            if let Some(abort_handle) = self.trigger_watchers.lock().await.remove(id) {
+                abort_handle.abort();
+            }
             Ok(())
         } else {
             Err(SchedulerError::SchedulerInternalError(response.message))
@@ -633,9 +739,7 @@ impl TemporalScheduler {
         let request = JobRequest {
             action: "pause".to_string(),
             job_id: Some(id.to_string()),
-            cron: None,
-            recipe_path: None,
-            execution_mode: None,
+            ..Default::default()
         };
 
         let response = self.make_request(request).await?;
@@ -652,9 +756,7 @@ impl TemporalScheduler {
         let request = JobRequest {
             action: "unpause".to_string(),
             job_id: Some(id.to_string()),
-            cron: None,
-            recipe_path: None,
-            execution_mode: None,
+            ..Default::default()
         };
 
         let response = self.make_request(request).await?;
@@ -672,9 +774,7 @@ impl TemporalScheduler {
         let request = JobRequest {
             action: "run_now".to_string(),
             job_id: Some(id.to_string()),
-            cron: None,
-            recipe_path: None,
-            execution_mode: None,
+            ..Default::default()
         };
 
         let response = self.make_request(request).await?;
@@ -699,6 +799,70 @@ impl TemporalScheduler {
         }
     }
 
+    pub async fn trigger_job(
+        &self,
+        id: &str,
+        event_parameters: HashMap<String, String>,
+    ) -> Result<(), SchedulerError> {
+        tracing::info!("TemporalScheduler: trigger_job() called for job '{}'", id);
+        let request = JobRequest {
+            action: "trigger".to_string(),
+            job_id: Some(id.to_string()),
+            parameters: Some(event_parameters),
+            ..Default::default()
+        };
+
+        let response = self.make_request(request).await?;
+
+        if response.success {
+            info!("Successfully started triggered run of job: {}", id);
+            Ok(())
+        } else {
+            Err(SchedulerError::SchedulerInternalError(response.message))
+        }
+    }
+
+    /// Watches the sources of the jobs' file and git triggers, e.g. after goose restarted
+    async fn start_trigger_watchers(&self) -> Result<(), SchedulerError> {
+        for job in self.list_scheduled_jobs().await? {
+            if let Err(e) = self.watch_trigger(&job).await {
+                warn!(
+                    "Could not watch the trigger of scheduled job {}: {}",
+                    job.id, e
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Temporal has no file or git triggers, so goose watches their sources and starts the
+    /// runs through the Temporal service
+    async fn watch_trigger(&self, job: &ScheduledJob) -> Result<(), SchedulerError> {
+        let scheduler = self.clone();
+        let job_id = job.id.clone();
+        let watcher =
+            spawn_trigger_watcher(&job.id, &job.trigger, job.debounce_seconds, move |params| {
+                let scheduler = scheduler.clone();
+                let job_id = job_id.clone();
+                async move {
+                    if let Err(e) = scheduler.trigger_job(&job_id, params).await {
+                        tracing::error!("Failed to start triggered run of job '{}': {}", job_id, e);
+                    }
+                }
+            })?;
+        if let Some(abort_handle) = watcher {
+            let previous = self
+                .trigger_watchers
+                .lock()
+                .await
+                .insert(job.id.clone(), abort_handle);
+            if let Some(previous) = previous {
+                previous.abort();
+            }
+        }
+        Ok(())
+    }
+
     pub async fn sessions(
         &self,
         sched_id: &str,
@@ -734,6 +898,18 @@ impl TemporalScheduler {
         Ok(result_sessions)
     }
 
+    /// Runs are only recorded by the built-in scheduler; Temporal keeps its own workflow history.
+    pub async fn runs(
+        &self,
+        sched_id: &str,
+        _limit: usize,
+    ) -> Result<Vec<ScheduledJobRun>, SchedulerError> {
+        Err(SchedulerError::Unsupported(format!(
+            "the Temporal scheduler does not record the runs of job '{}'",
+            sched_id
+        )))
+    }
+
     pub async fn update_schedule(
         &self,
         sched_id: &str,
@@ -759,8 +935,7 @@ impl TemporalScheduler {
             action: "update".to_string(),
             job_id: Some(sched_id.to_string()),
             cron: Some(normalized_cron),
-            recipe_path: None,
-            execution_mode: None,
+            ..Default::default()
         };
 
         let response = self.make_request(request).await?;
@@ -782,9 +957,7 @@ impl TemporalScheduler {
         let request = JobRequest {
             action: "kill_job".to_string(),
             job_id: Some(sched_id.to_string()),
-            cron: None,
-            recipe_path: None,
-            execution_mode: None,
+            ..Default::default()
         };
 
         let response = self.make_request(request).await?;
@@ -808,9 +981,7 @@ impl TemporalScheduler {
                 let request = JobRequest {
                     action: "status".to_string(),
                     job_id: Some(job.id.clone()),
-                    cron: None,
-                    recipe_path: None,
-                    execution_mode: None,
+                    ..Default::default()
                 };
 
                 match self.make_request(request).await {
@@ -893,9 +1064,7 @@ impl TemporalScheduler {
                     let request = JobRequest {
                         action: "mark_completed".to_string(),
                         job_id: Some(job.id.clone()),
-                        cron: None,
-                        recipe_path: None,
-                        execution_mode: None,
+                        ..Default::default()
                     };
 
                     if let Err(e) = self.make_request(request).await {
@@ -940,9 +1109,7 @@ impl TemporalScheduler {
         let request = JobRequest {
             action: "status".to_string(),
             job_id: Some(sched_id.to_string()),
-            cron: None,
-            recipe_path: None,
-            execution_mode: None,
+            ..Default::default()
         };
 
         let response = self.make_request(request).await?;
@@ -1191,6 +1358,14 @@ impl SchedulerTrait for TemporalScheduler {
         self.sessions(sched_id, limit).await
     }
 
+    async fn runs(
+        &self,
+        sched_id: &str,
+        limit: usize,
+    ) -> Result<Vec<ScheduledJobRun>, SchedulerError> {
+        self.runs(sched_id, limit).await
+    }
+
     async fn update_schedule(
         &self,
         sched_id: &str,
@@ -1199,6 +1374,14 @@ impl SchedulerTrait for TemporalScheduler {
         self.update_schedule(sched_id, new_cron).await
     }
 
+    async fn trigger_job(
+        &self,
+        sched_id: &str,
+        event_parameters: HashMap<String, String>,
+    ) -> Result<(), SchedulerError> {
+        self.trigger_job(sched_id, event_parameters).await
+    }
+
     async fn kill_running_job(&self, sched_id: &str) -> Result<(), SchedulerError> {
         self.kill_running_job(sched_id).await
     }
@@ -1382,4 +1565,90 @@ mod tests {
 
         println!("✅ Cron normalization works correctly in TemporalScheduler");
     }
+
+    #[test]
+    fn test_budget_retry_and_hooks_are_rejected() {
+        use crate::agents::BudgetLimits;
+        use crate::scheduler_history::RetryPolicy;
+
+        let mut job = ScheduledJob {
+            id: "nightly".to_string(),
+            source: "recipe.yaml".to_string(),
+            trigger: ScheduleTrigger::Cron {
+                expression: "0 0 * * *".to_string(),
+            },
+            last_run: None,
+            currently_running: false,
+            paused: false,
+            current_session_id: None,
+            process_start_time: None,
+            execution_mode: None,
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: None,
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
+        };
+        assert!(check_supported_options(&job).is_ok());
+
+        job.retry = Some(RetryPolicy {
+            max_retries: 2,
+            backoff_seconds: 1,
+            max_backoff_seconds: 1,
+        });
+        let err = check_supported_options(&job).unwrap_err();
+        assert!(matches!(err, SchedulerError::Unsupported(_)));
+        assert!(err.to_string().contains("retry"));
+
+        job.retry = None;
+        job.budget = Some(BudgetLimits {
+            hard_token_limit: Some(1000),
+            ..Default::default()
+        });
+        let err = check_supported_options(&job).unwrap_err();
+        assert!(err.to_string().contains("budget"));
+    }
+
+    #[test]
+    fn test_triggered_jobs_only_accept_skip_overlap_policy() {
+        let mut job = ScheduledJob {
+            id: "docs".to_string(),
+            source: "recipe.yaml".to_string(),
+            trigger: ScheduleTrigger::Webhook,
+            last_run: None,
+            currently_running: false,
+            paused: false,
+            current_session_id: None,
+            process_start_time: None,
+            execution_mode: None,
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: Some(OverlapPolicy::Skip),
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
+        };
+        assert!(check_supported_options(&job).is_ok());
+
+        job.overlap_policy = Some(OverlapPolicy::Queue);
+        let err = check_supported_options(&job).unwrap_err();
+        assert!(matches!(err, SchedulerError::Unsupported(_)));
+        assert!(err.to_string().contains("queue"));
+
+        job.trigger = ScheduleTrigger::Cron {
+            expression: "0 0 * * *".to_string(),
+        };
+        assert!(check_supported_options(&job).is_ok());
+    }
 }
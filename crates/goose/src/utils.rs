@@ -1,5 +1,6 @@
 use tokio_util::sync::CancellationToken;
 use unicode_normalization::UnicodeNormalization;
+use url::{Host, Url};
 
 /// Check if a character is in the Unicode Tags Block range (U+E0000-U+E007F)
 /// These characters are invisible and can be used for steganographic attacks
@@ -47,6 +48,16 @@ pub fn is_token_cancelled(cancellation_token: &Option<CancellationToken>) -> boo
         .is_some_and(|t| t.is_cancelled())
 }
 
+/// Whether the URL points at this machine, by the `localhost` name or a loopback address
+pub fn is_localhost_url(url: &Url) -> bool {
+    match url.host() {
+        Some(Host::Domain(domain)) => domain == "localhost",
+        Some(Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,4 +136,23 @@ mod tests {
         assert_eq!(safe_truncate(mixed, 20), mixed);
         assert_eq!(safe_truncate(mixed, 8), "Hello...");
     }
+
+    #[test]
+    fn test_is_localhost_url() {
+        for url in [
+            "http://localhost:3000/health",
+            "http://127.0.0.1/alerts",
+            "http://[::1]:8080/",
+        ] {
+            assert!(is_localhost_url(&Url::parse(url).unwrap()), "{}", url);
+        }
+        for url in [
+            "https://example.com/alerts",
+            "http://localhost.example.com/",
+            "http://10.0.0.1/",
+            "file:///tmp/alerts",
+        ] {
+            assert!(!is_localhost_url(&Url::parse(url).unwrap()), "{}", url);
+        }
+    }
 }
@@ -363,8 +363,10 @@ mod schedule_tool_tests {
     use chrono::{DateTime, Utc};
     use goose::agents::platform_tools::PLATFORM_MANAGE_SCHEDULE_TOOL_NAME;
     use goose::scheduler::{ScheduledJob, SchedulerError};
+    use goose::scheduler_history::ScheduledJobRun;
     use goose::scheduler_trait::SchedulerTrait;
     use goose::session::Session;
+    use std::collections::HashMap;
     use std::sync::Arc;
 
     struct MockScheduler {
@@ -422,6 +424,14 @@ mod schedule_tool_tests {
             Ok(vec![])
         }
 
+        async fn runs(
+            &self,
+            _sched_id: &str,
+            _limit: usize,
+        ) -> Result<Vec<ScheduledJobRun>, SchedulerError> {
+            Ok(vec![])
+        }
+
         async fn update_schedule(
             &self,
             _sched_id: &str,
@@ -430,6 +440,14 @@ mod schedule_tool_tests {
             Ok(())
         }
 
+        async fn trigger_job(
+            &self,
+            _sched_id: &str,
+            _event_parameters: HashMap<String, String>,
+        ) -> Result<(), SchedulerError> {
+            Ok(())
+        }
+
         async fn kill_running_job(&self, _sched_id: &str) -> Result<(), SchedulerError> {
             Ok(())
         }
@@ -11,7 +11,9 @@ use tokio::sync::Mutex;
 
 use goose::agents::Agent;
 use goose::scheduler::{ScheduledJob, SchedulerError};
+use goose::scheduler_history::ScheduledJobRun;
 use goose::scheduler_trait::SchedulerTrait;
+use goose::scheduler_trigger::ScheduleTrigger;
 use goose::session::Session;
 
 #[derive(Debug, Clone)]
@@ -199,6 +201,20 @@ impl SchedulerTrait for ConfigurableMockScheduler {
         }
     }
 
+    async fn runs(
+        &self,
+        _sched_id: &str,
+        _limit: usize,
+    ) -> Result<Vec<ScheduledJobRun>, SchedulerError> {
+        self.log_call("runs").await;
+
+        match self.get_behavior("runs").await {
+            MockBehavior::NotFound(job_id) => Err(SchedulerError::JobNotFound(job_id)),
+            MockBehavior::InternalError(msg) => Err(SchedulerError::SchedulerInternalError(msg)),
+            _ => Ok(vec![]),
+        }
+    }
+
     async fn update_schedule(
         &self,
         sched_id: &str,
@@ -221,6 +237,28 @@ impl SchedulerTrait for ConfigurableMockScheduler {
         }
     }
 
+    async fn trigger_job(
+        &self,
+        sched_id: &str,
+        _event_parameters: HashMap<String, String>,
+    ) -> Result<(), SchedulerError> {
+        self.log_call("trigger_job").await;
+
+        match self.get_behavior("trigger_job").await {
+            MockBehavior::Success => {
+                let jobs = self.jobs.lock().await;
+                if jobs.contains_key(sched_id) {
+                    Ok(())
+                } else {
+                    Err(SchedulerError::JobNotFound(sched_id.to_string()))
+                }
+            }
+            MockBehavior::NotFound(job_id) => Err(SchedulerError::JobNotFound(job_id)),
+            MockBehavior::InternalError(msg) => Err(SchedulerError::SchedulerInternalError(msg)),
+            _ => Ok(()),
+        }
+    }
+
     async fn kill_running_job(&self, sched_id: &str) -> Result<(), SchedulerError> {
         self.log_call("kill_running_job").await;
 
@@ -339,13 +377,26 @@ impl ScheduleToolTestBuilder {
         let job = ScheduledJob {
             id: job_id.to_string(),
             source: "/tmp/test.json".to_string(),
-            cron: cron.to_string(),
+            trigger: ScheduleTrigger::Cron {
+                expression: cron.to_string(),
+            },
             last_run: None,
             currently_running: false,
             paused: false,
             current_session_id: None,
             process_start_time: None,
             execution_mode: Some("background".to_string()),
+            budget: None,
+            retry: None,
+            on_success: None,
+            on_failure: None,
+            parameters: None,
+            overlap_policy: None,
+            timezone: None,
+            blackout_windows: None,
+            jitter_seconds: None,
+            debounce_seconds: None,
+            recipe_dir: None,
         };
         {
             let mut jobs = self.scheduler.jobs.lock().await;
@@ -395,5 +446,9 @@ pub fn create_test_session_metadata(message_count: usize, working_dir: &str) ->
         updated_at: "".to_string(),
         conversation: None,
         message_count,
+        parent_session_id: None,
+        fork_message_index: None,
+        accumulated_cost: None,
+        model_costs: Default::default(),
     }
 }
@@ -4,12 +4,14 @@ import (
 	"context"
 	"encoding/base64"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"log"
 	"os"
 	"os/exec"
 	"path/filepath"
 	"runtime"
+	"sort"
 	"strings"
 	"time"
 
@@ -27,8 +29,14 @@ type Recipe struct {
 	Prompt       *string `json:"prompt" yaml:"prompt"`
 }
 
-// Workflow definition for executing Goose recipes
-func GooseJobWorkflow(ctx workflow.Context, jobID, recipePath string) (string, error) {
+// skippedRunErrorType marks runs that were not started because they fell in a blackout window.
+// The workflow ends with this error so that Temporal's run history tells skipped runs apart
+// from successful ones.
+const skippedRunErrorType = "RunSkipped"
+
+// Workflow definition for executing Goose recipes. eventParameters are the values of the event
+// that triggered the run; runs started by the schedule itself have none.
+func GooseJobWorkflow(ctx workflow.Context, jobID, recipePath string, eventParameters map[string]string) (string, error) {
 	logger := workflow.GetLogger(ctx)
 	logger.Info("Starting Goose job workflow", "jobID", jobID, "recipePath", recipePath)
 
@@ -39,14 +47,19 @@ func GooseJobWorkflow(ctx workflow.Context, jobID, recipePath string) (string, e
 			BackoffCoefficient:     2.0,
 			MaximumInterval:        time.Minute,
 			MaximumAttempts:        3,
-			NonRetryableErrorTypes: []string{"InvalidRecipeError"},
+			NonRetryableErrorTypes: []string{"InvalidRecipeError", skippedRunErrorType},
 		},
 	}
 	ctx = workflow.WithActivityOptions(ctx, ao)
 
 	var sessionID string
-	err := workflow.ExecuteActivity(ctx, ExecuteGooseRecipe, jobID, recipePath).Get(ctx, &sessionID)
+	err := workflow.ExecuteActivity(ctx, ExecuteGooseRecipe, jobID, recipePath, eventParameters).Get(ctx, &sessionID)
 	if err != nil {
+		var appErr *temporal.ApplicationError
+		if errors.As(err, &appErr) && appErr.Type() == skippedRunErrorType {
+			logger.Info("Goose job workflow skipped", "jobID", jobID, "reason", appErr.Message())
+			return "", err
+		}
 		logger.Error("Goose job workflow failed", "jobID", jobID, "error", err)
 		return "", err
 	}
@@ -56,10 +69,40 @@ func GooseJobWorkflow(ctx workflow.Context, jobID, recipePath string) (string, e
 }
 
 // Activity definition for executing Goose recipes with proper cancellation handling
-func ExecuteGooseRecipe(ctx context.Context, jobID, recipePath string) (string, error) {
+func ExecuteGooseRecipe(ctx context.Context, jobID, recipePath string, eventParameters map[string]string) (string, error) {
 	logger := activity.GetLogger(ctx)
 	logger.Info("Executing Goose recipe", "jobID", jobID, "recipePath", recipePath)
 
+	// Read the schedule's run settings; manual runs ignore its blackout windows
+	var settings scheduleSettings
+	if globalService != nil {
+		if s, err := globalService.getScheduleSettings(jobID); err == nil {
+			settings = s
+		} else {
+			logger.Warn("Could not read schedule settings", "jobID", jobID, "error", err)
+		}
+	}
+	isManualRun := strings.HasPrefix(activity.GetInfo(ctx).WorkflowExecution.ID, "manual-")
+	if window := settings.activeBlackoutWindow(time.Now()); window != nil && !isManualRun {
+		logger.Info("Skipping run inside blackout window", "jobID", jobID,
+			"start", window.Start, "end", window.End, "reason", window.Reason)
+		message := fmt.Sprintf("run skipped: inside blackout window %s to %s",
+			window.Start.Format(time.RFC3339), window.End.Format(time.RFC3339))
+		if window.Reason != "" {
+			message = fmt.Sprintf("%s (%s)", message, window.Reason)
+		}
+		return "", temporal.NewNonRetryableApplicationError(message, skippedRunErrorType, nil)
+	}
+
+	// The event's values take precedence over the schedule's own parameters
+	parameters := make(map[string]string, len(settings.Parameters)+len(eventParameters))
+	for key, value := range settings.Parameters {
+		parameters[key] = value
+	}
+	for key, value := range eventParameters {
+		parameters[key] = value
+	}
+
 	// Mark job as running at the start
 	if globalService != nil {
 		globalService.markJobAsRunning(jobID)
@@ -104,12 +147,12 @@ func ExecuteGooseRecipe(ctx context.Context, jobID, recipePath string) (string,
 	// Check if this is a foreground job
 	if isForegroundJob(actualRecipePath) {
 		logger.Info("Executing foreground job with cancellation support", "jobID", jobID)
-		return executeForegroundJobWithCancellation(subCtx, jobID, actualRecipePath)
+		return executeForegroundJobWithCancellation(subCtx, jobID, actualRecipePath, parameters)
 	}
 
 	// For background jobs, execute with cancellation support
 	logger.Info("Executing background job with cancellation support", "jobID", jobID)
-	return executeBackgroundJobWithCancellation(subCtx, jobID, actualRecipePath)
+	return executeBackgroundJobWithCancellation(subCtx, jobID, actualRecipePath, parameters)
 }
 
 // resolveRecipePath resolves the actual recipe path, handling embedded recipes
@@ -137,7 +180,7 @@ func resolveRecipePath(jobID, recipePath string) (string, error) {
 }
 
 // executeBackgroundJobWithCancellation handles background job execution with proper process management
-func executeBackgroundJobWithCancellation(ctx context.Context, jobID, recipePath string) (string, error) {
+func executeBackgroundJobWithCancellation(ctx context.Context, jobID, recipePath string, parameters map[string]string) (string, error) {
 	log.Printf("Executing background job %s using recipe file: %s", jobID, recipePath)
 
 	// Find the goose CLI binary
@@ -150,11 +193,7 @@ func executeBackgroundJobWithCancellation(ctx context.Context, jobID, recipePath
 	sessionName := fmt.Sprintf("scheduled-%s", jobID)
 
 	// Create command with context for cancellation
-	cmd := exec.CommandContext(ctx, goosePath, "run",
-		"--recipe", recipePath,
-		"--name", sessionName,
-		"--scheduled-job-id", jobID,
-	)
+	cmd := exec.CommandContext(ctx, goosePath, gooseRunArgs(jobID, recipePath, sessionName, parameters)...)
 
 	// Set up process group for proper cleanup
 	configureSysProcAttr(cmd)
@@ -203,8 +242,28 @@ func executeBackgroundJobWithCancellation(ctx context.Context, jobID, recipePath
 	}
 }
 
+// gooseRunArgs builds the goose CLI arguments for running a scheduled recipe
+func gooseRunArgs(jobID, recipePath, sessionName string, parameters map[string]string) []string {
+	args := []string{"run",
+		"--recipe", recipePath,
+		"--name", sessionName,
+		"--scheduled-job-id", jobID,
+	}
+
+	// Sort the parameters so that every run gets the same command line
+	keys := make([]string, 0, len(parameters))
+	for key := range parameters {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	for _, key := range keys {
+		args = append(args, "--params", fmt.Sprintf("%s=%s", key, parameters[key]))
+	}
+	return args
+}
+
 // executeForegroundJobWithCancellation handles foreground job execution with proper process management
-func executeForegroundJobWithCancellation(ctx context.Context, jobID, recipePath string) (string, error) {
+func executeForegroundJobWithCancellation(ctx context.Context, jobID, recipePath string, parameters map[string]string) (string, error) {
 	log.Printf("Executing foreground job %s with recipe %s", jobID, recipePath)
 
 	// Parse the recipe file first
@@ -221,7 +280,7 @@ func executeForegroundJobWithCancellation(ctx context.Context, jobID, recipePath
 
 	// Desktop app not running, fall back to CLI
 	log.Printf("Desktop app not running, falling back to CLI mode for job %s", jobID)
-	return executeForegroundJobCLIWithCancellation(ctx, jobID, recipe, recipePath)
+	return executeForegroundJobCLIWithCancellation(ctx, jobID, recipe, recipePath, parameters)
 }
 
 // executeForegroundJobGUIWithCancellation handles GUI execution with cancellation
@@ -257,7 +316,7 @@ func executeForegroundJobGUIWithCancellation(ctx context.Context, jobID string,
 }
 
 // executeForegroundJobCLIWithCancellation handles CLI execution with cancellation
-func executeForegroundJobCLIWithCancellation(ctx context.Context, jobID string, recipe *Recipe, recipePath string) (string, error) {
+func executeForegroundJobCLIWithCancellation(ctx context.Context, jobID string, recipe *Recipe, recipePath string, parameters map[string]string) (string, error) {
 	log.Printf("Executing job %s via CLI fallback using recipe file: %s", jobID, recipePath)
 	// Find the goose CLI binary
 	goosePath, err := findGooseBinary()
@@ -268,11 +327,7 @@ func executeForegroundJobCLIWithCancellation(ctx context.Context, jobID string,
 	// Generate session name for this scheduled job
 	sessionName := fmt.Sprintf("scheduled-%s", jobID)
 	// Create command with context for cancellation
-	cmd := exec.CommandContext(ctx, goosePath, "run",
-		"--recipe", recipePath,
-		"--name", sessionName,
-		"--scheduled-job-id", jobID,
-	)
+	cmd := exec.CommandContext(ctx, goosePath, gooseRunArgs(jobID, recipePath, sessionName, parameters)...)
 
 	// Set up process group for proper cleanup
 	configureSysProcAttr(cmd)
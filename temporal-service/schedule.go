@@ -10,6 +10,7 @@ import (
 	"strings"
 	"time"
 
+	enumspb "go.temporal.io/api/enums/v1"
 	"go.temporal.io/sdk/client"
 )
 
@@ -24,15 +25,60 @@ type JobStatus struct {
 	CreatedAt        time.Time `json:"created_at"`
 	ExecutionMode    *string   `json:"execution_mode,omitempty"`  // "foreground" or "background"
 	LastManualRun    *string   `json:"last_manual_run,omitempty"` // Track manual runs separately
+	scheduleSettings
+}
+
+// BlackoutWindow is a period in which a schedule does not start runs, e.g. a deploy freeze
+type BlackoutWindow struct {
+	Start  time.Time `json:"start"`
+	End    time.Time `json:"end"`
+	Reason string    `json:"reason,omitempty"`
+}
+
+// scheduleSettings are the per-schedule run settings, kept in the schedule's metadata
+type scheduleSettings struct {
+	Parameters      map[string]string `json:"parameters,omitempty"`
+	OverlapPolicy   string            `json:"overlap_policy,omitempty"` // "skip", "queue" or "kill_previous"
+	Timezone        string            `json:"timezone,omitempty"`
+	BlackoutWindows []BlackoutWindow  `json:"blackout_windows,omitempty"`
+	JitterSeconds   uint64            `json:"jitter_seconds,omitempty"`
+	Trigger         json.RawMessage   `json:"trigger,omitempty"` // event trigger; cron schedules have none
+	DebounceSeconds uint64            `json:"debounce_seconds,omitempty"`
+}
+
+// activeBlackoutWindow returns the blackout window containing the given time, if any
+func (s scheduleSettings) activeBlackoutWindow(at time.Time) *BlackoutWindow {
+	for i := range s.BlackoutWindows {
+		window := &s.BlackoutWindows[i]
+		if !at.Before(window.Start) && at.Before(window.End) {
+			return window
+		}
+	}
+	return nil
+}
+
+// temporalOverlapPolicy maps a goose overlap policy onto the Temporal one
+func temporalOverlapPolicy(policy string) (enumspb.ScheduleOverlapPolicy, error) {
+	switch policy {
+	case "", "skip":
+		return enumspb.SCHEDULE_OVERLAP_POLICY_SKIP, nil
+	case "queue":
+		return enumspb.SCHEDULE_OVERLAP_POLICY_BUFFER_ONE, nil
+	case "kill_previous":
+		return enumspb.SCHEDULE_OVERLAP_POLICY_TERMINATE_OTHER, nil
+	default:
+		return enumspb.SCHEDULE_OVERLAP_POLICY_UNSPECIFIED, fmt.Errorf("unknown overlap policy '%s'", policy)
+	}
 }
 
 // Request/Response types for HTTP API
 type JobRequest struct {
-	Action        string `json:"action"` // create, delete, pause, unpause, list, run_now, kill_job, update
+	Action        string `json:"action"` // create, delete, pause, unpause, list, run_now, trigger, kill_job, update
 	JobID         string `json:"job_id"`
 	CronExpr      string `json:"cron"`
 	RecipePath    string `json:"recipe_path"`
 	ExecutionMode string `json:"execution_mode,omitempty"` // "foreground" or "background"
+	scheduleSettings
 }
 
 type JobResponse struct {
@@ -48,8 +94,8 @@ type RunNowResponse struct {
 
 // createSchedule handles the creation of a new schedule
 func (ts *TemporalService) createSchedule(req JobRequest) JobResponse {
-	if req.JobID == "" || req.CronExpr == "" || req.RecipePath == "" {
-		return JobResponse{Success: false, Message: "Missing required fields: job_id, cron, recipe_path"}
+	if req.JobID == "" || (req.CronExpr == "" && len(req.Trigger) == 0) || req.RecipePath == "" {
+		return JobResponse{Success: false, Message: "Missing required fields: job_id, cron or trigger, recipe_path"}
 	}
 
 	// Check if job already exists
@@ -57,6 +103,16 @@ func (ts *TemporalService) createSchedule(req JobRequest) JobResponse {
 		return JobResponse{Success: false, Message: fmt.Sprintf("Job with ID '%s' already exists", req.JobID)}
 	}
 
+	overlapPolicy, err := temporalOverlapPolicy(req.OverlapPolicy)
+	if err != nil {
+		return JobResponse{Success: false, Message: err.Error()}
+	}
+	if req.Timezone != "" {
+		if _, err := time.LoadLocation(req.Timezone); err != nil {
+			return JobResponse{Success: false, Message: fmt.Sprintf("Unknown timezone '%s'", req.Timezone)}
+		}
+	}
+
 	// Validate and copy recipe file to managed storage
 	managedRecipePath, recipeContent, err := ts.storeRecipeForSchedule(req.JobID, req.RecipePath)
 	if err != nil {
@@ -78,6 +134,17 @@ func (ts *TemporalService) createSchedule(req JobRequest) JobResponse {
 		"original_path":  req.RecipePath,    // Keep original for reference
 		"execution_mode": executionMode,
 		"created_at":     time.Now().Format(time.RFC3339),
+
+		// Run settings the activity reads back when the schedule fires
+		"parameters":       req.Parameters,
+		"overlap_policy":   req.OverlapPolicy,
+		"timezone":         req.Timezone,
+		"blackout_windows": req.BlackoutWindows,
+		"jitter_seconds":   req.JitterSeconds,
+		"debounce_seconds": req.DebounceSeconds,
+	}
+	if len(req.Trigger) > 0 {
+		scheduleMetadata["trigger"] = req.Trigger
 	}
 
 	// For small recipes, embed content directly in metadata
@@ -93,16 +160,24 @@ func (ts *TemporalService) createSchedule(req JobRequest) JobResponse {
 		return JobResponse{Success: false, Message: fmt.Sprintf("Failed to encode metadata: %v", err)}
 	}
 
+	spec := client.ScheduleSpec{
+		TimeZoneName: req.Timezone,
+		Jitter:       time.Duration(req.JitterSeconds) * time.Second,
+	}
+	// Event-triggered jobs have no spec; goose starts their runs with the trigger action
+	if req.CronExpr != "" {
+		spec.CronExpressions = []string{req.CronExpr}
+	}
+
 	// Create Temporal schedule with metadata in Note field
 	schedule := client.ScheduleOptions{
-		ID: scheduleID,
-		Spec: client.ScheduleSpec{
-			CronExpressions: []string{req.CronExpr},
-		},
+		ID:      scheduleID,
+		Spec:    spec,
+		Overlap: overlapPolicy,
 		Action: &client.ScheduleWorkflowAction{
 			ID:        fmt.Sprintf("workflow-%s-{{.ScheduledTime.Unix}}", req.JobID),
 			Workflow:  GooseJobWorkflow,
-			Args:      []interface{}{req.JobID, req.RecipePath},
+			Args:      []interface{}{req.JobID, req.RecipePath, map[string]string(nil)},
 			TaskQueue: TaskQueueName,
 		},
 		Note: string(metadataJSON), // Store metadata as JSON in the Note field
@@ -125,6 +200,7 @@ func (ts *TemporalService) createSchedule(req JobRequest) JobResponse {
 		Paused:           false,
 		CreatedAt:        time.Now(),
 		ExecutionMode:    &executionMode,
+		scheduleSettings: req.scheduleSettings,
 	}
 	ts.scheduleJobs[req.JobID] = jobStatus
 
@@ -227,6 +303,10 @@ func (ts *TemporalService) updateSchedule(req JobRequest) JobResponse {
 		return JobResponse{Success: false, Message: fmt.Sprintf("Job with ID '%s' not found", req.JobID)}
 	}
 
+	if job.CronExpr == "" {
+		return JobResponse{Success: false, Message: fmt.Sprintf("Schedule '%s' is triggered by events and has no cron expression", req.JobID)}
+	}
+
 	// Check if job is currently running
 	if job.CurrentlyRunning {
 		return JobResponse{Success: false, Message: fmt.Sprintf("Cannot update schedule '%s' while it's currently running", req.JobID)}
@@ -341,6 +421,12 @@ func (ts *TemporalService) listSchedules() JobResponse {
 							jobStatus.CreatedAt = createdAt
 						}
 					}
+
+					// Extract run settings
+					var settings scheduleSettings
+					if err := json.Unmarshal([]byte(desc.Schedule.State.Note), &settings); err == nil {
+						jobStatus.scheduleSettings = settings
+					}
 				} else {
 					log.Printf("Failed to parse metadata from Note field for schedule %s: %v", schedule.ID, err)
 					// Fallback to spec values
@@ -441,7 +527,7 @@ func (ts *TemporalService) runNow(req JobRequest) JobResponse {
 	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
 	defer cancel()
 
-	we, err := ts.client.ExecuteWorkflow(ctx, workflowOptions, GooseJobWorkflow, req.JobID, job.RecipePath)
+	we, err := ts.client.ExecuteWorkflow(ctx, workflowOptions, GooseJobWorkflow, req.JobID, job.RecipePath, map[string]string(nil))
 	if err != nil {
 		return JobResponse{Success: false, Message: fmt.Sprintf("Failed to start workflow: %v", err)}
 	}
@@ -458,6 +544,48 @@ func (ts *TemporalService) runNow(req JobRequest) JobResponse {
 	}
 }
 
+// triggerJob starts a run of a job for an event, passing the event's values as recipe parameters
+func (ts *TemporalService) triggerJob(req JobRequest) JobResponse {
+	if req.JobID == "" {
+		return JobResponse{Success: false, Message: "Missing job_id"}
+	}
+
+	job, exists := ts.scheduleJobs[req.JobID]
+	if !exists {
+		return JobResponse{Success: false, Message: fmt.Sprintf("Job '%s' not found", req.JobID)}
+	}
+	if job.Paused {
+		log.Printf("Skipping triggered run of paused job %s", req.JobID)
+		return JobResponse{Success: true, Message: "Job is paused, run skipped"}
+	}
+	if job.CurrentlyRunning && (job.OverlapPolicy == "" || job.OverlapPolicy == "skip") {
+		log.Printf("Skipping triggered run of job %s, its previous run is still in progress", req.JobID)
+		return JobResponse{Success: true, Message: "Previous run is still in progress, run skipped"}
+	}
+
+	workflowOptions := client.StartWorkflowOptions{
+		ID:        fmt.Sprintf("trigger-%s-%d", req.JobID, time.Now().UnixNano()),
+		TaskQueue: TaskQueueName,
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
+	defer cancel()
+
+	we, err := ts.client.ExecuteWorkflow(ctx, workflowOptions, GooseJobWorkflow, req.JobID, job.RecipePath, req.Parameters)
+	if err != nil {
+		return JobResponse{Success: false, Message: fmt.Sprintf("Failed to start workflow: %v", err)}
+	}
+
+	ts.addRunningWorkflow(req.JobID, we.GetID())
+
+	log.Printf("Triggered execution started for job: %s, workflow: %s", req.JobID, we.GetID())
+	return JobResponse{
+		Success: true,
+		Message: "Triggered run started",
+		Data:    RunNowResponse{SessionID: we.GetID()},
+	}
+}
+
 // killJob kills a running job
 func (ts *TemporalService) killJob(req JobRequest) JobResponse {
 	if req.JobID == "" {
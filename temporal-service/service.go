@@ -151,6 +151,8 @@ func (ts *TemporalService) handleJobs(w http.ResponseWriter, r *http.Request) {
 		resp = ts.listSchedules()
 	case "run_now":
 		resp = ts.runNow(req)
+	case "trigger":
+		resp = ts.triggerJob(req)
 	case "kill_job":
 		resp = ts.killJob(req)
 	case "inspect_job":
@@ -224,8 +226,8 @@ func (ts *TemporalService) removeRunningWorkflow(jobID, workflowID string) {
 	}
 }
 
-// getEmbeddedRecipeContent retrieves embedded recipe content from schedule metadata
-func (ts *TemporalService) getEmbeddedRecipeContent(jobID string) (string, error) {
+// getScheduleMetadata retrieves the JSON metadata stored in the schedule's Note field
+func (ts *TemporalService) getScheduleMetadata(jobID string) (string, error) {
 	ctx, cancel := context.WithTimeout(context.Background(), 10*time.Second)
 	defer cancel()
 
@@ -240,9 +242,31 @@ func (ts *TemporalService) getEmbeddedRecipeContent(jobID string) (string, error
 	if desc.Schedule.State.Note == "" {
 		return "", fmt.Errorf("no metadata found in schedule")
 	}
+	return desc.Schedule.State.Note, nil
+}
+
+// getScheduleSettings retrieves the run settings from schedule metadata
+func (ts *TemporalService) getScheduleSettings(jobID string) (scheduleSettings, error) {
+	var settings scheduleSettings
+	note, err := ts.getScheduleMetadata(jobID)
+	if err != nil {
+		return settings, err
+	}
+	if err := json.Unmarshal([]byte(note), &settings); err != nil {
+		return settings, fmt.Errorf("failed to parse schedule metadata: %w", err)
+	}
+	return settings, nil
+}
+
+// getEmbeddedRecipeContent retrieves embedded recipe content from schedule metadata
+func (ts *TemporalService) getEmbeddedRecipeContent(jobID string) (string, error) {
+	note, err := ts.getScheduleMetadata(jobID)
+	if err != nil {
+		return "", err
+	}
 
 	var metadata map[string]interface{}
-	if err := json.Unmarshal([]byte(desc.Schedule.State.Note), &metadata); err != nil {
+	if err := json.Unmarshal([]byte(note), &metadata); err != nil {
 		return "", fmt.Errorf("failed to parse schedule metadata: %w", err)
 	}
 
@@ -17,6 +17,58 @@ export type Annotations = {
     priority?: number;
 };
 
+/**
+ * Verdict of an inspector, or the final decision taken for a tool call
+ */
+export type AuditAction = 'allow' | 'require_approval' | 'deny' | 'auto_approved' | 'user_approved' | 'user_denied' | 'blocked';
+
+/**
+ * A permission audit entry as recorded
+ */
+export type AuditEntry = {
+    action: AuditAction;
+    /**
+     * Inspector confidence between 0 and 1; absent for user decisions
+     */
+    confidence?: number | null;
+    /**
+     * Inspector that produced the verdict, or "user" for decisions made by the user
+     */
+    inspector: string;
+    kind: AuditKind;
+    /**
+     * Account the agent was acting for
+     */
+    principal: string;
+    reason: string;
+    sessionId?: string | null;
+    toolName: string;
+    toolRequestId: string;
+};
+
+/**
+ * Whether an audit entry is one inspector's verdict or the decision that was acted on
+ */
+export type AuditKind = 'inspection' | 'decision';
+
+export type AuditLogResponse = {
+    /**
+     * Audit entries, oldest first
+     */
+    records: Array<AuditRecord>;
+};
+
+/**
+ * An entry read back from the append-only permission audit log
+ */
+export type AuditRecord = AuditEntry & {
+    /**
+     * UTC time the entry was recorded (YYYY-MM-DD HH:MM:SS)
+     */
+    createdAt: string;
+    id: number;
+};
+
 export type Author = {
     contact?: string | null;
     metadata?: string | null;
@@ -27,6 +79,45 @@ export type AuthorRequest = {
     metadata?: string | null;
 };
 
+/**
+ * A period in which the schedule does not start runs, e.g. a deploy freeze
+ */
+export type BlackoutWindow = {
+    end: string;
+    reason?: string | null;
+    start: string;
+};
+
+/**
+ * Token and estimated spend caps for a session or a schedule
+ */
+export type BudgetLimits = {
+    /**
+     * Model to switch to when a soft limit is reached
+     */
+    downgrade_model?: string | null;
+    /**
+     * Provider for `downgrade_model`, defaults to the configured GOOSE_PROVIDER
+     */
+    downgrade_provider?: string | null;
+    /**
+     * End the turn once the estimated cost reaches this many USD
+     */
+    hard_cost_limit?: number | null;
+    /**
+     * End the turn once this many tokens have been used
+     */
+    hard_token_limit?: number | null;
+    /**
+     * Warn once the estimated cost reaches this many USD
+     */
+    soft_cost_limit?: number | null;
+    /**
+     * Warn once this many tokens have been used
+     */
+    soft_token_limit?: number | null;
+};
+
 /**
  * Configuration key metadata for provider setup
  */
@@ -103,8 +194,32 @@ export type ContextManageResponse = {
     tokenCounts: Array<number>;
 };
 
+/**
+ * How the conversation is reduced once it nears the context limit
+ */
+export type ContextStrategy = 'summarize' | 'collapse_tool_responses' | 'drop_superseded_reads' | 'rolling_summary';
+
 export type Conversation = Array<Message>;
 
+/**
+ * Provider usage aggregated over one day or week for a single model
+ */
+export type CostRollup = {
+    calls: number;
+    /**
+     * Estimated USD cost of the priced calls in this period
+     */
+    cost: number;
+    inputTokens: number;
+    model: string;
+    outputTokens: number;
+    /**
+     * First day of the period (YYYY-MM-DD); weeks start on Monday
+     */
+    periodStart: string;
+    provider: string;
+};
+
 export type CreateCustomProviderRequest = {
     api_key: string;
     api_url: string;
@@ -129,10 +244,26 @@ export type CreateRecipeResponse = {
 };
 
 export type CreateScheduleRequest = {
-    cron: string;
+    blackout_windows?: Array<BlackoutWindow> | null;
+    budget?: BudgetLimits | null;
+    /**
+     * Cron expression the job runs on; give either this or `trigger`
+     */
+    cron?: string | null;
+    debounce_seconds?: number | null;
     execution_mode?: string | null;
     id: string;
+    jitter_seconds?: number | null;
+    on_failure?: ScheduleHook | null;
+    on_success?: ScheduleHook | null;
+    overlap_policy?: OverlapPolicy | null;
+    parameters?: {
+        [key: string]: string;
+    } | null;
     recipe_source: string;
+    retry?: RetryPolicy | null;
+    timezone?: string | null;
+    trigger?: ScheduleTrigger | null;
 };
 
 export type DecodeRecipeRequest = {
@@ -319,6 +450,13 @@ export type ExtensionResponse = {
     extensions: Array<ExtensionEntry>;
 };
 
+export type ForkSessionRequest = {
+    /**
+     * Number of messages to copy from the parent session (defaults to all of them)
+     */
+    messageIndex?: number | null;
+};
+
 export type FrontendToolRequest = {
     id: string;
     toolCall: {
@@ -348,6 +486,11 @@ export type InspectJobResponse = {
     sessionId?: string | null;
 };
 
+/**
+ * How a recipe's instructions combine with those of the recipe it extends
+ */
+export type InstructionsMerge = 'concatenate' | 'override';
+
 export type JsonObject = {
     [key: string]: unknown;
 };
@@ -414,6 +557,84 @@ export type MessageMetadata = {
     userVisible?: boolean;
 };
 
+/**
+ * A single message matching a search query
+ */
+export type MessageSearchHit = {
+    /**
+     * Unix timestamp of the message
+     */
+    created: number;
+    /**
+     * Position of the message within its session's conversation
+     */
+    message_index: number;
+    role: string;
+    session_description: string;
+    session_id: string;
+    /**
+     * Excerpt around the match with matched terms wrapped in `<<` and `>>`
+     */
+    snippet: string;
+};
+
+/**
+ * Token, cost and tool usage aggregated over the sessions in one group
+ */
+export type MetricsGroup = {
+    /**
+     * Tool calls per operation type (file_create, command_execute, ...)
+     */
+    callsByOperation: {
+        [key: string]: number;
+    };
+    /**
+     * Estimated USD cost of the priced calls in this group
+     */
+    cost: number;
+    /**
+     * Fraction of tool calls that failed, between 0 and 1
+     */
+    errorRate: number;
+    failedToolCalls: number;
+    /**
+     * Distinct files created by tool calls
+     */
+    filesCreated: number;
+    /**
+     * Distinct files modified by tool calls
+     */
+    filesModified: number;
+    inputTokens: number;
+    /**
+     * Day (YYYY-MM-DD), model name, recipe title or working directory
+     */
+    key: string;
+    outputTokens: number;
+    sessions: number;
+    toolCalls: number;
+    totalTokens: number;
+};
+
+/**
+ * Dimension that cross-session metrics are grouped by
+ */
+export type MetricsGroupBy = 'day' | 'model' | 'recipe' | 'working_dir';
+
+/**
+ * Token usage and estimated cost attributed to one model within a session
+ */
+export type ModelCost = {
+    calls: number;
+    /**
+     * Estimated USD cost, absent when no pricing is known for the model
+     */
+    cost?: number | null;
+    input_tokens: number;
+    output_tokens: number;
+    provider: string;
+};
+
 /**
  * Information about a model's capabilities
  */
@@ -444,6 +665,19 @@ export type ModelInfo = {
     supports_cache_control?: boolean | null;
 };
 
+/**
+ * What to do when a schedule fires while its previous run is still going
+ */
+export type OverlapPolicy = 'skip' | 'queue' | 'kill_previous';
+
+/**
+ * A parameter value that does not satisfy its parameter's type or constraints.
+ */
+export type ParameterValueError = {
+    key: string;
+    message: string;
+};
+
 export type PermissionConfirmationRequest = {
     action: string;
     id: string;
@@ -554,6 +788,8 @@ export type RawTextContent = {
  * * `parameters` - Additional parameters for the Recipe
  * * `response` - Response configuration including JSON schema validation
  * * `retry` - Retry configuration for automated validation and recovery
+ * * `extends` - Path or name of a recipe this Recipe inherits from
+ * * `instructions_merge` - Whether instructions are concatenated with or override the extended Recipe's
  * # Example
  *
  *
@@ -583,6 +819,8 @@ export type RawTextContent = {
  * response: None,
  * sub_recipes: None,
  * retry: None,
+ * extends: None,
+ * instructions_merge: None,
  * };
  *
  */
@@ -591,8 +829,10 @@ export type Recipe = {
     author?: Author | null;
     context?: Array<string> | null;
     description: string;
+    extends?: string | null;
     extensions?: Array<ExtensionConfig> | null;
     instructions?: string | null;
+    instructions_merge?: InstructionsMerge | null;
     parameters?: Array<RecipeParameter> | null;
     prompt?: string | null;
     response?: Response | null;
@@ -616,11 +856,31 @@ export type RecipeParameter = {
     description: string;
     input_type: RecipeParameterInputType;
     key: string;
+    /**
+     * Largest allowed value of a number parameter
+     */
+    max?: number | null;
+    /**
+     * Maximum length of a string value, or maximum number of items of a list, map or multi-select
+     */
+    max_length?: number | null;
+    /**
+     * Smallest allowed value of a number parameter
+     */
+    min?: number | null;
+    /**
+     * Minimum length of a string value, or minimum number of items of a list, map or multi-select
+     */
+    min_length?: number | null;
     options?: Array<string> | null;
+    /**
+     * Regular expression that string values, or every item of a list, must match
+     */
+    pattern?: string | null;
     requirement: RecipeParameterRequirement;
 };
 
-export type RecipeParameterInputType = 'string' | 'number' | 'boolean' | 'date' | 'file' | 'select';
+export type RecipeParameterInputType = 'string' | 'number' | 'boolean' | 'date' | 'file' | 'select' | 'multi_select' | 'list' | 'map' | 'secret';
 
 export type RecipeParameterRequirement = 'required' | 'optional' | 'user_prompt';
 
@@ -678,12 +938,26 @@ export type RetryConfig = {
     timeout_seconds?: number | null;
 };
 
+/**
+ * Retry a failed run, waiting `backoff_seconds` before the first retry and twice as long
+ * before each one after it, up to `max_backoff_seconds`
+ */
+export type RetryPolicy = {
+    backoff_seconds?: number;
+    max_backoff_seconds?: number;
+    max_retries: number;
+};
+
 export type Role = string;
 
 export type RunNowResponse = {
     session_id: string;
 };
 
+export type RunsQuery = {
+    limit?: number;
+};
+
 export type ScanRecipeRequest = {
     recipe: Recipe;
 };
@@ -692,19 +966,125 @@ export type ScanRecipeResponse = {
     has_security_warnings: boolean;
 };
 
+/**
+ * Alert fired when a scheduled job finishes, after any retries
+ */
+export type ScheduleHook = {
+    command: string;
+    type: 'command';
+} | {
+    type: 'webhook';
+    url: string;
+};
+
+/**
+ * What starts the runs of a scheduled job
+ */
+export type ScheduleTrigger = {
+    expression: string;
+    type: 'cron';
+} | {
+    path: string;
+    type: 'file_change';
+} | {
+    ref: string;
+    repo: string;
+    type: 'git_ref';
+} | {
+    type: 'webhook';
+};
+
 export type ScheduledJob = {
-    cron: string;
+    /**
+     * Periods in which the schedule does not start runs
+     */
+    blackout_windows?: Array<BlackoutWindow> | null;
+    budget?: BudgetLimits | null;
     current_session_id?: string | null;
     currently_running?: boolean;
+    /**
+     * File and git triggers start a run once their source has been quiet for this many
+     * seconds; 2 by default
+     */
+    debounce_seconds?: number | null;
     execution_mode?: string | null;
     id: string;
+    /**
+     * Delay every scheduled run by a random amount of up to this many seconds
+     */
+    jitter_seconds?: number | null;
     last_run?: string | null;
+    /**
+     * Fired once a run has failed and has no retries left
+     */
+    on_failure?: ScheduleHook | null;
+    /**
+     * Fired once a run succeeds
+     */
+    on_success?: ScheduleHook | null;
+    /**
+     * What to do when the schedule fires while its previous run is still going; skips by default
+     */
+    overlap_policy?: OverlapPolicy | null;
+    /**
+     * Values for the recipe's parameters, so that one recipe can serve several schedules
+     */
+    parameters?: {
+        [key: string]: string;
+    } | null;
     paused?: boolean;
     process_start_time?: string | null;
+    /**
+     * Directory the recipe was scheduled from. The stored copy of the recipe resolves the
+     * recipes it extends, its sub-recipes and `recipe_dir` against it, as the original did.
+     */
+    recipe_dir?: string | null;
+    /**
+     * Retry failed runs with backoff before giving up
+     */
+    retry?: RetryPolicy | null;
     source: string;
+    /**
+     * IANA timezone the cron expression is evaluated in; UTC by default
+     */
+    timezone?: string | null;
+    /**
+     * What starts the job's runs; schedules stored before triggers existed have a plain
+     * `cron` expression instead
+     */
+    trigger: ScheduleTrigger;
 };
 
+/**
+ * One attempt at executing a scheduled job
+ */
+export type ScheduledJobRun = {
+    /**
+     * Attempt number within one execution, starting at 1; retries count up from there
+     */
+    attempt: number;
+    /**
+     * Whether the recipe's retry success checks passed; absent when the recipe has none
+     */
+    checks_passed?: boolean | null;
+    ended_at: string;
+    error?: string | null;
+    input_tokens?: number | null;
+    output_tokens?: number | null;
+    schedule_id: string;
+    session_id?: string | null;
+    started_at: string;
+    status: ScheduledRunStatus;
+    total_tokens?: number | null;
+};
+
+export type ScheduledRunStatus = 'succeeded' | 'failed' | 'cancelled' | 'skipped';
+
 export type Session = {
+    /**
+     * Estimated USD cost of all priced provider calls in this session
+     */
+    accumulated_cost?: number | null;
     accumulated_input_tokens?: number | null;
     accumulated_output_tokens?: number | null;
     accumulated_total_tokens?: number | null;
@@ -712,10 +1092,24 @@ export type Session = {
     created_at: string;
     description: string;
     extension_data: ExtensionData;
+    /**
+     * Number of parent messages copied into this session when it was forked
+     */
+    fork_message_index?: number | null;
     id: string;
     input_tokens?: number | null;
     message_count: number;
+    /**
+     * Usage and cost broken down by model name
+     */
+    model_costs?: {
+        [key: string]: ModelCost;
+    };
     output_tokens?: number | null;
+    /**
+     * Session this one was forked from, if any
+     */
+    parent_session_id?: string | null;
     recipe?: Recipe | null;
     schedule_id?: string | null;
     total_tokens?: number | null;
@@ -744,6 +1138,14 @@ export type SessionDisplayInfo = {
 };
 
 export type SessionInsights = {
+    /**
+     * Cost per day and model over the last 30 days
+     */
+    dailyCosts: Array<CostRollup>;
+    /**
+     * Estimated USD cost across all sessions
+     */
+    totalCost: number;
     /**
      * Total number of sessions
      */
@@ -752,6 +1154,10 @@ export type SessionInsights = {
      * Total tokens used across all sessions
      */
     totalTokens: number;
+    /**
+     * Cost per week and model over the last 12 weeks
+     */
+    weeklyCosts: Array<CostRollup>;
 };
 
 export type SessionListResponse = {
@@ -761,11 +1167,27 @@ export type SessionListResponse = {
     sessions: Array<Session>;
 };
 
+export type SessionMetricsResponse = {
+    /**
+     * Aggregated metrics, newest day first or most tokens first
+     */
+    groups: Array<MetricsGroup>;
+};
+
+export type SessionSearchResponse = {
+    /**
+     * Matching messages, best match first
+     */
+    results: Array<MessageSearchHit>;
+};
+
 export type SessionsQuery = {
     limit?: number;
 };
 
 export type Settings = {
+    budget?: BudgetLimits | null;
+    context_strategy?: ContextStrategy | null;
     goose_model?: string | null;
     goose_provider?: string | null;
     temperature?: number | null;
@@ -782,6 +1204,7 @@ export type StartAgentRequest = {
 };
 
 export type SubRecipe = {
+    depends_on?: Array<string> | null;
     description?: string | null;
     name: string;
     path: string;
@@ -792,7 +1215,7 @@ export type SubRecipe = {
 };
 
 /**
- * Execute a shell command and check its exit status
+ * A single success check to validate recipe completion
  */
 export type SuccessCheck = {
     /**
@@ -800,6 +1223,64 @@ export type SuccessCheck = {
      */
     command: string;
     type: 'Shell';
+} | {
+    /**
+     * Path to the file, relative to the session working directory
+     */
+    path: string;
+    type: 'FileExists';
+} | {
+    /**
+     * Path to the file, relative to the session working directory
+     */
+    path: string;
+    /**
+     * Regular expression the contents must match
+     */
+    pattern: string;
+    type: 'FileContains';
+} | {
+    /**
+     * File holding the JSON to validate; the recipe's final output is used when omitted
+     */
+    path?: string | null;
+    /**
+     * The JSON schema to validate against
+     */
+    schema: unknown;
+    type: 'JsonSchema';
+} | {
+    /**
+     * Regular expression the response body must match
+     */
+    body?: string | null;
+    /**
+     * Expected response status (default: 200)
+     */
+    status?: number;
+    type: 'Http';
+    /**
+     * URL to request with GET; must point at localhost
+     */
+    url: string;
+} | {
+    /**
+     * What a successful outcome looks like
+     */
+    rubric: string;
+    type: 'LlmJudge';
+} | {
+    /**
+     * The nested checks
+     */
+    checks: Array<SuccessCheck>;
+    type: 'AllOf';
+} | {
+    /**
+     * The nested checks
+     */
+    checks: Array<SuccessCheck>;
+    type: 'AnyOf';
 };
 
 export type SummarizationRequested = {
@@ -910,6 +1391,17 @@ export type UpsertPermissionsQuery = {
     tool_permissions: Array<ToolPermission>;
 };
 
+export type ValidateRecipeParametersRequest = {
+    parameters: Array<RecipeParameter>;
+    values: {
+        [key: string]: string;
+    };
+};
+
+export type ValidateRecipeParametersResponse = {
+    errors: Array<ParameterValueError>;
+};
+
 export type AddSubRecipesData = {
     body: AddSubRecipesRequest;
     path?: never;
@@ -1161,6 +1653,46 @@ export type UpdateRouterToolSelectorResponses = {
 
 export type UpdateRouterToolSelectorResponse = UpdateRouterToolSelectorResponses[keyof UpdateRouterToolSelectorResponses];
 
+export type GetAuditLogData = {
+    body?: never;
+    path?: never;
+    query?: {
+        /**
+         * Only include entries recorded at or after this time (RFC 3339)
+         */
+        since?: string | null;
+        /**
+         * Only include entries for this session
+         */
+        sessionId?: string | null;
+        /**
+         * Maximum number of entries to return (default 1000)
+         */
+        limit?: number | null;
+    };
+    url: '/audit';
+};
+
+export type GetAuditLogErrors = {
+    /**
+     * Unauthorized - Invalid or missing API key
+     */
+    401: unknown;
+    /**
+     * Internal server error
+     */
+    500: unknown;
+};
+
+export type GetAuditLogResponses = {
+    /**
+     * Permission audit log retrieved successfully
+     */
+    200: AuditLogResponse;
+};
+
+export type GetAuditLogResponse = GetAuditLogResponses[keyof GetAuditLogResponses];
+
 export type ReadAllConfigData = {
     body?: never;
     path?: never;
@@ -1771,6 +2303,22 @@ export type ListRecipesResponses = {
 
 export type ListRecipesResponse = ListRecipesResponses[keyof ListRecipesResponses];
 
+export type ValidateRecipeParametersData = {
+    body: ValidateRecipeParametersRequest;
+    path?: never;
+    query?: never;
+    url: '/recipes/parameters/validate';
+};
+
+export type ValidateRecipeParametersResponses = {
+    /**
+     * Parameter values validated successfully
+     */
+    200: ValidateRecipeParametersResponse;
+};
+
+export type ValidateRecipeParametersResponse2 = ValidateRecipeParametersResponses[keyof ValidateRecipeParametersResponses];
+
 export type ScanRecipeData = {
     body: ScanRecipeRequest;
     path?: never;
@@ -1796,7 +2344,7 @@ export type CreateScheduleData = {
 
 export type CreateScheduleErrors = {
     /**
-     * Invalid cron expression or recipe file
+     * Invalid cron expression, trigger, recipe file, parameters, hook, timezone or blackout window
      */
     400: unknown;
     /**
@@ -2025,6 +2573,44 @@ export type RunNowHandlerResponses = {
 
 export type RunNowHandlerResponse = RunNowHandlerResponses[keyof RunNowHandlerResponses];
 
+export type RunsHandlerData = {
+    body?: never;
+    path: {
+        /**
+         * ID of the schedule
+         */
+        id: string;
+    };
+    query?: {
+        limit?: number;
+    };
+    url: '/schedule/{id}/runs';
+};
+
+export type RunsHandlerErrors = {
+    /**
+     * Scheduled job not found
+     */
+    404: unknown;
+    /**
+     * Internal server error
+     */
+    500: unknown;
+    /**
+     * The configured scheduler does not record runs
+     */
+    501: unknown;
+};
+
+export type RunsHandlerResponses = {
+    /**
+     * Recorded runs of the schedule, newest first
+     */
+    200: Array<ScheduledJobRun>;
+};
+
+export type RunsHandlerResponse = RunsHandlerResponses[keyof RunsHandlerResponses];
+
 export type SessionsHandlerData = {
     body?: never;
     path: {
@@ -2087,6 +2673,45 @@ export type UnpauseScheduleResponses = {
 
 export type UnpauseScheduleResponse = UnpauseScheduleResponses[keyof UnpauseScheduleResponses];
 
+export type WebhookHandlerData = {
+    /**
+     * Values passed to the recipe as parameters
+     */
+    body: {
+        [key: string]: unknown;
+    };
+    path: {
+        /**
+         * ID of the webhook-triggered schedule
+         */
+        id: string;
+    };
+    query?: never;
+    url: '/schedule/{id}/webhook';
+};
+
+export type WebhookHandlerErrors = {
+    /**
+     * Schedule is not triggered by webhooks or payload is not a JSON object
+     */
+    400: unknown;
+    /**
+     * Scheduled job not found
+     */
+    404: unknown;
+    /**
+     * Internal server error
+     */
+    500: unknown;
+};
+
+export type WebhookHandlerResponses = {
+    /**
+     * Run of the scheduled job started
+     */
+    202: unknown;
+};
+
 export type ListSessionsData = {
     body?: never;
     path?: never;
@@ -2141,6 +2766,94 @@ export type GetSessionInsightsResponses = {
 
 export type GetSessionInsightsResponse = GetSessionInsightsResponses[keyof GetSessionInsightsResponses];
 
+export type GetSessionMetricsData = {
+    body?: never;
+    path?: never;
+    query: {
+        /**
+         * Dimension to group sessions by
+         */
+        groupBy: MetricsGroupBy;
+        /**
+         * Only include sessions created at or after this time (RFC 3339), all sessions if omitted
+         */
+        since?: string | null;
+    };
+    url: '/sessions/metrics';
+};
+
+export type GetSessionMetricsErrors = {
+    /**
+     * Unauthorized - Invalid or missing API key
+     */
+    401: unknown;
+    /**
+     * Internal server error
+     */
+    500: unknown;
+};
+
+export type GetSessionMetricsResponses = {
+    /**
+     * Metrics aggregated successfully
+     */
+    200: SessionMetricsResponse;
+};
+
+export type GetSessionMetricsResponse = GetSessionMetricsResponses[keyof GetSessionMetricsResponses];
+
+export type SearchSessionsData = {
+    body?: never;
+    path?: never;
+    query: {
+        /**
+         * Text to search for in message content, tool arguments and tool results
+         */
+        query: string;
+        /**
+         * Only match sessions whose working directory contains this string
+         */
+        workingDir?: string | null;
+        /**
+         * Only match messages created at or after this time (RFC 3339)
+         */
+        since?: string | null;
+        /**
+         * Only match messages created at or before this time (RFC 3339)
+         */
+        until?: string | null;
+        /**
+         * Only match sessions started from a recipe with this title
+         */
+        recipe?: string | null;
+        /**
+         * Maximum number of results to return
+         */
+        limit?: number | null;
+    };
+    url: '/sessions/search';
+};
+
+export type SearchSessionsErrors = {
+    /**
+     * Unauthorized - Invalid or missing API key
+     */
+    401: unknown;
+    /**
+     * Internal server error
+     */
+    500: unknown;
+};
+
+export type SearchSessionsResponses = {
+    /**
+     * Search completed successfully
+     */
+    200: SessionSearchResponse;
+};
+
+export type SearchSessionsResponse = SearchSessionsResponses[keyof SearchSessionsResponses];
+
 export type DeleteSessionData = {
     body?: never;
     path: {
@@ -2249,6 +2962,46 @@ export type UpdateSessionDescriptionResponses = {
     200: unknown;
 };
 
+export type ForkSessionData = {
+    body: ForkSessionRequest;
+    path: {
+        /**
+         * Unique identifier for the parent session
+         */
+        session_id: string;
+    };
+    query?: never;
+    url: '/sessions/{session_id}/fork';
+};
+
+export type ForkSessionErrors = {
+    /**
+     * Bad request - Message index out of range
+     */
+    400: unknown;
+    /**
+     * Unauthorized - Invalid or missing API key
+     */
+    401: unknown;
+    /**
+     * Session not found
+     */
+    404: unknown;
+    /**
+     * Internal server error
+     */
+    500: unknown;
+};
+
+export type ForkSessionResponses = {
+    /**
+     * Session forked successfully
+     */
+    200: Session;
+};
+
+export type ForkSessionResponse = ForkSessionResponses[keyof ForkSessionResponses];
+
 export type StatusData = {
     body?: never;
     path?: never;
@@ -1,6 +1,7 @@
 import React, { useState, useEffect } from 'react';
 import { Parameter } from '../recipe';
 import { Button } from './ui/button';
+import { validateRecipeParameters } from '../api';
 
 interface ParameterInputModalProps {
   parameters: Parameter[];
@@ -34,7 +35,7 @@ const ParameterInputModal: React.FC<ParameterInputModalProps> = ({
     setInputValues((prevValues: Record<string, string>) => ({ ...prevValues, [name]: value }));
   };
 
-  const handleSubmit = (): void => {
+  const handleSubmit = async (): Promise<void> => {
     // Clear previous validation errors
     setValidationErrors({});
 
@@ -54,6 +55,23 @@ const ParameterInputModal: React.FC<ParameterInputModalProps> = ({
       return;
     }
 
+    // Check the values against the parameter types and constraints on the server
+    try {
+      const response = await validateRecipeParameters({
+        body: { parameters, values: inputValues },
+      });
+      response.data?.errors.forEach((error) => {
+        errors[error.key] = error.message;
+      });
+    } catch (err) {
+      console.error('Error validating recipe parameters:', err);
+    }
+
+    if (Object.keys(errors).length > 0) {
+      setValidationErrors(errors);
+      return;
+    }
+
     onSubmit(inputValues);
   };
 
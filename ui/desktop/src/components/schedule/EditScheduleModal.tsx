@@ -3,7 +3,7 @@ import { Card } from '../ui/card';
 import { Button } from '../ui/button';
 import { Input } from '../ui/input';
 import { Select } from '../ui/Select';
-import { ScheduledJob } from '../../schedule';
+import { ScheduledJob, scheduleCron } from '../../schedule';
 import cronstrue from 'cronstrue';
 
 type FrequencyValue = 'once' | 'every' | 'daily' | 'weekly' | 'monthly';
@@ -168,7 +168,8 @@ export const EditScheduleModal: React.FC<EditScheduleModalProps> = ({
   // Initialize form from existing schedule
   useEffect(() => {
     if (schedule && isOpen) {
-      const parsed = parseCronExpression(schedule.cron);
+      const cron = scheduleCron(schedule);
+      const parsed = cron === null ? null : parseCronExpression(cron);
 
       if (parsed) {
         setFrequency(parsed.frequency);
@@ -13,6 +13,8 @@ import {
   killRunningJob,
   inspectRunningJob,
   ScheduledJob,
+  scheduleCron,
+  describeTrigger,
 } from '../../schedule';
 import SessionHistoryView from '../sessions/SessionHistoryView';
 import { EditScheduleModal } from './EditScheduleModal';
@@ -46,14 +48,18 @@ interface ScheduleDetailViewProps {
 const ScheduleInfoCard = React.memo<{
   scheduleDetails: ScheduledJob;
 }>(({ scheduleDetails }) => {
+  const cron = scheduleCron(scheduleDetails);
   const readableCron = useMemo(() => {
+    if (cron === null) {
+      return describeTrigger(scheduleDetails.trigger);
+    }
     try {
-      return cronstrue.toString(scheduleDetails.cron);
+      return cronstrue.toString(cron);
     } catch (e) {
-      console.warn(`Could not parse cron string "${scheduleDetails.cron}":`, e);
-      return scheduleDetails.cron;
+      console.warn(`Could not parse cron string "${cron}":`, e);
+      return cron;
     }
-  }, [scheduleDetails.cron]);
+  }, [cron, scheduleDetails.trigger]);
 
   const formattedLastRun = useMemo(() => {
     return formatToLocalDateWithTimezone(scheduleDetails.last_run);
@@ -88,9 +94,11 @@ const ScheduleInfoCard = React.memo<{
         <p className="text-sm text-text-default">
           <span className="font-semibold">Schedule:</span> {readableCron}
         </p>
-        <p className="text-sm text-text-default">
-          <span className="font-semibold">Cron Expression:</span> {scheduleDetails.cron}
-        </p>
+        {cron !== null && (
+          <p className="text-sm text-text-default">
+            <span className="font-semibold">Cron Expression:</span> {cron}
+          </p>
+        )}
         <p className="text-sm text-text-default">
           <span className="font-semibold">Recipe Source:</span> {scheduleDetails.source}
         </p>
@@ -524,7 +532,12 @@ const ScheduleDetailView: React.FC<ScheduleDetailViewProps> = ({ scheduleId, onN
                     onClick={handleOpenEditModal}
                     variant="outline"
                     className="w-full md:w-auto flex items-center gap-2 text-blue-600 dark:text-blue-400 border-blue-300 dark:border-blue-600 hover:bg-blue-50 dark:hover:bg-blue-900/20"
-                    disabled={runNowLoading || pauseUnpauseLoading || isEditSubmitting}
+                    disabled={
+                      runNowLoading ||
+                      pauseUnpauseLoading ||
+                      isEditSubmitting ||
+                      scheduleCron(scheduleDetails) === null
+                    }
                   >
                     <Edit className="w-4 h-4" />
                     Edit Schedule
@@ -9,6 +9,8 @@ import {
   killRunningJob,
   inspectRunningJob,
   ScheduledJob,
+  scheduleCron,
+  describeTrigger,
 } from '../../schedule';
 import { ScrollArea } from '../ui/scroll-area';
 import { Card } from '../ui/card';
@@ -58,14 +60,18 @@ const ScheduleCard = React.memo<{
     isInspecting,
     isSubmitting,
   }) => {
+    const cron = scheduleCron(job);
     const readableCron = useMemo(() => {
+      if (cron === null) {
+        return describeTrigger(job.trigger);
+      }
       try {
-        return cronstrue.toString(job.cron);
+        return cronstrue.toString(cron);
       } catch (e) {
-        console.warn(`Could not parse cron string "${job.cron}":`, e);
-        return job.cron;
+        console.warn(`Could not parse cron string "${cron}":`, e);
+        return cron;
       }
-    }, [job.cron]);
+    }, [cron, job.trigger]);
 
     const formattedLastRun = useMemo(() => {
       return formatToLocalDateWithTimezone(job.last_run);
@@ -122,7 +128,7 @@ const ScheduleCard = React.memo<{
                     e.stopPropagation();
                     onEdit(job);
                   }}
-                  disabled={isPausing || isDeleting || isSubmitting}
+                  disabled={isPausing || isDeleting || isSubmitting || cron === null}
                   variant="outline"
                   size="sm"
                   className="h-8"
@@ -10,11 +10,12 @@ import {
   killRunningJob as apiKillRunningJob,
   inspectRunningJob as apiInspectRunningJob,
 } from './api';
+import type { ScheduleTrigger } from './api';
 
 export interface ScheduledJob {
   id: string;
   source: string;
-  cron: string;
+  trigger: ScheduleTrigger;
   last_run?: string | null;
   currently_running?: boolean;
   paused?: boolean;
@@ -23,6 +24,24 @@ export interface ScheduledJob {
   execution_mode?: string | null; // "foreground" or "background"
 }
 
+// The cron expression of a cron-triggered job, or null for file, git and webhook triggers
+export function scheduleCron(job: ScheduledJob): string | null {
+  return job.trigger.type === 'cron' ? job.trigger.expression : null;
+}
+
+export function describeTrigger(trigger: ScheduleTrigger): string {
+  switch (trigger.type) {
+    case 'cron':
+      return trigger.expression;
+    case 'file_change':
+      return `When files under ${trigger.path} change`;
+    case 'git_ref':
+      return `When ${trigger.ref} in ${trigger.repo} gets new commits`;
+    case 'webhook':
+      return 'When its webhook is called';
+  }
+}
+
 export interface ScheduleSession {
   id: string;
   name: string;